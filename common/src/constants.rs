@@ -3,6 +3,9 @@ const RISCV_REGISTER_COUNT: u64 = 32;
 const VIRTUAL_REGISTER_COUNT: u64 = 32; //  see Section 6.1 of Jolt paper
 pub const REGISTER_COUNT: u64 = RISCV_REGISTER_COUNT + VIRTUAL_REGISTER_COUNT;
 pub const BYTES_PER_INSTRUCTION: usize = 4;
+/// Compressed (RVC) instructions are 2 bytes long, so instruction addresses are only
+/// aligned to 2 bytes.
+pub const INSTRUCTION_ALIGNMENT: usize = 2;
 /// 3 registers (rd, rs1, rs2) + 1 RAM
 pub const MEMORY_OPS_PER_INSTRUCTION: usize = 4;
 
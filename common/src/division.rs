@@ -0,0 +1,151 @@
+//! Quotient and remainder of the division-family instructions (DIV, DIVU, REM, REMU), as
+//! computed by their virtual sequences. Operands and results are `word_size`-bit words
+//! held in `u64`s; only `word_size` of 32 and 64 are supported.
+
+fn mask(word_size: usize) -> u64 {
+    match word_size {
+        32 => u32::MAX as u64,
+        64 => u64::MAX,
+        _ => panic!("Unsupported WORD_SIZE: {}", word_size),
+    }
+}
+
+/// Signed division of `x` by `y`, returning `(quotient, remainder)` with
+/// `x = quotient * y + remainder` modulo `2^word_size`.
+///
+/// A nonzero remainder has the sign of the divisor (the quotient is rounded towards negative
+/// infinity), which is the convention `VIRTUAL_ASSERT_VALID_SIGNED_REMAINDER` checks. As in
+/// RISC-V, dividing by zero gives a quotient of all ones and a remainder of `x`, and the
+/// overflowing division of the most negative value by -1 gives that value and a remainder of 0.
+pub fn signed_divrem(x: u64, y: u64, word_size: usize) -> (u64, u64) {
+    let mask = mask(word_size);
+    if y & mask == 0 {
+        return (mask, x & mask);
+    }
+    // Sign-extend both operands from `word_size` bits
+    let shift = 64 - word_size;
+    let x = ((x << shift) as i64) >> shift;
+    let y = ((y << shift) as i64) >> shift;
+    let (mut quotient, mut remainder) = if word_size == 32 {
+        (
+            (x as i32).wrapping_div(y as i32) as i64,
+            (x as i32).wrapping_rem(y as i32) as i64,
+        )
+    } else {
+        (x.wrapping_div(y), x.wrapping_rem(y))
+    };
+    if (remainder < 0 && y > 0) || (remainder > 0 && y < 0) {
+        remainder += y;
+        quotient -= 1;
+    }
+    (quotient as u64 & mask, remainder as u64 & mask)
+}
+
+/// Unsigned division of `x` by `y`, returning `(quotient, remainder)`. As in RISC-V,
+/// dividing by zero gives a quotient of all ones and a remainder of `x`.
+pub fn unsigned_divrem(x: u64, y: u64, word_size: usize) -> (u64, u64) {
+    let mask = mask(word_size);
+    let (x, y) = (x & mask, y & mask);
+    if y == 0 {
+        (mask, x)
+    } else {
+        (x / y, x % y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_signed(word_size: usize, x: i64, y: i64, expected: (i64, i64)) {
+        let mask = mask(word_size);
+        assert_eq!(
+            signed_divrem(x as u64 & mask, y as u64 & mask, word_size),
+            (expected.0 as u64 & mask, expected.1 as u64 & mask),
+            "{x} / {y} over {word_size} bits"
+        );
+    }
+
+    #[test]
+    fn signed_divrem_edge_cases() {
+        for (word_size, min, max) in [
+            (32, i32::MIN as i64, i32::MAX as i64),
+            (64, i64::MIN, i64::MAX),
+        ] {
+            // Same signs round towards zero
+            check_signed(word_size, 7, 2, (3, 1));
+            check_signed(word_size, -7, -2, (3, -1));
+            // Mixed signs round towards negative infinity
+            check_signed(word_size, -7, 2, (-4, 1));
+            check_signed(word_size, 7, -2, (-4, -1));
+            // Exact divisions
+            check_signed(word_size, -6, 2, (-3, 0));
+            check_signed(word_size, 6, -3, (-2, 0));
+            check_signed(word_size, 0, -5, (0, 0));
+            // Division by zero
+            check_signed(word_size, 0, 0, (-1, 0));
+            check_signed(word_size, 7, 0, (-1, 7));
+            check_signed(word_size, -7, 0, (-1, -7));
+            check_signed(word_size, min, 0, (-1, min));
+            // Overflow
+            check_signed(word_size, min, -1, (min, 0));
+            check_signed(word_size, min, 1, (min, 0));
+            check_signed(word_size, max, -1, (-max, 0));
+            check_signed(word_size, min, max, (-2, max - 1));
+            check_signed(word_size, max, min, (-1, -1));
+            check_signed(word_size, min, min, (1, 0));
+        }
+    }
+
+    #[test]
+    fn signed_divrem_invariants() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for word_size in [32, 64] {
+            let shift = 64 - word_size;
+            for _ in 0..1000 {
+                // Divisors of all sizes and both signs
+                let small = (next() % word_size as u64) as usize;
+                let y = ((next() as i64) >> (shift + small)) as u64 & mask(word_size);
+                let x = next() >> shift;
+                let (q, r) = signed_divrem(x, y, word_size);
+                let extend = |v: u64| (((v << shift) as i64) >> shift) as i128;
+                let (x, y, q, r) = (extend(x), extend(y), extend(q), extend(r));
+                if y == 0 {
+                    continue;
+                }
+                assert!(r == 0 || (r < 0) == (y < 0));
+                assert!(r.abs() < y.abs());
+                // q * y only overflows for the most negative value divided by -1
+                assert_eq!((q * y + r - x) % (1i128 << word_size), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn unsigned_divrem_edge_cases() {
+        for word_size in [32, 64] {
+            let max = mask(word_size);
+            assert_eq!(unsigned_divrem(7, 2, word_size), (3, 1));
+            assert_eq!(unsigned_divrem(0, 5, word_size), (0, 0));
+            assert_eq!(unsigned_divrem(7, 0, word_size), (max, 7));
+            assert_eq!(unsigned_divrem(0, 0, word_size), (max, 0));
+            assert_eq!(unsigned_divrem(max, 0, word_size), (max, max));
+            assert_eq!(unsigned_divrem(max, 1, word_size), (max, 0));
+            assert_eq!(unsigned_divrem(max, max, word_size), (1, 0));
+            assert_eq!(unsigned_divrem(max - 1, max, word_size), (0, max - 1));
+            // The top bit is a magnitude bit, not a sign
+            assert_eq!(
+                unsigned_divrem(1 << (word_size - 1), 3, word_size),
+                ((1 << (word_size - 1)) / 3, (1 << (word_size - 1)) % 3)
+            );
+        }
+        // 32-bit operands are truncated to their low word
+        assert_eq!(unsigned_divrem((1 << 32) | 9, (1 << 32) | 2, 32), (4, 1));
+    }
+}
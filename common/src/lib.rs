@@ -1,3 +1,4 @@
 pub mod attributes;
 pub mod constants;
+pub mod division;
 pub mod rv_trace;
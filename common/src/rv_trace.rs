@@ -16,6 +16,41 @@ pub struct RVTraceRow {
     pub precompile_output_address: Option<u64>,
 }
 
+/// Contents of the full register file, i.e. the RISC-V registers followed by the
+/// virtual registers.
+pub type RegisterFile = [u64; REGISTER_COUNT as usize];
+
+impl RVTraceRow {
+    /// Applies this row's write to `rd` (if any) to `registers`.
+    pub fn apply_register_write(&self, registers: &mut RegisterFile) {
+        if let (Some(rd), Some(value)) = (self.instruction.rd, self.register_state.rd_post_val) {
+            registers[rd as usize] = value;
+        }
+    }
+}
+
+/// Replays the register writes in `trace` starting from `initial`, returning a
+/// snapshot of the register file after each row.
+pub fn register_snapshots(initial: &RegisterFile, trace: &[RVTraceRow]) -> Vec<RegisterFile> {
+    let mut registers = *initial;
+    trace
+        .iter()
+        .map(|row| {
+            row.apply_register_write(&mut registers);
+            registers
+        })
+        .collect()
+}
+
+/// Returns the index of the first row at which two sequences of register-file
+/// snapshots disagree (or at which the shorter one ends), or `None` if they match.
+pub fn first_register_divergence(a: &[RegisterFile], b: &[RegisterFile]) -> Option<usize> {
+    a.iter()
+        .zip(b.iter())
+        .position(|(a, b)| a != b)
+        .or_else(|| (a.len() != b.len()).then(|| a.len().min(b.len())))
+}
+
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum MemoryOp {
     Read(u64),       // (address)
@@ -104,7 +139,10 @@ impl From<&RVTraceRow> for [MemoryOp; MEMORY_OPS_PER_INSTRUCTION] {
             | RV32IM::DIV
             | RV32IM::DIVU
             | RV32IM::REM
-            | RV32IM::REMU => [rs1_read(), rs2_read(), rd_write(), MemoryOp::noop_read()],
+            | RV32IM::REMU
+            | RV32IM::ADDW
+            | RV32IM::SUBW
+            | RV32IM::MULW => [rs1_read(), rs2_read(), rd_write(), MemoryOp::noop_read()],
 
             RV32IM::LUI | RV32IM::AUIPC | RV32IM::VIRTUAL_ADVICE => [
                 MemoryOp::noop_read(),
@@ -209,6 +247,10 @@ pub struct ELFInstruction {
     /// `virtual_sequence_remaining` will be Some(0); if this is the penultimate instruction
     /// in the sequence, `virtual_sequence_remaining` will be Some(1); etc.
     pub virtual_sequence_remaining: Option<usize>,
+    /// True if the instruction was expanded from a 16-bit compressed (RVC) instruction, so
+    /// the instruction after it starts 2 bytes later instead of 4. The rows of a virtual
+    /// sequence share the value of the instruction they replace.
+    pub is_compressed: bool,
 }
 
 /// Boolean flags used in Jolt's R1CS constraints (`opflags` in the Jolt paper).
@@ -241,17 +283,46 @@ pub enum CircuitFlags {
     Assert,
     /// Used in virtual sequences; the program counter should be the same for the full sequence.
     DoNotUpdatePC,
+    /// 1 if the instruction is compressed and updates the PC, i.e. the next instruction
+    /// (or the return address of a jump) is 2 bytes after it instead of 4.
+    IsCompressed,
 }
 pub const NUM_CIRCUIT_FLAGS: usize = CircuitFlags::COUNT;
 
 impl ELFInstruction {
+    /// Checks that `sequence` is a well-formed virtual sequence: its instructions share one
+    /// address, and their `virtual_sequence_remaining` count down from `sequence.len() - 1`
+    /// to 0.
+    pub fn validate_virtual_sequence(sequence: &[ELFInstruction]) -> Result<(), String> {
+        let Some(first) = sequence.first() else {
+            return Err("empty virtual sequence".to_string());
+        };
+        for (i, instruction) in sequence.iter().enumerate() {
+            let expected = sequence.len() - i - 1;
+            if instruction.virtual_sequence_remaining != Some(expected) {
+                return Err(format!(
+                    "instruction {i} of the virtual sequence at {:#x} has \
+                     virtual_sequence_remaining {:?}, expected Some({expected})",
+                    first.address, instruction.virtual_sequence_remaining
+                ));
+            }
+            if instruction.address != first.address {
+                return Err(format!(
+                    "instruction {i} of the virtual sequence at {:#x} has address {:#x}",
+                    first.address, instruction.address
+                ));
+            }
+        }
+        Ok(())
+    }
+
     #[rustfmt::skip]
     pub fn to_circuit_flags(&self) -> [bool; NUM_CIRCUIT_FLAGS] {
         let mut flags = [false; NUM_CIRCUIT_FLAGS];
 
         flags[CircuitFlags::LeftOperandIsPC as usize] = matches!(
             self.opcode,
-            RV32IM::JAL | RV32IM::LUI | RV32IM::AUIPC,
+            RV32IM::JAL | RV32IM::AUIPC,
         );
 
         flags[CircuitFlags::RightOperandIsImm as usize] = matches!(
@@ -265,6 +336,7 @@ impl ELFInstruction {
             | RV32IM::SRAI
             | RV32IM::SLTI
             | RV32IM::SLTIU
+            | RV32IM::LUI
             | RV32IM::AUIPC
             | RV32IM::JAL
             | RV32IM::JALR
@@ -288,10 +360,7 @@ impl ELFInstruction {
             RV32IM::JAL | RV32IM::JALR,
         );
 
-        flags[CircuitFlags::Branch as usize] = matches!(
-            self.opcode,
-            RV32IM::BEQ | RV32IM::BNE | RV32IM::BLT | RV32IM::BGE | RV32IM::BLTU | RV32IM::BGEU,
-        );
+        flags[CircuitFlags::Branch as usize] = self.opcode.is_branch();
 
         // Stores, branches, jumps, and asserts do not store the lookup output to rd (they may update rd in other ways)
         flags[CircuitFlags::WriteLookupOutputToRD as usize] = !matches!(
@@ -306,7 +375,6 @@ impl ELFInstruction {
             | RV32IM::BGEU
             | RV32IM::JAL
             | RV32IM::JALR
-            | RV32IM::LUI
             | RV32IM::VIRTUAL_ASSERT_EQ
             | RV32IM::VIRTUAL_ASSERT_LTE
             | RV32IM::VIRTUAL_ASSERT_VALID_DIV0
@@ -365,6 +433,9 @@ impl ELFInstruction {
             None => false
         };
 
+        flags[CircuitFlags::IsCompressed as usize] =
+            self.is_compressed && !flags[CircuitFlags::DoNotUpdatePC as usize];
+
         flags
     }
 }
@@ -452,6 +523,9 @@ pub enum RV32IM {
     DIVU,
     REM,
     REMU,
+    ADDW,
+    SUBW,
+    MULW,
     FENCE,
     UNIMPL,
     // Virtual instructions
@@ -466,6 +540,90 @@ pub enum RV32IM {
     VIRTUAL_ASSERT_HALFWORD_ALIGNMENT,
 }
 
+impl RV32IM {
+    /// Instructions that the host expands into a virtual sequence before proving
+    /// (see `Program::trace`).
+    pub fn is_virtual_sequence(&self) -> bool {
+        matches!(
+            self,
+            RV32IM::MULH
+                | RV32IM::MULHSU
+                | RV32IM::DIV
+                | RV32IM::DIVU
+                | RV32IM::REM
+                | RV32IM::REMU
+                | RV32IM::SH
+                | RV32IM::SB
+                | RV32IM::LBU
+                | RV32IM::LHU
+                | RV32IM::LB
+                | RV32IM::LH
+        )
+    }
+
+    /// Virtual instructions, which only appear inside virtual sequences.
+    pub fn is_virtual(&self) -> bool {
+        matches!(
+            self,
+            RV32IM::VIRTUAL_MOVSIGN
+                | RV32IM::VIRTUAL_MOVE
+                | RV32IM::VIRTUAL_ADVICE
+                | RV32IM::VIRTUAL_ASSERT_LTE
+                | RV32IM::VIRTUAL_ASSERT_VALID_UNSIGNED_REMAINDER
+                | RV32IM::VIRTUAL_ASSERT_VALID_SIGNED_REMAINDER
+                | RV32IM::VIRTUAL_ASSERT_EQ
+                | RV32IM::VIRTUAL_ASSERT_VALID_DIV0
+                | RV32IM::VIRTUAL_ASSERT_HALFWORD_ALIGNMENT
+        )
+    }
+
+    pub fn is_branch(&self) -> bool {
+        matches!(
+            self,
+            RV32IM::BEQ | RV32IM::BNE | RV32IM::BLT | RV32IM::BGE | RV32IM::BLTU | RV32IM::BGEU
+        )
+    }
+
+    pub fn is_load(&self) -> bool {
+        matches!(
+            self,
+            RV32IM::LB | RV32IM::LH | RV32IM::LW | RV32IM::LBU | RV32IM::LHU
+        )
+    }
+
+    pub fn is_store(&self) -> bool {
+        matches!(self, RV32IM::SB | RV32IM::SH | RV32IM::SW)
+    }
+
+    /// Multiplication and division instructions of the M extension.
+    pub fn is_m_extension(&self) -> bool {
+        matches!(
+            self,
+            RV32IM::MUL
+                | RV32IM::MULH
+                | RV32IM::MULHU
+                | RV32IM::MULHSU
+                | RV32IM::MULU
+                | RV32IM::DIV
+                | RV32IM::DIVU
+                | RV32IM::REM
+                | RV32IM::REMU
+                | RV32IM::MULW
+        )
+    }
+
+    /// The RV64 instruction operating on the low 32 bits of its operands (the `*W` variant)
+    /// corresponding to this instruction, if there is one.
+    pub fn word_size_variant(&self) -> Option<RV32IM> {
+        match self {
+            RV32IM::ADD => Some(RV32IM::ADDW),
+            RV32IM::SUB => Some(RV32IM::SUBW),
+            RV32IM::MUL => Some(RV32IM::MULW),
+            _ => None,
+        }
+    }
+}
+
 impl FromStr for RV32IM {
     type Err = String;
 
@@ -519,6 +677,9 @@ impl FromStr for RV32IM {
             "DIVU" => Ok(Self::DIVU),
             "REM" => Ok(Self::REM),
             "REMU" => Ok(Self::REMU),
+            "ADDW" => Ok(Self::ADDW),
+            "SUBW" => Ok(Self::SUBW),
+            "MULW" => Ok(Self::MULW),
             "FENCE" => Ok(Self::FENCE),
             "UNIMPL" => Ok(Self::UNIMPL),
             _ => Err("Could not match instruction to RV32IM set.".to_string()),
@@ -670,3 +831,92 @@ impl MemoryLayout {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opcode_classification() {
+        let classify = |opcode: RV32IM| {
+            (
+                opcode.is_virtual_sequence(),
+                opcode.is_branch(),
+                opcode.is_m_extension(),
+                opcode.word_size_variant(),
+            )
+        };
+        assert_eq!(classify(RV32IM::REM), (true, false, true, None));
+        assert_eq!(classify(RV32IM::BEQ), (false, true, false, None));
+        assert_eq!(
+            classify(RV32IM::MUL),
+            (false, false, true, Some(RV32IM::MULW))
+        );
+        assert_eq!(
+            classify(RV32IM::ADD),
+            (false, false, false, Some(RV32IM::ADDW))
+        );
+
+        assert!(RV32IM::LBU.is_load() && RV32IM::LBU.is_virtual_sequence());
+        assert!(RV32IM::SW.is_store() && !RV32IM::SW.is_virtual_sequence());
+        assert!(RV32IM::VIRTUAL_ADVICE.is_virtual());
+        assert!(!RV32IM::ADD.is_virtual());
+    }
+
+    #[test]
+    fn validate_virtual_sequence() {
+        let instruction = |remaining| ELFInstruction {
+            address: 0x1000,
+            opcode: RV32IM::ADD,
+            rs1: Some(1),
+            rs2: Some(2),
+            rd: Some(3),
+            imm: None,
+            virtual_sequence_remaining: remaining,
+            is_compressed: false,
+        };
+        let sequence = vec![
+            instruction(Some(2)),
+            instruction(Some(1)),
+            instruction(Some(0)),
+        ];
+        assert!(ELFInstruction::validate_virtual_sequence(&sequence).is_ok());
+
+        // Misnumbered
+        let mut misnumbered = sequence.clone();
+        misnumbered[1].virtual_sequence_remaining = Some(2);
+        assert!(ELFInstruction::validate_virtual_sequence(&misnumbered).is_err());
+        // Not ending at 0
+        assert!(ELFInstruction::validate_virtual_sequence(&sequence[..2]).is_err());
+        // Not part of a sequence
+        let mut unnumbered = sequence.clone();
+        unnumbered[0].virtual_sequence_remaining = None;
+        assert!(ELFInstruction::validate_virtual_sequence(&unnumbered).is_err());
+        // Spanning two addresses
+        let mut moved = sequence.clone();
+        moved[2].address += 4;
+        assert!(ELFInstruction::validate_virtual_sequence(&moved).is_err());
+        assert!(ELFInstruction::validate_virtual_sequence(&[]).is_err());
+    }
+
+    #[test]
+    fn compressed_circuit_flag() {
+        let mut instruction = ELFInstruction {
+            address: 0x1000,
+            opcode: RV32IM::ADD,
+            rs1: Some(1),
+            rs2: Some(2),
+            rd: Some(3),
+            imm: None,
+            virtual_sequence_remaining: None,
+            is_compressed: true,
+        };
+        assert!(instruction.to_circuit_flags()[CircuitFlags::IsCompressed as usize]);
+
+        // Rows that do not update the PC never advance it by 2
+        instruction.virtual_sequence_remaining = Some(1);
+        let flags = instruction.to_circuit_flags();
+        assert!(flags[CircuitFlags::DoNotUpdatePC as usize]);
+        assert!(!flags[CircuitFlags::IsCompressed as usize]);
+    }
+}
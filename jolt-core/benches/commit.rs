@@ -86,6 +86,30 @@ fn benchmark_commit<PCS, F, ProofTranscript>(
     });
 }
 
+fn benchmark_open<PCS, F, ProofTranscript>(c: &mut Criterion, name: &str, num_vars: usize)
+where
+    PCS: CommitmentScheme<ProofTranscript, Field = F>,
+    F: JoltField,
+    ProofTranscript: Transcript,
+{
+    let mut rng = ChaCha20Rng::seed_from_u64(111111u64);
+    let setup = PCS::setup(1 << num_vars);
+    let poly = MultilinearPolynomial::from(
+        (0..1 << num_vars)
+            .map(|_| F::random(&mut rng))
+            .collect::<Vec<_>>(),
+    );
+    let point = (0..num_vars)
+        .map(|_| F::random(&mut rng))
+        .collect::<Vec<_>>();
+    c.bench_function(&format!("{} Open: {} vars", name, num_vars), |b| {
+        b.iter(|| {
+            let mut transcript = ProofTranscript::new(b"bench_open");
+            PCS::prove(&setup, &poly, &point, &mut transcript);
+        });
+    });
+}
+
 fn main() {
     let mut criterion = Criterion::default()
         .configure_from_args()
@@ -107,6 +131,11 @@ fn main() {
         layer_size,
         90,
     );
+    benchmark_open::<HyperKZG<Bn254, KeccakTranscript>, Fr, KeccakTranscript>(
+        &mut criterion,
+        "HyperKZG",
+        18,
+    );
 
     criterion.final_summary();
 }
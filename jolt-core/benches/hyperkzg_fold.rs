@@ -0,0 +1,51 @@
+use ark_bn254::Fr;
+use ark_std::test_rng;
+use criterion::Criterion;
+use jolt_core::field::JoltField;
+use jolt_core::poly::commitment::hyperkzg::fold_levels;
+use rayon::prelude::*;
+
+fn benchmark_fold<F: JoltField>(c: &mut Criterion, num_vars: usize) {
+    let mut rng = test_rng();
+    let evals: Vec<F> = std::iter::repeat_with(|| F::random(&mut rng))
+        .take(1 << num_vars)
+        .collect();
+    let rs: Vec<F> = std::iter::repeat_with(|| F::random(&mut rng))
+        .take(num_vars - 1)
+        .collect();
+
+    c.bench_function(
+        &format!("HyperKZG fold_levels {} variables", num_vars),
+        |b| b.iter(|| criterion::black_box(fold_levels(&evals, &rs))),
+    );
+    // HyperKZG::open keeps every fold, so the level-by-level baseline does too
+    c.bench_function(
+        &format!("HyperKZG fold level by level {} variables", num_vars),
+        |b| {
+            b.iter(|| {
+                let mut levels: Vec<Vec<F>> = vec![];
+                for r in rs.iter() {
+                    let previous = levels.last().unwrap_or(&evals);
+                    let next = (0..previous.len() / 2)
+                        .into_par_iter()
+                        .map(|j| *r * (previous[2 * j + 1] - previous[2 * j]) + previous[2 * j])
+                        .collect();
+                    levels.push(next);
+                }
+                criterion::black_box(levels)
+            })
+        },
+    );
+}
+
+fn main() {
+    let mut criterion = Criterion::default()
+        .configure_from_args()
+        .warm_up_time(std::time::Duration::from_secs(5));
+
+    benchmark_fold::<Fr>(&mut criterion, 20);
+    benchmark_fold::<Fr>(&mut criterion, 22);
+    benchmark_fold::<Fr>(&mut criterion, 24);
+
+    criterion.final_summary();
+}
@@ -0,0 +1,85 @@
+use ark_bn254::{Bn254, Fr};
+use ark_std::test_rng;
+use criterion::Criterion;
+use jolt_core::field::JoltField;
+use jolt_core::poly::commitment::kzg::UnivariateKZG;
+use jolt_core::poly::unipoly::synthetic_division;
+use rayon::prelude::*;
+
+/// The witness polynomials for the openings at `r` and `-r`, sharing the division of `f` by
+/// `x^2 - r^2`: if `f(x) = q(x) (x^2 - r^2) + a x + b`, then the witness polynomials are
+/// `q(x) (x + r) + a` and `q(x) (x - r) + a`.
+fn shared_witness_polynomials<F: JoltField>(f: &[F], r: F) -> (Vec<F>, Vec<F>) {
+    let d = f.len();
+    let r_squared = r * r;
+    // q has degree d - 3; padded with two zeros so the recurrence needs no bounds checks
+    let mut q = vec![F::zero(); d];
+    for i in (2..d).rev() {
+        q[i - 2] = f[i] + q[i] * r_squared;
+    }
+    let a = f[1] + q[1] * r_squared;
+
+    let r_q: Vec<F> = q.par_iter().map(|q_i| *q_i * r).collect();
+    // Since q[d - 2] = q[d - 1] = 0, the last coefficients are zero, matching the padding of
+    // the synthetic division's output
+    (0..d)
+        .into_par_iter()
+        .map(|k| {
+            let shifted = if k == 0 { a } else { q[k - 1] };
+            (shifted + r_q[k], shifted - r_q[k])
+        })
+        .unzip()
+}
+
+fn benchmark_witness_polynomials(c: &mut Criterion, num_vars: usize) {
+    let mut rng = test_rng();
+    let f: Vec<Fr> = std::iter::repeat_with(|| Fr::random(&mut rng))
+        .take(1 << num_vars)
+        .collect();
+    let r = Fr::random(&mut rng);
+    let u = [r, -r, r * r];
+
+    let (h_r, h_minus_r) = shared_witness_polynomials(&f, r);
+    assert_eq!(h_r, synthetic_division(&f, r));
+    assert_eq!(h_minus_r, synthetic_division(&f, -r));
+
+    // As in HyperKZG's batch opening
+    c.bench_function(
+        &format!(
+            "HyperKZG witness polynomials per point {} variables",
+            num_vars
+        ),
+        |b| {
+            b.iter(|| {
+                criterion::black_box(
+                    u.par_iter()
+                        .map(|u_i| UnivariateKZG::<Bn254>::witness_polynomial(&f, *u_i))
+                        .collect::<Vec<_>>(),
+                )
+            })
+        },
+    );
+    c.bench_function(
+        &format!("HyperKZG witness polynomials shared {} variables", num_vars),
+        |b| {
+            b.iter(|| {
+                criterion::black_box(rayon::join(
+                    || shared_witness_polynomials(&f, r),
+                    || UnivariateKZG::<Bn254>::witness_polynomial(&f, r * r),
+                ))
+            })
+        },
+    );
+}
+
+fn main() {
+    let mut criterion = Criterion::default()
+        .configure_from_args()
+        .warm_up_time(std::time::Duration::from_secs(5));
+
+    benchmark_witness_polynomials(&mut criterion, 20);
+    benchmark_witness_polynomials(&mut criterion, 22);
+    benchmark_witness_polynomials(&mut criterion, 24);
+
+    criterion.final_summary();
+}
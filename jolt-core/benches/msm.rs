@@ -1,5 +1,5 @@
 use ark_bn254::{Bn254, Fr, G1Affine, G1Projective};
-use ark_std::UniformRand;
+use ark_std::{UniformRand, Zero};
 use criterion::Criterion;
 use jolt_core::field::JoltField;
 #[cfg(feature = "icicle")]
@@ -100,6 +100,71 @@ where
     });
 }
 
+// A commitment to a polynomial whose coefficients are 90% zeros, e.g. a sparse polynomial
+// committed densely
+fn benchmark_sparse_msm(c: &mut Criterion) {
+    let mut rng = ChaCha20Rng::seed_from_u64(SRS_SIZE as u64);
+    let bases: Vec<G1Affine> = std::iter::repeat_with(|| G1Affine::rand(&mut rng))
+        .take(SRS_SIZE)
+        .collect();
+    let poly = MultilinearPolynomial::from(
+        (0..SRS_SIZE)
+            .map(|_| {
+                if rng.next_u32() % 10 == 0 {
+                    Fr::random(&mut rng)
+                } else {
+                    Fr::zero()
+                }
+            })
+            .collect::<Vec<_>>(),
+    );
+    c.bench_function("VariableBaseMSM::msm(256 bit scalars, 90% zeros)", |b| {
+        b.iter(|| {
+            let msm = <G1Projective as VariableBaseMSM>::msm(&bases, None, &poly, None);
+            let _ = msm.expect("MSM failed");
+        });
+    });
+}
+
+// Compares the automatically chosen window against fixed ones, both for a large MSM and for
+// one the size of the HyperKZG verifier's (k + 4 bases).
+fn benchmark_msm_windows(c: &mut Criterion, num_bases: usize) {
+    let mut rng = ChaCha20Rng::seed_from_u64(num_bases as u64);
+    let bases: Vec<G1Affine> = std::iter::repeat_with(|| G1Affine::rand(&mut rng))
+        .take(num_bases)
+        .collect();
+    let scalars: Vec<Fr> = std::iter::repeat_with(|| Fr::random(&mut rng))
+        .take(num_bases)
+        .collect();
+
+    c.bench_function(
+        &format!("VariableBaseMSM::msm_field_elements({num_bases} bases, auto window)"),
+        |b| {
+            b.iter(|| {
+                <G1Projective as VariableBaseMSM>::msm_field_elements(
+                    &bases, None, &scalars, None, false,
+                )
+                .expect("MSM failed")
+            });
+        },
+    );
+    for window_bits in [2, 3, 4, 8, 12, 16] {
+        c.bench_function(
+            &format!("VariableBaseMSM::msm_with_window({num_bases} bases, {window_bits} bits)"),
+            |b| {
+                b.iter(|| {
+                    <G1Projective as VariableBaseMSM>::msm_with_window(
+                        &bases,
+                        &scalars,
+                        window_bits,
+                    )
+                    .expect("MSM failed")
+                });
+            },
+        );
+    }
+}
+
 fn main() {
     let small_value_lookup_tables = <Fr as JoltField>::compute_lookup_tables();
     <Fr as JoltField>::initialize_lookup_tables(small_value_lookup_tables);
@@ -138,5 +203,8 @@ fn main() {
         "VariableBaseMSM::msm(1 bit scalars)",
         1,
     );
+    benchmark_sparse_msm(&mut criterion);
+    benchmark_msm_windows(&mut criterion, 24);
+    benchmark_msm_windows(&mut criterion, 1 << 16);
     criterion.final_summary();
 }
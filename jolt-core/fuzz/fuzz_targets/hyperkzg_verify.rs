@@ -0,0 +1,35 @@
+//! Deserializes arbitrary bytes into a `HyperKZGProof` and verifies it against a fixed
+//! claim. Verification must never panic, and may only accept the honest proof.
+#![no_main]
+
+use ark_bn254::Bn254;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use jolt_core::poly::commitment::hyperkzg::{HyperKZG, HyperKZGProof};
+use jolt_core::utils::transcript::{KeccakTranscript, Transcript};
+use jolt_core_fuzz::{instance, TRANSCRIPT_LABEL};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let instance = instance();
+    let Ok(proof) = HyperKZGProof::<Bn254>::deserialize_compressed(data) else {
+        return;
+    };
+
+    let mut transcript = KeccakTranscript::new(TRANSCRIPT_LABEL);
+    let result = HyperKZG::verify(
+        &instance.vk,
+        &instance.commitment,
+        &instance.point,
+        &instance.eval,
+        &proof,
+        &mut transcript,
+    );
+    if result.is_ok() {
+        let mut bytes = vec![];
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            bytes, instance.valid_proof,
+            "verify accepted a forged proof"
+        );
+    }
+});
@@ -0,0 +1,18 @@
+//! Writes the seed corpus for the `hyperkzg_verify` target: the honest proof, and a few
+//! truncations of it. Run with `cargo run --bin seed_corpus` from this directory.
+use std::fs;
+use std::path::Path;
+
+use jolt_core_fuzz::instance;
+
+fn main() -> std::io::Result<()> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus/hyperkzg_verify");
+    fs::create_dir_all(&dir)?;
+
+    let proof = &instance().valid_proof;
+    fs::write(dir.join("valid_proof"), proof)?;
+    for len in [proof.len() / 4, proof.len() / 2, proof.len() - 1] {
+        fs::write(dir.join(format!("truncated_{len}")), &proof[..len])?;
+    }
+    Ok(())
+}
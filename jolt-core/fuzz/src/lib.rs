@@ -0,0 +1,42 @@
+use std::sync::OnceLock;
+
+use ark_bn254::{Bn254, Fr};
+use ark_serialize::CanonicalSerialize;
+use jolt_core::poly::commitment::hyperkzg::{
+    testing, HyperKZG, HyperKZGCommitment, HyperKZGProof, HyperKZGVerifierKey,
+};
+use jolt_core::poly::multilinear_polynomial::MultilinearPolynomial;
+use jolt_core::utils::transcript::{KeccakTranscript, Transcript};
+
+pub const TRANSCRIPT_LABEL: &[u8] = b"fuzz_hyperkzg_verify";
+
+/// The fixed claim that fuzzed proofs are checked against.
+pub struct Instance {
+    pub vk: HyperKZGVerifierKey<Bn254>,
+    pub commitment: HyperKZGCommitment<Bn254>,
+    pub point: Vec<Fr>,
+    pub eval: Fr,
+    /// The checked, compressed encoding of an honest proof of the claim.
+    pub valid_proof: Vec<u8>,
+}
+
+pub fn instance() -> &'static Instance {
+    static INSTANCE: OnceLock<Instance> = OnceLock::new();
+    INSTANCE.get_or_init(|| {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(4, 238);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let commitment = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut transcript = KeccakTranscript::new(TRANSCRIPT_LABEL);
+        let proof: HyperKZGProof<Bn254> =
+            HyperKZG::open(&pk, &poly, &point, &eval, &mut transcript).unwrap();
+        let mut valid_proof = vec![];
+        proof.serialize_compressed(&mut valid_proof).unwrap();
+        Instance {
+            vk,
+            commitment,
+            point,
+            eval,
+            valid_proof,
+        }
+    })
+}
@@ -1,6 +1,8 @@
 use ark_ff::{prelude::*, BigInt, PrimeField, UniformRand};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+use crate::maybe_into_par_iter;
 use crate::utils::thread::unsafe_allocate_zero_vec;
 
 use super::{FieldOps, JoltField};
@@ -31,8 +33,7 @@ impl JoltField for ark_bn254::Fr {
         for i in 0..4 {
             let bitshift = 16 * i;
             let unit = <Self as ark_ff::PrimeField>::from_u64(1 << bitshift).unwrap();
-            lookup_tables[i] = (0..(1 << 16))
-                .into_par_iter()
+            lookup_tables[i] = maybe_into_par_iter!(0..(1 << 16))
                 .map(|j| unit * <Self as ark_ff::PrimeField>::from_u64(j).unwrap())
                 .collect();
         }
@@ -197,6 +198,114 @@ impl JoltField for ark_bn254::Fr {
     }
 }
 
+// BLS12-381 is only used to check that code generic over the field (e.g. HyperKZG) does not
+// silently depend on BN254, so it has no small-value lookup tables.
+#[cfg(test)]
+mod bls12_381 {
+    use ark_bls12_381::Fr;
+    use ark_ff::{BigInt, PrimeField, UniformRand};
+
+    use super::{FieldOps, JoltField};
+
+    impl FieldOps for Fr {}
+    impl<'a, 'b> FieldOps<&'b Fr, Fr> for &'a Fr {}
+    impl<'b> FieldOps<&'b Fr, Fr> for Fr {}
+
+    impl JoltField for Fr {
+        const NUM_BYTES: usize = 32;
+
+        fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
+            <Self as UniformRand>::rand(rng)
+        }
+
+        fn from_u8(n: u8) -> Self {
+            <Self as PrimeField>::from_u64(n as u64).unwrap()
+        }
+
+        fn from_u16(n: u16) -> Self {
+            <Self as PrimeField>::from_u64(n as u64).unwrap()
+        }
+
+        fn from_u32(n: u32) -> Self {
+            <Self as PrimeField>::from_u64(n as u64).unwrap()
+        }
+
+        fn from_u64(n: u64) -> Self {
+            <Self as PrimeField>::from_u64(n).unwrap()
+        }
+
+        fn from_i64(val: i64) -> Self {
+            let abs = <Self as JoltField>::from_u64(val.unsigned_abs());
+            if val.is_negative() {
+                -abs
+            } else {
+                abs
+            }
+        }
+
+        fn from_i128(val: i128) -> Self {
+            let abs = val.unsigned_abs();
+            let bigint = BigInt::new([abs as u64, (abs >> 64) as u64, 0, 0]);
+            let abs = <Self as PrimeField>::from_bigint(bigint).unwrap();
+            if val.is_negative() {
+                -abs
+            } else {
+                abs
+            }
+        }
+
+        fn to_u64(&self) -> Option<u64> {
+            let bigint = self.into_bigint();
+            let limbs: &[u64] = bigint.as_ref();
+            limbs[1..].iter().all(|limb| *limb == 0).then_some(limbs[0])
+        }
+
+        fn square(&self) -> Self {
+            <Self as ark_ff::Field>::square(self)
+        }
+
+        fn inverse(&self) -> Option<Self> {
+            <Self as ark_ff::Field>::inverse(self)
+        }
+
+        fn from_bytes(bytes: &[u8]) -> Self {
+            assert_eq!(bytes.len(), Self::NUM_BYTES);
+            Fr::from_le_bytes_mod_order(bytes)
+        }
+
+        fn num_bits(&self) -> u32 {
+            self.into_bigint().num_bits()
+        }
+
+        fn montgomery_r2() -> Option<Self> {
+            Some(ark_ff::Fp::new_unchecked(Self::R2))
+        }
+
+        #[inline(always)]
+        fn mul_u64_unchecked(&self, n: u64) -> Self {
+            ark_ff::Fp::mul_u64(*self, n)
+        }
+    }
+
+    #[test]
+    fn small_value_conversions() {
+        use ark_std::One;
+
+        assert_eq!(<Fr as JoltField>::from_i64(-3), -Fr::from(3u64));
+        assert_eq!(
+            <Fr as JoltField>::from_i128(i128::MIN),
+            -ark_ff::Field::pow(&Fr::from(2u64), [127])
+        );
+        assert_eq!(Fr::from(u64::MAX).to_u64(), Some(u64::MAX));
+        assert_eq!((Fr::from(u64::MAX) + Fr::one()).to_u64(), None);
+        let x = Fr::from(12345u64);
+        assert_eq!(
+            (x * Fr::montgomery_r2().unwrap()).mul_u64_unchecked(678),
+            x * Fr::from(678u64)
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,4 +333,37 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn byte_layout() {
+        use crate::utils::transcript::{KeccakTranscript, Transcript};
+
+        let x = Fr::from(0x0102_0304_0506_0708u64);
+        let mut expected_le = vec![0u8; 32];
+        expected_le[..8].copy_from_slice(&[8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(x.to_bytes_le(), expected_le);
+        let mut expected_be = vec![0u8; 32];
+        expected_be[24..].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(x.to_bytes_be(), expected_be);
+
+        // -1 = r - 1, where r is the BN254 scalar field modulus
+        assert_eq!(
+            (-Fr::one()).to_bytes_be(),
+            vec![
+                0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+                0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
+                0xf0, 0x00, 0x00, 0x00,
+            ]
+        );
+
+        // The transcript absorbs scalars big-endian
+        let mut t1 = KeccakTranscript::new(b"test_byte_layout");
+        let mut t2 = KeccakTranscript::new(b"test_byte_layout");
+        t1.append_scalar(&x);
+        t2.append_bytes(&x.to_bytes_be());
+        assert_eq!(t1.state, t2.state);
+        let mut t3 = KeccakTranscript::new(b"test_byte_layout");
+        t3.append_bytes(&x.to_bytes_le());
+        assert_ne!(t1.state, t3.state);
+    }
 }
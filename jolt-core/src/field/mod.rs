@@ -64,6 +64,20 @@ pub trait JoltField:
     fn from_i128(val: i128) -> Self;
     fn square(&self) -> Self;
     fn from_bytes(bytes: &[u8]) -> Self;
+    /// Canonical (non-Montgomery) little-endian encoding of this field element, as
+    /// produced by `serialize_uncompressed`.
+    fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        self.serialize_uncompressed(&mut bytes).unwrap();
+        bytes
+    }
+    /// Canonical big-endian encoding of this field element. This is the encoding
+    /// absorbed by `Transcript::append_scalar`, matching the EVM's word layout.
+    fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
     fn inverse(&self) -> Option<Self>;
     fn to_u64(&self) -> Option<u64> {
         unimplemented!("conversion to u64 not implemented");
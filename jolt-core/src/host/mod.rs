@@ -9,6 +9,7 @@ use std::{
 };
 
 use postcard;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use serde::Serialize;
 
@@ -19,6 +20,7 @@ use common::{
     rv_trace::JoltDevice,
 };
 pub use tracer::ELFInstruction;
+use tracer::RVTraceRow;
 
 use crate::{
     field::JoltField,
@@ -31,6 +33,7 @@ use crate::{
         },
         vm::{bytecode::BytecodeRow, rv32i_vm::RV32I, JoltTraceStep},
     },
+    maybe_into_par_iter,
 };
 
 use self::analyze::ProgramSummary;
@@ -51,6 +54,7 @@ pub struct Program {
     max_input_size: u64,
     max_output_size: u64,
     std: bool,
+    compressed: bool,
     pub elf: Option<PathBuf>,
 }
 
@@ -65,6 +69,7 @@ impl Program {
             max_input_size: DEFAULT_MAX_INPUT_SIZE,
             max_output_size: DEFAULT_MAX_OUTPUT_SIZE,
             std: false,
+            compressed: false,
             elf: None,
         }
     }
@@ -73,6 +78,12 @@ impl Program {
         self.std = std;
     }
 
+    /// Builds the guest with the `C` extension, so that it mixes 16-bit compressed
+    /// instructions with the usual 32-bit ones.
+    pub fn set_compressed(&mut self, compressed: bool) {
+        self.compressed = compressed;
+    }
+
     pub fn set_func(&mut self, func: &str) {
         self.func = Some(func.to_string())
     }
@@ -108,9 +119,10 @@ impl Program {
 
             self.save_linker();
 
-            let rust_flags = [
+            let link_arg = format!("link-arg=-T{}", self.linker_path());
+            let mut rust_flags = vec![
                 "-C",
-                &format!("link-arg=-T{}", self.linker_path()),
+                &link_arg,
                 "-C",
                 "passes=lower-atomic",
                 "-C",
@@ -120,6 +132,9 @@ impl Program {
                 "-C",
                 "opt-level=z",
             ];
+            if self.compressed {
+                rust_flags.extend(["-C", "target-feature=+c"]);
+            }
 
             let toolchain = if self.std {
                 "riscv32im-jolt-zkvm-elf"
@@ -138,9 +153,10 @@ impl Program {
             }
 
             let target = format!(
-                "/tmp/jolt-guest-target-{}-{}",
+                "/tmp/jolt-guest-target-{}-{}{}",
                 self.guest,
-                self.func.as_ref().unwrap_or(&"".to_string())
+                self.func.as_ref().unwrap_or(&"".to_string()),
+                if self.compressed { "-c" } else { "" }
             );
 
             let output = Command::new("cargo")
@@ -188,41 +204,7 @@ impl Program {
         let (raw_trace, io_device) =
             tracer::trace(&elf, &self.input, self.max_input_size, self.max_output_size);
 
-        let trace: Vec<_> = raw_trace
-            .into_par_iter()
-            .flat_map(|row| match row.instruction.opcode {
-                tracer::RV32IM::MULH => MULHInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::MULHSU => MULHSUInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::DIV => DIVInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::DIVU => DIVUInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::REM => REMInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::REMU => REMUInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::SH => SHInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::SB => SBInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::LBU => LBUInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::LHU => LHUInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::LB => LBInstruction::<32>::virtual_trace(row),
-                tracer::RV32IM::LH => LHInstruction::<32>::virtual_trace(row),
-                _ => vec![row],
-            })
-            .map(|row| {
-                let instruction_lookup = if let Ok(jolt_instruction) = RV32I::try_from(&row) {
-                    Some(jolt_instruction)
-                } else {
-                    // Instruction does not use lookups
-                    None
-                };
-
-                JoltTraceStep {
-                    instruction_lookup,
-                    bytecode_row: BytecodeRow::from_instruction::<RV32I>(&row.instruction),
-                    memory_ops: (&row).into(),
-                    circuit_flags: row.instruction.to_circuit_flags(),
-                }
-            })
-            .collect();
-
-        (io_device, trace)
+        (io_device, trace_steps(raw_trace))
     }
 
     pub fn trace_analyze<F: JoltField>(mut self) -> ProgramSummary {
@@ -263,6 +245,88 @@ impl Program {
     }
 }
 
+/// Expands the virtual sequences of `raw_trace` and turns each row into a step of the
+/// RV32I VM. Panics on the RV64 word instructions (`ADDW`, `SUBW`, `MULW`), which the RV32I
+/// instruction set cannot look up.
+pub fn trace_steps(raw_trace: Vec<RVTraceRow>) -> Vec<JoltTraceStep<RV32I>> {
+    maybe_into_par_iter!(raw_trace)
+        .flat_map(|row| {
+            let is_compressed = row.instruction.is_compressed;
+            let mut expanded = match row.instruction.opcode {
+                tracer::RV32IM::MULH => MULHInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::MULHSU => MULHSUInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::DIV => DIVInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::DIVU => DIVUInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::REM => REMInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::REMU => REMUInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::SH => SHInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::SB => SBInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::LBU => LBUInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::LHU => LHUInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::LB => LBInstruction::<32>::virtual_trace(row),
+                tracer::RV32IM::LH => LHInstruction::<32>::virtual_trace(row),
+                opcode @ (tracer::RV32IM::ADDW | tracer::RV32IM::SUBW | tracer::RV32IM::MULW) => {
+                    panic!("{opcode:?} is an RV64 instruction, which the RV32I VM does not support")
+                }
+                _ => vec![row],
+            };
+            // Rows of a virtual sequence advance the PC like the instruction they replace
+            for row in expanded.iter_mut() {
+                row.instruction.is_compressed = is_compressed;
+            }
+            expanded
+        })
+        .map(|row| {
+            let instruction_lookup = if let Ok(jolt_instruction) = RV32I::try_from(&row) {
+                Some(jolt_instruction)
+            } else {
+                // Instruction does not use lookups
+                None
+            };
+
+            JoltTraceStep {
+                instruction_lookup,
+                bytecode_row: BytecodeRow::from_instruction::<RV32I>(&row.instruction),
+                memory_ops: (&row).into(),
+                circuit_flags: row.instruction.to_circuit_flags(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracer::{RegisterState, RV32IM};
+
+    #[test]
+    #[should_panic(expected = "ADDW is an RV64 instruction")]
+    fn rv32_trace_rejects_addw() {
+        let row = RVTraceRow {
+            instruction: ELFInstruction {
+                address: 0x80000000,
+                opcode: RV32IM::ADDW,
+                rs1: Some(1),
+                rs2: Some(2),
+                rd: Some(3),
+                imm: None,
+                virtual_sequence_remaining: None,
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(1),
+                rs2_val: Some(2),
+                rd_post_val: Some(3),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        };
+        trace_steps(vec![row]);
+    }
+}
+
 const LINKER_SCRIPT_TEMPLATE: &str = r#"
 MEMORY {
   program (rwx) : ORIGIN = 0x80000000, LENGTH = {MEMORY_SIZE}
@@ -3,7 +3,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{JoltInstruction, SubtableIndices};
+use super::{JoltInstruction, LookupEntryBatch, SubtableIndices, WordSize};
 use crate::field::JoltField;
 use crate::jolt::subtable::{identity::IdentitySubtable, LassoSubtable};
 use crate::utils::instruction_utils::{
@@ -46,6 +46,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for ADDInstruction<WORD_SIZE> {
     }
 
     fn lookup_entry(&self) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             (self.0 as u32).overflowing_add(self.1 as u32).0.into()
         } else if WORD_SIZE == 64 {
@@ -56,6 +57,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for ADDInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
@@ -66,6 +68,25 @@ impl<const WORD_SIZE: usize> JoltInstruction for ADDInstruction<WORD_SIZE> {
     }
 }
 
+impl<const WORD_SIZE: usize> LookupEntryBatch for ADDInstruction<WORD_SIZE> {
+    fn from_operands(x: u64, y: u64) -> Self {
+        Self(x, y)
+    }
+
+    fn lookup_entry_batch(inputs: &[(u64, u64)]) -> Vec<u64> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        let mask = if WORD_SIZE == 64 {
+            u64::MAX
+        } else {
+            (1 << WORD_SIZE) - 1
+        };
+        inputs
+            .iter()
+            .map(|(x, y)| x.wrapping_add(*y) & mask)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ark_bn254::Fr;
@@ -73,7 +94,10 @@ mod test {
     use rand_chacha::rand_core::RngCore;
 
     use super::ADDInstruction;
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        jolt::instruction::{JoltInstruction, LookupEntryBatch},
+        jolt_instruction_test,
+    };
 
     #[test]
     fn add_instruction_32_e2e() {
@@ -140,4 +164,87 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    fn add_lookup_entry_wraps<const WORD_SIZE: usize>() {
+        let word_max: u64 = if WORD_SIZE == 32 {
+            u32::MAX as u64
+        } else {
+            u64::MAX
+        };
+        let msb: u64 = 1 << (WORD_SIZE - 1);
+
+        // (x, y, x + y mod 2^WORD_SIZE)
+        let cases = [
+            (0, 0, 0),
+            (word_max, 1, 0),
+            (1, word_max, 0),
+            (word_max, word_max, word_max - 1),
+            (msb, msb, 0),
+            (msb - 1, 1, msb),
+            (msb, word_max, msb - 1),
+        ];
+        for (x, y, expected) in cases {
+            assert_eq!(
+                ADDInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+                expected,
+                "{x} + {y} (WORD_SIZE = {WORD_SIZE})"
+            );
+        }
+    }
+
+    #[test]
+    fn add_lookup_entry_wraps_32() {
+        add_lookup_entry_wraps::<32>();
+    }
+
+    #[test]
+    fn add_lookup_entry_wraps_64() {
+        add_lookup_entry_wraps::<64>();
+    }
+
+    #[test]
+    fn add_64_overflow() {
+        const C: usize = 8;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 64;
+
+        // u64::MAX + 1 wraps around to 0
+        let instruction = ADDInstruction::<WORD_SIZE>(u64::MAX, 1);
+        assert_eq!(instruction.lookup_entry(), 0);
+        jolt_instruction_test!(instruction);
+
+        let instruction = ADDInstruction::<WORD_SIZE>(u64::MAX, u64::MAX);
+        assert_eq!(instruction.lookup_entry(), u64::MAX - 1);
+        jolt_instruction_test!(instruction);
+    }
+
+    fn add_lookup_entry_batch<const WORD_SIZE: usize>() {
+        let mut rng = test_rng();
+        let mut inputs: Vec<(u64, u64)> =
+            (0..256).map(|_| (rng.next_u64(), rng.next_u64())).collect();
+        inputs.extend([
+            (0, 0),
+            (u64::MAX, 1),
+            (u32::MAX as u64, 1),
+            (u64::MAX, u64::MAX),
+        ]);
+
+        let batch = ADDInstruction::<WORD_SIZE>::lookup_entry_batch(&inputs);
+        let expected: Vec<u64> = inputs
+            .iter()
+            .map(|&(x, y)| ADDInstruction::<WORD_SIZE>(x, y).lookup_entry())
+            .collect();
+        assert_eq!(batch, expected);
+        assert!(ADDInstruction::<WORD_SIZE>::lookup_entry_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn add_lookup_entry_batch_32() {
+        add_lookup_entry_batch::<32>();
+    }
+
+    #[test]
+    fn add_lookup_entry_batch_64() {
+        add_lookup_entry_batch::<64>();
+    }
 }
@@ -3,7 +3,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{JoltInstruction, SubtableIndices};
+use super::{JoltInstruction, LookupEntryBatch, SubtableIndices, WordSize};
 use crate::field::JoltField;
 use crate::jolt::subtable::{and::AndSubtable, LassoSubtable};
 use crate::utils::instruction_utils::{chunk_and_concatenate_operands, concatenate_lookups};
@@ -42,6 +42,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for ANDInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
@@ -52,6 +53,16 @@ impl<const WORD_SIZE: usize> JoltInstruction for ANDInstruction<WORD_SIZE> {
     }
 }
 
+impl<const WORD_SIZE: usize> LookupEntryBatch for ANDInstruction<WORD_SIZE> {
+    fn from_operands(x: u64, y: u64) -> Self {
+        Self(x, y)
+    }
+
+    fn lookup_entry_batch(inputs: &[(u64, u64)]) -> Vec<u64> {
+        inputs.iter().map(|(x, y)| x & y).collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ark_bn254::Fr;
@@ -0,0 +1,146 @@
+use crate::field::JoltField;
+use rand::prelude::StdRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use super::{add::ADDInstruction, lui::upper_immediate, JoltInstruction, SubtableIndices};
+use crate::jolt::subtable::LassoSubtable;
+
+/// AUIPC: adds the 20-bit upper immediate `imm` (see [`upper_immediate`]) to the address
+/// `pc` of the instruction, wrapping around at `WORD_SIZE` bits. This is ADD of the address
+/// and the immediate's value, so it uses the same subtables.
+#[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AUIPCInstruction<const WORD_SIZE: usize>(pub u64, pub u64);
+
+impl<const WORD_SIZE: usize> AUIPCInstruction<WORD_SIZE> {
+    fn as_add(&self) -> ADDInstruction<WORD_SIZE> {
+        ADDInstruction(self.0, upper_immediate::<WORD_SIZE>(self.1))
+    }
+}
+
+impl<const WORD_SIZE: usize> JoltInstruction for AUIPCInstruction<WORD_SIZE> {
+    fn operands(&self) -> (u64, u64) {
+        self.as_add().operands()
+    }
+
+    fn combine_lookups<F: JoltField>(&self, vals: &[F], C: usize, M: usize) -> F {
+        self.as_add().combine_lookups(vals, C, M)
+    }
+
+    fn g_poly_degree(&self, C: usize) -> usize {
+        self.as_add().g_poly_degree(C)
+    }
+
+    fn subtables<F: JoltField>(
+        &self,
+        C: usize,
+        M: usize,
+    ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
+        self.as_add().subtables(C, M)
+    }
+
+    fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
+        self.as_add().to_indices(C, log_M)
+    }
+
+    fn lookup_entry(&self) -> u64 {
+        self.as_add().lookup_entry()
+    }
+
+    fn random(&self, rng: &mut StdRng) -> Self {
+        let ADDInstruction(pc, _) = ADDInstruction::<WORD_SIZE>::default().random(rng);
+        Self(pc, rng.next_u32() as u64 & 0xfffff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+
+    use super::AUIPCInstruction;
+
+    #[test]
+    fn auipc_instruction_32_e2e() {
+        let mut rng = test_rng();
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 32;
+
+        for _ in 0..256 {
+            let pc = rng.next_u32() as u64;
+            let imm = rng.next_u32() as u64 & 0xfffff;
+            let instruction = AUIPCInstruction::<WORD_SIZE>(pc, imm);
+            jolt_instruction_test!(instruction);
+        }
+
+        let instructions = vec![
+            AUIPCInstruction::<WORD_SIZE>(0x1000, 0),
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0004, 2),
+            AUIPCInstruction::<WORD_SIZE>(0x1000, 0x80000),
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0000, 0x80000),
+            AUIPCInstruction::<WORD_SIZE>(0x8000_1000, 0xfffff),
+        ];
+        for instruction in instructions {
+            jolt_instruction_test!(instruction);
+        }
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0004, 2).lookup_entry(),
+            0x8000_2004
+        );
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x1000, 0x80000).lookup_entry(),
+            0x8000_1000
+        );
+        // The sum wraps around at 32 bits
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0000, 0x80000).lookup_entry(),
+            0
+        );
+        // An immediate of 0xfffff subtracts 0x1000
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x8000_1000, 0xfffff).lookup_entry(),
+            0x8000_0000
+        );
+    }
+
+    #[test]
+    fn auipc_instruction_64_e2e() {
+        let mut rng = test_rng();
+        const C: usize = 8;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 64;
+
+        for _ in 0..256 {
+            let pc = rng.next_u64();
+            let imm = rng.next_u32() as u64 & 0xfffff;
+            let instruction = AUIPCInstruction::<WORD_SIZE>(pc, imm);
+            jolt_instruction_test!(instruction);
+        }
+
+        let instructions = vec![
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0004, 2),
+            AUIPCInstruction::<WORD_SIZE>(0x1000, 0x80000),
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0000, 0x80000),
+        ];
+        for instruction in instructions {
+            jolt_instruction_test!(instruction);
+        }
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0004, 2).lookup_entry(),
+            0x8000_2004
+        );
+        // The immediate is sign extended before being added
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x1000, 0x80000).lookup_entry(),
+            0xffff_ffff_8000_1000
+        );
+        assert_eq!(
+            AUIPCInstruction::<WORD_SIZE>(0x8000_0000, 0x80000).lookup_entry(),
+            0
+        );
+    }
+}
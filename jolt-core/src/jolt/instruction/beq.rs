@@ -2,7 +2,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::JoltInstruction;
+use super::{JoltInstruction, WordSize};
 use crate::{
     field::JoltField,
     jolt::{
@@ -46,6 +46,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for BEQInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
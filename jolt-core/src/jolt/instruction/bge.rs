@@ -2,13 +2,9 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{slt::SLTInstruction, JoltInstruction, SubtableIndices};
+use super::{less_than, slt::SLTInstruction, JoltInstruction, SubtableIndices, WordSize};
 use crate::{
-    field::JoltField,
-    jolt::subtable::{
-        eq::EqSubtable, eq_abs::EqAbsSubtable, left_msb::LeftMSBSubtable, lt_abs::LtAbsSubtable,
-        ltu::LtuSubtable, right_msb::RightMSBSubtable, LassoSubtable,
-    },
+    field::JoltField, jolt::subtable::LassoSubtable,
     utils::instruction_utils::chunk_and_concatenate_operands,
 };
 
@@ -34,14 +30,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for BGEInstruction<WORD_SIZE> {
         C: usize,
         _: usize,
     ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
-        vec![
-            (Box::new(LeftMSBSubtable::new()), SubtableIndices::from(0)),
-            (Box::new(RightMSBSubtable::new()), SubtableIndices::from(0)),
-            (Box::new(LtuSubtable::new()), SubtableIndices::from(1..C)),
-            (Box::new(EqSubtable::new()), SubtableIndices::from(1..C - 1)),
-            (Box::new(LtAbsSubtable::new()), SubtableIndices::from(0)),
-            (Box::new(EqAbsSubtable::new()), SubtableIndices::from(0)),
-        ]
+        less_than::signed_subtables(C)
     }
 
     fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
@@ -49,20 +38,11 @@ impl<const WORD_SIZE: usize> JoltInstruction for BGEInstruction<WORD_SIZE> {
     }
 
     fn lookup_entry(&self) -> u64 {
-        if WORD_SIZE == 32 {
-            let x = self.0 as i32;
-            let y = self.1 as i32;
-            (x >= y) as u64
-        } else if WORD_SIZE == 64 {
-            let x = self.0 as i64;
-            let y = self.1 as i64;
-            (x >= y) as u64
-        } else {
-            panic!("BGE is only implemented for 32-bit or 64-bit word sizes")
-        }
+        !less_than::signed_less_than::<WORD_SIZE>(self.0, self.1) as u64
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
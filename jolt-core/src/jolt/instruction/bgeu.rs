@@ -2,10 +2,9 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{sltu::SLTUInstruction, JoltInstruction, SubtableIndices};
+use super::{less_than, sltu::SLTUInstruction, JoltInstruction, SubtableIndices, WordSize};
 use crate::{
-    field::JoltField,
-    jolt::subtable::{eq::EqSubtable, ltu::LtuSubtable, LassoSubtable},
+    field::JoltField, jolt::subtable::LassoSubtable,
     utils::instruction_utils::chunk_and_concatenate_operands,
 };
 
@@ -31,10 +30,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for BGEUInstruction<WORD_SIZE> {
         C: usize,
         _: usize,
     ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
-        vec![
-            (Box::new(LtuSubtable::new()), SubtableIndices::from(0..C)),
-            (Box::new(EqSubtable::new()), SubtableIndices::from(0..C - 1)),
-        ]
+        less_than::unsigned_subtables(C)
     }
 
     fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
@@ -47,6 +43,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for BGEUInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use tracer::{ELFInstruction, MemoryState, RVTraceRow, RegisterState};
+
+/// The per-row values of an expanded trace, i.e. everything in an [`RVTraceRow`] apart
+/// from its instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowValues {
+    pub register_state: RegisterState,
+    pub memory_state: Option<MemoryState>,
+    pub advice_value: Option<u64>,
+    pub precompile_input: Option<[u32; 16]>,
+    pub precompile_output_address: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CompactStep {
+    /// A row that is not part of a virtual sequence.
+    Row(RVTraceRow),
+    /// One execution of a virtual sequence: the index of its template in
+    /// [`CompactTrace::templates`], and the values of each of its rows.
+    Sequence {
+        template: usize,
+        values: Vec<RowValues>,
+    },
+}
+
+/// A trace in which identical virtual-sequence expansions (e.g. every execution of a given
+/// REM or DIV instruction) share a single template of instructions, so only per-execution
+/// operand values are stored for each of them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CompactTrace {
+    pub templates: Vec<Vec<ELFInstruction>>,
+    pub steps: Vec<CompactStep>,
+}
+
+impl CompactTrace {
+    /// Compacts a trace whose virtual instructions have already been expanded, as done
+    /// by `Program::trace`. A virtual sequence is a run of rows whose
+    /// `virtual_sequence_remaining` counts down to `Some(0)`.
+    pub fn new(trace: Vec<RVTraceRow>) -> Self {
+        let mut compact = Self::default();
+        // Templates by the address of the instruction they expand
+        let mut templates_by_address: HashMap<u64, Vec<usize>> = HashMap::new();
+        let mut sequence: Vec<RVTraceRow> = vec![];
+
+        for row in trace {
+            match row.instruction.virtual_sequence_remaining {
+                None => {
+                    assert!(sequence.is_empty(), "unterminated virtual sequence");
+                    compact.steps.push(CompactStep::Row(row));
+                }
+                Some(remaining) => {
+                    sequence.push(row);
+                    if remaining > 0 {
+                        continue;
+                    }
+                    let (instructions, values): (Vec<_>, Vec<_>) = std::mem::take(&mut sequence)
+                        .into_iter()
+                        .map(|row| {
+                            (
+                                row.instruction,
+                                RowValues {
+                                    register_state: row.register_state,
+                                    memory_state: row.memory_state,
+                                    advice_value: row.advice_value,
+                                    precompile_input: row.precompile_input,
+                                    precompile_output_address: row.precompile_output_address,
+                                },
+                            )
+                        })
+                        .unzip();
+
+                    let candidates = templates_by_address
+                        .entry(instructions[0].address)
+                        .or_default();
+                    let existing = candidates
+                        .iter()
+                        .copied()
+                        .find(|&index| compact.templates[index] == instructions);
+                    let template = match existing {
+                        Some(index) => index,
+                        None => {
+                            compact.templates.push(instructions);
+                            candidates.push(compact.templates.len() - 1);
+                            compact.templates.len() - 1
+                        }
+                    };
+                    compact
+                        .steps
+                        .push(CompactStep::Sequence { template, values });
+                }
+            }
+        }
+        assert!(sequence.is_empty(), "unterminated virtual sequence");
+        compact
+    }
+
+    /// Number of rows in the expanded trace.
+    pub fn len(&self) -> usize {
+        self.steps
+            .iter()
+            .map(|step| match step {
+                CompactStep::Row(_) => 1,
+                CompactStep::Sequence { values, .. } => values.len(),
+            })
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Expands the trace back into the rows it was built from.
+    pub fn expand(&self) -> Vec<RVTraceRow> {
+        let mut trace = Vec::with_capacity(self.len());
+        for step in self.steps.iter() {
+            match step {
+                CompactStep::Row(row) => trace.push(row.clone()),
+                CompactStep::Sequence { template, values } => {
+                    trace.extend(self.templates[*template].iter().zip(values.iter()).map(
+                        |(instruction, values)| RVTraceRow {
+                            instruction: instruction.clone(),
+                            register_state: values.register_state.clone(),
+                            memory_state: values.memory_state.clone(),
+                            advice_value: values.advice_value,
+                            precompile_input: values.precompile_input,
+                            precompile_output_address: values.precompile_output_address,
+                        },
+                    ))
+                }
+            }
+        }
+        trace
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tracer::RV32IM;
+
+    use super::*;
+    use crate::jolt::instruction::{
+        rem::REMInstruction, test::register_row, VirtualInstructionSequence,
+    };
+
+    fn rem_row(address: u64, x: u64, y: u64) -> RVTraceRow {
+        let mut row = register_row(RV32IM::REM, x, y);
+        row.instruction.address = address;
+        row.register_state.rd_post_val = Some(REMInstruction::<32>::sequence_output(x, y));
+        row
+    }
+
+    #[test]
+    fn rem_sequences_share_template() {
+        let add = RVTraceRow {
+            instruction: ELFInstruction {
+                address: 0x1004,
+                opcode: RV32IM::ADD,
+                rs1: Some(7),
+                rs2: Some(7),
+                rd: Some(8),
+                imm: None,
+                virtual_sequence_remaining: None,
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(2),
+                rs2_val: Some(2),
+                rd_post_val: Some(4),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        };
+
+        // The same REM instruction executed twice with different operands, then a REM
+        // elsewhere in the program
+        let trace: Vec<_> = [
+            REMInstruction::<32>::virtual_trace(rem_row(0x1000, 17, 5)),
+            vec![add.clone()],
+            REMInstruction::<32>::virtual_trace(rem_row(0x1000, -17i32 as u32 as u64, 3)),
+            REMInstruction::<32>::virtual_trace(rem_row(0x2000, 9, 4)),
+        ]
+        .concat();
+
+        let compact = CompactTrace::new(trace.clone());
+        assert_eq!(compact.templates.len(), 2);
+        assert_eq!(compact.steps.len(), 4);
+        assert_eq!(compact.steps[1], CompactStep::Row(add));
+        match (&compact.steps[0], &compact.steps[2]) {
+            (
+                CompactStep::Sequence {
+                    template: first,
+                    values: first_values,
+                },
+                CompactStep::Sequence {
+                    template: second,
+                    values: second_values,
+                },
+            ) => {
+                assert_eq!(first, second);
+                assert_eq!(first_values.len(), REMInstruction::<32>::SEQUENCE_LENGTH);
+                assert_ne!(first_values, second_values);
+            }
+            steps => panic!("expected two virtual sequences, got {steps:?}"),
+        }
+        assert!(
+            matches!(compact.steps[3], CompactStep::Sequence { template, .. } if template == 1)
+        );
+
+        assert_eq!(compact.len(), trace.len());
+        assert_eq!(compact.expand(), trace);
+    }
+}
@@ -1,19 +1,25 @@
 use common::constants::virtual_register_index;
+use common::division::signed_divrem;
 use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, beq::BEQInstruction, mul::MULInstruction,
     virtual_advice::ADVICEInstruction, virtual_assert_valid_div0::AssertValidDiv0Instruction,
     virtual_assert_valid_signed_remainder::AssertValidSignedRemainderInstruction, JoltInstruction,
 };
+use crate::utils::errors::TraceError;
 /// Perform signed division and return the result
 pub struct DIVInstruction<const WORD_SIZE: usize>;
 
-impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_SIZE> {
-    const SEQUENCE_LENGTH: usize = 8;
-
-    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+impl<const WORD_SIZE: usize> DIVInstruction<WORD_SIZE> {
+    /// [`VirtualInstructionSequence::try_virtual_trace`], but with the given `quotient` and
+    /// `remainder` as the sequence's advice rather than the correct ones.
+    pub fn virtual_trace_with_advice(
+        trace_row: RVTraceRow,
+        quotient: u64,
+        remainder: u64,
+    ) -> Result<Vec<RVTraceRow>, TraceError> {
         assert_eq!(trace_row.instruction.opcode, RV32IM::DIV);
         // DIV source registers
         let r_x = trace_row.instruction.rs1;
@@ -29,36 +35,6 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
 
         let mut virtual_trace = vec![];
 
-        let (quotient, remainder) = match WORD_SIZE {
-            32 => {
-                if y == 0 {
-                    (u32::MAX as u64, x)
-                } else {
-                    let mut quotient = x as i32 / y as i32;
-                    let mut remainder = x as i32 % y as i32;
-                    if (remainder < 0 && (y as i32) > 0) || (remainder > 0 && (y as i32) < 0) {
-                        remainder += y as i32;
-                        quotient -= 1;
-                    }
-                    (quotient as u32 as u64, remainder as u32 as u64)
-                }
-            }
-            64 => {
-                if y == 0 {
-                    (u64::MAX, x)
-                } else {
-                    let mut quotient = x as i64 / y as i64;
-                    let mut remainder = x as i64 % y as i64;
-                    if (remainder < 0 && (y as i64) > 0) || (remainder > 0 && (y as i64) < 0) {
-                        remainder += y as i64;
-                        quotient -= 1;
-                    }
-                    (quotient as u64, remainder as u64)
-                }
-            }
-            _ => panic!("Unsupported WORD_SIZE: {}", WORD_SIZE),
-        };
-
         let q = ADVICEInstruction::<WORD_SIZE>(quotient).lookup_entry();
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
@@ -69,6 +45,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: v_q,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: None,
@@ -91,6 +68,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: v_r,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: None,
@@ -103,8 +81,14 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
             precompile_output_address: None,
         });
 
-        let is_valid: u64 = AssertValidSignedRemainderInstruction::<WORD_SIZE>(r, y).lookup_entry();
-        assert_eq!(is_valid, 1);
+        if AssertValidSignedRemainderInstruction::<WORD_SIZE>(r, y).lookup_entry() != 1 {
+            return Err(TraceError::InvalidRemainder {
+                opcode: RV32IM::DIV,
+                x,
+                y,
+                remainder: r,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -114,6 +98,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(r),
@@ -126,8 +111,13 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
             precompile_output_address: None,
         });
 
-        let is_valid: u64 = AssertValidDiv0Instruction::<WORD_SIZE>(y, q).lookup_entry();
-        assert_eq!(is_valid, 1);
+        if AssertValidDiv0Instruction::<WORD_SIZE>(y, q).lookup_entry() != 1 {
+            return Err(TraceError::InvalidDivByZero {
+                opcode: RV32IM::DIV,
+                x,
+                quotient: q,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -137,6 +127,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(y),
@@ -159,6 +150,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: v_qy,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q),
@@ -181,6 +173,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: v_0,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q_y),
@@ -193,7 +186,15 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
             precompile_output_address: None,
         });
 
-        let _assert_eq = BEQInstruction::<WORD_SIZE>(add_0, x).lookup_entry();
+        if BEQInstruction::<WORD_SIZE>(add_0, x).lookup_entry() != 1 {
+            return Err(TraceError::InconsistentAdvice {
+                opcode: RV32IM::DIV,
+                x,
+                y,
+                quotient: q,
+                remainder: r,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -203,6 +204,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(add_0),
@@ -224,6 +226,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
                 rd: trace_row.instruction.rd,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q),
@@ -236,28 +239,56 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_
             precompile_output_address: None,
         });
 
-        virtual_trace
+        Ok(virtual_trace)
+    }
+}
+
+impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVInstruction<WORD_SIZE> {
+    const SEQUENCE_LENGTH: usize = 8;
+
+    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        Self::try_virtual_trace(trace_row).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_virtual_trace(trace_row: RVTraceRow) -> Result<Vec<RVTraceRow>, TraceError> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        let x = trace_row.register_state.rs1_val.unwrap();
+        let y = trace_row.register_state.rs2_val.unwrap();
+        let (quotient, remainder) = signed_divrem(x, y, WORD_SIZE);
+        Self::virtual_trace_with_advice(trace_row, quotient, remainder)
     }
 
     fn sequence_output(x: u64, y: u64) -> u64 {
-        let x = x as i32;
-        let y = y as i32;
-        let mut quotient = x / y;
-        let remainder = x % y;
-        if (remainder < 0 && y > 0) || (remainder > 0 && y < 0) {
-            quotient -= 1;
-        }
-        quotient as u32 as u64
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        signed_divrem(x, y, WORD_SIZE).0
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{jolt::instruction::JoltInstruction, jolt_virtual_sequence_test};
+    use crate::jolt_virtual_sequence_test;
 
     #[test]
     fn div_virtual_sequence_32() {
         jolt_virtual_sequence_test!(DIVInstruction::<32>, RV32IM::DIV);
     }
+
+    #[test]
+    fn div_virtual_sequence_signed_operands_32() {
+        // Includes the overflowing i32::MIN / -1
+        let operands = [7i32, -7, 0, i32::MAX, i32::MIN]
+            .iter()
+            .flat_map(|x| [2i32, -2, 1, -1, i32::MAX, i32::MIN].map(|y| (*x, y)))
+            .map(|(x, y)| (x as u32 as u64, y as u32 as u64));
+        jolt_virtual_sequence_test!(DIVInstruction::<32>, RV32IM::DIV, operands: operands);
+    }
+
+    #[test]
+    fn div_virtual_sequence_div_by_zero_32() {
+        for x in [0, 1, 12345, i32::MAX as u64, 1 << 31, u32::MAX as u64] {
+            assert_eq!(DIVInstruction::<32>::sequence_output(x, 0), u32::MAX as u64);
+            jolt_virtual_sequence_test!(DIVInstruction::<32>, RV32IM::DIV, x, 0);
+        }
+    }
 }
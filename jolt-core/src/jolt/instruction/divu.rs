@@ -1,7 +1,8 @@
 use common::constants::virtual_register_index;
+use common::division::unsigned_divrem;
 use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, beq::BEQInstruction, mulu::MULUInstruction,
     virtual_advice::ADVICEInstruction, virtual_assert_lte::ASSERTLTEInstruction,
@@ -9,13 +10,18 @@ use crate::jolt::instruction::{
     virtual_assert_valid_unsigned_remainder::AssertValidUnsignedRemainderInstruction,
     JoltInstruction,
 };
+use crate::utils::errors::TraceError;
 /// Perform unsigned division and return quotient
 pub struct DIVUInstruction<const WORD_SIZE: usize>;
 
-impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD_SIZE> {
-    const SEQUENCE_LENGTH: usize = 9;
-
-    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+impl<const WORD_SIZE: usize> DIVUInstruction<WORD_SIZE> {
+    /// [`VirtualInstructionSequence::try_virtual_trace`], but with the given `quotient` and
+    /// `remainder` as the sequence's advice rather than the correct ones.
+    pub fn virtual_trace_with_advice(
+        trace_row: RVTraceRow,
+        quotient: u64,
+        remainder: u64,
+    ) -> Result<Vec<RVTraceRow>, TraceError> {
         assert_eq!(trace_row.instruction.opcode, RV32IM::DIVU);
         // DIVU source registers
         let r_x = trace_row.instruction.rs1;
@@ -31,17 +37,6 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
 
         let mut virtual_trace = vec![];
 
-        let quotient = if y == 0 {
-            match WORD_SIZE {
-                32 => u32::MAX as u64,
-                64 => u64::MAX,
-                _ => panic!("Unsupported WORD_SIZE: {}", WORD_SIZE),
-            }
-        } else {
-            x / y
-        };
-        let remainder = if y == 0 { x } else { x - quotient * y };
-
         let q = ADVICEInstruction::<WORD_SIZE>(quotient).lookup_entry();
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
@@ -52,6 +47,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: v_q,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: None,
@@ -74,6 +70,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: v_r,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: None,
@@ -96,6 +93,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: v_qy,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q),
@@ -108,8 +106,14 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
             precompile_output_address: None,
         });
 
-        let is_valid = AssertValidUnsignedRemainderInstruction::<WORD_SIZE>(r, y).lookup_entry();
-        assert_eq!(is_valid, 1);
+        if AssertValidUnsignedRemainderInstruction::<WORD_SIZE>(r, y).lookup_entry() != 1 {
+            return Err(TraceError::InvalidRemainder {
+                opcode: RV32IM::DIVU,
+                x,
+                y,
+                remainder: r,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -119,6 +123,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(r),
@@ -131,8 +136,14 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
             precompile_output_address: None,
         });
 
-        let lte = ASSERTLTEInstruction::<WORD_SIZE>(q_y, x).lookup_entry();
-        assert_eq!(lte, 1);
+        if ASSERTLTEInstruction::<WORD_SIZE>(q_y, x).lookup_entry() != 1 {
+            return Err(TraceError::QuotientOverflow {
+                opcode: RV32IM::DIVU,
+                x,
+                y,
+                quotient: q,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -142,6 +153,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q_y),
@@ -154,8 +166,13 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
             precompile_output_address: None,
         });
 
-        let is_valid = AssertValidDiv0Instruction::<WORD_SIZE>(y, q).lookup_entry();
-        assert_eq!(is_valid, 1);
+        if AssertValidDiv0Instruction::<WORD_SIZE>(y, q).lookup_entry() != 1 {
+            return Err(TraceError::InvalidDivByZero {
+                opcode: RV32IM::DIVU,
+                x,
+                quotient: q,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -165,6 +182,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(y),
@@ -187,6 +205,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: v_0,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q_y),
@@ -199,7 +218,15 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
             precompile_output_address: None,
         });
 
-        let _assert_eq = BEQInstruction::<WORD_SIZE>(add_0, x).lookup_entry();
+        if BEQInstruction::<WORD_SIZE>(add_0, x).lookup_entry() != 1 {
+            return Err(TraceError::InconsistentAdvice {
+                opcode: RV32IM::DIVU,
+                x,
+                y,
+                quotient: q,
+                remainder: r,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -209,6 +236,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(add_0),
@@ -230,6 +258,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
                 rd: trace_row.instruction.rd,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q),
@@ -242,29 +271,85 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD
             precompile_output_address: None,
         });
 
-        virtual_trace
+        Ok(virtual_trace)
+    }
+}
+
+impl<const WORD_SIZE: usize> VirtualInstructionSequence for DIVUInstruction<WORD_SIZE> {
+    const SEQUENCE_LENGTH: usize = 9;
+
+    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        Self::try_virtual_trace(trace_row).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_virtual_trace(trace_row: RVTraceRow) -> Result<Vec<RVTraceRow>, TraceError> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        let x = trace_row.register_state.rs1_val.unwrap();
+        let y = trace_row.register_state.rs2_val.unwrap();
+        let (quotient, remainder) = unsigned_divrem(x, y, WORD_SIZE);
+        Self::virtual_trace_with_advice(trace_row, quotient, remainder)
     }
 
     fn sequence_output(x: u64, y: u64) -> u64 {
-        if y == 0 {
-            match WORD_SIZE {
-                32 => u32::MAX as u64,
-                64 => u64::MAX,
-                _ => panic!("Unsupported WORD_SIZE: {}", WORD_SIZE),
-            }
-        } else {
-            x / y
-        }
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        unsigned_divrem(x, y, WORD_SIZE).0
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{jolt::instruction::JoltInstruction, jolt_virtual_sequence_test};
+    use crate::jolt::instruction::test::register_row;
+    use crate::jolt_virtual_sequence_test;
+
+    #[test]
+    fn divu_virtual_sequence_corrupted_advice_32() {
+        assert_eq!(
+            DIVUInstruction::<32>::virtual_trace_with_advice(
+                register_row(RV32IM::DIVU, 17, 5),
+                4,
+                2
+            ),
+            Err(TraceError::QuotientOverflow {
+                opcode: RV32IM::DIVU,
+                x: 17,
+                y: 5,
+                quotient: 4,
+            })
+        );
+        assert_eq!(
+            DIVUInstruction::<32>::virtual_trace_with_advice(
+                register_row(RV32IM::DIVU, 17, 0),
+                0,
+                17
+            ),
+            Err(TraceError::InvalidDivByZero {
+                opcode: RV32IM::DIVU,
+                x: 17,
+                quotient: 0,
+            })
+        );
+        assert_eq!(
+            DIVUInstruction::<32>::try_virtual_trace(register_row(RV32IM::DIVU, 17, 0))
+                .unwrap()
+                .len(),
+            DIVUInstruction::<32>::SEQUENCE_LENGTH
+        );
+    }
 
     #[test]
     fn divu_virtual_sequence_32() {
         jolt_virtual_sequence_test!(DIVUInstruction::<32>, RV32IM::DIVU);
     }
+
+    #[test]
+    fn divu_virtual_sequence_div_by_zero_32() {
+        for x in [0, 1, 12345, i32::MAX as u64, 1 << 31, u32::MAX as u64] {
+            assert_eq!(
+                DIVUInstruction::<32>::sequence_output(x, 0),
+                u32::MAX as u64
+            );
+            jolt_virtual_sequence_test!(DIVUInstruction::<32>, RV32IM::DIVU, x, 0);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use common::constants::virtual_register_index;
 use tracer::{ELFInstruction, MemoryState, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, and::ANDInstruction, sll::SLLInstruction, sra::SRAInstruction,
     xor::XORInstruction, JoltInstruction,
@@ -13,6 +13,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
     const SEQUENCE_LENGTH: usize = 7;
 
     fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         assert_eq!(trace_row.instruction.opcode, RV32IM::LB);
         let expected_rd_post_val = trace_row.register_state.rd_post_val.unwrap();
         // LB source registers
@@ -45,6 +46,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd: v_address,
                 imm: Some(offset),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(rs1_val),
@@ -69,6 +71,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd: v_word_address,
                 imm: Some(word_address_bitmask as i64),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(ram_address),
@@ -106,6 +109,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd: v_word,
                 imm: Some(0),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_address),
@@ -131,6 +135,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd: v_shift,
                 imm: Some(0b11),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(ram_address),
@@ -153,6 +158,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd: v_shift,
                 imm: Some(3),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(byte_shift),
@@ -175,6 +181,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word),
@@ -198,6 +205,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LBInstruction<WORD_S
                 rd,
                 imm: Some(24),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(left_aligned_byte),
@@ -284,6 +292,7 @@ mod test {
                     rd: Some(rd),
                     imm: Some(imm),
                     virtual_sequence_remaining: None,
+                    is_compressed: false,
                 },
                 register_state: RegisterState {
                     rs1_val: Some(rs1_val),
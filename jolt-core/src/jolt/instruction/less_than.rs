@@ -0,0 +1,85 @@
+use crate::field::JoltField;
+
+use super::{SubtableIndices, WordSize};
+use crate::jolt::subtable::{
+    eq::EqSubtable, eq_abs::EqAbsSubtable, left_msb::LeftMSBSubtable, lt_abs::LtAbsSubtable,
+    ltu::LtuSubtable, right_msb::RightMSBSubtable, LassoSubtable,
+};
+
+/// Subtables of an unsigned less-than over `C` chunks, as combined by
+/// [`combine_unsigned_lookups`]: LTU over every chunk and EQ over all but the last.
+pub fn unsigned_subtables<F: JoltField>(
+    C: usize,
+) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
+    vec![
+        (Box::new(LtuSubtable::new()), SubtableIndices::from(0..C)),
+        (Box::new(EqSubtable::new()), SubtableIndices::from(0..C - 1)),
+    ]
+}
+
+/// Subtables of a signed less-than over `C` chunks, as combined by
+/// [`combine_signed_lookups`]. The first chunk is split into its sign bits (LeftMSB,
+/// RightMSB) and the comparison of the rest of its bits (LtAbs, EqAbs); the other chunks
+/// are compared as in [`unsigned_subtables`].
+pub fn signed_subtables<F: JoltField>(
+    C: usize,
+) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
+    vec![
+        (Box::new(LeftMSBSubtable::new()), SubtableIndices::from(0)),
+        (Box::new(RightMSBSubtable::new()), SubtableIndices::from(0)),
+        (Box::new(LtuSubtable::new()), SubtableIndices::from(1..C)),
+        (Box::new(EqSubtable::new()), SubtableIndices::from(1..C - 1)),
+        (Box::new(LtAbsSubtable::new()), SubtableIndices::from(0)),
+        (Box::new(EqAbsSubtable::new()), SubtableIndices::from(0)),
+    ]
+}
+
+/// LTU(x, y) from the per-chunk `ltu` and `eq` values, most significant chunk first:
+/// `sum_i ltu[i] * prod_{j < i} eq[j]`. There is one fewer `eq` value than `ltu`
+/// values, as the last chunk's equality is never needed.
+pub fn combine_ltu<F: JoltField>(ltu: &[F], eq: &[F]) -> F {
+    debug_assert_eq!(ltu.len(), eq.len() + 1);
+    let mut sum = F::zero();
+    let mut eq_prod = F::one();
+    for i in 0..eq.len() {
+        sum += ltu[i] * eq_prod;
+        eq_prod *= eq[i];
+    }
+    // Do not need to update `eq_prod` for the last iteration
+    sum + ltu[eq.len()] * eq_prod
+}
+
+/// Combines the values of [`unsigned_subtables`] (grouped by subtable) into `x < y`.
+pub fn combine_unsigned_lookups<F: JoltField>(vals_by_subtable: &[&[F]]) -> F {
+    combine_ltu(vals_by_subtable[0], vals_by_subtable[1])
+}
+
+/// Combines the values of [`signed_subtables`] (grouped by subtable) into `x < y` for
+/// two's complement operands.
+pub fn combine_signed_lookups<F: JoltField>(vals_by_subtable: &[&[F]]) -> F {
+    let left_msb = vals_by_subtable[0][0];
+    let right_msb = vals_by_subtable[1][0];
+    let ltu = vals_by_subtable[2];
+    let eq = vals_by_subtable[3];
+    let lt_abs = vals_by_subtable[4][0];
+    let eq_abs = vals_by_subtable[5][0];
+
+    // LTU(x_{<s}, y_{<s})
+    let ltu_sum = lt_abs + eq_abs * combine_ltu(ltu, eq);
+
+    // x_s * (1 - y_s) + EQ(x_s, y_s) * LTU(x_{<s}, y_{<s})
+    left_msb * (F::one() - right_msb)
+        + (left_msb * right_msb + (F::one() - left_msb) * (F::one() - right_msb)) * ltu_sum
+}
+
+/// `x < y` for `WORD_SIZE`-bit two's complement operands.
+pub fn signed_less_than<const WORD_SIZE: usize>(x: u64, y: u64) -> bool {
+    let () = WordSize::<WORD_SIZE>::SUPPORTED;
+    if WORD_SIZE == 32 {
+        (x as i32) < (y as i32)
+    } else if WORD_SIZE == 64 {
+        (x as i64) < (y as i64)
+    } else {
+        panic!("Signed comparisons are only implemented for 32-bit or 64-bit word sizes")
+    }
+}
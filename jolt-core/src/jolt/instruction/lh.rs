@@ -1,7 +1,7 @@
 use common::constants::virtual_register_index;
 use tracer::{ELFInstruction, MemoryState, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, and::ANDInstruction, sll::SLLInstruction, sra::SRAInstruction,
     virtual_assert_aligned_memory_access::AssertAlignedMemoryAccessInstruction,
@@ -14,6 +14,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
     const SEQUENCE_LENGTH: usize = 8;
 
     fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         assert_eq!(trace_row.instruction.opcode, RV32IM::LH);
         let expected_rd_post_val = trace_row.register_state.rd_post_val.unwrap();
         // LH source registers
@@ -49,6 +50,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd: None,
                 imm: Some(offset),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(rs1_val),
@@ -71,6 +73,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd: v_address,
                 imm: Some(offset),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(rs1_val),
@@ -95,6 +98,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd: v_word_address,
                 imm: Some(word_address_bitmask as i64),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(ram_address),
@@ -132,6 +136,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd: v_word,
                 imm: Some(0),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_address),
@@ -157,6 +162,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd: v_shift,
                 imm: Some(0b10),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(ram_address),
@@ -179,6 +185,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd: v_shift,
                 imm: Some(3),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(byte_shift),
@@ -201,6 +208,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word),
@@ -225,6 +233,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for LHInstruction<WORD_S
                 rd,
                 imm: Some(16),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(left_aligned_halfword),
@@ -309,6 +318,7 @@ mod test {
                     rd: Some(rd),
                     imm: Some(imm),
                     virtual_sequence_remaining: None,
+                    is_compressed: false,
                 },
                 register_state: RegisterState {
                     rs1_val: Some(rs1_val),
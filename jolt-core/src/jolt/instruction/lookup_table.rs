@@ -0,0 +1,140 @@
+use std::ops::Range;
+
+use ark_ec::pairing::Pairing;
+
+use super::JoltInstruction;
+use crate::field::JoltField;
+use crate::msm::Icicle;
+use crate::poly::commitment::hyperkzg::{HyperKZG, HyperKZGCommitment, HyperKZGProverKey};
+use crate::poly::dense_mlpoly::DensePolynomial;
+use crate::poly::multilinear_polynomial::MultilinearPolynomial;
+use crate::utils::errors::ProofVerifyError;
+use crate::utils::transcript::Transcript;
+
+/// Materializes rows `rows` of the lookup table of an instruction whose operands are each
+/// `operand_bits` bits wide. Row `i` holds the `lookup_entry` of `instruction(x, y)`, where
+/// `x` is the high and `y` the low `operand_bits` bits of `i`, so the full table has
+/// `2^(2 * operand_bits)` rows. The number of rows must be a power of two.
+pub fn lookup_table_chunk<F, I>(
+    instruction: impl Fn(u64, u64) -> I,
+    operand_bits: usize,
+    rows: Range<usize>,
+) -> DensePolynomial<F>
+where
+    F: JoltField,
+    I: JoltInstruction,
+{
+    assert!(rows.end <= 1 << (2 * operand_bits));
+    let mask = (1 << operand_bits) - 1;
+    let table_chunk = rows
+        .map(|i| {
+            let (x, y) = ((i >> operand_bits) as u64, (i & mask) as u64);
+            F::from_u64(instruction(x, y).lookup_entry())
+        })
+        .collect();
+    DensePolynomial::new(table_chunk)
+}
+
+/// Commits to rows `rows` of an instruction's lookup table (see [`lookup_table_chunk`]),
+/// returning the commitment along with the table it commits to.
+pub fn commit_lookup_table_chunk<P, ProofTranscript, I>(
+    pp: &HyperKZGProverKey<P>,
+    instruction: impl Fn(u64, u64) -> I,
+    operand_bits: usize,
+    rows: Range<usize>,
+) -> Result<(HyperKZGCommitment<P>, MultilinearPolynomial<P::ScalarField>), ProofVerifyError>
+where
+    P: Pairing,
+    P::ScalarField: JoltField,
+    P::G1: Icicle,
+    ProofTranscript: Transcript,
+    I: JoltInstruction,
+{
+    let table =
+        MultilinearPolynomial::LargeScalars(lookup_table_chunk(instruction, operand_bits, rows));
+    let commitment = HyperKZG::<P, ProofTranscript>::commit(pp, &table)?;
+    Ok((commitment, table))
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::{Bn254, Fr};
+    use ark_std::One;
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::jolt::instruction::add::ADDInstruction;
+    use crate::poly::commitment::hyperkzg::{HyperKZGSRS, HyperKZGVerifierKey};
+    use crate::poly::multilinear_polynomial::PolynomialEvaluation;
+    use crate::utils::transcript::KeccakTranscript;
+
+    #[test]
+    fn add_lookup_table_chunk() {
+        const OPERAND_BITS: usize = 3;
+        let table = lookup_table_chunk::<Fr, _>(
+            ADDInstruction::<32>,
+            OPERAND_BITS,
+            0..1 << (2 * OPERAND_BITS),
+        );
+        for x in 0..1 << OPERAND_BITS {
+            for y in 0..1 << OPERAND_BITS {
+                assert_eq!(
+                    table[(x << OPERAND_BITS) | y],
+                    Fr::from_u64(x as u64 + y as u64)
+                );
+            }
+        }
+
+        // A chunk is the corresponding slice of the full table
+        let chunk = lookup_table_chunk::<Fr, _>(ADDInstruction::<32>, OPERAND_BITS, 16..32);
+        assert_eq!(chunk.evals_ref(), &table.evals_ref()[16..32]);
+    }
+
+    #[test]
+    fn add_lookup_table_commit_and_open() {
+        const OPERAND_BITS: usize = 3;
+        let num_vars = 2 * OPERAND_BITS;
+        let n = 1 << num_vars;
+
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(225);
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let (commitment, table) = commit_lookup_table_chunk::<_, KeccakTranscript, _>(
+            &pk,
+            ADDInstruction::<32>,
+            OPERAND_BITS,
+            0..n,
+        )
+        .unwrap();
+
+        for (x, y) in [(0u64, 0u64), (3, 4), (5, 7), (7, 7)] {
+            // Evaluations are indexed with the first variable as the most significant bit
+            let index = (x << OPERAND_BITS) | y;
+            let point = (0..num_vars)
+                .rev()
+                .map(|bit| Fr::from_u64((index >> bit) & 1))
+                .collect::<Vec<_>>();
+            let entry = Fr::from_u64(ADDInstruction::<32>(x, y).lookup_entry());
+            assert_eq!(table.evaluate(&point), entry);
+
+            let mut prover_tr = KeccakTranscript::new(b"TestLookupTable");
+            let proof = HyperKZG::open(&pk, &table, &point, &entry, &mut prover_tr).unwrap();
+            let mut verifier_tr = KeccakTranscript::new(b"TestLookupTable");
+            assert!(
+                HyperKZG::verify(&vk, &commitment, &point, &entry, &proof, &mut verifier_tr)
+                    .is_ok()
+            );
+            let mut verifier_tr = KeccakTranscript::new(b"TestLookupTable");
+            assert!(HyperKZG::verify(
+                &vk,
+                &commitment,
+                &point,
+                &(entry + Fr::one()),
+                &proof,
+                &mut verifier_tr
+            )
+            .is_err());
+        }
+    }
+}
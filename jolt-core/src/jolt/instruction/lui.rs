@@ -0,0 +1,148 @@
+use crate::field::JoltField;
+use rand::prelude::StdRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use super::{virtual_move::MOVEInstruction, JoltInstruction, SubtableIndices, WordSize};
+use crate::jolt::subtable::LassoSubtable;
+
+/// The value `imm << 12` of a 20-bit upper immediate `imm` (the low 20 bits of the operand),
+/// as a `WORD_SIZE`-bit word. On RV64 the 32-bit result is sign extended to 64 bits, so an
+/// immediate with its top bit set gives a negative value on either word size.
+pub fn upper_immediate<const WORD_SIZE: usize>(imm: u64) -> u64 {
+    let () = WordSize::<WORD_SIZE>::SUPPORTED;
+    let value = ((imm & 0xfffff) << 12) as u32;
+    if WORD_SIZE == 32 {
+        value as u64
+    } else if WORD_SIZE == 64 {
+        value as i32 as i64 as u64
+    } else {
+        panic!("Only 32-bit and 64-bit word sizes are supported");
+    }
+}
+
+/// LUI: loads the 20-bit upper immediate `imm` into the upper bits of the destination
+/// register, i.e. writes [`upper_immediate`]. As the result only depends on the immediate,
+/// its lookup is the same as MOVE's of that value.
+#[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+pub struct LUIInstruction<const WORD_SIZE: usize>(pub u64);
+
+impl<const WORD_SIZE: usize> LUIInstruction<WORD_SIZE> {
+    fn as_move(&self) -> MOVEInstruction<WORD_SIZE> {
+        MOVEInstruction(upper_immediate::<WORD_SIZE>(self.0))
+    }
+}
+
+impl<const WORD_SIZE: usize> JoltInstruction for LUIInstruction<WORD_SIZE> {
+    fn operands(&self) -> (u64, u64) {
+        self.as_move().operands()
+    }
+
+    fn combine_lookups<F: JoltField>(&self, vals: &[F], C: usize, M: usize) -> F {
+        self.as_move().combine_lookups(vals, C, M)
+    }
+
+    fn g_poly_degree(&self, C: usize) -> usize {
+        self.as_move().g_poly_degree(C)
+    }
+
+    fn subtables<F: JoltField>(
+        &self,
+        C: usize,
+        M: usize,
+    ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
+        self.as_move().subtables(C, M)
+    }
+
+    fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
+        self.as_move().to_indices(C, log_M)
+    }
+
+    fn lookup_entry(&self) -> u64 {
+        upper_immediate::<WORD_SIZE>(self.0)
+    }
+
+    fn random(&self, rng: &mut StdRng) -> Self {
+        Self(rng.next_u32() as u64 & 0xfffff)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+
+    use super::LUIInstruction;
+
+    #[test]
+    fn lui_instruction_32_e2e() {
+        let mut rng = test_rng();
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 32;
+
+        for _ in 0..256 {
+            let instruction = LUIInstruction::<WORD_SIZE>(rng.next_u32() as u64 & 0xfffff);
+            jolt_instruction_test!(instruction);
+        }
+
+        let instructions = vec![
+            LUIInstruction::<WORD_SIZE>(0),
+            LUIInstruction::<WORD_SIZE>(1),
+            LUIInstruction::<WORD_SIZE>(0x7ffff),
+            LUIInstruction::<WORD_SIZE>(0x80000),
+            LUIInstruction::<WORD_SIZE>(0xfffff),
+        ];
+        for instruction in instructions {
+            jolt_instruction_test!(instruction);
+        }
+        assert_eq!(LUIInstruction::<WORD_SIZE>(1).lookup_entry(), 0x1000);
+        assert_eq!(
+            LUIInstruction::<WORD_SIZE>(0x80000).lookup_entry(),
+            0x8000_0000
+        );
+        assert_eq!(
+            LUIInstruction::<WORD_SIZE>(0xfffff).lookup_entry(),
+            0xffff_f000
+        );
+    }
+
+    #[test]
+    fn lui_instruction_64_e2e() {
+        let mut rng = test_rng();
+        const C: usize = 8;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 64;
+
+        for _ in 0..256 {
+            let instruction = LUIInstruction::<WORD_SIZE>(rng.next_u32() as u64 & 0xfffff);
+            jolt_instruction_test!(instruction);
+        }
+
+        let instructions = vec![
+            LUIInstruction::<WORD_SIZE>(0),
+            LUIInstruction::<WORD_SIZE>(0x7ffff),
+            LUIInstruction::<WORD_SIZE>(0x80000),
+            LUIInstruction::<WORD_SIZE>(0xfffff),
+        ];
+        for instruction in instructions {
+            jolt_instruction_test!(instruction);
+        }
+        // An immediate with its top bit set is sign extended
+        assert_eq!(
+            LUIInstruction::<WORD_SIZE>(0x7ffff).lookup_entry(),
+            0x7fff_f000
+        );
+        assert_eq!(
+            LUIInstruction::<WORD_SIZE>(0x80000).lookup_entry(),
+            0xffff_ffff_8000_0000
+        );
+        assert_eq!(
+            LUIInstruction::<WORD_SIZE>(0xfffff).lookup_entry(),
+            (-0x1000i64) as u64
+        );
+    }
+}
@@ -5,10 +5,11 @@ use serde::Serialize;
 use std::marker::Sync;
 use std::ops::Range;
 use strum::{EnumCount, IntoEnumIterator};
-use tracer::{RVTraceRow, RegisterState};
+use tracer::{RVTraceRow, RegisterState, RV32IM};
 
 use crate::field::JoltField;
 use crate::jolt::subtable::LassoSubtable;
+use crate::utils::errors::TraceError;
 use crate::utils::instruction_utils::chunk_operand;
 use common::rv_trace::ELFInstruction;
 use std::fmt::Debug;
@@ -70,6 +71,21 @@ pub trait JoltInstruction: Clone + Debug + Send + Sync + Serialize {
     }
 }
 
+/// Evaluates an instruction's `lookup_entry` for many operand pairs at once, e.g. when
+/// replaying a trace. Instructions whose lookups reduce to plain word arithmetic override
+/// [`LookupEntryBatch::lookup_entry_batch`] with a loop the compiler can vectorize.
+pub trait LookupEntryBatch: JoltInstruction + Sized {
+    /// The instruction with operands `(x, y)`, as returned by `operands`.
+    fn from_operands(x: u64, y: u64) -> Self;
+
+    fn lookup_entry_batch(inputs: &[(u64, u64)]) -> Vec<u64> {
+        inputs
+            .iter()
+            .map(|&(x, y)| Self::from_operands(x, y).lookup_entry())
+            .collect()
+    }
+}
+
 pub trait JoltInstructionSet:
     JoltInstruction + IntoEnumIterator + EnumCount + for<'a> TryFrom<&'a ELFInstruction> + Send + Sync
 {
@@ -126,7 +142,13 @@ impl From<Range<usize>> for SubtableIndices {
 }
 
 pub trait VirtualInstructionSequence {
+    /// Number of rows the instruction expands to.
     const SEQUENCE_LENGTH: usize;
+    /// Same as `SEQUENCE_LENGTH`, for callers that size trace buffers without naming the
+    /// constant (e.g. through a function pointer).
+    fn virtual_sequence_len() -> usize {
+        Self::SEQUENCE_LENGTH
+    }
     fn virtual_sequence(instruction: ELFInstruction) -> Vec<ELFInstruction> {
         let dummy_trace_row = RVTraceRow {
             instruction,
@@ -140,43 +162,191 @@ pub trait VirtualInstructionSequence {
             precompile_input: None,
             precompile_output_address: None,
         };
-        Self::virtual_trace(dummy_trace_row)
+        let sequence: Vec<ELFInstruction> = Self::virtual_trace(dummy_trace_row)
             .into_iter()
             .map(|trace_row| trace_row.instruction)
-            .collect()
+            .collect();
+        debug_assert_eq!(ELFInstruction::validate_virtual_sequence(&sequence), Ok(()));
+        sequence
     }
     fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow>;
+    /// Fallible [`Self::virtual_trace`], for rows that may not come from an honest
+    /// execution. Sequences that check their advice (division and remainder) report a failed
+    /// check as a [`TraceError`] instead of panicking; the others cannot fail.
+    fn try_virtual_trace(trace_row: RVTraceRow) -> Result<Vec<RVTraceRow>, TraceError> {
+        Ok(Self::virtual_trace(trace_row))
+    }
     fn sequence_output(x: u64, y: u64) -> u64;
 }
 
+/// Panics unless `WORD_SIZE` is 32 or 64, the only word sizes the instructions implement.
+/// Evaluated in a `const` context through [`WordSize::SUPPORTED`], so instantiating an
+/// instruction with any other word size fails to compile:
+///
+/// ```compile_fail
+/// use jolt_core::jolt::instruction::{add::ADDInstruction, JoltInstruction};
+///
+/// ADDInstruction::<16>(1, 2).lookup_entry();
+/// ```
+///
+/// ```
+/// use jolt_core::jolt::instruction::{add::ADDInstruction, JoltInstruction};
+///
+/// assert_eq!(ADDInstruction::<32>(1, 2).lookup_entry(), 3);
+/// ```
+pub const fn assert_supported_word_size<const WORD_SIZE: usize>() {
+    assert!(
+        WORD_SIZE == 32 || WORD_SIZE == 64,
+        "Only 32-bit and 64-bit word sizes are supported"
+    );
+}
+
+pub struct WordSize<const WORD_SIZE: usize>;
+
+impl<const WORD_SIZE: usize> WordSize<WORD_SIZE> {
+    /// Referenced as `let () = WordSize::<WORD_SIZE>::SUPPORTED;` at the top of functions that
+    /// branch on `WORD_SIZE`, which makes [`assert_supported_word_size`] a build error for
+    /// every instantiation of them. Their runtime panics remain as a fallback.
+    pub const SUPPORTED: () = assert_supported_word_size::<WORD_SIZE>();
+}
+
+/// Builds a virtual sequence row for `ANDI`, `ORI` or `XORI`, combining the value `rs1_val`
+/// of register `rs1` with the constant `imm` and writing the result to `rd`. This lets a
+/// sequence mask or flip bits without first moving the constant into a virtual register;
+/// the row is proven by the same lookup as the corresponding register-register instruction.
+/// As in RISC-V, `imm` is sign-extended to the word size, so e.g. `ANDI` with `-4` clears the
+/// two lowest bits of the whole word.
+pub fn bitwise_immediate_row<const WORD_SIZE: usize>(
+    address: u64,
+    opcode: RV32IM,
+    rs1: Option<u64>,
+    rs1_val: u64,
+    rd: Option<u64>,
+    imm: i64,
+    virtual_sequence_remaining: usize,
+) -> RVTraceRow {
+    let () = WordSize::<WORD_SIZE>::SUPPORTED;
+    let imm_operand = if WORD_SIZE == 32 {
+        imm as u32 as u64
+    } else if WORD_SIZE == 64 {
+        imm as u64
+    } else {
+        panic!("Only 32-bit and 64-bit word sizes are supported")
+    };
+    let rd_post_val = match opcode {
+        RV32IM::ANDI => and::ANDInstruction::<WORD_SIZE>(rs1_val, imm_operand).lookup_entry(),
+        RV32IM::ORI => or::ORInstruction::<WORD_SIZE>(rs1_val, imm_operand).lookup_entry(),
+        RV32IM::XORI => xor::XORInstruction::<WORD_SIZE>(rs1_val, imm_operand).lookup_entry(),
+        _ => panic!("Not a bitwise immediate instruction: {opcode:?}"),
+    };
+    RVTraceRow {
+        instruction: ELFInstruction {
+            address,
+            opcode,
+            rs1,
+            rs2: None,
+            rd,
+            imm: Some(imm),
+            virtual_sequence_remaining: Some(virtual_sequence_remaining),
+            is_compressed: false,
+        },
+        register_state: RegisterState {
+            rs1_val: Some(rs1_val),
+            rs2_val: None,
+            rd_post_val: Some(rd_post_val),
+        },
+        memory_state: None,
+        advice_value: None,
+        precompile_input: None,
+        precompile_output_address: None,
+    }
+}
+
+/// Builds the two rows that end the RV64 `ADDW`, `SUBW` and `MULW` sequences, which
+/// sign-extend the low word of `value`, held in register `rs1`, into `rd`. `SLLI 32` moves
+/// the word into the upper half of the register and `SRAI 32` shifts it back down, copying
+/// bit 31 into the upper half.
+pub fn sign_extend_word_rows(
+    address: u64,
+    rs1: Option<u64>,
+    value: u64,
+    rd: Option<u64>,
+) -> [RVTraceRow; 2] {
+    let left_aligned_word = sll::SLLInstruction::<64>(value, 32).lookup_entry();
+    let sign_extended_word = sra::SRAInstruction::<64>(left_aligned_word, 32).lookup_entry();
+    let shift_row =
+        |opcode, rs1, rs1_val, rd, rd_post_val, virtual_sequence_remaining| RVTraceRow {
+            instruction: ELFInstruction {
+                address,
+                opcode,
+                rs1,
+                rs2: None,
+                rd,
+                imm: Some(32),
+                virtual_sequence_remaining: Some(virtual_sequence_remaining),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(rs1_val),
+                rs2_val: None,
+                rd_post_val: Some(rd_post_val),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        };
+    [
+        shift_row(RV32IM::SLLI, rs1, value, rs1, left_aligned_word, 1),
+        shift_row(
+            RV32IM::SRAI,
+            rs1,
+            left_aligned_word,
+            rd,
+            sign_extended_word,
+            0,
+        ),
+    ]
+}
+
 pub mod add;
+pub mod addw;
 pub mod and;
+pub mod auipc;
 pub mod beq;
 pub mod bge;
 pub mod bgeu;
 pub mod bne;
+pub mod compact_trace;
 pub mod div;
 pub mod divu;
 pub mod lb;
 pub mod lbu;
+pub mod less_than;
 pub mod lh;
 pub mod lhu;
+pub mod lookup_table;
+pub mod lui;
 pub mod mul;
 pub mod mulh;
 pub mod mulhsu;
 pub mod mulhu;
 pub mod mulu;
+pub mod mulw;
 pub mod or;
 pub mod rem;
 pub mod remu;
 pub mod sb;
+pub mod select;
 pub mod sh;
 pub mod sll;
 pub mod slt;
+pub mod slti;
 pub mod sltu;
 pub mod sra;
 pub mod srl;
 pub mod sub;
+pub mod subw;
 pub mod virtual_advice;
 pub mod virtual_assert_aligned_memory_access;
 pub mod virtual_assert_lte;
@@ -189,3 +359,120 @@ pub mod xor;
 
 #[cfg(test)]
 pub mod test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jolt::vm::rv32i_vm::RV32I;
+
+    const PATTERNS: [u64; 8] = [
+        0,
+        1,
+        0b11,
+        0x5555_5555_5555_5555,
+        0xAAAA_AAAA_AAAA_AAAA,
+        0x8000_0000,
+        0xFFFF_FFFC,
+        u64::MAX,
+    ];
+
+    /// 12-bit immediates, as `ANDI`, `ORI` and `XORI` take, of both signs
+    const IMMEDIATES: [i64; 8] = [0, 1, 0b11, 0x1f, 0x7ff, -1, -4, -0x800];
+
+    fn check_bitwise_immediate<const WORD_SIZE: usize>() {
+        let mask = if WORD_SIZE == 32 {
+            u32::MAX as u64
+        } else {
+            u64::MAX
+        };
+        let cases: [(RV32IM, fn(u64, u64) -> u64); 3] = [
+            (RV32IM::ANDI, |x, y| x & y),
+            (RV32IM::ORI, |x, y| x | y),
+            (RV32IM::XORI, |x, y| x ^ y),
+        ];
+        for (opcode, expected) in cases {
+            for x in PATTERNS.map(|x| x & mask) {
+                for imm in IMMEDIATES {
+                    let row = bitwise_immediate_row::<WORD_SIZE>(
+                        0x1000,
+                        opcode,
+                        Some(5),
+                        x,
+                        Some(6),
+                        imm,
+                        2,
+                    );
+                    assert_eq!(row.instruction.opcode, opcode);
+                    assert_eq!(row.instruction.rs1, Some(5));
+                    assert_eq!(row.instruction.rs2, None);
+                    assert_eq!(row.instruction.rd, Some(6));
+                    assert_eq!(row.instruction.imm, Some(imm));
+                    assert_eq!(row.imm_u32(), imm as u32);
+                    assert_eq!(row.instruction.virtual_sequence_remaining, Some(2));
+                    assert_eq!(row.register_state.rs1_val, Some(x));
+                    assert_eq!(
+                        row.register_state.rd_post_val,
+                        Some(expected(x, imm as u64 & mask)),
+                        "{opcode:?} {x:#x} {imm:#x}"
+                    );
+                    if WORD_SIZE == 32 {
+                        // The row is proven like any other ANDI/ORI/XORI in a trace
+                        assert_eq!(
+                            Some(RV32I::try_from(&row).unwrap().lookup_entry()),
+                            row.register_state.rd_post_val
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bitwise_immediate_row_32() {
+        check_bitwise_immediate::<32>();
+    }
+
+    #[test]
+    fn bitwise_immediate_row_64() {
+        check_bitwise_immediate::<64>();
+    }
+
+    fn check_negative_immediates<const WORD_SIZE: usize>() {
+        let mask = if WORD_SIZE == 32 {
+            u32::MAX as u64
+        } else {
+            u64::MAX
+        };
+        let x = 0x1234_5678_9ABC_DEF3 & mask;
+        let rd_post_val = |opcode, imm| {
+            bitwise_immediate_row::<WORD_SIZE>(0, opcode, Some(5), x, Some(6), imm, 0)
+                .register_state
+                .rd_post_val
+                .unwrap()
+        };
+        // The sign bit of the immediate reaches the top of the word
+        assert_eq!(rd_post_val(RV32IM::ANDI, -4), x & !0b11 & mask);
+        assert_eq!(rd_post_val(RV32IM::ANDI, -1), x);
+        assert_eq!(rd_post_val(RV32IM::ANDI, -0x800), x & !0x7ff & mask);
+        assert_eq!(rd_post_val(RV32IM::ORI, -1), mask);
+        assert_eq!(rd_post_val(RV32IM::ORI, -0x800), x | (!0x7ff & mask));
+        assert_eq!(rd_post_val(RV32IM::XORI, -1), !x & mask);
+        assert_eq!(rd_post_val(RV32IM::XORI, -2), (x ^ !1) & mask);
+    }
+
+    #[test]
+    fn bitwise_immediate_row_negative_32() {
+        check_negative_immediates::<32>();
+    }
+
+    #[test]
+    fn bitwise_immediate_row_negative_64() {
+        check_negative_immediates::<64>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn bitwise_immediate_row_rejects_other_opcodes() {
+        bitwise_immediate_row::<32>(0, RV32IM::ADDI, Some(1), 1, Some(2), 1, 0);
+    }
+}
@@ -3,7 +3,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{JoltInstruction, SubtableIndices};
+use super::{JoltInstruction, SubtableIndices, WordSize};
 use crate::field::JoltField;
 use crate::jolt::subtable::{identity::IdentitySubtable, LassoSubtable};
 use crate::utils::instruction_utils::{
@@ -45,6 +45,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for MULInstruction<WORD_SIZE> {
     }
 
     fn lookup_entry(&self) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             let x = self.0 as i32;
             let y = self.1 as i32;
@@ -59,6 +60,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for MULInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
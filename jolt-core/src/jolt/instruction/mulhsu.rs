@@ -1,7 +1,7 @@
 use common::constants::virtual_register_index;
 use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, mulhu::MULHUInstruction, mulu::MULUInstruction,
     virtual_movsign::MOVSIGNInstruction, JoltInstruction,
@@ -38,6 +38,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for MULHSUInstruction<WO
                 rd: v_sx,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(x),
@@ -60,6 +61,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for MULHSUInstruction<WO
                 rd: v_1,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(x),
@@ -82,6 +84,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for MULHSUInstruction<WO
                 rd: v_2,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(s_x),
@@ -104,6 +107,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for MULHSUInstruction<WO
                 rd: trace_row.instruction.rd,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(xy_high_bits),
@@ -119,6 +123,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for MULHSUInstruction<WO
     }
 
     fn sequence_output(x: u64, y: u64) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         match WORD_SIZE {
             32 => {
                 let result = ((x as i32 as i64) * (y as i64)) >> 32;
@@ -136,10 +141,26 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for MULHSUInstruction<WO
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{jolt::instruction::JoltInstruction, jolt_virtual_sequence_test};
+    use crate::jolt_virtual_sequence_test;
 
     #[test]
     fn mulhsu_virtual_sequence_32() {
         jolt_virtual_sequence_test!(MULHSUInstruction::<32>, RV32IM::MULHSU);
     }
+
+    #[test]
+    fn mulhsu_virtual_sequence_negative_32() {
+        // rs1 is signed and rs2 unsigned, so a negative rs1 times a large rs2 has a
+        // negative high word
+        let negative = [-1i32, -2, i32::MIN, -12345].map(|x| x as u32 as u64);
+        for x in negative {
+            for y in [0, 1, 7, 1 << 31, u32::MAX as u64] {
+                jolt_virtual_sequence_test!(MULHSUInstruction::<32>, RV32IM::MULHSU, x, y);
+            }
+        }
+        assert_eq!(
+            MULHSUInstruction::<32>::sequence_output(-1i32 as u32 as u64, u32::MAX as u64),
+            u32::MAX as u64
+        );
+    }
 }
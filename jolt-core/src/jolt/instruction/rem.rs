@@ -1,20 +1,26 @@
 use common::constants::virtual_register_index;
+use common::division::signed_divrem;
 use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, beq::BEQInstruction, mul::MULInstruction,
     virtual_advice::ADVICEInstruction,
     virtual_assert_valid_signed_remainder::AssertValidSignedRemainderInstruction, JoltInstruction,
 };
+use crate::utils::errors::TraceError;
 
 /// Perform signed division and return the remainder
 pub struct REMInstruction<const WORD_SIZE: usize>;
 
-impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_SIZE> {
-    const SEQUENCE_LENGTH: usize = 7;
-
-    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+impl<const WORD_SIZE: usize> REMInstruction<WORD_SIZE> {
+    /// [`VirtualInstructionSequence::try_virtual_trace`], but with the given `quotient` and
+    /// `remainder` as the sequence's advice rather than the correct ones.
+    pub fn virtual_trace_with_advice(
+        trace_row: RVTraceRow,
+        quotient: u64,
+        remainder: u64,
+    ) -> Result<Vec<RVTraceRow>, TraceError> {
         assert_eq!(trace_row.instruction.opcode, RV32IM::REM);
         // REM source registers
         let r_x = trace_row.instruction.rs1;
@@ -30,36 +36,6 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
 
         let mut virtual_trace = vec![];
 
-        let (quotient, remainder) = match WORD_SIZE {
-            32 => {
-                if y == 0 {
-                    (u32::MAX as u64, x)
-                } else {
-                    let mut quotient = x as i32 / y as i32;
-                    let mut remainder = x as i32 % y as i32;
-                    if (remainder < 0 && (y as i32) > 0) || (remainder > 0 && (y as i32) < 0) {
-                        remainder += y as i32;
-                        quotient -= 1;
-                    }
-                    (quotient as u32 as u64, remainder as u32 as u64)
-                }
-            }
-            64 => {
-                if y == 0 {
-                    (u64::MAX, x)
-                } else {
-                    let mut quotient = x as i64 / y as i64;
-                    let mut remainder = x as i64 % y as i64;
-                    if (remainder < 0 && (y as i64) > 0) || (remainder > 0 && (y as i64) < 0) {
-                        remainder += y as i64;
-                        quotient -= 1;
-                    }
-                    (quotient as u64, remainder as u64)
-                }
-            }
-            _ => panic!("Unsupported WORD_SIZE: {}", WORD_SIZE),
-        };
-
         let q = ADVICEInstruction::<WORD_SIZE>(quotient).lookup_entry();
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
@@ -70,6 +46,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: v_q,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: None,
@@ -92,6 +69,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: v_r,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: None,
@@ -104,8 +82,14 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
             precompile_output_address: None,
         });
 
-        let is_valid: u64 = AssertValidSignedRemainderInstruction::<WORD_SIZE>(r, y).lookup_entry();
-        assert_eq!(is_valid, 1);
+        if AssertValidSignedRemainderInstruction::<WORD_SIZE>(r, y).lookup_entry() != 1 {
+            return Err(TraceError::InvalidRemainder {
+                opcode: RV32IM::REM,
+                x,
+                y,
+                remainder: r,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -115,6 +99,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(r),
@@ -137,6 +122,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: v_qy,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q),
@@ -159,6 +145,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: v_0,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(q_y),
@@ -171,7 +158,15 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
             precompile_output_address: None,
         });
 
-        let _assert_eq = BEQInstruction::<WORD_SIZE>(add_0, x).lookup_entry();
+        if BEQInstruction::<WORD_SIZE>(add_0, x).lookup_entry() != 1 {
+            return Err(TraceError::InconsistentAdvice {
+                opcode: RV32IM::REM,
+                x,
+                y,
+                quotient: q,
+                remainder: r,
+            });
+        }
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
@@ -181,6 +176,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: None,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(add_0),
@@ -202,6 +198,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
                 rd: trace_row.instruction.rd,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(r),
@@ -214,37 +211,255 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_
             precompile_output_address: None,
         });
 
-        virtual_trace
+        Ok(virtual_trace)
+    }
+}
+
+impl<const WORD_SIZE: usize> VirtualInstructionSequence for REMInstruction<WORD_SIZE> {
+    const SEQUENCE_LENGTH: usize = 7;
+
+    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        Self::try_virtual_trace(trace_row).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_virtual_trace(trace_row: RVTraceRow) -> Result<Vec<RVTraceRow>, TraceError> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        let x = trace_row.register_state.rs1_val.unwrap();
+        let y = trace_row.register_state.rs2_val.unwrap();
+        let (quotient, remainder) = signed_divrem(x, y, WORD_SIZE);
+        Self::virtual_trace_with_advice(trace_row, quotient, remainder)
     }
 
     fn sequence_output(x: u64, y: u64) -> u64 {
-        match WORD_SIZE {
-            32 => {
-                let mut remainder = (x as i32) % (y as i32);
-                if (remainder < 0 && (y as i32) > 0) || (remainder > 0 && (y as i32) < 0) {
-                    remainder += y as i32;
-                }
-                remainder as u32 as u64
-            }
-            64 => {
-                let mut remainder = (x as i64) % (y as i64);
-                if (remainder < 0 && (y as i64) > 0) || (remainder > 0 && (y as i64) < 0) {
-                    remainder += y as i64;
-                }
-                remainder as u64
-            }
-            _ => panic!("Unsupported WORD_SIZE: {}", WORD_SIZE),
-        }
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        signed_divrem(x, y, WORD_SIZE).1
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{jolt::instruction::JoltInstruction, jolt_virtual_sequence_test};
+    use crate::jolt::instruction::test::{assert_virtual_sequence_matches_trace, register_row};
+    use crate::jolt_virtual_sequence_test;
 
     #[test]
     fn rem_virtual_sequence_32() {
         jolt_virtual_sequence_test!(REMInstruction::<32>, RV32IM::REM);
     }
+
+    #[test]
+    fn rem_virtual_sequence_signed_operands_32() {
+        let operands = [7i32, -7, 1, -1, i32::MAX, i32::MIN]
+            .iter()
+            .flat_map(|x| [2i32, -2, 3, -3, i32::MAX, i32::MIN].map(|y| (*x, y)))
+            .map(|(x, y)| (x as u32 as u64, y as u32 as u64));
+        jolt_virtual_sequence_test!(REMInstruction::<32>, RV32IM::REM, operands: operands);
+    }
+
+    #[test]
+    fn rem_virtual_sequence_len() {
+        let row = register_row(RV32IM::REM, 17, 5);
+        assert_eq!(
+            REMInstruction::<32>::virtual_sequence_len(),
+            REMInstruction::<32>::virtual_trace(row.clone()).len()
+        );
+        assert_eq!(
+            REMInstruction::<32>::virtual_sequence_len(),
+            REMInstruction::<32>::virtual_sequence(row.instruction).len()
+        );
+    }
+
+    #[test]
+    fn rem_virtual_sequence_div_by_zero_32() {
+        for x in [0, 1, 12345, i32::MAX as u64, 1 << 31, u32::MAX as u64] {
+            assert_eq!(REMInstruction::<32>::sequence_output(x, 0), x);
+            jolt_virtual_sequence_test!(REMInstruction::<32>, RV32IM::REM, x, 0);
+        }
+    }
+
+    #[test]
+    fn rem_virtual_sequence_overflow_32() {
+        // RISC-V defines the remainder of the overflowing division i32::MIN / -1 as 0
+        let (x, y) = (i32::MIN as u32 as u64, -1i32 as u32 as u64);
+        assert_eq!(REMInstruction::<32>::sequence_output(x, y), 0);
+        jolt_virtual_sequence_test!(REMInstruction::<32>, RV32IM::REM, x, y);
+    }
+
+    #[test]
+    fn rem_virtual_sequence_matches_trace() {
+        let operands = [
+            (17, 5),
+            (-17i32 as u32 as u64, 3),
+            (12345, 0),
+            (i32::MIN as u32 as u64, -1i32 as u32 as u64),
+            (u32::MAX as u64, i32::MAX as u64),
+        ];
+        for (x, y) in operands {
+            assert_virtual_sequence_matches_trace::<REMInstruction<32>>(register_row(
+                RV32IM::REM,
+                x,
+                y,
+            ));
+        }
+        let operands = [
+            (17, 5),
+            (-17i64 as u64, 3),
+            (12345, 0),
+            (i64::MIN as u64, -1i64 as u64),
+            (u64::MAX, i64::MAX as u64),
+        ];
+        for (x, y) in operands {
+            assert_virtual_sequence_matches_trace::<REMInstruction<64>>(register_row(
+                RV32IM::REM,
+                x,
+                y,
+            ));
+        }
+    }
+
+    #[test]
+    fn rem_virtual_sequence_corrupted_advice_32() {
+        // 17 = 3 * 5 + 2
+        assert!(REMInstruction::<32>::try_virtual_trace(register_row(RV32IM::REM, 17, 5)).is_ok());
+        assert_eq!(
+            REMInstruction::<32>::virtual_trace_with_advice(register_row(RV32IM::REM, 17, 5), 3, 7),
+            Err(TraceError::InvalidRemainder {
+                opcode: RV32IM::REM,
+                x: 17,
+                y: 5,
+                remainder: 7,
+            })
+        );
+        assert_eq!(
+            REMInstruction::<32>::virtual_trace_with_advice(register_row(RV32IM::REM, 17, 5), 4, 2),
+            Err(TraceError::InconsistentAdvice {
+                opcode: RV32IM::REM,
+                x: 17,
+                y: 5,
+                quotient: 4,
+                remainder: 2,
+            })
+        );
+        // A remainder other than the dividend is rejected by q * y + r == x when y == 0
+        assert_eq!(
+            REMInstruction::<32>::virtual_trace_with_advice(
+                register_row(RV32IM::REM, 17, 0),
+                0,
+                16
+            ),
+            Err(TraceError::InconsistentAdvice {
+                opcode: RV32IM::REM,
+                x: 17,
+                y: 0,
+                quotient: 0,
+                remainder: 16,
+            })
+        );
+    }
+
+    #[test]
+    fn rem_virtual_sequence_64() {
+        use ark_std::test_rng;
+        use rand_chacha::rand_core::RngCore;
+
+        let mut rng = test_rng();
+        let edge_cases = [
+            0,
+            1,
+            -1,
+            7,
+            -7,
+            i64::MIN,
+            i64::MIN + 1,
+            i64::MAX,
+            i64::MAX - 1,
+            i32::MIN as i64,
+            u32::MAX as i64,
+        ];
+        let operands: Vec<(u64, u64)> = edge_cases
+            .iter()
+            .flat_map(|x| edge_cases.iter().map(move |y| (*x as u64, *y as u64)))
+            .chain((0..64).map(|_| (rng.next_u64(), rng.next_u64())))
+            .chain((0..64).map(|_| (rng.next_u64() | (1 << 63), rng.next_u64() >> 40)))
+            .collect();
+
+        for &(x, y) in operands.iter() {
+            let result = REMInstruction::<64>::sequence_output(x, y);
+            if y == 0 {
+                assert_eq!(result, x);
+            } else {
+                // The remainder has the divisor's sign, is smaller than it in magnitude,
+                // and differs from the dividend by a multiple of it
+                let (x, y, r) = (x as i64 as i128, y as i64 as i128, result as i64 as i128);
+                assert!(r == 0 || (r < 0) == (y < 0), "{x} % {y} = {r}");
+                assert!(r.abs() < y.abs());
+                assert_eq!((x - r) % y, 0);
+            }
+        }
+        jolt_virtual_sequence_test!(
+            REMInstruction::<64>,
+            RV32IM::REM,
+            word_size: 64,
+            operands: operands
+        );
+    }
+
+    #[test]
+    fn rem_register_snapshots() {
+        use common::rv_trace::{first_register_divergence, register_snapshots, RegisterFile};
+
+        let (x, y) = (-17i32 as u32 as u64, 5);
+        let trace_row = register_row(RV32IM::REM, x, y);
+        let (r_x, r_y, rd) = (
+            trace_row.instruction.rs1.unwrap(),
+            trace_row.instruction.rs2.unwrap(),
+            trace_row.instruction.rd.unwrap(),
+        );
+        let trace = REMInstruction::<32>::virtual_trace(trace_row);
+
+        let mut initial: RegisterFile = [0; common::constants::REGISTER_COUNT as usize];
+        initial[r_x as usize] = x;
+        initial[r_y as usize] = y;
+        let snapshots = register_snapshots(&initial, &trace);
+        assert_eq!(snapshots.len(), trace.len());
+
+        // Each row changes at most its own `rd`, and reads the current register values
+        let mut previous = initial;
+        for (row, snapshot) in trace.iter().zip(snapshots.iter()) {
+            if let Some(rs1) = row.instruction.rs1 {
+                assert_eq!(row.register_state.rs1_val, Some(previous[rs1 as usize]));
+            }
+            if let Some(rs2) = row.instruction.rs2 {
+                assert_eq!(row.register_state.rs2_val, Some(previous[rs2 as usize]));
+            }
+            for (i, (before, after)) in previous.iter().zip(snapshot.iter()).enumerate() {
+                if Some(i as u64) != row.instruction.rd {
+                    assert_eq!(before, after);
+                }
+            }
+            previous = *snapshot;
+        }
+
+        let last = snapshots.last().unwrap();
+        assert_eq!(
+            last[rd as usize],
+            REMInstruction::<32>::sequence_output(x, y)
+        );
+        assert_eq!(last[r_x as usize], x);
+        assert_eq!(last[r_y as usize], y);
+
+        // A tampered row is pinpointed by the divergence check
+        let mut tampered = trace.clone();
+        tampered[1].register_state.rd_post_val = Some(42);
+        let tampered_snapshots = register_snapshots(&initial, &tampered);
+        assert_eq!(
+            first_register_divergence(&snapshots, &tampered_snapshots),
+            Some(2)
+        );
+        assert_eq!(first_register_divergence(&snapshots, &snapshots), None);
+        assert_eq!(
+            first_register_divergence(&snapshots, &snapshots[..3]),
+            Some(3)
+        );
+    }
 }
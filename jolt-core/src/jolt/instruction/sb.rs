@@ -1,7 +1,7 @@
 use common::constants::virtual_register_index;
 use tracer::{ELFInstruction, MemoryState, RVTraceRow, RegisterState, RV32IM};
 
-use super::VirtualInstructionSequence;
+use super::{VirtualInstructionSequence, WordSize};
 use crate::jolt::instruction::{
     add::ADDInstruction, and::ANDInstruction, sll::SLLInstruction, xor::XORInstruction,
     JoltInstruction,
@@ -13,6 +13,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
     const SEQUENCE_LENGTH: usize = 11;
 
     fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         assert_eq!(trace_row.instruction.opcode, RV32IM::SB);
         // SB source registers
         let r_dest = trace_row.instruction.rs1;
@@ -47,6 +48,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_address,
                 imm: Some(offset),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(dest),
@@ -71,6 +73,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_word_address,
                 imm: Some(word_address_bitmask as i64),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(ram_address),
@@ -111,6 +114,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_word,
                 imm: Some(0),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_address),
@@ -136,6 +140,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_shift,
                 imm: Some(3),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(ram_address),
@@ -148,21 +153,22 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
             precompile_output_address: None,
         });
 
-        // Technically such a LUI instruction isn't valid RISC-V, since the lower
-        // 12 bits of the immediate should be 0s, but this shouldn't impact soundness.
+        // Materialize the mask with `ADDI v_mask, x0, 0xff`, since LUI can only set the upper
+        // 20 bits of a register.
         let byte_mask = 0xff;
         virtual_trace.push(RVTraceRow {
             instruction: ELFInstruction {
                 address: trace_row.instruction.address,
-                opcode: RV32IM::LUI,
-                rs1: None,
+                opcode: RV32IM::ADDI,
+                rs1: Some(0),
                 rs2: None,
                 rd: v_mask,
                 imm: Some(byte_mask),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
-                rs1_val: None,
+                rs1_val: Some(0),
                 rs2_val: None,
                 rd_post_val: Some(byte_mask as u64),
             },
@@ -188,6 +194,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_mask,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(byte_mask as u64),
@@ -210,6 +217,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_byte,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(value),
@@ -236,6 +244,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_byte,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_loaded),
@@ -258,6 +267,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_byte,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_xor_halfword),
@@ -281,6 +291,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: v_word,
                 imm: None,
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_loaded),
@@ -302,6 +313,7 @@ impl<const WORD_SIZE: usize> VirtualInstructionSequence for SBInstruction<WORD_S
                 rd: None,
                 imm: Some(0),
                 virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(word_address),
@@ -396,6 +408,7 @@ mod test {
                     rd: None,
                     imm: Some(imm),
                     virtual_sequence_remaining: None,
+                    is_compressed: false,
                 },
                 register_state: RegisterState {
                     rs1_val: Some(rs1_val),
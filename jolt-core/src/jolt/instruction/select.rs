@@ -0,0 +1,270 @@
+use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
+
+use crate::jolt::instruction::{
+    add::ADDInstruction, mul::MULInstruction, sub::SUBInstruction, JoltInstruction,
+};
+
+/// Branch-free select `cond ? a : b` for a boolean `cond`, for use inside other virtual
+/// sequences (e.g. MIN/MAX or saturating arithmetic). It is computed as
+/// `b + cond * (a - b)`, which equals `cond * a + (1 - cond) * b`, after asserting that
+/// `cond * cond == cond`; modulo 2^WORD_SIZE this only holds for `cond` in {0, 1}.
+pub struct SelectInstruction<const WORD_SIZE: usize>;
+
+impl<const WORD_SIZE: usize> SelectInstruction<WORD_SIZE> {
+    pub const SEQUENCE_LENGTH: usize = 5;
+
+    pub fn select(cond: u64, a: u64, b: u64) -> u64 {
+        if cond == 0 {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// Emits the `SEQUENCE_LENGTH` rows computing the select into `rd`. Each operand is
+    /// given as its register and current value. `scratch` is a virtual register that must
+    /// not alias any of the operands; `virtual_sequence_remaining` is the number of rows
+    /// of the enclosing sequence that follow the select.
+    pub fn select_trace(
+        address: u64,
+        cond: (Option<u64>, u64),
+        a: (Option<u64>, u64),
+        b: (Option<u64>, u64),
+        rd: Option<u64>,
+        scratch: Option<u64>,
+        virtual_sequence_remaining: usize,
+    ) -> Vec<RVTraceRow> {
+        let (r_cond, cond) = cond;
+        let (r_a, a) = a;
+        let (r_b, b) = b;
+        let remaining = |virtual_trace: &Vec<RVTraceRow>| {
+            Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1 + virtual_sequence_remaining)
+        };
+
+        let mut virtual_trace = vec![];
+
+        let cond_squared = MULInstruction::<WORD_SIZE>(cond, cond).lookup_entry();
+        virtual_trace.push(RVTraceRow {
+            instruction: ELFInstruction {
+                address,
+                opcode: RV32IM::MUL,
+                rs1: r_cond,
+                rs2: r_cond,
+                rd: scratch,
+                imm: None,
+                virtual_sequence_remaining: remaining(&virtual_trace),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(cond),
+                rs2_val: Some(cond),
+                rd_post_val: Some(cond_squared),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        });
+
+        virtual_trace.push(RVTraceRow {
+            instruction: ELFInstruction {
+                address,
+                opcode: RV32IM::VIRTUAL_ASSERT_EQ,
+                rs1: scratch,
+                rs2: r_cond,
+                rd: None,
+                imm: None,
+                virtual_sequence_remaining: remaining(&virtual_trace),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(cond_squared),
+                rs2_val: Some(cond),
+                rd_post_val: None,
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        });
+
+        let diff = SUBInstruction::<WORD_SIZE>(a, b).lookup_entry();
+        virtual_trace.push(RVTraceRow {
+            instruction: ELFInstruction {
+                address,
+                opcode: RV32IM::SUB,
+                rs1: r_a,
+                rs2: r_b,
+                rd: scratch,
+                imm: None,
+                virtual_sequence_remaining: remaining(&virtual_trace),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(a),
+                rs2_val: Some(b),
+                rd_post_val: Some(diff),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        });
+
+        let masked_diff = MULInstruction::<WORD_SIZE>(cond, diff).lookup_entry();
+        virtual_trace.push(RVTraceRow {
+            instruction: ELFInstruction {
+                address,
+                opcode: RV32IM::MUL,
+                rs1: r_cond,
+                rs2: scratch,
+                rd: scratch,
+                imm: None,
+                virtual_sequence_remaining: remaining(&virtual_trace),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(cond),
+                rs2_val: Some(diff),
+                rd_post_val: Some(masked_diff),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        });
+
+        let result = ADDInstruction::<WORD_SIZE>(b, masked_diff).lookup_entry();
+        virtual_trace.push(RVTraceRow {
+            instruction: ELFInstruction {
+                address,
+                opcode: RV32IM::ADD,
+                rs1: r_b,
+                rs2: scratch,
+                rd,
+                imm: None,
+                virtual_sequence_remaining: remaining(&virtual_trace),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(b),
+                rs2_val: Some(masked_diff),
+                rd_post_val: Some(result),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        });
+
+        virtual_trace
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use ark_std::test_rng;
+    use common::constants::virtual_register_index;
+    use rand_chacha::rand_core::RngCore;
+
+    use super::*;
+
+    const R_COND: u64 = 5;
+    const R_A: u64 = 6;
+    const R_B: u64 = 7;
+    const R_D: u64 = 8;
+
+    /// Replays `trace` against a register file, checking that every row reads the
+    /// values currently held by its source registers, and applies its writes.
+    fn replay(registers: &mut HashMap<u64, u64>, trace: &[RVTraceRow]) {
+        for row in trace {
+            if let Some(rs1) = row.instruction.rs1 {
+                assert_eq!(row.register_state.rs1_val, Some(registers[&rs1]));
+            }
+            if let Some(rs2) = row.instruction.rs2 {
+                assert_eq!(row.register_state.rs2_val, Some(registers[&rs2]));
+            }
+            if row.instruction.opcode == RV32IM::VIRTUAL_ASSERT_EQ {
+                assert_eq!(row.register_state.rs1_val, row.register_state.rs2_val);
+            }
+            if let Some(rd) = row.instruction.rd {
+                registers.insert(rd, row.register_state.rd_post_val.unwrap());
+            }
+        }
+    }
+
+    fn select_test<const WORD_SIZE: usize>() {
+        let mut rng = test_rng();
+        let mask = if WORD_SIZE == 32 {
+            u32::MAX as u64
+        } else {
+            u64::MAX
+        };
+        let scratch = Some(virtual_register_index(0));
+
+        for _ in 0..64 {
+            let a = rng.next_u64() & mask;
+            let b = rng.next_u64() & mask;
+            if a == b {
+                continue;
+            }
+            for cond in [0, 1] {
+                let mut registers = HashMap::from([(R_COND, cond), (R_A, a), (R_B, b)]);
+                let trace = SelectInstruction::<WORD_SIZE>::select_trace(
+                    0x1000,
+                    (Some(R_COND), cond),
+                    (Some(R_A), a),
+                    (Some(R_B), b),
+                    Some(R_D),
+                    scratch,
+                    3,
+                );
+                assert_eq!(trace.len(), SelectInstruction::<WORD_SIZE>::SEQUENCE_LENGTH);
+                for (i, row) in trace.iter().enumerate() {
+                    assert_eq!(row.instruction.virtual_sequence_remaining, Some(3 + 4 - i));
+                }
+
+                replay(&mut registers, &trace);
+                let expected = SelectInstruction::<WORD_SIZE>::select(cond, a, b);
+                assert_eq!(expected, if cond == 1 { a } else { b });
+                assert_eq!(registers[&R_D], expected);
+                // The sources are left untouched
+                assert_eq!(registers[&R_A], a);
+                assert_eq!(registers[&R_B], b);
+            }
+        }
+    }
+
+    #[test]
+    fn select_32() {
+        select_test::<32>();
+    }
+
+    #[test]
+    fn select_64() {
+        select_test::<64>();
+    }
+
+    #[test]
+    fn select_rejects_non_boolean_condition() {
+        for cond in [2, 3, u32::MAX as u64] {
+            let trace = SelectInstruction::<32>::select_trace(
+                0x1000,
+                (Some(R_COND), cond),
+                (Some(R_A), 10),
+                (Some(R_B), 20),
+                Some(R_D),
+                Some(virtual_register_index(0)),
+                0,
+            );
+            let assert_row = &trace[1];
+            assert_eq!(assert_row.instruction.opcode, RV32IM::VIRTUAL_ASSERT_EQ);
+            assert_ne!(
+                assert_row.register_state.rs1_val,
+                assert_row.register_state.rs2_val
+            );
+        }
+    }
+}
@@ -3,13 +3,9 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{JoltInstruction, SubtableIndices};
+use super::{less_than, JoltInstruction, SubtableIndices, WordSize};
 use crate::{
-    jolt::subtable::{
-        eq::EqSubtable, eq_abs::EqAbsSubtable, left_msb::LeftMSBSubtable, lt_abs::LtAbsSubtable,
-        ltu::LtuSubtable, right_msb::RightMSBSubtable, LassoSubtable,
-    },
-    utils::instruction_utils::chunk_and_concatenate_operands,
+    jolt::subtable::LassoSubtable, utils::instruction_utils::chunk_and_concatenate_operands,
 };
 
 #[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
@@ -21,31 +17,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for SLTInstruction<WORD_SIZE> {
     }
 
     fn combine_lookups<F: JoltField>(&self, vals: &[F], C: usize, M: usize) -> F {
-        let vals_by_subtable = self.slice_values(vals, C, M);
-
-        let left_msb = vals_by_subtable[0];
-        let right_msb = vals_by_subtable[1];
-        let ltu = vals_by_subtable[2];
-        let eq = vals_by_subtable[3];
-        let lt_abs = vals_by_subtable[4];
-        let eq_abs = vals_by_subtable[5];
-
-        // Accumulator for LTU(x_{<s}, y_{<s})
-        let mut ltu_sum = lt_abs[0];
-        // Accumulator for EQ(x_{<s}, y_{<s})
-        let mut eq_prod = eq_abs[0];
-
-        for i in 0..C - 2 {
-            ltu_sum += ltu[i] * eq_prod;
-            eq_prod *= eq[i];
-        }
-        // Do not need to update `eq_prod` for the last iteration
-        ltu_sum += ltu[C - 2] * eq_prod;
-
-        // x_s * (1 - y_s) + EQ(x_s, y_s) * LTU(x_{<s}, y_{<s})
-        left_msb[0] * (F::one() - right_msb[0])
-            + (left_msb[0] * right_msb[0] + (F::one() - left_msb[0]) * (F::one() - right_msb[0]))
-                * ltu_sum
+        less_than::combine_signed_lookups(&self.slice_values(vals, C, M))
     }
 
     fn g_poly_degree(&self, C: usize) -> usize {
@@ -57,14 +29,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for SLTInstruction<WORD_SIZE> {
         C: usize,
         _: usize,
     ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
-        vec![
-            (Box::new(LeftMSBSubtable::new()), SubtableIndices::from(0)),
-            (Box::new(RightMSBSubtable::new()), SubtableIndices::from(0)),
-            (Box::new(LtuSubtable::new()), SubtableIndices::from(1..C)),
-            (Box::new(EqSubtable::new()), SubtableIndices::from(1..C - 1)),
-            (Box::new(LtAbsSubtable::new()), SubtableIndices::from(0)),
-            (Box::new(EqAbsSubtable::new()), SubtableIndices::from(0)),
-        ]
+        less_than::signed_subtables(C)
     }
 
     fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
@@ -72,20 +37,11 @@ impl<const WORD_SIZE: usize> JoltInstruction for SLTInstruction<WORD_SIZE> {
     }
 
     fn lookup_entry(&self) -> u64 {
-        if WORD_SIZE == 32 {
-            let x = self.0 as i32;
-            let y = self.1 as i32;
-            (x < y) as u64
-        } else if WORD_SIZE == 64 {
-            let x = self.0 as i64;
-            let y = self.1 as i64;
-            (x < y) as u64
-        } else {
-            panic!("SLT is only implemented for 32-bit or 64-bit word sizes")
-        }
+        less_than::signed_less_than::<WORD_SIZE>(self.0, self.1) as u64
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
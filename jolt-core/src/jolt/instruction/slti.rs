@@ -0,0 +1,186 @@
+use crate::field::JoltField;
+use rand::prelude::StdRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use super::{less_than, JoltInstruction, SubtableIndices, WordSize};
+use crate::{
+    jolt::subtable::LassoSubtable, utils::instruction_utils::chunk_and_concatenate_operands,
+};
+
+/// SLTI: whether `x < imm` as signed integers, where the 12-bit immediate `imm` is sign
+/// extended to `WORD_SIZE` bits. This is SLT with the extended immediate as its second
+/// operand, so it uses the same subtables.
+#[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SLTIInstruction<const WORD_SIZE: usize>(pub u64, pub i64);
+
+impl<const WORD_SIZE: usize> SLTIInstruction<WORD_SIZE> {
+    /// The immediate, sign extended to `WORD_SIZE` bits.
+    pub fn imm_operand(&self) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        if WORD_SIZE == 64 {
+            self.1 as u64
+        } else {
+            self.1 as u64 & ((1 << WORD_SIZE) - 1)
+        }
+    }
+}
+
+impl<const WORD_SIZE: usize> JoltInstruction for SLTIInstruction<WORD_SIZE> {
+    fn operands(&self) -> (u64, u64) {
+        (self.0, self.imm_operand())
+    }
+
+    fn combine_lookups<F: JoltField>(&self, vals: &[F], C: usize, M: usize) -> F {
+        less_than::combine_signed_lookups(&self.slice_values(vals, C, M))
+    }
+
+    fn g_poly_degree(&self, C: usize) -> usize {
+        C + 1
+    }
+
+    fn subtables<F: JoltField>(
+        &self,
+        C: usize,
+        _: usize,
+    ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
+        less_than::signed_subtables(C)
+    }
+
+    fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
+        chunk_and_concatenate_operands(self.0, self.imm_operand(), C, log_M)
+    }
+
+    fn lookup_entry(&self) -> u64 {
+        less_than::signed_less_than::<WORD_SIZE>(self.0, self.imm_operand()) as u64
+    }
+
+    fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
+        let imm = (rng.next_u32() % (1 << 12)) as i64 - (1 << 11);
+        if WORD_SIZE == 32 {
+            Self(rng.next_u32() as u64, imm)
+        } else if WORD_SIZE == 64 {
+            Self(rng.next_u64(), imm)
+        } else {
+            panic!("Only 32-bit and 64-bit word sizes are supported");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    use crate::{
+        jolt::{
+            instruction::{slt::SLTInstruction, test::register_row, JoltInstruction},
+            vm::rv32i_vm::RV32I,
+        },
+        jolt_instruction_test,
+    };
+    use tracer::RV32IM;
+
+    use super::SLTIInstruction;
+
+    #[test]
+    fn slti_instruction_32_e2e() {
+        let mut rng = test_rng();
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 32;
+
+        for _ in 0..256 {
+            let x = rng.next_u32() as u64;
+            let imm = (rng.next_u32() % (1 << 12)) as i64 - (1 << 11);
+            let instruction = SLTIInstruction::<WORD_SIZE>(x, imm);
+            jolt_instruction_test!(instruction);
+        }
+
+        let u32_max: u64 = u32::MAX as u64;
+        let instructions = vec![
+            // Equal operands
+            SLTIInstruction::<WORD_SIZE>(0, 0),
+            SLTIInstruction::<WORD_SIZE>(7, 7),
+            SLTIInstruction::<WORD_SIZE>(u32_max, -1),
+            SLTIInstruction::<WORD_SIZE>(-2048i32 as u32 as u64, -2048),
+            SLTIInstruction::<WORD_SIZE>(2047, 2047),
+            // Operands one apart
+            SLTIInstruction::<WORD_SIZE>(2046, 2047),
+            SLTIInstruction::<WORD_SIZE>(-2047i32 as u32 as u64, -2048),
+            // Sign bits differ
+            SLTIInstruction::<WORD_SIZE>(u32_max, 0),
+            SLTIInstruction::<WORD_SIZE>(0, -1),
+            SLTIInstruction::<WORD_SIZE>(1 << 31, 2047),
+            SLTIInstruction::<WORD_SIZE>((1 << 31) - 1, -2048),
+        ];
+        for instruction in instructions {
+            jolt_instruction_test!(instruction);
+            assert_eq!(
+                instruction.lookup_entry(),
+                SLTInstruction::<WORD_SIZE>(instruction.0, instruction.imm_operand())
+                    .lookup_entry()
+            );
+        }
+        assert_eq!(SLTIInstruction::<WORD_SIZE>(u32_max, 0).lookup_entry(), 1);
+        assert_eq!(SLTIInstruction::<WORD_SIZE>(0, -1).lookup_entry(), 0);
+        assert_eq!(SLTIInstruction::<WORD_SIZE>(7, 7).lookup_entry(), 0);
+    }
+
+    #[test]
+    fn slti_instruction_64_e2e() {
+        let mut rng = test_rng();
+        const C: usize = 8;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 64;
+
+        for _ in 0..256 {
+            let x = rng.next_u64();
+            let imm = (rng.next_u32() % (1 << 12)) as i64 - (1 << 11);
+            let instruction = SLTIInstruction::<WORD_SIZE>(x, imm);
+            jolt_instruction_test!(instruction);
+        }
+
+        let instructions = vec![
+            // Equal operands
+            SLTIInstruction::<WORD_SIZE>(0, 0),
+            SLTIInstruction::<WORD_SIZE>(u64::MAX, -1),
+            SLTIInstruction::<WORD_SIZE>(-2048i64 as u64, -2048),
+            // Sign bits differ
+            SLTIInstruction::<WORD_SIZE>(u64::MAX, 0),
+            SLTIInstruction::<WORD_SIZE>(0, -1),
+            SLTIInstruction::<WORD_SIZE>(1 << 63, 2047),
+            SLTIInstruction::<WORD_SIZE>((1 << 63) - 1, -2048),
+            // The upper half of the word matters
+            SLTIInstruction::<WORD_SIZE>(1 << 32, 1),
+            SLTIInstruction::<WORD_SIZE>(u32::MAX as u64, -1),
+        ];
+        for instruction in instructions {
+            jolt_instruction_test!(instruction);
+        }
+        assert_eq!(SLTIInstruction::<WORD_SIZE>(1 << 32, 1).lookup_entry(), 0);
+        assert_eq!(
+            SLTIInstruction::<WORD_SIZE>(u32::MAX as u64, -1).lookup_entry(),
+            0
+        );
+    }
+
+    #[test]
+    fn slti_trace_row() {
+        let x = -5i32 as u32 as u64;
+        for imm in [-2048, -1, 0, 7, 2047] {
+            let mut row = register_row(RV32IM::SLTI, x, 0);
+            row.instruction.imm = Some(imm);
+            let instruction = RV32I::try_from(&row).unwrap();
+            assert_eq!(instruction, RV32I::SLTI(SLTIInstruction(x, imm)));
+            assert_eq!(instruction.lookup_entry(), (-5 < imm) as u64);
+        }
+        for imm in [2048, -2049] {
+            let mut row = register_row(RV32IM::SLTI, x, 0);
+            row.instruction.imm = Some(imm);
+            assert!(RV32I::try_from(&row).is_err());
+        }
+    }
+}
@@ -3,12 +3,9 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::JoltInstruction;
+use super::{less_than, JoltInstruction, WordSize};
 use crate::{
-    jolt::{
-        instruction::SubtableIndices,
-        subtable::{eq::EqSubtable, ltu::LtuSubtable, LassoSubtable},
-    },
+    jolt::{instruction::SubtableIndices, subtable::LassoSubtable},
     utils::instruction_utils::chunk_and_concatenate_operands,
 };
 
@@ -21,19 +18,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for SLTUInstruction<WORD_SIZE> {
     }
 
     fn combine_lookups<F: JoltField>(&self, vals: &[F], C: usize, M: usize) -> F {
-        let vals_by_subtable = self.slice_values(vals, C, M);
-        let ltu = vals_by_subtable[0];
-        let eq = vals_by_subtable[1];
-
-        let mut sum = F::zero();
-        let mut eq_prod = F::one();
-
-        for i in 0..C - 1 {
-            sum += ltu[i] * eq_prod;
-            eq_prod *= eq[i];
-        }
-        // Do not need to update `eq_prod` for the last iteration
-        sum + ltu[C - 1] * eq_prod
+        less_than::combine_unsigned_lookups(&self.slice_values(vals, C, M))
     }
 
     fn g_poly_degree(&self, C: usize) -> usize {
@@ -45,10 +30,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for SLTUInstruction<WORD_SIZE> {
         C: usize,
         _: usize,
     ) -> Vec<(Box<dyn LassoSubtable<F>>, SubtableIndices)> {
-        vec![
-            (Box::new(LtuSubtable::new()), SubtableIndices::from(0..C)),
-            (Box::new(EqSubtable::new()), SubtableIndices::from(0..C - 1)),
-        ]
+        less_than::unsigned_subtables(C)
     }
 
     fn to_indices(&self, C: usize, log_M: usize) -> Vec<usize> {
@@ -61,6 +43,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for SLTUInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
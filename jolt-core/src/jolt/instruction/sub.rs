@@ -4,7 +4,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{JoltInstruction, SubtableIndices};
+use super::{JoltInstruction, SubtableIndices, WordSize};
 use crate::jolt::subtable::{identity::IdentitySubtable, LassoSubtable};
 use crate::utils::instruction_utils::{
     add_and_chunk_operands, assert_valid_parameters, concatenate_lookups,
@@ -44,13 +44,15 @@ impl<const WORD_SIZE: usize> JoltInstruction for SUBInstruction<WORD_SIZE> {
         assert_valid_parameters(WORD_SIZE, C, log_M);
         add_and_chunk_operands(
             self.0 as u128,
-            (1u128 << WORD_SIZE) - self.1 as u128,
+            // Only the low `WORD_SIZE` bits of `y` take part, so this cannot underflow
+            (1u128 << WORD_SIZE) - (self.1 as u128 & ((1u128 << WORD_SIZE) - 1)),
             C,
             log_M,
         )
     }
 
     fn lookup_entry(&self) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             (self.0 as u32).overflowing_sub(self.1 as u32).0.into()
         } else if WORD_SIZE == 64 {
@@ -61,6 +63,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for SUBInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
@@ -144,4 +147,70 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    fn sub_lookup_entry_wraps<const WORD_SIZE: usize>() {
+        let word_max: u64 = if WORD_SIZE == 32 {
+            u32::MAX as u64
+        } else {
+            u64::MAX
+        };
+        let msb: u64 = 1 << (WORD_SIZE - 1);
+
+        // (x, y, x - y mod 2^WORD_SIZE)
+        let cases = [
+            (0, 0, 0),
+            // 0 - 1 underflows to all ones
+            (0, 1, word_max),
+            (0, word_max, 1),
+            (1, 2, word_max),
+            (word_max, word_max, 0),
+            (msb, 1, msb - 1),
+            (msb - 1, msb, word_max),
+            (0, msb, msb),
+        ];
+        for (x, y, expected) in cases {
+            assert_eq!(
+                SUBInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+                expected,
+                "{x} - {y} (WORD_SIZE = {WORD_SIZE})"
+            );
+        }
+    }
+
+    #[test]
+    fn sub_lookup_entry_wraps_32() {
+        sub_lookup_entry_wraps::<32>();
+    }
+
+    #[test]
+    fn sub_lookup_entry_wraps_64() {
+        sub_lookup_entry_wraps::<64>();
+    }
+
+    #[test]
+    fn sub_64_underflow() {
+        const C: usize = 8;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 64;
+
+        // 0 - 1 wraps around to u64::MAX
+        let instruction = SUBInstruction::<WORD_SIZE>(0, 1);
+        assert_eq!(instruction.lookup_entry(), u64::MAX);
+        jolt_instruction_test!(instruction);
+
+        let instruction = SUBInstruction::<WORD_SIZE>(1, u64::MAX);
+        assert_eq!(instruction.lookup_entry(), 2);
+        jolt_instruction_test!(instruction);
+    }
+
+    #[test]
+    fn sub_32_ignores_bits_above_word() {
+        const C: usize = 4;
+        const M: usize = 1 << 16;
+        const WORD_SIZE: usize = 32;
+
+        let instruction = SUBInstruction::<WORD_SIZE>(0, (1 << 32) + 1);
+        assert_eq!(instruction.lookup_entry(), u32::MAX as u64);
+        jolt_instruction_test!(instruction);
+    }
 }
@@ -0,0 +1,132 @@
+use common::constants::virtual_register_index;
+use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
+
+use super::{sign_extend_word_rows, VirtualInstructionSequence};
+use crate::jolt::instruction::{sub::SUBInstruction, JoltInstruction};
+
+/// RV64 `SUBW`: computes the low 32 bits of `rs1 - rs2` and sign-extends them to
+/// 64 bits. Only implemented for WORD_SIZE = 64.
+pub struct SUBWInstruction<const WORD_SIZE: usize>;
+
+impl VirtualInstructionSequence for SUBWInstruction<64> {
+    const SEQUENCE_LENGTH: usize = 3;
+
+    fn virtual_trace(trace_row: RVTraceRow) -> Vec<RVTraceRow> {
+        assert_eq!(trace_row.instruction.opcode, RV32IM::SUBW);
+        // SUBW source registers
+        let r_x = trace_row.instruction.rs1;
+        let r_y = trace_row.instruction.rs2;
+        // Virtual registers used in sequence
+        let v_result = Some(virtual_register_index(0));
+        // SUBW operands
+        let x = trace_row.register_state.rs1_val.unwrap();
+        let y = trace_row.register_state.rs2_val.unwrap();
+
+        let mut virtual_trace = vec![];
+
+        let result = SUBInstruction::<64>(x, y).lookup_entry();
+        virtual_trace.push(RVTraceRow {
+            instruction: ELFInstruction {
+                address: trace_row.instruction.address,
+                opcode: RV32IM::SUB,
+                rs1: r_x,
+                rs2: r_y,
+                rd: v_result,
+                imm: None,
+                virtual_sequence_remaining: Some(Self::SEQUENCE_LENGTH - virtual_trace.len() - 1),
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(x),
+                rs2_val: Some(y),
+                rd_post_val: Some(result),
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        });
+
+        virtual_trace.extend(sign_extend_word_rows(
+            trace_row.instruction.address,
+            v_result,
+            result,
+            trace_row.instruction.rd,
+        ));
+
+        virtual_trace
+    }
+
+    fn sequence_output(x: u64, y: u64) -> u64 {
+        x.wrapping_sub(y) as i32 as i64 as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::test_rng;
+    use rand_chacha::rand_core::RngCore;
+
+    fn subw_trace(x: u64, y: u64) -> Vec<RVTraceRow> {
+        SUBWInstruction::<64>::virtual_trace(RVTraceRow {
+            instruction: ELFInstruction {
+                address: 0,
+                opcode: RV32IM::SUBW,
+                rs1: Some(1),
+                rs2: Some(2),
+                rd: Some(3),
+                imm: None,
+                virtual_sequence_remaining: None,
+                is_compressed: false,
+            },
+            register_state: RegisterState {
+                rs1_val: Some(x),
+                rs2_val: Some(y),
+                rd_post_val: None,
+            },
+            memory_state: None,
+            advice_value: None,
+            precompile_input: None,
+            precompile_output_address: None,
+        })
+    }
+
+    #[test]
+    fn subw_sign_extends() {
+        // (x, y, sign-extended 32-bit result)
+        let cases = [
+            (0, 1, u64::MAX),
+            (0x80000000, 1, 0x7fff_ffff),
+            (0x7fffffff, 0xffffffff, 0xffff_ffff_8000_0000),
+            (0x100000005, 0x200000003, 2),
+        ];
+        for (x, y, expected) in cases {
+            assert_eq!(SUBWInstruction::<64>::sequence_output(x, y), expected);
+            let trace = subw_trace(x, y);
+            let last = trace.last().unwrap();
+            assert_eq!(last.instruction.rd, Some(3));
+            assert_eq!(last.register_state.rd_post_val, Some(expected));
+        }
+    }
+
+    #[test]
+    fn subw_virtual_sequence_64() {
+        let mut rng = test_rng();
+        for _ in 0..256 {
+            let (x, y) = (rng.next_u64(), rng.next_u64());
+            let trace = subw_trace(x, y);
+            assert_eq!(trace.len(), SUBWInstruction::<64>::SEQUENCE_LENGTH);
+            // Each row reads the value written by the previous one
+            for (prev, row) in trace.iter().zip(trace.iter().skip(1)) {
+                assert_eq!(row.instruction.rs1, prev.instruction.rd);
+                assert_eq!(row.register_state.rs1_val, prev.register_state.rd_post_val);
+            }
+            let result = trace.last().unwrap().register_state.rd_post_val.unwrap();
+            assert_eq!(result, SUBWInstruction::<64>::sequence_output(x, y));
+            // Upper 32 bits are copies of bit 31
+            let expected_upper = if result & (1 << 31) != 0 { u32::MAX } else { 0 };
+            assert_eq!((result >> 32) as u32, expected_upper);
+        }
+    }
+}
@@ -1,3 +1,19 @@
+use tracer::{ELFInstruction, RVTraceRow, RegisterState, RV32IM};
+
+use super::{
+    add::ADDInstruction, and::ANDInstruction, beq::BEQInstruction, mul::MULInstruction,
+    mulhu::MULHUInstruction, mulu::MULUInstruction, or::ORInstruction, sll::SLLInstruction,
+    slt::SLTInstruction, sltu::SLTUInstruction, sra::SRAInstruction, srl::SRLInstruction,
+    sub::SUBInstruction, virtual_advice::ADVICEInstruction,
+    virtual_assert_lte::ASSERTLTEInstruction,
+    virtual_assert_valid_div0::AssertValidDiv0Instruction,
+    virtual_assert_valid_signed_remainder::AssertValidSignedRemainderInstruction,
+    virtual_assert_valid_unsigned_remainder::AssertValidUnsignedRemainderInstruction,
+    virtual_move::MOVEInstruction, virtual_movsign::MOVSIGNInstruction, xor::XORInstruction,
+    JoltInstruction, VirtualInstructionSequence,
+};
+use crate::jolt::vm::rv32i_vm::RV32I;
+
 #[macro_export]
 /// Tests the consistency of an instruction's `subtables``, `to_indices`, and `combine_lookups`
 /// methods. In detail:
@@ -30,26 +46,50 @@ macro_rules! jolt_instruction_test {
 #[macro_export]
 /// Tests the consistency and correctness of a virtual instruction sequence.
 /// In detail:
-/// 1. Sets the registers to given values for `x` and `y`.
+/// 1. Sets the registers to given values for `x` and `y` (random if not provided).
 /// 2. Constructs an `RVTraceRow` with the provided opcode and register values.
 /// 3. Generates the virtual instruction sequence using the specified instruction type.
 /// 4. Iterates over each row in the virtual sequence and validates the state changes.
 /// 5. Verifies that the registers `r_x` and `r_y` have not been modified (not clobbered).
 /// 6. Ensures that the result of the instruction sequence is correctly written to the `rd` register.
 /// 7. Checks that no unintended modifications have been made to other registers.
+///
+/// With `operands: <iterator of (x, y)>`, runs these checks for each pair of operands.
+/// With `word_size: <bits>` before the operands, the sequence is checked at that word
+/// size (32 otherwise), its lookups being replayed by `test::virtual_row_lookup_entry`.
 macro_rules! jolt_virtual_sequence_test {
-    ($instr_type:ty, $opcode:expr) => {
-        use crate::jolt::vm::rv32i_vm::RV32I;
+    ($instr_type:ty, $opcode:expr, word_size: $word_size:expr, operands: $operands:expr) => {{
+        for (x, y) in $operands {
+            $crate::jolt_virtual_sequence_test!($instr_type, $opcode, word_size: $word_size, x, y);
+        }
+    }};
+    ($instr_type:ty, $opcode:expr, word_size: $word_size:expr) => {{
+        use ark_std::test_rng;
+        use rand_chacha::rand_core::RngCore;
+
+        let mut rng = test_rng();
+        let (x, y) = if $word_size == 64 {
+            (rng.next_u64(), rng.next_u64())
+        } else {
+            (rng.next_u32() as u64, rng.next_u32() as u64)
+        };
+        $crate::jolt_virtual_sequence_test!($instr_type, $opcode, word_size: $word_size, x, y);
+    }};
+    ($instr_type:ty, $opcode:expr, word_size: $word_size:expr, $x:expr, $y:expr) => {{
         use ark_std::test_rng;
         use common::constants::REGISTER_COUNT;
         use rand_chacha::rand_core::RngCore;
 
         let mut rng = test_rng();
+        let x: u64 = $x;
+        let y: u64 = $y;
         let r_x = rng.next_u64() % 32;
-        let r_y = rng.next_u64() % 32;
+        let mut r_y = rng.next_u64() % 32;
+        if r_y == r_x && x != y {
+            // Distinct operand values cannot share a register
+            r_y = (r_x + 1) % 32;
+        }
         let rd = rng.next_u64() % 32;
-        let x = rng.next_u32() as u64;
-        let y = if r_y == r_x { x } else { rng.next_u32() as u64 };
         let result = <$instr_type>::sequence_output(x, y);
 
         let mut registers = vec![0u64; REGISTER_COUNT as usize];
@@ -65,6 +105,7 @@ macro_rules! jolt_virtual_sequence_test {
                 rd: Some(rd),
                 imm: None,
                 virtual_sequence_remaining: None,
+                is_compressed: false,
             },
             register_state: RegisterState {
                 rs1_val: Some(x),
@@ -88,8 +129,8 @@ macro_rules! jolt_virtual_sequence_test {
                 assert_eq!(registers[row.instruction.rs2.unwrap() as usize], rs2_val);
             }
 
-            let lookup = RV32I::try_from(&row).unwrap();
-            let output = lookup.lookup_entry();
+            let output =
+                $crate::jolt::instruction::test::virtual_row_lookup_entry::<{ $word_size }>(&row);
             if let Some(rd) = row.instruction.rd {
                 registers[rd as usize] = output;
                 assert_eq!(
@@ -116,5 +157,125 @@ macro_rules! jolt_virtual_sequence_test {
                 assert_eq!(*val, 0, "Other 'real' registers should not be touched");
             }
         }
-    };
+    }};
+    ($instr_type:ty, $opcode:expr, operands: $operands:expr) => {{
+        $crate::jolt_virtual_sequence_test!($instr_type, $opcode, word_size: 32, operands: $operands);
+    }};
+    ($instr_type:ty, $opcode:expr) => {{
+        $crate::jolt_virtual_sequence_test!($instr_type, $opcode, word_size: 32);
+    }};
+    ($instr_type:ty, $opcode:expr, $x:expr, $y:expr) => {{
+        $crate::jolt_virtual_sequence_test!($instr_type, $opcode, word_size: 32, $x, $y);
+    }};
+}
+
+/// A trace row of the register-register instruction `opcode`, at address `0x1000`, reading
+/// `x` from register 5 and `y` from register 6 and writing register 7, with no result
+/// recorded: the input virtual sequence tests expand.
+pub fn register_row(opcode: RV32IM, x: u64, y: u64) -> RVTraceRow {
+    RVTraceRow {
+        instruction: ELFInstruction {
+            address: 0x1000,
+            opcode,
+            rs1: Some(5),
+            rs2: Some(6),
+            rd: Some(7),
+            imm: None,
+            virtual_sequence_remaining: None,
+            is_compressed: false,
+        },
+        register_state: RegisterState {
+            rs1_val: Some(x),
+            rs2_val: Some(y),
+            rd_post_val: None,
+        },
+        memory_state: None,
+        advice_value: None,
+        precompile_input: None,
+        precompile_output_address: None,
+    }
+}
+
+/// Checks that `I::virtual_trace` and `I::virtual_sequence` expand `trace_row`'s
+/// instruction into the same instructions: position by position, each row of the trace
+/// must have the opcode, registers, immediate and `virtual_sequence_remaining` of the
+/// corresponding instruction of the sequence. The sequence is built without the row's
+/// operand values, so a trace that takes a different shape depending on its operands fails
+/// this check.
+pub fn assert_virtual_sequence_matches_trace<I: VirtualInstructionSequence>(trace_row: RVTraceRow) {
+    let sequence = I::virtual_sequence(trace_row.instruction.clone());
+    let trace = I::virtual_trace(trace_row);
+    assert_eq!(sequence.len(), I::SEQUENCE_LENGTH);
+    assert_eq!(trace.len(), I::SEQUENCE_LENGTH);
+
+    for (index, (instruction, row)) in sequence.iter().zip(trace.iter()).enumerate() {
+        let traced = &row.instruction;
+        assert_eq!(
+            (
+                traced.opcode,
+                traced.rs1,
+                traced.rs2,
+                traced.rd,
+                traced.imm,
+                traced.virtual_sequence_remaining
+            ),
+            (
+                instruction.opcode,
+                instruction.rs1,
+                instruction.rs2,
+                instruction.rd,
+                instruction.imm,
+                instruction.virtual_sequence_remaining
+            ),
+            "row {index} of the trace does not match the sequence"
+        );
+    }
+}
+
+/// The lookup output of a row of a `WORD_SIZE`-bit virtual sequence. 32-bit rows are
+/// replayed through [`RV32I`], as the prover does; there is no 64-bit instruction set, so
+/// 64-bit rows are mapped to the 64-bit instruction of their opcode here.
+pub fn virtual_row_lookup_entry<const WORD_SIZE: usize>(row: &RVTraceRow) -> u64 {
+    if WORD_SIZE == 32 {
+        return RV32I::try_from(row).unwrap().lookup_entry();
+    }
+    let x = row.register_state.rs1_val.unwrap_or(0);
+    let y = row.register_state.rs2_val.unwrap_or(0);
+    let imm = row.instruction.imm.unwrap_or(0) as u64;
+    match row.instruction.opcode {
+        RV32IM::ADD => ADDInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::SUB => SUBInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::AND => ANDInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::OR => ORInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::XOR => XORInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::SLL => SLLInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::SRL => SRLInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::SRA => SRAInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::SLT => SLTInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::SLTU => SLTUInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::ADDI => ADDInstruction::<WORD_SIZE>(x, imm).lookup_entry(),
+        RV32IM::SLLI => SLLInstruction::<WORD_SIZE>(x, imm).lookup_entry(),
+        RV32IM::SRLI => SRLInstruction::<WORD_SIZE>(x, imm).lookup_entry(),
+        RV32IM::SRAI => SRAInstruction::<WORD_SIZE>(x, imm).lookup_entry(),
+        RV32IM::MUL => MULInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::MULU => MULUInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::MULHU => MULHUInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::VIRTUAL_ADVICE => {
+            ADVICEInstruction::<WORD_SIZE>(row.advice_value.unwrap()).lookup_entry()
+        }
+        RV32IM::VIRTUAL_MOVE => MOVEInstruction::<WORD_SIZE>(x).lookup_entry(),
+        RV32IM::VIRTUAL_MOVSIGN => MOVSIGNInstruction::<WORD_SIZE>(x).lookup_entry(),
+        RV32IM::VIRTUAL_ASSERT_EQ => BEQInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::VIRTUAL_ASSERT_LTE => ASSERTLTEInstruction::<WORD_SIZE>(x, y).lookup_entry(),
+        RV32IM::VIRTUAL_ASSERT_VALID_UNSIGNED_REMAINDER => {
+            AssertValidUnsignedRemainderInstruction::<WORD_SIZE>(x, y).lookup_entry()
+        }
+        RV32IM::VIRTUAL_ASSERT_VALID_SIGNED_REMAINDER => {
+            AssertValidSignedRemainderInstruction::<WORD_SIZE>(x, y).lookup_entry()
+        }
+        RV32IM::VIRTUAL_ASSERT_VALID_DIV0 => {
+            AssertValidDiv0Instruction::<WORD_SIZE>(x, y).lookup_entry()
+        }
+        opcode => panic!("no {WORD_SIZE}-bit lookup for {opcode:?}"),
+    }
 }
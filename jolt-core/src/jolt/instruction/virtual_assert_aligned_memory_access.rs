@@ -2,7 +2,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::{JoltInstruction, SubtableIndices};
+use super::{JoltInstruction, SubtableIndices, WordSize};
 use crate::{
     field::JoltField,
     jolt::subtable::{low_bit::LowBitSubtable, LassoSubtable},
@@ -78,6 +78,7 @@ impl<const WORD_SIZE: usize, const ALIGN: usize> JoltInstruction
     }
 
     fn lookup_entry(&self) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             ((self.0 as u32 as i32 + self.1 as u32 as i32) % ALIGN as i32 == 0) as u64
         } else if WORD_SIZE == 64 {
@@ -88,6 +89,7 @@ impl<const WORD_SIZE: usize, const ALIGN: usize> JoltInstruction
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, (rng.next_u32() % (1 << 12)) as u64)
         } else if WORD_SIZE == 64 {
@@ -6,7 +6,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::JoltInstruction;
+use super::{JoltInstruction, WordSize};
 use crate::{
     jolt::{instruction::SubtableIndices, subtable::LassoSubtable},
     utils::instruction_utils::chunk_and_concatenate_operands,
@@ -14,6 +14,11 @@ use crate::{
 
 #[derive(Copy, Clone, Default, Debug, Serialize, Deserialize, PartialEq)]
 /// (divisor, quotient)
+///
+/// Enforces the RISC-V division-by-zero result in-circuit: outputs 1 iff the
+/// divisor is nonzero, or the quotient is all ones (`2^WORD_SIZE - 1`). The
+/// remainder-by-zero result (`x`) needs no separate gadget, since it is already
+/// pinned down by the `q * y + r == x` check in the REM/REMU sequences.
 pub struct AssertValidDiv0Instruction<const WORD_SIZE: usize>(pub u64, pub u64);
 
 impl<const WORD_SIZE: usize> JoltInstruction for AssertValidDiv0Instruction<WORD_SIZE> {
@@ -55,6 +60,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for AssertValidDiv0Instruction<WORD
     }
 
     fn lookup_entry(&self) -> u64 {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         let divisor = self.0;
         let quotient = self.1;
         if divisor == 0 {
@@ -69,6 +75,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for AssertValidDiv0Instruction<WORD
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
@@ -89,6 +96,28 @@ mod test {
 
     use super::AssertValidDiv0Instruction;
 
+    #[test]
+    fn assert_valid_div0_lookup_entry() {
+        // Divisor is zero: only the all-ones quotient is valid
+        assert_eq!(
+            AssertValidDiv0Instruction::<32>(0, u32::MAX as u64).lookup_entry(),
+            1
+        );
+        assert_eq!(AssertValidDiv0Instruction::<32>(0, 0).lookup_entry(), 0);
+        assert_eq!(AssertValidDiv0Instruction::<32>(0, 1).lookup_entry(), 0);
+        assert_eq!(
+            AssertValidDiv0Instruction::<64>(0, u64::MAX).lookup_entry(),
+            1
+        );
+        assert_eq!(
+            AssertValidDiv0Instruction::<64>(0, u32::MAX as u64).lookup_entry(),
+            0
+        );
+        // Divisor is nonzero: no-op
+        assert_eq!(AssertValidDiv0Instruction::<32>(7, 0).lookup_entry(), 1);
+        assert_eq!(AssertValidDiv0Instruction::<64>(7, 12345).lookup_entry(), 1);
+    }
+
     #[test]
     fn assert_valid_div0_instruction_32_e2e() {
         let mut rng = test_rng();
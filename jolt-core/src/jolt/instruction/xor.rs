@@ -4,7 +4,7 @@ use rand::prelude::StdRng;
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
 
-use super::JoltInstruction;
+use super::{JoltInstruction, LookupEntryBatch, WordSize};
 use crate::jolt::instruction::SubtableIndices;
 use crate::jolt::subtable::{xor::XorSubtable, LassoSubtable};
 use crate::utils::instruction_utils::{chunk_and_concatenate_operands, concatenate_lookups};
@@ -43,6 +43,7 @@ impl<const WORD_SIZE: usize> JoltInstruction for XORInstruction<WORD_SIZE> {
     }
 
     fn random(&self, rng: &mut StdRng) -> Self {
+        let () = WordSize::<WORD_SIZE>::SUPPORTED;
         if WORD_SIZE == 32 {
             Self(rng.next_u32() as u64, rng.next_u32() as u64)
         } else if WORD_SIZE == 64 {
@@ -53,13 +54,26 @@ impl<const WORD_SIZE: usize> JoltInstruction for XORInstruction<WORD_SIZE> {
     }
 }
 
+impl<const WORD_SIZE: usize> LookupEntryBatch for XORInstruction<WORD_SIZE> {
+    fn from_operands(x: u64, y: u64) -> Self {
+        Self(x, y)
+    }
+
+    fn lookup_entry_batch(inputs: &[(u64, u64)]) -> Vec<u64> {
+        inputs.iter().map(|(x, y)| x ^ y).collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ark_bn254::Fr;
     use ark_std::test_rng;
     use rand_chacha::rand_core::RngCore;
 
-    use crate::{jolt::instruction::JoltInstruction, jolt_instruction_test};
+    use crate::{
+        jolt::instruction::{JoltInstruction, LookupEntryBatch},
+        jolt_instruction_test,
+    };
 
     use super::XORInstruction;
 
@@ -125,4 +139,34 @@ mod test {
             jolt_instruction_test!(instruction);
         }
     }
+
+    fn xor_lookup_entry_batch<const WORD_SIZE: usize>() {
+        let mut rng = test_rng();
+        let mut inputs: Vec<(u64, u64)> =
+            (0..256).map(|_| (rng.next_u64(), rng.next_u64())).collect();
+        inputs.extend([
+            (0, 0),
+            (u64::MAX, 1),
+            (u32::MAX as u64, 1),
+            (u64::MAX, u64::MAX),
+        ]);
+
+        let batch = XORInstruction::<WORD_SIZE>::lookup_entry_batch(&inputs);
+        let expected: Vec<u64> = inputs
+            .iter()
+            .map(|&(x, y)| XORInstruction::<WORD_SIZE>(x, y).lookup_entry())
+            .collect();
+        assert_eq!(batch, expected);
+        assert!(XORInstruction::<WORD_SIZE>::lookup_entry_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn xor_lookup_entry_batch_32() {
+        xor_lookup_entry_batch::<32>();
+    }
+
+    #[test]
+    fn xor_lookup_entry_batch_64() {
+        xor_lookup_entry_batch::<64>();
+    }
 }
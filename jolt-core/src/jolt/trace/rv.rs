@@ -1,14 +1,17 @@
 use crate::jolt::instruction::and::ANDInstruction;
+use crate::jolt::instruction::auipc::AUIPCInstruction;
 use crate::jolt::instruction::beq::BEQInstruction;
 use crate::jolt::instruction::bge::BGEInstruction;
 use crate::jolt::instruction::bgeu::BGEUInstruction;
 use crate::jolt::instruction::bne::BNEInstruction;
+use crate::jolt::instruction::lui::LUIInstruction;
 use crate::jolt::instruction::mul::MULInstruction;
 use crate::jolt::instruction::mulhu::MULHUInstruction;
 use crate::jolt::instruction::mulu::MULUInstruction;
 use crate::jolt::instruction::or::ORInstruction;
 use crate::jolt::instruction::sll::SLLInstruction;
 use crate::jolt::instruction::slt::SLTInstruction;
+use crate::jolt::instruction::slti::SLTIInstruction;
 use crate::jolt::instruction::sltu::SLTUInstruction;
 use crate::jolt::instruction::sra::SRAInstruction;
 use crate::jolt::instruction::srl::SRLInstruction;
@@ -49,7 +52,7 @@ impl TryFrom<&ELFInstruction> for RV32I {
             RV32IM::SLLI  => Ok(SLLInstruction::default().into()),
             RV32IM::SRLI  => Ok(SRLInstruction::default().into()),
             RV32IM::SRAI  => Ok(SRAInstruction::default().into()),
-            RV32IM::SLTI  => Ok(SLTInstruction::default().into()),
+            RV32IM::SLTI  => Ok(SLTIInstruction::default().into()),
             RV32IM::SLTIU => Ok(SLTUInstruction::default().into()),
 
             RV32IM::BEQ  => Ok(BEQInstruction::default().into()),
@@ -61,7 +64,8 @@ impl TryFrom<&ELFInstruction> for RV32I {
 
             RV32IM::JAL   => Ok(ADDInstruction::default().into()),
             RV32IM::JALR  => Ok(ADDInstruction::default().into()),
-            RV32IM::AUIPC => Ok(ADDInstruction::default().into()),
+            RV32IM::LUI   => Ok(LUIInstruction::default().into()),
+            RV32IM::AUIPC => Ok(AUIPCInstruction::default().into()),
 
             RV32IM::MUL => Ok(MULInstruction::default().into()),
             RV32IM::MULU => Ok(MULUInstruction::default().into()),
@@ -76,6 +80,7 @@ impl TryFrom<&ELFInstruction> for RV32I {
             RV32IM::VIRTUAL_ASSERT_VALID_SIGNED_REMAINDER => Ok(AssertValidSignedRemainderInstruction::default().into()),
             RV32IM::VIRTUAL_ASSERT_VALID_DIV0 => Ok(AssertValidDiv0Instruction::default().into()),
             RV32IM::VIRTUAL_ASSERT_HALFWORD_ALIGNMENT => Ok(AssertAlignedMemoryAccessInstruction::<32, 2>::default().into()),
+            RV32IM::ADDW | RV32IM::SUBW | RV32IM::MULW => Err("RV64 word instruction must be expanded into its virtual sequence"),
 
             _ => Err("No corresponding RV32I instruction")
         }
@@ -106,7 +111,7 @@ impl TryFrom<&RVTraceRow> for RV32I {
             RV32IM::SLLI  => Ok(SLLInstruction(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
             RV32IM::SRLI  => Ok(SRLInstruction(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
             RV32IM::SRAI  => Ok(SRAInstruction(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
-            RV32IM::SLTI  => Ok(SLTInstruction(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
+            RV32IM::SLTI  => Ok(SLTIInstruction(row.register_state.rs1_val.unwrap(), slti_imm(row)?).into()),
             RV32IM::SLTIU => Ok(SLTUInstruction(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
 
             RV32IM::BEQ  => Ok(BEQInstruction(row.register_state.rs1_val.unwrap(), row.register_state.rs2_val.unwrap()).into()),
@@ -118,7 +123,8 @@ impl TryFrom<&RVTraceRow> for RV32I {
 
             RV32IM::JAL  => Ok(ADDInstruction(row.instruction.address, row.imm_u32() as u64).into()),
             RV32IM::JALR => Ok(ADDInstruction(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
-            RV32IM::AUIPC => Ok(ADDInstruction(row.instruction.address, row.imm_u32() as u64).into()),
+            RV32IM::LUI   => Ok(LUIInstruction(upper_immediate_bits(row)?).into()),
+            RV32IM::AUIPC => Ok(AUIPCInstruction(row.instruction.address, upper_immediate_bits(row)?).into()),
 
             RV32IM::MUL => Ok(MULInstruction(row.register_state.rs1_val.unwrap(), row.register_state.rs2_val.unwrap()).into()),
             RV32IM::MULU => Ok(MULUInstruction(row.register_state.rs1_val.unwrap(), row.register_state.rs2_val.unwrap()).into()),
@@ -133,8 +139,28 @@ impl TryFrom<&RVTraceRow> for RV32I {
             RV32IM::VIRTUAL_ASSERT_VALID_SIGNED_REMAINDER => Ok(AssertValidSignedRemainderInstruction(row.register_state.rs1_val.unwrap(), row.register_state.rs2_val.unwrap()).into()),
             RV32IM::VIRTUAL_ASSERT_VALID_DIV0 => Ok(AssertValidDiv0Instruction(row.register_state.rs1_val.unwrap(), row.register_state.rs2_val.unwrap()).into()),
             RV32IM::VIRTUAL_ASSERT_HALFWORD_ALIGNMENT => Ok(AssertAlignedMemoryAccessInstruction::<32, 2>(row.register_state.rs1_val.unwrap(), row.imm_u32() as u64).into()),
+            RV32IM::ADDW | RV32IM::SUBW | RV32IM::MULW => Err("RV64 word instruction must be expanded into its virtual sequence"),
 
             _ => Err("No corresponding RV32I instruction")
         }
     }
 }
+
+/// The immediate of an `SLTI` row, sign extended from the 12 bits of an I-type immediate.
+fn slti_imm(row: &RVTraceRow) -> Result<i64, &'static str> {
+    let imm = row.imm_u32() as i32 as i64;
+    if !(-(1 << 11)..1 << 11).contains(&imm) {
+        return Err("SLTI immediate does not fit in 12 bits");
+    }
+    Ok(imm)
+}
+
+/// The 20-bit upper immediate of an `LUI` or `AUIPC` row. The tracer stores the immediate
+/// already shifted into bits 31:12, so the low 12 bits must be zero.
+fn upper_immediate_bits(row: &RVTraceRow) -> Result<u64, &'static str> {
+    let imm = row.imm_u32();
+    if imm & 0xfff != 0 {
+        return Err("Upper immediate has nonzero low 12 bits");
+    }
+    Ok((imm >> 12) as u64)
+}
@@ -16,9 +16,11 @@ use crate::lasso::memory_checking::{
 use crate::poly::commitment::commitment_scheme::CommitmentScheme;
 use crate::poly::compact_polynomial::{CompactPolynomial, SmallScalar};
 use crate::poly::multilinear_polynomial::{MultilinearPolynomial, PolynomialEvaluation};
-use common::constants::{BYTES_PER_INSTRUCTION, RAM_START_ADDRESS};
+use common::constants::{INSTRUCTION_ALIGNMENT, RAM_START_ADDRESS};
 use common::rv_trace::ELFInstruction;
 
+use crate::maybe_into_par_iter;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 use super::{JoltPolynomials, JoltTraceStep};
@@ -241,10 +243,10 @@ impl<F: JoltField> BytecodePreprocessing<F> {
         let mut virtual_address = 1; // Account for no-op instruction prepended to bytecode
         for instruction in bytecode.iter_mut() {
             assert!(instruction.address >= RAM_START_ADDRESS as usize);
-            assert!(instruction.address % BYTES_PER_INSTRUCTION == 0);
+            assert!(instruction.address % INSTRUCTION_ALIGNMENT == 0);
             // Compress instruction address for more efficient commitment:
             instruction.address =
-                1 + (instruction.address - RAM_START_ADDRESS as usize) / BYTES_PER_INSTRUCTION;
+                1 + (instruction.address - RAM_START_ADDRESS as usize) / INSTRUCTION_ALIGNMENT;
             assert_eq!(
                 virtual_address_map.insert(
                     (
@@ -319,11 +321,11 @@ where
         for (step_index, step) in trace.iter_mut().enumerate() {
             if !step.bytecode_row.address.is_zero() {
                 assert!(step.bytecode_row.address >= RAM_START_ADDRESS as usize);
-                assert!(step.bytecode_row.address % BYTES_PER_INSTRUCTION == 0);
+                assert!(step.bytecode_row.address % INSTRUCTION_ALIGNMENT == 0);
                 // Compress instruction address for more efficient commitment:
                 step.bytecode_row.address = 1
                     + (step.bytecode_row.address - RAM_START_ADDRESS as usize)
-                        / BYTES_PER_INSTRUCTION;
+                        / INSTRUCTION_ALIGNMENT;
             }
 
             let virtual_address = preprocessing
@@ -526,8 +528,7 @@ where
         let v_imm: &CompactPolynomial<i64, F> = (&polynomials.v_read_write[5]).try_into().unwrap();
         let t: &CompactPolynomial<u32, F> = (&polynomials.t_read).try_into().unwrap();
 
-        let read_leaves: Vec<F> = (0..num_ops)
-            .into_par_iter()
+        let read_leaves: Vec<F> = maybe_into_par_iter!(0..num_ops)
             .map(|i| {
                 F::from_i64(v_imm[i])
                     + a[i].field_mul(gamma_terms[0])
@@ -542,8 +543,7 @@ where
             .collect();
 
         // TODO(moodlezoup): Compute write_leaves from read_leaves
-        let write_leaves: Vec<F> = (0..num_ops)
-            .into_par_iter()
+        let write_leaves: Vec<F> = maybe_into_par_iter!(0..num_ops)
             .map(|i| {
                 F::from_i64(v_imm[i])
                     + a[i].field_mul(gamma_terms[0])
@@ -567,8 +567,7 @@ where
         let v_imm: &CompactPolynomial<i64, F> =
             (&preprocessing.v_init_final[5]).try_into().unwrap();
 
-        let init_leaves: Vec<F> = (0..bytecode_size)
-            .into_par_iter()
+        let init_leaves: Vec<F> = maybe_into_par_iter!(0..bytecode_size)
             .map(|i| {
                 F::from_i64(v_imm[i])
                     + (i as u64).field_mul(gamma_terms[0])
@@ -584,8 +583,7 @@ where
 
         // TODO(moodlezoup): Compute final_leaves from init_leaves
         let t_final: &CompactPolynomial<u32, F> = (&polynomials.t_final).try_into().unwrap();
-        let final_leaves: Vec<F> = (0..bytecode_size)
-            .into_par_iter()
+        let final_leaves: Vec<F> = maybe_into_par_iter!(0..bytecode_size)
             .map(|i| {
                 F::from_i64(v_imm[i])
                     + (i as u64).field_mul(gamma_terms[0])
@@ -734,7 +732,7 @@ mod tests {
     }
 
     fn to_ram_address(index: usize) -> usize {
-        index * BYTES_PER_INSTRUCTION + RAM_START_ADDRESS as usize
+        index * INSTRUCTION_ALIGNMENT + RAM_START_ADDRESS as usize
     }
 
     #[test]
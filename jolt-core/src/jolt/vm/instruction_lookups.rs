@@ -6,9 +6,12 @@ use crate::poly::opening_proof::{ProverOpeningAccumulator, VerifierOpeningAccumu
 use crate::subprotocols::grand_product::BatchedGrandProduct;
 use crate::subprotocols::sparse_grand_product::ToggledBatchedGrandProduct;
 use crate::utils::thread::unsafe_allocate_zero_vec;
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
+use crate::{maybe_into_par_iter, maybe_par_iter, maybe_par_iter_mut, utils::MaybeParReduce};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use itertools::{interleave, Itertools};
-use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::marker::PhantomData;
 use tracing::trace_span;
@@ -204,8 +207,7 @@ where
 
         let num_lookups = polynomials.dim[0].len();
 
-        let read_write_leaves: Vec<_> = (0..preprocessing.num_memories)
-            .into_par_iter()
+        let read_write_leaves: Vec<_> = maybe_into_par_iter!(0..preprocessing.num_memories)
             .flat_map_iter(|memory_index| {
                 let dim_index = preprocessing.memory_to_dimension_index[memory_index];
 
@@ -232,9 +234,7 @@ where
             })
             .collect();
 
-        let init_final_leaves: Vec<F> = preprocessing
-            .materialized_subtables
-            .par_iter()
+        let init_final_leaves: Vec<F> = maybe_par_iter!(preprocessing.materialized_subtables)
             .enumerate()
             .flat_map_iter(|(subtable_index, subtable)| {
                 let mut leaves: Vec<F> = unsafe_allocate_zero_vec(
@@ -361,20 +361,17 @@ where
             multiset_hashes.final_hashes.len(),
             preprocessing.num_memories
         );
-        (0..preprocessing.num_memories)
-            .into_par_iter()
-            .for_each(|i| {
-                let read_hash = multiset_hashes.read_hashes[i];
-                let write_hash = multiset_hashes.write_hashes[i];
-                let init_hash =
-                    multiset_hashes.init_hashes[preprocessing.memory_to_subtable_index[i]];
-                let final_hash = multiset_hashes.final_hashes[i];
-                assert_eq!(
-                    init_hash * write_hash,
-                    final_hash * read_hash,
-                    "Multiset hashes don't match"
-                );
-            });
+        maybe_into_par_iter!(0..preprocessing.num_memories).for_each(|i| {
+            let read_hash = multiset_hashes.read_hashes[i];
+            let write_hash = multiset_hashes.write_hashes[i];
+            let init_hash = multiset_hashes.init_hashes[preprocessing.memory_to_subtable_index[i]];
+            let final_hash = multiset_hashes.final_hashes[i];
+            assert_eq!(
+                init_hash * write_hash,
+                final_hash * read_hash,
+                "Multiset hashes don't match"
+            );
+        });
     }
 
     fn protocol_name() -> &'static [u8] {
@@ -874,8 +871,7 @@ where
             MultilinearPolynomial<F>,
             MultilinearPolynomial<F>,
             MultilinearPolynomial<F>,
-        )> = (0..preprocessing.num_memories)
-            .into_par_iter()
+        )> = maybe_into_par_iter!(0..preprocessing.num_memories)
             .map(|memory_index| {
                 let dim_index = preprocessing.memory_to_dimension_index[memory_index];
                 let subtable_index = preprocessing.memory_to_subtable_index[memory_index];
@@ -926,8 +922,7 @@ where
             },
         );
 
-        let dim: Vec<MultilinearPolynomial<F>> = subtable_lookup_indices
-            .into_par_iter()
+        let dim: Vec<MultilinearPolynomial<F>> = maybe_into_par_iter!(subtable_lookup_indices)
             .map(MultilinearPolynomial::from)
             .collect();
 
@@ -939,10 +934,10 @@ where
             }
         }
 
-        let instruction_flag_polys: Vec<MultilinearPolynomial<F>> = instruction_flag_bitvectors
-            .into_par_iter()
-            .map(MultilinearPolynomial::from)
-            .collect();
+        let instruction_flag_polys: Vec<MultilinearPolynomial<F>> =
+            maybe_into_par_iter!(instruction_flag_bitvectors)
+                .map(MultilinearPolynomial::from)
+                .collect();
 
         let mut lookup_outputs = Self::compute_lookup_outputs(ops);
         lookup_outputs.resize(m, 0);
@@ -1028,10 +1023,9 @@ where
             // Bind all polys
             let _bind_span = trace_span!("bind");
             let _bind_enter = _bind_span.enter();
-            flag_polys
-                .par_iter_mut()
-                .chain(memory_polys.par_iter_mut())
-                .chain([&mut eq_poly, lookup_outputs_poly].into_par_iter())
+            maybe_par_iter_mut!(flag_polys)
+                .chain(maybe_par_iter_mut!(memory_polys))
+                .chain(maybe_into_par_iter!([&mut eq_poly, lookup_outputs_poly]))
                 .for_each(|poly| poly.bind(r_j, BindingOrder::LowToHigh));
         } // End rounds
 
@@ -1073,8 +1067,7 @@ where
         let mle_len = eq_poly.len();
         let mle_half = mle_len / 2;
 
-        let mut evaluations: Vec<F> = (0..mle_half)
-            .into_par_iter()
+        let mut evaluations: Vec<F> = maybe_into_par_iter!(0..mle_half)
             .map(|i| {
                 let eq_evals = eq_poly.sumcheck_evals(i, degree, BindingOrder::LowToHigh);
                 let output_evals =
@@ -1122,7 +1115,7 @@ where
                     .collect();
                 evaluations
             })
-            .reduce(
+            .reduce_with_identity(
                 || vec![F::zero(); degree],
                 |running, new| {
                     debug_assert_eq!(running.len(), new.len());
@@ -1188,8 +1181,7 @@ where
     ) -> Vec<Vec<usize>> {
         let m = instruction_flag_polys[0].coeffs.len();
 
-        (0..preprocessing.num_memories)
-            .into_par_iter()
+        maybe_into_par_iter!(0..preprocessing.num_memories)
             .map(|memory_index| {
                 let instruction_indices: Vec<_> = (0..Self::NUM_INSTRUCTIONS)
                     .filter(|instruction_index| {
@@ -1254,8 +1246,7 @@ where
 
     #[tracing::instrument(skip_all, name = "InstructionLookupsProof::compute_lookup_outputs")]
     fn compute_lookup_outputs(instructions: &Vec<JoltTraceStep<InstructionSet>>) -> Vec<u32> {
-        instructions
-            .par_iter()
+        maybe_par_iter!(instructions)
             .map(|op| {
                 if let Some(instr) = &op.instruction_lookup {
                     instr.lookup_entry() as u32
@@ -28,6 +28,7 @@ use crate::jolt::{
 use crate::lasso::memory_checking::{
     Initializable, MemoryCheckingProver, MemoryCheckingVerifier, StructuredPolynomialData,
 };
+use crate::maybe_join;
 use crate::msm::icicle;
 use crate::poly::commitment::commitment_scheme::CommitmentScheme;
 use crate::r1cs::inputs::{ConstraintInput, R1CSPolynomials, R1CSProof, R1CSStuff};
@@ -316,20 +317,37 @@ where
 
         let bytecode_rows: Vec<BytecodeRow> = bytecode
             .into_iter()
-            .flat_map(|instruction| match instruction.opcode {
-                tracer::RV32IM::MULH => MULHInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::MULHSU => MULHSUInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::DIV => DIVInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::DIVU => DIVUInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::REM => REMInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::REMU => REMUInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::SH => SHInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::SB => SBInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::LBU => LBUInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::LHU => LHUInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::LB => LBInstruction::<32>::virtual_sequence(instruction),
-                tracer::RV32IM::LH => LHInstruction::<32>::virtual_sequence(instruction),
-                _ => vec![instruction],
+            .flat_map(|instruction| {
+                let is_compressed = instruction.is_compressed;
+                let mut expanded = match instruction.opcode {
+                    tracer::RV32IM::MULH => MULHInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::MULHSU => {
+                        MULHSUInstruction::<32>::virtual_sequence(instruction)
+                    }
+                    tracer::RV32IM::DIV => DIVInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::DIVU => DIVUInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::REM => REMInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::REMU => REMUInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::SH => SHInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::SB => SBInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::LBU => LBUInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::LHU => LHUInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::LB => LBInstruction::<32>::virtual_sequence(instruction),
+                    tracer::RV32IM::LH => LHInstruction::<32>::virtual_sequence(instruction),
+                    opcode @ (tracer::RV32IM::ADDW
+                    | tracer::RV32IM::SUBW
+                    | tracer::RV32IM::MULW) => {
+                        panic!(
+                            "{opcode:?} is an RV64 instruction, which the RV32I VM does not support"
+                        )
+                    }
+                    _ => vec![instruction],
+                };
+                // Rows of a virtual sequence advance the PC like the instruction they replace
+                for instruction in expanded.iter_mut() {
+                    instruction.is_compressed = is_compressed;
+                }
+                expanded
             })
             .map(|instruction| BytecodeRow::from_instruction::<Self::InstructionSet>(&instruction))
             .collect();
@@ -412,7 +430,7 @@ where
             &trace,
         );
 
-        let (bytecode_polynomials, range_check_polys) = rayon::join(
+        let (bytecode_polynomials, range_check_polys) = maybe_join!(
             || {
                 BytecodeProof::<F, PCS, ProofTranscript>::generate_witness(
                     &preprocessing.bytecode,
@@ -7,6 +7,8 @@ use crate::poly::compact_polynomial::{CompactPolynomial, SmallScalar};
 use crate::poly::multilinear_polynomial::{MultilinearPolynomial, PolynomialEvaluation};
 use crate::poly::opening_proof::{ProverOpeningAccumulator, VerifierOpeningAccumulator};
 use crate::utils::thread::unsafe_allocate_zero_vec;
+use crate::{maybe_into_par_iter, maybe_par_iter_mut};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 #[cfg(test)]
 use std::collections::HashSet;
@@ -242,7 +244,7 @@ impl<F: JoltField> ExogenousOpenings<F> for RegisterAddressOpenings<F> {
 fn map_to_polys<F: JoltField, const N: usize>(
     vals: [Vec<u32>; N],
 ) -> [MultilinearPolynomial<F>; N] {
-    vals.into_par_iter()
+    maybe_into_par_iter!(vals)
         .map(MultilinearPolynomial::from)
         .collect::<Vec<MultilinearPolynomial<F>>>()
         .try_into()
@@ -562,8 +564,7 @@ where
         let mut read_write_leaves: Vec<F> =
             unsafe_allocate_zero_vec(2 * MEMORY_OPS_PER_INSTRUCTION * num_ops);
         for (i, chunk) in read_write_leaves.chunks_mut(2 * num_ops).enumerate() {
-            chunk[..num_ops]
-                .par_iter_mut()
+            maybe_par_iter_mut!(chunk[..num_ops])
                 .enumerate()
                 .for_each(|(j, read_fingerprint)| {
                     match i {
@@ -595,8 +596,9 @@ where
                     };
                 });
 
-            chunk[num_ops..].par_iter_mut().enumerate().for_each(
-                |(j, write_fingerprint)| match i {
+            maybe_par_iter_mut!(chunk[num_ops..])
+                .enumerate()
+                .for_each(|(j, write_fingerprint)| match i {
                     RS1 => {
                         *write_fingerprint = (j as u64).field_mul(gamma_squared)
                             + v_read_rs1[j].field_mul(gamma)
@@ -622,21 +624,18 @@ where
                             - *tau;
                     }
                     _ => unreachable!(),
-                },
-            );
+                });
         }
 
         let v_init: &CompactPolynomial<u32, F> =
             polynomials.v_init.as_ref().unwrap().try_into().unwrap();
-        let init_fingerprints: Vec<F> = (0..memory_size)
-            .into_par_iter()
+        let init_fingerprints: Vec<F> = maybe_into_par_iter!(0..memory_size)
             .map(|i| /* 0 * gamma^2 + */ v_init[i].field_mul(gamma) + F::from_u32(i as u32) - *tau)
             .collect();
 
         let v_final: &CompactPolynomial<u32, F> = (&polynomials.v_final).try_into().unwrap();
         let t_final: &CompactPolynomial<u32, F> = (&polynomials.t_final).try_into().unwrap();
-        let final_fingerprints = (0..memory_size)
-            .into_par_iter()
+        let final_fingerprints = maybe_into_par_iter!(0..memory_size)
             .map(|i| {
                 t_final[i].field_mul(gamma_squared)
                     + v_final[i].field_mul(gamma)
@@ -20,10 +20,11 @@ use strum_macros::{EnumCount as EnumCountMacro, EnumIter};
 
 use super::{Jolt, JoltCommitments, JoltProof};
 use crate::jolt::instruction::{
-    add::ADDInstruction, and::ANDInstruction, beq::BEQInstruction, bge::BGEInstruction,
-    bgeu::BGEUInstruction, bne::BNEInstruction, mul::MULInstruction, mulhu::MULHUInstruction,
-    mulu::MULUInstruction, or::ORInstruction, sll::SLLInstruction, slt::SLTInstruction,
-    sltu::SLTUInstruction, sra::SRAInstruction, srl::SRLInstruction, sub::SUBInstruction,
+    add::ADDInstruction, and::ANDInstruction, auipc::AUIPCInstruction, beq::BEQInstruction,
+    bge::BGEInstruction, bgeu::BGEUInstruction, bne::BNEInstruction, lui::LUIInstruction,
+    mul::MULInstruction, mulhu::MULHUInstruction, mulu::MULUInstruction, or::ORInstruction,
+    sll::SLLInstruction, slt::SLTInstruction, slti::SLTIInstruction, sltu::SLTUInstruction,
+    sra::SRAInstruction, srl::SRLInstruction, sub::SUBInstruction,
     virtual_advice::ADVICEInstruction, virtual_assert_lte::ASSERTLTEInstruction,
     virtual_assert_valid_signed_remainder::AssertValidSignedRemainderInstruction,
     virtual_movsign::MOVSIGNInstruction, xor::XORInstruction, JoltInstruction, JoltInstructionSet,
@@ -117,6 +118,7 @@ instruction_set!(
   BNE: BNEInstruction<WORD_SIZE>,
   SLT: SLTInstruction<WORD_SIZE>,
   SLTU: SLTUInstruction<WORD_SIZE>,
+  SLTI: SLTIInstruction<WORD_SIZE>,
   SLL: SLLInstruction<WORD_SIZE>,
   SRA: SRAInstruction<WORD_SIZE>,
   SRL: SRLInstruction<WORD_SIZE>,
@@ -124,6 +126,8 @@ instruction_set!(
   MUL: MULInstruction<WORD_SIZE>,
   MULU: MULUInstruction<WORD_SIZE>,
   MULHU: MULHUInstruction<WORD_SIZE>,
+  LUI: LUIInstruction<WORD_SIZE>,
+  AUIPC: AUIPCInstruction<WORD_SIZE>,
   VIRTUAL_ADVICE: ADVICEInstruction<WORD_SIZE>,
   VIRTUAL_MOVE: MOVEInstruction<WORD_SIZE>,
   VIRTUAL_ASSERT_LTE: ASSERTLTEInstruction<WORD_SIZE>,
@@ -245,7 +249,8 @@ mod tests {
     use crate::field::JoltField;
     use crate::host;
     use crate::jolt::instruction::JoltInstruction;
-    use crate::jolt::vm::rv32i_vm::{Jolt, RV32IJoltVM, C, M};
+    use crate::jolt::vm::bytecode::BytecodeRow;
+    use crate::jolt::vm::rv32i_vm::{Jolt, RV32IJoltVM, C, M, RV32I};
     use crate::poly::commitment::commitment_scheme::CommitmentScheme;
     use crate::poly::commitment::hyperkzg::HyperKZG;
     use crate::poly::commitment::mock::MockCommitScheme;
@@ -288,7 +293,7 @@ mod tests {
         test_instruction_set_subtables::<HyperKZG<Bn254, KeccakTranscript>, KeccakTranscript>();
     }
 
-    fn fib_e2e<F, PCS, ProofTranscript>()
+    fn fib_e2e<F, PCS, ProofTranscript>(n: u32)
     where
         F: JoltField,
         PCS: CommitmentScheme<ProofTranscript, Field = F>,
@@ -296,7 +301,7 @@ mod tests {
     {
         let artifact_guard = FIB_FILE_LOCK.lock().unwrap();
         let mut program = host::Program::new("fibonacci-guest");
-        program.set_input(&9u32);
+        program.set_input(&n);
         let (bytecode, memory_init) = program.decode();
         let (io_device, trace) = program.trace();
         drop(artifact_guard);
@@ -326,17 +331,25 @@ mod tests {
 
     #[test]
     fn fib_e2e_mock() {
-        fib_e2e::<Fr, MockCommitScheme<Fr, KeccakTranscript>, KeccakTranscript>();
+        fib_e2e::<Fr, MockCommitScheme<Fr, KeccakTranscript>, KeccakTranscript>(9);
     }
 
     #[test]
     fn fib_e2e_zeromorph() {
-        fib_e2e::<Fr, Zeromorph<Bn254, KeccakTranscript>, KeccakTranscript>();
+        fib_e2e::<Fr, Zeromorph<Bn254, KeccakTranscript>, KeccakTranscript>(9);
     }
 
     #[test]
     fn fib_e2e_hyperkzg() {
-        fib_e2e::<Fr, HyperKZG<Bn254, KeccakTranscript>, KeccakTranscript>();
+        fib_e2e::<Fr, HyperKZG<Bn254, KeccakTranscript>, KeccakTranscript>(9);
+    }
+
+    #[test]
+    fn small_e2e_hyperkzg_commitment_scheme() {
+        // The smallest VM proof, with the VM instantiated over HyperKZG only through
+        // `CommitmentScheme`; see also `test_hyperkzg_commitment_scheme`, which drives the
+        // same trait surface without the VM.
+        fib_e2e::<Fr, HyperKZG<Bn254, KeccakTranscript>, KeccakTranscript>(1);
     }
 
     // TODO(sragss): Finish Binius.
@@ -453,6 +466,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compressed_fib_e2e_hyperkzg() {
+        let artifact_guard = FIB_FILE_LOCK.lock().unwrap();
+        let mut program = host::Program::new("fibonacci-guest");
+        program.set_compressed(true);
+        program.set_input(&9u32);
+        let (bytecode, memory_init) = program.decode();
+        let (io_device, trace) = program.trace();
+        drop(artifact_guard);
+
+        // The guest mixes 2-byte and 4-byte instructions
+        assert!(bytecode.iter().any(|instruction| instruction.is_compressed));
+        assert!(bytecode
+            .iter()
+            .any(|instruction| !instruction.is_compressed));
+
+        let preprocessing = RV32IJoltVM::preprocess(
+            bytecode.clone(),
+            io_device.memory_layout.clone(),
+            memory_init,
+            1 << 20,
+            1 << 20,
+            1 << 20,
+        );
+        let (proof, commitments, debug_info) = <RV32IJoltVM as Jolt<
+            Fr,
+            HyperKZG<Bn254, KeccakTranscript>,
+            C,
+            M,
+            KeccakTranscript,
+        >>::prove(
+            io_device, trace, preprocessing.clone()
+        );
+        let verification_result =
+            RV32IJoltVM::verify(preprocessing, proof, commitments, debug_info);
+        assert!(
+            verification_result.is_ok(),
+            "Verification failed with error: {:?}",
+            verification_result.err()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn compressed_flag_tampered() {
+        let artifact_guard = FIB_FILE_LOCK.lock().unwrap();
+        let mut program = host::Program::new("fibonacci-guest");
+        program.set_compressed(true);
+        program.set_input(&9u32);
+        let (mut bytecode, memory_init) = program.decode();
+        let (io_device, mut trace) = program.trace();
+        drop(artifact_guard);
+
+        // Claim that the first executed compressed instruction is 4 bytes long, in both the
+        // bytecode and the trace
+        let (index, original_row) = bytecode
+            .iter()
+            .enumerate()
+            .find_map(|(index, instruction)| {
+                let row = BytecodeRow::from_instruction::<RV32I>(instruction);
+                (instruction.is_compressed
+                    && instruction.virtual_sequence_remaining.is_none()
+                    && trace.iter().any(|step| step.bytecode_row == row))
+                .then_some((index, row))
+            })
+            .unwrap();
+        bytecode[index].is_compressed = false;
+        let tampered_row = BytecodeRow::from_instruction::<RV32I>(&bytecode[index]);
+        let tampered_flags = bytecode[index].to_circuit_flags();
+        for step in trace
+            .iter_mut()
+            .filter(|step| step.bytecode_row == original_row)
+        {
+            step.bytecode_row = tampered_row.clone();
+            step.circuit_flags = tampered_flags;
+        }
+
+        let preprocessing = RV32IJoltVM::preprocess(
+            bytecode.clone(),
+            io_device.memory_layout.clone(),
+            memory_init,
+            1 << 20,
+            1 << 20,
+            1 << 20,
+        );
+        let (proof, commitments, debug_info) = <RV32IJoltVM as Jolt<
+            Fr,
+            HyperKZG<Bn254, KeccakTranscript>,
+            C,
+            M,
+            KeccakTranscript,
+        >>::prove(
+            io_device, trace, preprocessing.clone()
+        );
+        // The next PC no longer matches the claimed instruction length, so either the
+        // prover's constraint check or verification fails
+        RV32IJoltVM::verify(preprocessing, proof, commitments, debug_info).unwrap();
+    }
+
     #[test]
     #[should_panic]
     fn truncated_trace() {
@@ -26,9 +26,11 @@ use crate::{
 };
 
 use super::read_write_memory::ReadWriteMemoryPolynomials;
+use crate::{maybe_into_par_iter, maybe_par_iter, maybe_par_iter_mut};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use common::constants::MEMORY_OPS_PER_INSTRUCTION;
 use itertools::interleave;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 #[cfg(test)]
 use std::collections::HashSet;
@@ -148,75 +150,75 @@ where
             }
         }
 
-        let mut read_and_final_cts: Vec<[Vec<u32>; 4]> = (0..MEMORY_OPS_PER_INSTRUCTION)
-            .into_par_iter()
-            .map(|i| {
-                let mut read_cts_read_timestamp: Vec<u32> = vec![0; M];
-                let mut read_cts_global_minus_read: Vec<u32> = vec![0; M];
-                let mut final_cts_read_timestamp: Vec<u32> = vec![0; M];
-                let mut final_cts_global_minus_read: Vec<u32> = vec![0; M];
-
-                for (j, read_timestamp) in read_timestamps[i].iter().enumerate() {
-                    read_cts_read_timestamp[j] = final_cts_read_timestamp[*read_timestamp as usize];
-                    final_cts_read_timestamp[*read_timestamp as usize] += 1;
-                    let lookup_index = j - *read_timestamp as usize;
-                    read_cts_global_minus_read[j] = final_cts_global_minus_read[lookup_index];
-                    final_cts_global_minus_read[lookup_index] += 1;
-                }
-
-                #[cfg(test)]
-                {
-                    let global_minus_read_timestamps: Vec<_> = read_timestamps[i]
-                        .iter()
-                        .enumerate()
-                        .map(|(j, timestamp)| j as u32 - *timestamp)
-                        .collect();
-
-                    for (lookup_indices, read_cts, final_cts) in [
-                        (
-                            &read_timestamps[i].coeffs,
-                            &read_cts_read_timestamp,
-                            &final_cts_read_timestamp,
-                        ),
-                        (
-                            &global_minus_read_timestamps,
-                            &read_cts_global_minus_read,
-                            &final_cts_global_minus_read,
-                        ),
-                    ]
-                    .iter()
-                    {
-                        let mut read_tuples: HashSet<(u32, u32)> = HashSet::new();
-                        let mut write_tuples: HashSet<(u32, u32)> = HashSet::new();
-                        for (v, t) in lookup_indices.iter().zip(read_cts.iter()) {
-                            read_tuples.insert((*v, *t));
-                            write_tuples.insert((*v, *t + 1));
-                        }
+        let mut read_and_final_cts: Vec<[Vec<u32>; 4]> =
+            maybe_into_par_iter!(0..MEMORY_OPS_PER_INSTRUCTION)
+                .map(|i| {
+                    let mut read_cts_read_timestamp: Vec<u32> = vec![0; M];
+                    let mut read_cts_global_minus_read: Vec<u32> = vec![0; M];
+                    let mut final_cts_read_timestamp: Vec<u32> = vec![0; M];
+                    let mut final_cts_global_minus_read: Vec<u32> = vec![0; M];
+
+                    for (j, read_timestamp) in read_timestamps[i].iter().enumerate() {
+                        read_cts_read_timestamp[j] =
+                            final_cts_read_timestamp[*read_timestamp as usize];
+                        final_cts_read_timestamp[*read_timestamp as usize] += 1;
+                        let lookup_index = j - *read_timestamp as usize;
+                        read_cts_global_minus_read[j] = final_cts_global_minus_read[lookup_index];
+                        final_cts_global_minus_read[lookup_index] += 1;
+                    }
 
-                        let mut final_tuples: HashSet<(u32, u32)> = HashSet::new();
-                        for (i, t) in final_cts.iter().enumerate() {
-                            final_tuples.insert((i as u32, *t));
+                    #[cfg(test)]
+                    {
+                        let global_minus_read_timestamps: Vec<_> = read_timestamps[i]
+                            .iter()
+                            .enumerate()
+                            .map(|(j, timestamp)| j as u32 - *timestamp)
+                            .collect();
+
+                        for (lookup_indices, read_cts, final_cts) in [
+                            (
+                                &read_timestamps[i].coeffs,
+                                &read_cts_read_timestamp,
+                                &final_cts_read_timestamp,
+                            ),
+                            (
+                                &global_minus_read_timestamps,
+                                &read_cts_global_minus_read,
+                                &final_cts_global_minus_read,
+                            ),
+                        ]
+                        .iter()
+                        {
+                            let mut read_tuples: HashSet<(u32, u32)> = HashSet::new();
+                            let mut write_tuples: HashSet<(u32, u32)> = HashSet::new();
+                            for (v, t) in lookup_indices.iter().zip(read_cts.iter()) {
+                                read_tuples.insert((*v, *t));
+                                write_tuples.insert((*v, *t + 1));
+                            }
+
+                            let mut final_tuples: HashSet<(u32, u32)> = HashSet::new();
+                            for (i, t) in final_cts.iter().enumerate() {
+                                final_tuples.insert((i as u32, *t));
+                            }
+
+                            let init_write: HashSet<_> = init_tuples.union(&write_tuples).collect();
+                            let read_final: HashSet<_> = read_tuples.union(&final_tuples).collect();
+                            let set_difference: Vec<_> =
+                                init_write.symmetric_difference(&read_final).collect();
+                            assert_eq!(set_difference.len(), 0);
                         }
-
-                        let init_write: HashSet<_> = init_tuples.union(&write_tuples).collect();
-                        let read_final: HashSet<_> = read_tuples.union(&final_tuples).collect();
-                        let set_difference: Vec<_> =
-                            init_write.symmetric_difference(&read_final).collect();
-                        assert_eq!(set_difference.len(), 0);
                     }
-                }
 
-                [
-                    read_cts_read_timestamp,
-                    read_cts_global_minus_read,
-                    final_cts_read_timestamp,
-                    final_cts_global_minus_read,
-                ]
-            })
-            .collect();
+                    [
+                        read_cts_read_timestamp,
+                        read_cts_global_minus_read,
+                        final_cts_read_timestamp,
+                        final_cts_global_minus_read,
+                    ]
+                })
+                .collect();
 
-        let read_cts_read_timestamp = read_and_final_cts
-            .par_iter_mut()
+        let read_cts_read_timestamp = maybe_par_iter_mut!(read_and_final_cts)
             .map(|cts| {
                 let cts = std::mem::take(&mut cts[0]);
                 MultilinearPolynomial::from(cts)
@@ -224,8 +226,7 @@ where
             .collect::<Vec<_>>()
             .try_into()
             .unwrap();
-        let read_cts_global_minus_read = read_and_final_cts
-            .par_iter_mut()
+        let read_cts_global_minus_read = maybe_par_iter_mut!(read_and_final_cts)
             .map(|cts| {
                 let cts = std::mem::take(&mut cts[1]);
                 MultilinearPolynomial::from(cts)
@@ -233,8 +234,7 @@ where
             .collect::<Vec<_>>()
             .try_into()
             .unwrap();
-        let final_cts_read_timestamp = read_and_final_cts
-            .par_iter_mut()
+        let final_cts_read_timestamp = maybe_par_iter_mut!(read_and_final_cts)
             .map(|cts| {
                 let cts = std::mem::take(&mut cts[2]);
                 MultilinearPolynomial::from(cts)
@@ -242,8 +242,7 @@ where
             .collect::<Vec<_>>()
             .try_into()
             .unwrap();
-        let final_cts_global_minus_read = read_and_final_cts
-            .par_iter_mut()
+        let final_cts_global_minus_read = maybe_par_iter_mut!(read_and_final_cts)
             .map(|cts| {
                 let cts = std::mem::take(&mut cts[3]);
                 MultilinearPolynomial::from(cts)
@@ -350,24 +349,20 @@ where
 
         let M = read_timestamps[0].len();
 
-        let read_write_leaves: Vec<Vec<F>> = (0..MEMORY_OPS_PER_INSTRUCTION)
-            .into_par_iter()
+        let read_write_leaves: Vec<Vec<F>> = maybe_into_par_iter!(0..MEMORY_OPS_PER_INSTRUCTION)
             .flat_map(|i| {
-                let read_fingerprints_0: Vec<F> = (0..M)
-                    .into_par_iter()
+                let read_fingerprints_0: Vec<F> = maybe_into_par_iter!(0..M)
                     .map(|j| {
                         read_timestamps[i][j].field_mul(gamma)
                             + F::from_u32(read_cts_read_timestamp[i][j])
                             - *tau
                     })
                     .collect();
-                let write_fingeprints_0 = read_fingerprints_0
-                    .par_iter()
+                let write_fingeprints_0 = maybe_par_iter!(read_fingerprints_0)
                     .map(|read_fingerprint| *read_fingerprint + F::one())
                     .collect();
 
-                let read_fingerprints_1: Vec<F> = (0..M)
-                    .into_par_iter()
+                let read_fingerprints_1: Vec<F> = maybe_into_par_iter!(0..M)
                     .map(|j| {
                         let global_minus_read = j as u32 - read_timestamps[i][j];
                         global_minus_read.field_mul(gamma)
@@ -375,8 +370,7 @@ where
                             - *tau
                     })
                     .collect();
-                let write_fingeprints_1 = read_fingerprints_1
-                    .par_iter()
+                let write_fingeprints_1 = maybe_par_iter!(read_fingerprints_1)
                     .map(|read_fingerprint| *read_fingerprint + F::one())
                     .collect();
 
@@ -391,8 +385,7 @@ where
 
         let mut leaves = read_write_leaves;
 
-        let init_leaves: Vec<F> = (0..M)
-            .into_par_iter()
+        let init_leaves: Vec<F> = maybe_into_par_iter!(0..M)
             .map(|i| {
                 // t = 0
                 (i as u64).field_mul(gamma) - *tau
@@ -415,23 +408,21 @@ where
                 .collect::<Vec<_>>()
                 .try_into()
                 .unwrap();
-        leaves.par_extend(
-            (0..MEMORY_OPS_PER_INSTRUCTION)
-                .into_par_iter()
-                .flat_map(|i| {
-                    let final_fingerprints_0 = (0..M)
-                        .into_par_iter()
-                        .map(|j| F::from_u32(final_cts_read_timestamp[i][j]) + init_leaves[j])
-                        .collect();
-
-                    let final_fingerprints_1 = (0..M)
-                        .into_par_iter()
-                        .map(|j| F::from_u32(final_cts_global_minus_read[i][j]) + init_leaves[j])
-                        .collect();
-
-                    [final_fingerprints_0, final_fingerprints_1]
-                }),
-        );
+        let final_leaves = maybe_into_par_iter!(0..MEMORY_OPS_PER_INSTRUCTION).flat_map(|i| {
+            let final_fingerprints_0: Vec<F> = maybe_into_par_iter!(0..M)
+                .map(|j| F::from_u32(final_cts_read_timestamp[i][j]) + init_leaves[j])
+                .collect();
+
+            let final_fingerprints_1: Vec<F> = maybe_into_par_iter!(0..M)
+                .map(|j| F::from_u32(final_cts_global_minus_read[i][j]) + init_leaves[j])
+                .collect();
+
+            [final_fingerprints_0, final_fingerprints_1]
+        });
+        #[cfg(feature = "parallel")]
+        leaves.par_extend(final_leaves);
+        #[cfg(not(feature = "parallel"))]
+        leaves.extend(final_leaves);
         leaves.push(init_leaves);
 
         let batch_size = leaves.len();
@@ -488,7 +479,7 @@ where
         assert_eq!(multiset_hashes.final_hashes.len(), num_memories);
         assert_eq!(multiset_hashes.init_hashes.len(), 1);
 
-        (0..num_memories).into_par_iter().for_each(|i| {
+        maybe_into_par_iter!(0..num_memories).for_each(|i| {
             let read_hash = multiset_hashes.read_hashes[i];
             let write_hash = multiset_hashes.write_hashes[i];
             let init_hash = multiset_hashes.init_hashes[0];
@@ -18,8 +18,10 @@ use crate::{
 };
 
 use crate::field::JoltField;
+use crate::maybe_into_par_iter;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use itertools::interleave;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 #[derive(CanonicalSerialize, CanonicalDeserialize)]
@@ -487,7 +489,7 @@ where
         assert_eq!(multiset_hashes.write_hashes.len(), num_memories);
         assert_eq!(multiset_hashes.init_hashes.len(), num_memories);
 
-        (0..num_memories).into_par_iter().for_each(|i| {
+        maybe_into_par_iter!(0..num_memories).for_each(|i| {
             let read_hash = multiset_hashes.read_hashes[i];
             let write_hash = multiset_hashes.write_hashes[i];
             let init_hash = multiset_hashes.init_hashes[i];
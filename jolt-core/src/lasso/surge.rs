@@ -1,3 +1,5 @@
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
 use crate::{
     field::JoltField,
     jolt::vm::{JoltCommitments, JoltPolynomials, ProverDebugInfo},
@@ -7,7 +9,9 @@ use crate::{
         opening_proof::{ProverOpeningAccumulator, VerifierOpeningAccumulator},
     },
 };
+use crate::{maybe_into_par_iter, maybe_par_iter};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+#[cfg(feature = "parallel")]
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use std::marker::{PhantomData, Sync};
 
@@ -131,8 +135,7 @@ where
 
         let num_lookups = polynomials.dim[0].len();
 
-        let read_write_leaves: Vec<_> = (0..Self::num_memories())
-            .into_par_iter()
+        let read_write_leaves: Vec<_> = maybe_into_par_iter!(0..Self::num_memories())
             .flat_map_iter(|memory_index| {
                 let dim_index = Self::memory_to_dimension_index(memory_index);
                 let read_cts: &CompactPolynomial<u32, F> =
@@ -159,8 +162,7 @@ where
             })
             .collect();
 
-        let init_final_leaves: Vec<_> = (0..Self::num_memories())
-            .into_par_iter()
+        let init_final_leaves: Vec<_> = maybe_into_par_iter!(0..Self::num_memories())
             .flat_map_iter(|memory_index| {
                 let dim_index = Self::memory_to_dimension_index(memory_index);
                 let subtable_index = Self::memory_to_subtable_index(memory_index);
@@ -346,9 +348,7 @@ where
     pub fn preprocess() -> Self {
         let instruction = Instruction::default();
 
-        let materialized_subtables = instruction
-            .subtables::<F>(C, M)
-            .par_iter()
+        let materialized_subtables = maybe_par_iter!(instruction.subtables::<F>(C, M))
             .map(|(subtable, _)| subtable.materialize(M))
             .collect();
 
@@ -654,8 +654,7 @@ where
 
         let instruction = Instruction::default();
 
-        (0..hypercube_size)
-            .into_par_iter()
+        maybe_into_par_iter!(0..hypercube_size)
             .map(|eval_index| {
                 let g_operands: Vec<F> = (0..Self::num_memories())
                     .map(|memory_index| g_operands[memory_index].get_coeff(eval_index))
@@ -1,5 +1,6 @@
 use crate::field::JoltField;
 use crate::msm::{GpuBaseType, VariableBaseMSM};
+use crate::{maybe_into_par_iter, maybe_par_iter};
 use ark_bn254::G1Projective;
 use ark_ec::{CurveGroup, ScalarMul};
 use ark_ff::{BigInteger, Field, PrimeField};
@@ -15,6 +16,7 @@ use icicle_runtime::{
     memory::{DeviceVec, HostSlice},
     stream::IcicleStream,
 };
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::os::raw::c_void;
 
@@ -215,8 +217,7 @@ where
 
     stream.synchronize().unwrap();
     stream.destroy().unwrap();
-    batch_info
-        .par_iter()
+    maybe_par_iter!(batch_info)
         .zip(msm_host_results)
         .map(|((batch_id, _, _), result)| (*batch_id, V::to_ark_projective(&result)))
         .collect()
@@ -236,7 +237,7 @@ where
 {
     let bases_len = bases.len();
     let batch_size = scalar_batches.len();
-    assert!(scalar_batches.par_iter().all(|s| s.len() == bases_len));
+    assert!(maybe_par_iter!(scalar_batches).all(|s| s.len() == bases_len));
 
     let mut stream = IcicleStream::create().unwrap();
     icicle_runtime::warmup(&stream).unwrap();
@@ -254,7 +255,9 @@ where
     let mut msm_result =
         DeviceVec::<Projective<V::C>>::device_malloc_async(batch_size, &stream).unwrap();
     let mut msm_host_results = vec![Projective::<V::C>::zero(); batch_size];
-    let total_len: usize = scalar_batches.par_iter().map(|batch| batch.len()).sum();
+    let total_len: usize = maybe_par_iter!(scalar_batches)
+        .map(|batch| batch.len())
+        .sum();
     let mut scalars_slice =
         DeviceVec::<<<V as Icicle>::C as Curve>::ScalarField>::device_malloc_async(
             total_len, &stream,
@@ -315,8 +318,7 @@ where
     let span = tracing::span!(tracing::Level::INFO, "converting_results");
     let _guard = span.enter();
     stream.destroy().unwrap();
-    msm_host_results
-        .into_par_iter()
+    maybe_into_par_iter!(msm_host_results)
         .map(|res| V::to_ark_projective(&res))
         .collect()
 }
@@ -380,7 +382,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::msm::total_memory_bits;
+    use crate::msm::{total_memory_bits, MsmBackend, ICICLE_MIN_MSM_SIZE};
+    use crate::poly::multilinear_polynomial::MultilinearPolynomial;
     use ark_bn254::{Fr, G1Affine, G1Projective};
     use ark_ec::VariableBaseMSM as ark_VariableBaseMSM;
     use ark_std::UniformRand;
@@ -400,8 +403,7 @@ mod tests {
                 .take(n)
                 .collect();
 
-            let gpu_bases = bases
-                .par_iter()
+            let gpu_bases = maybe_par_iter!(bases)
                 .map(|base| <G1Projective as Icicle>::from_ark_affine(base))
                 .collect::<Vec<_>>();
             let icicle_res = icicle_msm::<G1Projective>(&gpu_bases, &scalars, 256);
@@ -429,8 +431,7 @@ mod tests {
                 .take(n)
                 .collect();
 
-            let gpu_bases = bases
-                .par_iter()
+            let gpu_bases = maybe_par_iter!(bases)
                 .map(|base| <G1Projective as Icicle>::from_ark_affine(base))
                 .collect::<Vec<_>>();
             let icicle_res = icicle_batch_msm::<G1Projective>(&gpu_bases, &scalar_batches, 256);
@@ -451,6 +452,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_icicle_msm_matches_cpu() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(275);
+        for n in [1 << 16, ICICLE_MIN_MSM_SIZE / 2] {
+            let bases: Vec<G1Affine> = std::iter::repeat_with(|| G1Affine::rand(&mut rng))
+                .take(n)
+                .collect();
+            let poly = MultilinearPolynomial::from(
+                std::iter::repeat_with(|| Fr::rand(&mut rng))
+                    .take(n)
+                    .collect::<Vec<_>>(),
+            );
+            let gpu_bases = maybe_par_iter!(bases)
+                .map(|base| <G1Projective as Icicle>::from_ark_affine(base))
+                .collect::<Vec<_>>();
+
+            // Without a device, or below the size threshold, this takes the CPU path
+            let gpu_res = <G1Projective as VariableBaseMSM>::msm_with_backend(
+                &bases,
+                Some(&gpu_bases),
+                &poly,
+                None,
+                MsmBackend::Icicle,
+                None,
+            )
+            .unwrap();
+            let cpu_res = <G1Projective as VariableBaseMSM>::msm_with_backend(
+                &bases,
+                None,
+                &poly,
+                None,
+                MsmBackend::Cpu,
+                None,
+            )
+            .unwrap();
+            assert_eq!(gpu_res, cpu_res, "n = {n}");
+        }
+    }
+
     #[test]
     fn test_casting() {
         let ark = Fr::from(100);
@@ -25,6 +25,8 @@ pub trait ScalarMulConfig: ScalarMul {}
 pub trait Icicle {}
 #[cfg(not(feature = "icicle"))]
 impl Icicle for G1Projective {}
+#[cfg(all(test, not(feature = "icicle")))]
+impl Icicle for ark_bls12_381::G1Projective {}
 
 /// Initializes the icicle backend and sets the CUDA device as active and returns true if successful.
 ///
@@ -5,6 +5,7 @@ use ark_std::vec::Vec;
 #[cfg(feature = "icicle")]
 use icicle_core::curve::Affine;
 use num_integer::Integer;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::borrow::Borrow;
 
@@ -13,7 +14,12 @@ use crate::field::JoltField;
 use crate::poly::multilinear_polynomial::MultilinearPolynomial;
 use crate::utils::errors::ProofVerifyError;
 use crate::utils::math::Math;
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
+use crate::{maybe_into_par_iter, maybe_par_iter};
 pub use icicle::*;
+#[cfg(not(feature = "parallel"))]
+use itertools::Itertools;
 
 impl<F: JoltField, G: CurveGroup<ScalarField = F> + Icicle> VariableBaseMSM for G {}
 
@@ -46,7 +52,10 @@ where
                     _ => msm_small(bases, scalars, max_num_bits),
                 }
             })
-            .ok_or(ProofVerifyError::KeyLengthError(bases.len(), scalars.len()))
+            .ok_or(ProofVerifyError::KeyLengthError {
+                available_powers: bases.len(),
+                required_powers: scalars.len(),
+            })
     }
 
     fn msm_u16(
@@ -64,10 +73,20 @@ where
                     0 => Self::zero(),
                     1 => msm_binary(bases, scalars),
                     2..=10 => msm_small(bases, scalars, max_num_bits),
-                    _ => msm_medium(bases, gpu_bases, scalars, max_num_bits, use_icicle),
+                    _ => msm_medium(
+                        bases,
+                        gpu_bases,
+                        scalars,
+                        max_num_bits,
+                        use_icicle,
+                        default_window_bits(bases.len()),
+                    ),
                 }
             })
-            .ok_or(ProofVerifyError::KeyLengthError(bases.len(), scalars.len()))
+            .ok_or(ProofVerifyError::KeyLengthError {
+                available_powers: bases.len(),
+                required_powers: scalars.len(),
+            })
     }
 
     fn msm_u32(
@@ -85,10 +104,20 @@ where
                     0 => Self::zero(),
                     1 => msm_binary(bases, scalars),
                     2..=10 => msm_small(bases, scalars, max_num_bits),
-                    _ => msm_medium(bases, gpu_bases, scalars, max_num_bits, use_icicle),
+                    _ => msm_medium(
+                        bases,
+                        gpu_bases,
+                        scalars,
+                        max_num_bits,
+                        use_icicle,
+                        default_window_bits(bases.len()),
+                    ),
                 }
             })
-            .ok_or(ProofVerifyError::KeyLengthError(bases.len(), scalars.len()))
+            .ok_or(ProofVerifyError::KeyLengthError {
+                available_powers: bases.len(),
+                required_powers: scalars.len(),
+            })
     }
 
     fn msm_u64(
@@ -106,12 +135,24 @@ where
                     0 => Self::zero(),
                     1 => msm_binary(bases, scalars),
                     2..=10 => msm_small(bases, scalars, max_num_bits),
-                    _ => msm_medium(bases, gpu_bases, scalars, max_num_bits, use_icicle),
+                    _ => msm_medium(
+                        bases,
+                        gpu_bases,
+                        scalars,
+                        max_num_bits,
+                        use_icicle,
+                        default_window_bits(bases.len()),
+                    ),
                 }
             })
-            .ok_or(ProofVerifyError::KeyLengthError(bases.len(), scalars.len()))
+            .ok_or(ProofVerifyError::KeyLengthError {
+                available_powers: bases.len(),
+                required_powers: scalars.len(),
+            })
     }
 
+    /// On the CPU this is [`VariableBaseMSM::msm_with_window`] with [`default_window_bits`];
+    /// `max_num_bits` only decides whether an MSM with `use_icicle` runs on the GPU.
     fn msm_field_elements(
         bases: &[Self::MulBase],
         gpu_bases: Option<&[GpuBaseType<Self>]>,
@@ -119,80 +160,170 @@ where
         max_num_bits: Option<usize>,
         use_icicle: bool,
     ) -> Result<Self, ProofVerifyError> {
-        (bases.len() == scalars.len())
-            .then(|| {
-                let max_num_bits =
-                    max_num_bits.unwrap_or((*scalars.iter().max().unwrap()).num_bits() as usize);
-                match max_num_bits {
-                    0 => Self::zero(),
-                    1 => scalars
-                        .iter()
-                        .zip(bases)
-                        .filter(|(scalar, _base)| !scalar.is_zero())
-                        .map(|(_scalar, base)| base)
-                        .fold(Self::zero(), |sum, base| sum + base),
-                    2..=10 => {
-                        let scalars_u16 = &map_field_elements_to_u16(scalars);
-                        msm_small(bases, scalars_u16, max_num_bits)
-                    }
-                    11..=64 => {
-                        let scalars_u64 = &map_field_elements_to_u64(scalars);
-                        msm_medium(bases, gpu_bases, scalars_u64, max_num_bits, use_icicle)
-                    }
-                    _ => {
-                        if use_icicle {
-                            #[cfg(feature = "icicle")]
-                            {
-                                let mut backup = vec![];
-                                let gpu_bases = gpu_bases.unwrap_or_else(|| {
-                                    backup = Self::get_gpu_bases(bases);
-                                    &backup
-                                });
-                                return icicle_msm::<Self>(gpu_bases, scalars, max_num_bits);
-                            }
-                            #[cfg(not(feature = "icicle"))]
-                            {
-                                unreachable!(
-                                    "icicle_init must not return true without the icicle feature"
-                                );
-                            }
-                        }
+        if use_icicle && bases.len() == scalars.len() {
+            let max_num_bits =
+                max_num_bits.unwrap_or((*scalars.iter().max().unwrap()).num_bits() as usize);
+            if max_num_bits > 64 {
+                #[cfg(feature = "icicle")]
+                {
+                    let mut backup = vec![];
+                    let gpu_bases = gpu_bases.unwrap_or_else(|| {
+                        backup = Self::get_gpu_bases(bases);
+                        &backup
+                    });
+                    return Ok(icicle_msm::<Self>(gpu_bases, scalars, max_num_bits));
+                }
+                #[cfg(not(feature = "icicle"))]
+                {
+                    let _ = gpu_bases;
+                    unreachable!("icicle_init must not return true without the icicle feature");
+                }
+            }
+        }
 
-                        let scalars = scalars
-                            .par_iter()
-                            .map(|s| s.into_bigint())
-                            .collect::<Vec<_>>();
-                        if Self::NEGATION_IS_CHEAP {
-                            msm_bigint_wnaf(bases, &scalars, max_num_bits)
-                        } else {
-                            msm_bigint(bases, &scalars, max_num_bits)
-                        }
-                    }
+        Self::msm_with_window(bases, scalars, default_window_bits(bases.len()))
+    }
+
+    /// Computes `sum_i scalars[i] * base_i` over the bases `affine_bases` followed by
+    /// `projective_bases`, on the CPU.
+    ///
+    /// Affine bases are the fastest representation, as bucket accumulation then uses mixed
+    /// additions, so bases already in affine form should be passed as such. Projective bases
+    /// are normalized together with a single field inversion before joining the affine ones,
+    /// which is cheaper than converting each of them to affine separately.
+    fn msm_mixed(
+        affine_bases: &[Self::MulBase],
+        projective_bases: &[Self],
+        scalars: &[Self::ScalarField],
+    ) -> Result<Self, ProofVerifyError> {
+        let num_bases = affine_bases.len() + projective_bases.len();
+        if num_bases != scalars.len() {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: num_bases,
+                required_powers: scalars.len(),
+            });
+        }
+        if num_bases == 0 {
+            return Ok(Self::zero());
+        }
+        if projective_bases.is_empty() {
+            return Self::msm_field_elements(affine_bases, None, scalars, None, false);
+        }
+        let normalized = Self::batch_convert_to_mul_base(projective_bases);
+        if affine_bases.is_empty() {
+            return Self::msm_field_elements(&normalized, None, scalars, None, false);
+        }
+        Self::msm_field_elements(
+            &[affine_bases, &normalized].concat(),
+            None,
+            scalars,
+            None,
+            false,
+        )
+    }
+
+    /// Computes the MSM on the CPU with Pippenger windows of `window_bits` bits, which must be
+    /// between 1 and [`MAX_WINDOW_BITS`]. Scalars of up to 10 bits are handled without
+    /// windows, so `window_bits` does not apply to them.
+    fn msm_with_window(
+        bases: &[Self::MulBase],
+        scalars: &[Self::ScalarField],
+        window_bits: usize,
+    ) -> Result<Self, ProofVerifyError> {
+        if !(1..=MAX_WINDOW_BITS).contains(&window_bits) {
+            return Err(ProofVerifyError::InvalidWindowBits(window_bits));
+        }
+        if bases.len() != scalars.len() {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: bases.len(),
+                required_powers: scalars.len(),
+            });
+        }
+        if let Some((bases, scalars)) = drop_zero_scalars(bases, scalars) {
+            return Self::msm_with_window(&bases, &scalars, window_bits);
+        }
+
+        let max_num_bits = maybe_par_iter!(scalars)
+            .map(|scalar| scalar.num_bits() as usize)
+            .max()
+            .unwrap_or(0);
+        Ok(match max_num_bits {
+            0 => Self::zero(),
+            1 => scalars
+                .iter()
+                .zip(bases)
+                .filter(|(scalar, _base)| !scalar.is_zero())
+                .map(|(_scalar, base)| base)
+                .fold(Self::zero(), |sum, base| sum + base),
+            2..=10 => msm_small(bases, &map_field_elements_to_u16(scalars), max_num_bits),
+            11..=64 => msm_medium(
+                bases,
+                None,
+                &map_field_elements_to_u64(scalars),
+                max_num_bits,
+                false,
+                window_bits,
+            ),
+            _ => {
+                let scalars = maybe_par_iter!(scalars)
+                    .map(|s| s.into_bigint())
+                    .collect::<Vec<_>>();
+                if Self::NEGATION_IS_CHEAP {
+                    msm_bigint_wnaf(bases, &scalars, max_num_bits, window_bits)
+                } else {
+                    msm_bigint(bases, &scalars, max_num_bits, window_bits)
                 }
-            })
-            .ok_or(ProofVerifyError::KeyLengthError(bases.len(), scalars.len()))
+            }
+        })
     }
 
+    /// Computes the MSM of `bases` with the coefficients of `poly`.
+    ///
+    /// Partial sums are only ever combined via group addition, so the output does not
+    /// depend on how rayon schedules the work (e.g. on the size of the thread pool).
     #[tracing::instrument(skip_all)]
     fn msm(
         bases: &[Self::MulBase],
         gpu_bases: Option<&[GpuBaseType<Self>]>,
         poly: &MultilinearPolynomial<Self::ScalarField>,
         max_num_bits: Option<usize>,
+    ) -> Result<Self, ProofVerifyError> {
+        Self::msm_with_backend(
+            bases,
+            gpu_bases,
+            poly,
+            max_num_bits,
+            MsmBackend::default(),
+            None,
+        )
+    }
+
+    /// Same as [`VariableBaseMSM::msm`], but with an explicit choice of backend for the
+    /// large-scalar MSMs, and optionally of the Pippenger window they use on the CPU (see
+    /// [`VariableBaseMSM::msm_field_elements_with_backend`]).
+    fn msm_with_backend(
+        bases: &[Self::MulBase],
+        gpu_bases: Option<&[GpuBaseType<Self>]>,
+        poly: &MultilinearPolynomial<Self::ScalarField>,
+        max_num_bits: Option<usize>,
+        backend: MsmBackend,
+        window_bits: Option<usize>,
     ) -> Result<Self, ProofVerifyError> {
         #[cfg(not(feature = "icicle"))]
         assert!(gpu_bases.is_none());
         assert_eq!(bases.len(), gpu_bases.map_or(bases.len(), |b| b.len()));
 
-        let use_icicle = use_icicle();
+        let use_icicle =
+            backend == MsmBackend::Icicle && bases.len() >= ICICLE_MIN_MSM_SIZE && use_icicle();
 
         match poly {
-            MultilinearPolynomial::LargeScalars(poly) => Self::msm_field_elements(
+            MultilinearPolynomial::LargeScalars(poly) => Self::msm_field_elements_with_backend(
                 bases,
                 gpu_bases,
                 poly.evals_ref(),
                 max_num_bits,
-                use_icicle,
+                backend,
+                window_bits,
             ),
             MultilinearPolynomial::U8Scalars(poly) => {
                 Self::msm_u8(bases, &poly.coeffs, max_num_bits)
@@ -208,16 +339,41 @@ where
             }
             MultilinearPolynomial::I64Scalars(poly) => {
                 // TODO(moodlezoup): This can be optimized
-                let scalars: Vec<_> = poly
-                    .coeffs
-                    .par_iter()
+                let scalars: Vec<_> = maybe_par_iter!(poly.coeffs)
                     .map(|x| Self::ScalarField::from_i64(*x))
                     .collect();
-                Self::msm_field_elements(bases, gpu_bases, &scalars, max_num_bits, use_icicle)
+                Self::msm_field_elements_with_backend(
+                    bases,
+                    gpu_bases,
+                    &scalars,
+                    max_num_bits,
+                    backend,
+                    window_bits,
+                )
             }
         }
     }
 
+    /// [`VariableBaseMSM::msm_field_elements`] on `backend`, falling back to the CPU if
+    /// `backend` is unavailable or if there are fewer than [`ICICLE_MIN_MSM_SIZE`] bases. On
+    /// the CPU, `window_bits` overrides the Pippenger window (see
+    /// [`VariableBaseMSM::msm_with_window`]).
+    fn msm_field_elements_with_backend(
+        bases: &[Self::MulBase],
+        gpu_bases: Option<&[GpuBaseType<Self>]>,
+        scalars: &[Self::ScalarField],
+        max_num_bits: Option<usize>,
+        backend: MsmBackend,
+        window_bits: Option<usize>,
+    ) -> Result<Self, ProofVerifyError> {
+        let use_icicle =
+            backend == MsmBackend::Icicle && bases.len() >= ICICLE_MIN_MSM_SIZE && use_icicle();
+        match window_bits {
+            Some(window_bits) if !use_icicle => Self::msm_with_window(bases, scalars, window_bits),
+            _ => Self::msm_field_elements(bases, gpu_bases, scalars, max_num_bits, use_icicle),
+        }
+    }
+
     #[tracing::instrument(skip_all)]
     fn batch_msm_common<P>(
         bases: &[Self::MulBase],
@@ -230,9 +386,9 @@ where
     {
         // Validate input lengths
         if variable_batches {
-            assert!(polys.par_iter().all(|s| s.borrow().len() <= bases.len()));
+            assert!(maybe_par_iter!(polys).all(|s| s.borrow().len() <= bases.len()));
         } else {
-            assert!(polys.par_iter().all(|s| s.borrow().len() == bases.len()));
+            assert!(maybe_par_iter!(polys).all(|s| s.borrow().len() == bases.len()));
             assert_eq!(bases.len(), gpu_bases.map_or(bases.len(), |b| b.len()));
         }
 
@@ -245,8 +401,7 @@ where
         if !use_icicle {
             let span = tracing::span!(tracing::Level::INFO, "batch_msm_cpu_only");
             let _guard = span.enter();
-            return polys
-                .into_par_iter()
+            return maybe_into_par_iter!(polys)
                 .map(|poly| {
                     let poly = poly.borrow();
                     let bases_slice = &bases[..poly.len()];
@@ -258,8 +413,9 @@ where
         // Split scalar batches into CPU and GPU workloads
         let span = tracing::span!(tracing::Level::INFO, "group_scalar_indices_parallel");
         let _guard = span.enter();
-        let (cpu_batch, gpu_batch): (Vec<_>, Vec<_>) =
-            polys.par_iter().enumerate().partition_map(|(i, poly)| {
+        let (cpu_batch, gpu_batch): (Vec<_>, Vec<_>) = maybe_par_iter!(polys)
+            .enumerate()
+            .partition_map(|(i, poly)| {
                 let poly = poly.borrow();
                 let max_num_bits = poly.max_num_bits();
 
@@ -295,8 +451,7 @@ where
         // Handle CPU computations in parallel
         let span = tracing::span!(tracing::Level::INFO, "batch_msm_cpu");
         let _guard = span.enter();
-        let cpu_results: Vec<(usize, Self)> = cpu_batch
-            .into_par_iter()
+        let cpu_results: Vec<(usize, Self)> = maybe_into_par_iter!(cpu_batch)
             .map(|(i, max_num_bits, poly)| {
                 let bases_slice = &bases[..poly.len()];
                 (
@@ -341,10 +496,10 @@ where
                     let slices_at_a_time = total_memory_bits() / slice_bit_size;
 
                     for work_chunk in gpu_batch.chunks(slices_at_a_time) {
-                        let (max_num_bits, chunk_polys): (Vec<_>, Vec<_>) = work_chunk
-                            .par_iter()
-                            .map(|(_, max_num_bits, poly)| (*max_num_bits, poly.as_slice()))
-                            .unzip();
+                        let (max_num_bits, chunk_polys): (Vec<_>, Vec<_>) =
+                            maybe_par_iter!(work_chunk)
+                                .map(|(_, max_num_bits, poly)| (*max_num_bits, poly.as_slice()))
+                                .unzip();
 
                         let max_num_bits = max_num_bits.iter().max().unwrap();
                         let batch_results =
@@ -399,9 +554,7 @@ where
     where
         P: Borrow<UniPoly<Self::ScalarField>> + Sync,
     {
-        assert!(polys
-            .par_iter()
-            .all(|s| s.borrow().coeffs.len() <= bases.len()));
+        assert!(maybe_par_iter!(polys).all(|s| s.borrow().coeffs.len() <= bases.len()));
         #[cfg(not(feature = "icicle"))]
         assert!(gpu_bases.is_none());
 
@@ -410,8 +563,7 @@ where
         if !use_icicle {
             let span = tracing::span!(tracing::Level::INFO, "batch_msm_cpu_only");
             let _guard = span.enter();
-            return polys
-                .into_par_iter()
+            return maybe_into_par_iter!(polys)
                 .map(|poly| {
                     Self::msm_field_elements(
                         &bases[..poly.borrow().coeffs.len()],
@@ -428,8 +580,9 @@ where
         // Split scalar batches into CPU and GPU workloads
         let span = tracing::span!(tracing::Level::INFO, "group_scalar_indices_parallel");
         let _guard = span.enter();
-        let (cpu_batch, gpu_batch): (Vec<_>, Vec<_>) =
-            polys.par_iter().enumerate().partition_map(|(i, poly)| {
+        let (cpu_batch, gpu_batch): (Vec<_>, Vec<_>) = maybe_par_iter!(polys)
+            .enumerate()
+            .partition_map(|(i, poly)| {
                 let poly = poly.borrow();
                 let max_num_bits = (*poly.coeffs.iter().max().unwrap()).num_bits() as usize;
                 if use_icicle && max_num_bits > 10 {
@@ -445,8 +598,7 @@ where
         // Handle CPU computations in parallel
         let span = tracing::span!(tracing::Level::INFO, "batch_msm_cpu");
         let _guard = span.enter();
-        let cpu_results: Vec<(usize, Self)> = cpu_batch
-            .into_par_iter()
+        let cpu_results: Vec<(usize, Self)> = maybe_into_par_iter!(cpu_batch)
             .map(|(i, max_num_bits, poly)| {
                 (
                     i,
@@ -497,8 +649,7 @@ where
     #[cfg(feature = "icicle")]
     #[tracing::instrument(skip_all)]
     fn get_gpu_bases(bases: &[Self::MulBase]) -> Vec<GpuBaseType<Self>> {
-        bases
-            .par_iter()
+        maybe_par_iter!(bases)
             .map(|base| <Self as Icicle>::from_ark_affine(base))
             .collect()
     }
@@ -511,9 +662,41 @@ pub fn use_icicle() -> bool {
     false
 }
 
+/// Below this many bases, [`VariableBaseMSM::msm_with_backend`] stays on the CPU even when
+/// asked for the GPU, as copying the inputs to the device then costs more than the MSM.
+pub const ICICLE_MIN_MSM_SIZE: usize = 1 << 12;
+
+/// Backend used for MSMs over large scalars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MsmBackend {
+    Cpu,
+    /// GPU via ICICLE; requires the `icicle` feature and an available device.
+    Icicle,
+}
+
+impl MsmBackend {
+    /// The backends usable on this host.
+    pub fn available() -> Vec<Self> {
+        if use_icicle() {
+            vec![MsmBackend::Cpu, MsmBackend::Icicle]
+        } else {
+            vec![MsmBackend::Cpu]
+        }
+    }
+}
+
+impl Default for MsmBackend {
+    fn default() -> Self {
+        if use_icicle() {
+            MsmBackend::Icicle
+        } else {
+            MsmBackend::Cpu
+        }
+    }
+}
+
 fn map_field_elements_to_u16<F: PrimeField>(field_elements: &[F]) -> Vec<u16> {
-    field_elements
-        .par_iter()
+    maybe_par_iter!(field_elements)
         .map(|s| {
             let bigint = s.into_bigint();
             let limbs: &[u64] = bigint.as_ref();
@@ -523,8 +706,7 @@ fn map_field_elements_to_u16<F: PrimeField>(field_elements: &[F]) -> Vec<u16> {
 }
 
 fn map_field_elements_to_u64<F: PrimeField>(field_elements: &[F]) -> Vec<u64> {
-    field_elements
-        .par_iter()
+    maybe_par_iter!(field_elements)
         .map(|s| {
             let bigint = s.into_bigint();
             let limbs: &[u64] = bigint.as_ref();
@@ -533,28 +715,59 @@ fn map_field_elements_to_u64<F: PrimeField>(field_elements: &[F]) -> Vec<u64> {
         .collect::<Vec<_>>()
 }
 
+/// [`VariableBaseMSM::msm_field_elements`] drops the zero scalars, and their bases, before
+/// bucketing once at least `1 / SPARSE_MSM_ZERO_FRACTION` of the scalars are zero. Below
+/// that, copying out the nonzero pairs costs more than skipping the zeros saves.
+const SPARSE_MSM_ZERO_FRACTION: usize = 4;
+
+/// Returns the `(base, scalar)` pairs of nonzero scalars, or None if too few scalars are
+/// zero for it to pay off (see [`SPARSE_MSM_ZERO_FRACTION`]).
+fn drop_zero_scalars<B: Copy + Send + Sync, F: JoltField>(
+    bases: &[B],
+    scalars: &[F],
+) -> Option<(Vec<B>, Vec<F>)> {
+    let num_zeros = maybe_par_iter!(scalars).filter(|s| s.is_zero()).count();
+    if num_zeros == 0 || num_zeros * SPARSE_MSM_ZERO_FRACTION < scalars.len() {
+        return None;
+    }
+    Some(
+        maybe_par_iter!(bases)
+            .zip(maybe_par_iter!(scalars))
+            .filter(|(_, s)| !s.is_zero())
+            .map(|(base, s)| (*base, *s))
+            .unzip(),
+    )
+}
+
+/// The largest window [`VariableBaseMSM::msm_with_window`] accepts; a window of `c` bits
+/// uses `2^c` buckets.
+pub const MAX_WINDOW_BITS: usize = 24;
+
+/// The Pippenger window size, in bits, used for an MSM of `num_bases` bases unless the
+/// caller picks one.
+pub fn default_window_bits(num_bases: usize) -> usize {
+    if num_bases < 32 {
+        3
+    } else {
+        ln_without_floats(num_bases) + 2
+    }
+}
+
 // Compute msm using windowed non-adjacent form
 #[tracing::instrument(skip_all)]
 fn msm_bigint_wnaf<F: JoltField + PrimeField, V: VariableBaseMSM<ScalarField = F>>(
     bases: &[V::MulBase],
     scalars: &[<F as PrimeField>::BigInt],
     max_num_bits: usize,
+    c: usize,
 ) -> V {
-    let c = if bases.len() < 32 {
-        3
-    } else {
-        ln_without_floats(bases.len()) + 2
-    };
-
     let num_bits = max_num_bits;
     let digits_count = num_bits.div_ceil(c);
-    let scalar_digits = scalars
-        .into_par_iter()
+    let scalar_digits = maybe_into_par_iter!(scalars)
         .flat_map_iter(|s| make_digits_bigint(s, c, num_bits))
         .collect::<Vec<_>>();
     let zero = V::zero();
-    let window_sums: Vec<_> = (0..digits_count)
-        .into_par_iter()
+    let window_sums: Vec<_> = maybe_into_par_iter!(0..digits_count)
         .map(|i| {
             let mut buckets = vec![zero; 1 << c];
             for (digits, base) in scalar_digits.chunks(digits_count).zip(bases) {
@@ -599,15 +812,10 @@ fn msm_bigint<F: JoltField + PrimeField, V: VariableBaseMSM<ScalarField = F>>(
     bases: &[V::MulBase],
     scalars: &[<F as PrimeField>::BigInt],
     max_num_bits: usize,
+    c: usize,
 ) -> V {
     let scalars_and_bases_iter = scalars.iter().zip(bases).filter(|(s, _)| !s.is_zero());
 
-    let c = if bases.len() < 32 {
-        3
-    } else {
-        ln_without_floats(bases.len()) + 2
-    };
-
     let one = V::ScalarField::one().into_bigint();
 
     let zero = V::zero();
@@ -741,22 +949,17 @@ fn msm_medium<F, V, T>(
     scalars: &[T],
     max_num_bits: usize,
     _use_icicle: bool,
+    c: usize,
 ) -> V
 where
     F: JoltField,
     V: VariableBaseMSM<ScalarField = F>,
     T: Into<u64> + Zero + Copy + Sync,
 {
-    let c = if bases.len() < 32 {
-        3
-    } else {
-        ln_without_floats(bases.len()) + 2
-    };
-
     let zero = V::zero();
 
     let scalars_and_bases_iter = scalars.iter().zip(bases).filter(|(s, _base)| !s.is_zero());
-    let window_starts = (0..max_num_bits).into_par_iter().step_by(c);
+    let window_starts = maybe_into_par_iter!(0..max_num_bits).step_by(c);
 
     // Each window is of size `c`.
     // We divide up the bits 0..num_bits into windows of size `c`, and
@@ -883,3 +1086,209 @@ fn ln_without_floats(a: usize) -> usize {
     // log2(a) * ln(2)
     (ark_std::log2(a) * 69 / 100) as usize
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fr, G1Affine, G1Projective};
+    use ark_std::UniformRand;
+    use rand_core::{RngCore, SeedableRng};
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn msm_is_independent_of_thread_count() {
+        let n = 1 << 10;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(n as u64);
+        let bases: Vec<G1Affine> = std::iter::repeat_with(|| G1Affine::rand(&mut rng))
+            .take(n)
+            .collect();
+        let polys: Vec<MultilinearPolynomial<Fr>> = vec![
+            MultilinearPolynomial::from(
+                std::iter::repeat_with(|| Fr::rand(&mut rng))
+                    .take(n)
+                    .collect::<Vec<_>>(),
+            ),
+            MultilinearPolynomial::from(
+                std::iter::repeat_with(|| rng.next_u64())
+                    .take(n)
+                    .collect::<Vec<_>>(),
+            ),
+            MultilinearPolynomial::from(
+                std::iter::repeat_with(|| rng.next_u32() as u8)
+                    .take(n)
+                    .collect::<Vec<_>>(),
+            ),
+        ];
+
+        let results: Vec<(Vec<G1Affine>, Vec<G1Affine>)> = [1, 2, 8]
+            .into_iter()
+            .map(|num_threads| {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .unwrap();
+                pool.install(|| {
+                    let single = polys
+                        .iter()
+                        .map(|poly| {
+                            <G1Projective as VariableBaseMSM>::msm(&bases, None, poly, None)
+                                .unwrap()
+                                .into_affine()
+                        })
+                        .collect();
+                    let batched =
+                        <G1Projective as VariableBaseMSM>::batch_msm(&bases, None, &polys)
+                            .into_iter()
+                            .map(|c| c.into_affine())
+                            .collect();
+                    (single, batched)
+                })
+            })
+            .collect();
+
+        for (single, batched) in results.iter() {
+            assert_eq!(single, &results[0].0);
+            assert_eq!(batched, &results[0].1);
+            assert_eq!(single, batched);
+        }
+    }
+
+    #[test]
+    fn msm_skips_zero_scalars() {
+        let n = 1 << 10;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(274);
+        let bases: Vec<G1Affine> = std::iter::repeat_with(|| G1Affine::rand(&mut rng))
+            .take(n)
+            .collect();
+        // 90% of the coefficients are zero
+        let coeffs: Vec<Fr> = (0..n)
+            .map(|_| {
+                if rng.next_u32() % 10 == 0 {
+                    Fr::rand(&mut rng)
+                } else {
+                    Fr::zero()
+                }
+            })
+            .collect();
+        let naive: G1Projective = bases
+            .iter()
+            .zip(coeffs.iter())
+            .map(|(base, coeff)| *base * coeff)
+            .sum();
+
+        let poly = MultilinearPolynomial::from(coeffs.clone());
+        assert_eq!(
+            <G1Projective as VariableBaseMSM>::msm(&bases, None, &poly, None).unwrap(),
+            naive
+        );
+        assert_eq!(
+            <G1Projective as VariableBaseMSM>::msm_field_elements(
+                &bases, None, &coeffs, None, false
+            )
+            .unwrap(),
+            naive
+        );
+
+        let zeros = vec![Fr::zero(); n];
+        assert_eq!(
+            <G1Projective as VariableBaseMSM>::msm_field_elements(
+                &bases, None, &zeros, None, false
+            )
+            .unwrap(),
+            G1Projective::zero()
+        );
+    }
+
+    #[test]
+    fn msm_mixed_matches_msm() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(273);
+        let points: Vec<G1Projective> = std::iter::repeat_with(|| G1Projective::rand(&mut rng))
+            .take(40)
+            .collect();
+        let scalars: Vec<Fr> = std::iter::repeat_with(|| Fr::rand(&mut rng))
+            .take(40)
+            .collect();
+        let affine = G1Projective::normalize_batch(&points);
+
+        let expected = <G1Projective as VariableBaseMSM>::msm_field_elements(
+            &affine, None, &scalars, None, false,
+        )
+        .unwrap();
+        for num_affine in [0, 1, 7, 39, 40] {
+            let mixed = <G1Projective as VariableBaseMSM>::msm_mixed(
+                &affine[..num_affine],
+                &points[num_affine..],
+                &scalars,
+            )
+            .unwrap();
+            assert_eq!(mixed, expected, "{num_affine} affine bases");
+        }
+
+        assert_eq!(
+            <G1Projective as VariableBaseMSM>::msm_mixed(&[], &[], &[]).unwrap(),
+            G1Projective::zero()
+        );
+        assert!(<G1Projective as VariableBaseMSM>::msm_mixed(
+            &affine[..3],
+            &points[3..5],
+            &scalars
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn msm_with_window_matches_default_window() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(272);
+        for n in [1, 24, 300] {
+            let bases: Vec<G1Affine> = std::iter::repeat_with(|| G1Affine::rand(&mut rng))
+                .take(n)
+                .collect();
+            let scalar_sets: Vec<Vec<Fr>> = vec![
+                std::iter::repeat_with(|| Fr::rand(&mut rng))
+                    .take(n)
+                    .collect(),
+                std::iter::repeat_with(|| Fr::from_u64(rng.next_u64()))
+                    .take(n)
+                    .collect(),
+                std::iter::repeat_with(|| Fr::from_u64(rng.next_u64() & 0xfff))
+                    .take(n)
+                    .collect(),
+                std::iter::repeat_with(|| Fr::from_u64(rng.next_u64() & 0xf))
+                    .take(n)
+                    .collect(),
+                vec![Fr::zero(); n],
+            ];
+
+            for scalars in scalar_sets.iter() {
+                let expected = <G1Projective as VariableBaseMSM>::msm_field_elements(
+                    &bases, None, scalars, None, false,
+                )
+                .unwrap();
+                for window_bits in 1..=16 {
+                    let result = <G1Projective as VariableBaseMSM>::msm_with_window(
+                        &bases,
+                        scalars,
+                        window_bits,
+                    )
+                    .unwrap();
+                    assert_eq!(result, expected, "n = {n}, window of {window_bits} bits");
+                }
+            }
+        }
+
+        let bases = vec![G1Affine::rand(&mut rng); 2];
+        assert!(
+            <G1Projective as VariableBaseMSM>::msm_with_window(&bases, &[Fr::one()], 4).is_err()
+        );
+        for window_bits in [0, MAX_WINDOW_BITS + 1] {
+            assert!(matches!(
+                <G1Projective as VariableBaseMSM>::msm_with_window(
+                    &bases,
+                    &[Fr::one(); 2],
+                    window_bits
+                ),
+                Err(ProofVerifyError::InvalidWindowBits(_))
+            ));
+        }
+    }
+}
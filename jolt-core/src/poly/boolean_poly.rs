@@ -0,0 +1,125 @@
+use crate::field::JoltField;
+use crate::utils::math::Math;
+
+use super::multilinear_polynomial::MultilinearPolynomial;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A multilinear polynomial whose evaluations over the Boolean hypercube are all
+/// 0 or 1, stored as a bitset (one bit per evaluation) rather than as field elements.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BooleanPolynomial {
+    /// Evaluations packed little-endian: evaluation `i` is bit `i % 64` of `bits[i / 64]`.
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl BooleanPolynomial {
+    /// Creates an all-zero polynomial with `len` evaluations.
+    pub fn new(len: usize) -> Self {
+        Self {
+            bits: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    pub fn from_bools(evals: &[bool]) -> Self {
+        let mut poly = Self::new(evals.len());
+        for (i, _) in evals.iter().enumerate().filter(|(_, bit)| **bit) {
+            poly.set(i, true);
+        }
+        poly
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get_num_vars(&self) -> usize {
+        self.len.log_2()
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len);
+        (self.bits[index / WORD_BITS] >> (index % WORD_BITS)) & 1 == 1
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len);
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            self.bits[index / WORD_BITS] |= mask;
+        } else {
+            self.bits[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// The packed evaluations, 64 per word.
+    pub fn words(&self) -> &[u64] {
+        &self.bits
+    }
+
+    /// Number of evaluations equal to 1.
+    pub fn count_ones(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterates over the indices of the evaluations equal to 1, in increasing order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(word_index, word)| {
+            set_bits(*word).map(move |bit| word_index * WORD_BITS + bit)
+        })
+    }
+}
+
+/// Iterates over the positions of the set bits of `word`, lowest first.
+pub(crate) fn set_bits(mut word: u64) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        if word == 0 {
+            return None;
+        }
+        let bit = word.trailing_zeros() as usize;
+        word &= word - 1;
+        Some(bit)
+    })
+}
+
+impl<F: JoltField> From<&BooleanPolynomial> for MultilinearPolynomial<F> {
+    fn from(poly: &BooleanPolynomial) -> Self {
+        let evals: Vec<u8> = (0..poly.len()).map(|i| poly.get(i) as u8).collect();
+        MultilinearPolynomial::from(evals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::test_rng;
+    use rand_core::RngCore;
+
+    #[test]
+    fn boolean_poly_get_set() {
+        let mut rng = test_rng();
+        let evals: Vec<bool> = (0..200).map(|_| rng.next_u32() % 3 == 0).collect();
+        let mut poly = BooleanPolynomial::from_bools(&evals);
+
+        assert_eq!(poly.len(), evals.len());
+        for (i, bit) in evals.iter().enumerate() {
+            assert_eq!(poly.get(i), *bit);
+        }
+        assert_eq!(poly.count_ones(), evals.iter().filter(|bit| **bit).count());
+        assert!(poly.iter_ones().eq((0..evals.len()).filter(|i| evals[*i])));
+
+        poly.set(199, true);
+        assert!(poly.get(199));
+        poly.set(199, false);
+        assert!(!poly.get(199));
+    }
+}
@@ -0,0 +1,688 @@
+//! This module implements `Dory`, a transparent polynomial commitment for multilinear
+//! polynomials based on inner-pairing-product arguments (Lee, <https://eprint.iacr.org/2020/1274.pdf>).
+//!
+//! The evaluations of a polynomial in `ν` variables are laid out as a `2^(ν - ⌈ν/2⌉) x 2^⌈ν/2⌉`
+//! matrix `M` whose rows are selected by the first variables. The commitment is the pairing of
+//! the Pedersen commitments to the rows of `M` with a vector of G2 generators, and an evaluation
+//! `L^T M R` is proven with the Dory-Reduce inner-product argument, which folds the committed
+//! vectors in half each round, followed by a final scalar-product check.
+//!
+//! The setup is transparent: its generators are sampled from a public seed, so nobody knows
+//! their discrete logarithms. This implementation differs from the paper in two ways:
+//! (1) the verifier folds the public vectors `s1 = R` and `s2 = L` itself and computes the
+//! cross terms `<Γ1, s2>` and `<s1, Γ2>` with MSMs, so verification costs `O(2^(ν/2))` group
+//! operations instead of being fully logarithmic; and (2) the scheme is not zero-knowledge.
+use crate::field::JoltField;
+use crate::maybe_into_par_iter;
+use crate::poly::{dense_mlpoly::DensePolynomial, eq_poly::EqPolynomial};
+use crate::utils::{
+    errors::ProofVerifyError,
+    transcript::{AppendToTranscript, Transcript},
+};
+use ark_ec::{
+    pairing::{Pairing, PairingOutput},
+    AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{UniformRand, Zero};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use sha3::{Digest, Keccak256};
+use std::marker::PhantomData;
+use thiserror::Error;
+
+/// The public parameters of [`Dory`], supporting polynomials in up to `2 * log2(g1.len())`
+/// variables.
+#[derive(Clone, Debug)]
+pub struct DorySRS<P: Pairing> {
+    /// The G1 generators `Γ1`.
+    pub g1: Vec<P::G1Affine>,
+    /// The G2 generators `Γ2`.
+    pub g2: Vec<P::G2Affine>,
+    /// The G2 generator used to commit to the evaluation vector `L^T M`.
+    pub h2: P::G2Affine,
+    /// `chi[j] = <Γ1[..2^j], Γ2[..2^j]>`.
+    chi: Vec<PairingOutput<P>>,
+    /// `delta_1r[j] = <Γ1[2^(j-1)..2^j], Γ2[..2^(j-1)]>`, for `j >= 1`.
+    delta_1r: Vec<PairingOutput<P>>,
+    /// `delta_2r[j] = <Γ1[..2^(j-1)], Γ2[2^(j-1)..2^j]>`, for `j >= 1`.
+    delta_2r: Vec<PairingOutput<P>>,
+}
+
+impl<P: Pairing> DorySRS<P> {
+    /// Samples generators for polynomials in up to `max_num_vars` variables from a fixed
+    /// public seed, and precomputes the pairings the verifier needs.
+    pub fn setup(max_num_vars: usize) -> Self {
+        let max_col_vars = max_num_vars.div_ceil(2);
+        let n = 1 << max_col_vars;
+
+        let seed: [u8; 32] = Keccak256::digest(b"Jolt Dory generators").into();
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let g1: Vec<P::G1> = (0..n).map(|_| P::G1::rand(&mut rng)).collect();
+        let g2: Vec<P::G2> = (0..=n).map(|_| P::G2::rand(&mut rng)).collect();
+        let g1 = P::G1::normalize_batch(&g1);
+        let mut g2 = P::G2::normalize_batch(&g2);
+        let h2 = g2.pop().unwrap();
+
+        let chi = (0..=max_col_vars)
+            .map(|j| P::multi_pairing(&g1[..1 << j], &g2[..1 << j]))
+            .collect();
+        let (delta_1r, delta_2r) = (0..=max_col_vars)
+            .map(|j| {
+                if j == 0 {
+                    return (PairingOutput::zero(), PairingOutput::zero());
+                }
+                let half = 1 << (j - 1);
+                (
+                    P::multi_pairing(&g1[half..2 * half], &g2[..half]),
+                    P::multi_pairing(&g1[..half], &g2[half..2 * half]),
+                )
+            })
+            .unzip();
+
+        Self {
+            g1,
+            g2,
+            h2,
+            chi,
+            delta_1r,
+            delta_2r,
+        }
+    }
+
+    /// The largest number of columns of the evaluation matrix the parameters support.
+    pub fn max_num_cols(&self) -> usize {
+        self.g1.len()
+    }
+}
+
+/// The check a [`DoryProof`] failed, returned by [`Dory::verify`] as
+/// [`ProofVerifyError::DoryError`].
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum DoryError {
+    /// returned when a Fiat-Shamir challenge is zero, and so has no inverse
+    #[error("ZeroChallenge")]
+    ZeroChallenge,
+
+    /// returned when `e1` does not open the rows to the vector committed to by `d2`
+    #[error("InvalidEvaluationVector")]
+    InvalidEvaluationVector,
+
+    /// returned when the final folded `C` is not `e(v1, v2)`
+    #[error("InvalidFinalC")]
+    InvalidFinalC,
+
+    /// returned when the final folded `D1` is not `e(v1, Γ2[0])`
+    #[error("InvalidFinalD1")]
+    InvalidFinalD1,
+
+    /// returned when the final folded `D2` is not `e(Γ1[0], v2)`
+    #[error("InvalidFinalD2")]
+    InvalidFinalD2,
+
+    /// returned when the final folded `E1` is not `v1 * s2[0]`
+    #[error("InvalidFinalE1")]
+    InvalidFinalE1,
+
+    /// returned when the final folded `E2` is not `v2 * s1[0]`
+    #[error("InvalidFinalE2")]
+    InvalidFinalE2,
+}
+
+/// Splits `num_vars` variables into the number of row and column variables of the
+/// evaluation matrix.
+fn matrix_dims(num_vars: usize) -> (usize, usize) {
+    let col_vars = num_vars.div_ceil(2);
+    (num_vars - col_vars, col_vars)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DoryCommitment<P: Pairing>(pub PairingOutput<P>);
+
+impl<P: Pairing> AppendToTranscript for DoryCommitment<P> {
+    fn append_to_transcript<ProofTranscript: Transcript>(&self, transcript: &mut ProofTranscript) {
+        append_gt(transcript, &self.0);
+    }
+}
+
+fn append_gt<P: Pairing, ProofTranscript: Transcript>(
+    transcript: &mut ProofTranscript,
+    gt: &PairingOutput<P>,
+) {
+    let mut bytes = vec![];
+    gt.serialize_compressed(&mut bytes).unwrap();
+    transcript.append_bytes(&bytes);
+}
+
+/// The messages of one Dory-Reduce round: the first four are sent before the challenge
+/// `β`, the rest before `α`.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DoryRound<P: Pairing> {
+    pub d1_left: PairingOutput<P>,
+    pub d1_right: PairingOutput<P>,
+    pub d2_left: PairingOutput<P>,
+    pub d2_right: PairingOutput<P>,
+    pub c_plus: PairingOutput<P>,
+    pub c_minus: PairingOutput<P>,
+    pub e1_plus: P::G1Affine,
+    pub e1_minus: P::G1Affine,
+    pub e2_plus: P::G2Affine,
+    pub e2_minus: P::G2Affine,
+}
+
+impl<P: Pairing> DoryRound<P> {
+    fn append_first_message<ProofTranscript: Transcript>(&self, transcript: &mut ProofTranscript) {
+        for gt in [&self.d1_left, &self.d1_right, &self.d2_left, &self.d2_right] {
+            append_gt(transcript, gt);
+        }
+    }
+
+    fn append_second_message<ProofTranscript: Transcript>(&self, transcript: &mut ProofTranscript) {
+        append_gt(transcript, &self.c_plus);
+        append_gt(transcript, &self.c_minus);
+        transcript.append_point(&self.e1_plus.into_group());
+        transcript.append_point(&self.e1_minus.into_group());
+        transcript.append_point(&self.e2_plus.into_group());
+        transcript.append_point(&self.e2_minus.into_group());
+    }
+}
+
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DoryProof<P: Pairing> {
+    /// `e(Σ_i v_i V_i, h2)`, where `v = L^T M` and `V_i` commits to row `i`.
+    pub c: PairingOutput<P>,
+    /// `e(<v, Γ1>, h2)`.
+    pub d2: PairingOutput<P>,
+    /// `Σ_i L_i V_i`.
+    pub e1: P::G1Affine,
+    pub rounds: Vec<DoryRound<P>>,
+    /// The folded vectors once they have a single element.
+    pub v1: P::G1Affine,
+    pub v2: P::G2Affine,
+}
+
+/// Folds `left || right` into `scalar * left + right`.
+fn fold_scalars<F: JoltField>(s: &mut Vec<F>, scalar: F) {
+    let half = s.len() / 2;
+    let (left, right) = s.split_at(half);
+    *s = left
+        .iter()
+        .zip(right.iter())
+        .map(|(l, r)| scalar * *l + *r)
+        .collect();
+}
+
+fn fold_points<G: CurveGroup>(v: &mut Vec<G>, scalar: G::ScalarField) {
+    let half = v.len() / 2;
+    let (left, right) = v.split_at(half);
+    *v = left
+        .iter()
+        .zip(right.iter())
+        .map(|(l, r)| *l * scalar + r)
+        .collect();
+}
+
+/// Binds the transcript to the claim that `C` opens to `eval` at `point`, before any
+/// challenge is drawn, so that neither the point nor the evaluation can be chosen after
+/// seeing a challenge.
+fn append_claim<P: Pairing, ProofTranscript: Transcript>(
+    transcript: &mut ProofTranscript,
+    C: &DoryCommitment<P>,
+    point: &[P::ScalarField],
+    eval: &P::ScalarField,
+) {
+    C.append_to_transcript(transcript);
+    transcript.append_scalars::<P::ScalarField>(point);
+    transcript.append_scalar(eval);
+}
+
+fn msm<G: CurveGroup>(bases: &[G::Affine], scalars: &[G::ScalarField]) -> G {
+    assert_eq!(bases.len(), scalars.len());
+    G::msm_unchecked(bases, scalars)
+}
+
+#[derive(Clone)]
+pub struct Dory<P: Pairing, ProofTranscript: Transcript> {
+    _phantom: PhantomData<(P, ProofTranscript)>,
+}
+
+impl<P: Pairing, ProofTranscript: Transcript> Dory<P, ProofTranscript>
+where
+    P::ScalarField: JoltField,
+{
+    /// The Pedersen commitments to the rows of the evaluation matrix of `poly`.
+    fn row_commitments(
+        srs: &DorySRS<P>,
+        poly: &DensePolynomial<P::ScalarField>,
+    ) -> Result<Vec<P::G1>, ProofVerifyError> {
+        let (row_vars, col_vars) = matrix_dims(poly.get_num_vars());
+        let cols = 1 << col_vars;
+        if cols > srs.max_num_cols() {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: srs.max_num_cols(),
+                required_powers: cols,
+            });
+        }
+        let evals = poly.evals_ref();
+        Ok(maybe_into_par_iter!(0..1 << row_vars)
+            .map(|i| msm::<P::G1>(&srs.g1[..cols], &evals[i * cols..(i + 1) * cols]))
+            .collect())
+    }
+
+    pub fn commit(
+        srs: &DorySRS<P>,
+        poly: &DensePolynomial<P::ScalarField>,
+    ) -> Result<DoryCommitment<P>, ProofVerifyError> {
+        let row_commitments = Self::row_commitments(srs, poly)?;
+        let rows = row_commitments.len();
+        Ok(DoryCommitment(P::multi_pairing(
+            &row_commitments,
+            &srs.g2[..rows],
+        )))
+    }
+
+    pub fn open(
+        srs: &DorySRS<P>,
+        poly: &DensePolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<DoryProof<P>, ProofVerifyError> {
+        let num_vars = poly.get_num_vars();
+        if point.len() != num_vars {
+            return Err(ProofVerifyError::InvalidInputLength(num_vars, point.len()));
+        }
+        debug_assert_eq!(poly.evaluate(point), *eval);
+
+        let (row_vars, col_vars) = matrix_dims(num_vars);
+        let (rows, cols) = (1 << row_vars, 1 << col_vars);
+        let row_commitments = Self::row_commitments(srs, poly)?;
+        let C = DoryCommitment(P::multi_pairing(&row_commitments, &srs.g2[..rows]));
+        append_claim(transcript, &C, point, eval);
+        let L = EqPolynomial::evals(&point[..row_vars]);
+        let R = EqPolynomial::evals(&point[row_vars..]);
+
+        // v = L^T M
+        let evals = poly.evals_ref();
+        let v: Vec<P::ScalarField> = maybe_into_par_iter!(0..cols)
+            .map(|j| (0..rows).map(|i| L[i] * evals[i * cols + j]).sum())
+            .collect();
+
+        let row_commitments_affine = P::G1::normalize_batch(&row_commitments);
+        let c = P::pairing(msm::<P::G1>(&row_commitments_affine, &v[..rows]), srs.h2);
+        let e1 = msm::<P::G1>(&row_commitments_affine, &L).into_affine();
+        let d2 = P::pairing(msm::<P::G1>(&srs.g1[..cols], &v), srs.h2);
+        append_gt(transcript, &c);
+        append_gt(transcript, &d2);
+        transcript.append_point(&e1.into_group());
+
+        let mut v1 = row_commitments;
+        v1.resize(cols, P::G1::zero());
+        let mut v2: Vec<P::G2> = v.iter().map(|v_j| srs.h2 * v_j).collect();
+        let mut s1 = R;
+        let mut s2 = L;
+        s2.resize(cols, P::ScalarField::zero());
+
+        let mut rounds = Vec::with_capacity(col_vars);
+        for j in (1..=col_vars).rev() {
+            let half = 1 << (j - 1);
+            let (g1, g2) = (&srs.g1[..2 * half], &srs.g2[..2 * half]);
+
+            let d1_left = P::multi_pairing(&v1[..half], &g2[..half]);
+            let d1_right = P::multi_pairing(&v1[half..], &g2[..half]);
+            let d2_left = P::multi_pairing(&g1[..half], &v2[..half]);
+            let d2_right = P::multi_pairing(&g1[..half], &v2[half..]);
+            for gt in [&d1_left, &d1_right, &d2_left, &d2_right] {
+                append_gt(transcript, gt);
+            }
+            let beta: P::ScalarField = transcript.challenge_scalar();
+            let beta_inv = beta.inverse().ok_or(DoryError::ZeroChallenge)?;
+
+            v1.iter_mut()
+                .zip(g1.iter())
+                .for_each(|(v, g)| *v += *g * beta);
+            v2.iter_mut()
+                .zip(g2.iter())
+                .for_each(|(v, g)| *v += *g * beta_inv);
+
+            let v1_affine = P::G1::normalize_batch(&v1);
+            let v2_affine = P::G2::normalize_batch(&v2);
+            let round = DoryRound {
+                d1_left,
+                d1_right,
+                d2_left,
+                d2_right,
+                c_plus: P::multi_pairing(&v1_affine[..half], &v2_affine[half..]),
+                c_minus: P::multi_pairing(&v1_affine[half..], &v2_affine[..half]),
+                e1_plus: msm::<P::G1>(&v1_affine[..half], &s2[half..]).into_affine(),
+                e1_minus: msm::<P::G1>(&v1_affine[half..], &s2[..half]).into_affine(),
+                e2_plus: msm::<P::G2>(&v2_affine[half..], &s1[..half]).into_affine(),
+                e2_minus: msm::<P::G2>(&v2_affine[..half], &s1[half..]).into_affine(),
+            };
+            round.append_second_message(transcript);
+            let alpha: P::ScalarField = transcript.challenge_scalar();
+            let alpha_inv = alpha.inverse().ok_or(DoryError::ZeroChallenge)?;
+
+            fold_points(&mut v1, alpha);
+            fold_points(&mut v2, alpha_inv);
+            fold_scalars(&mut s1, alpha);
+            fold_scalars(&mut s2, alpha_inv);
+            rounds.push(round);
+        }
+
+        let v1 = v1[0].into_affine();
+        let v2 = v2[0].into_affine();
+        transcript.append_point(&v1.into_group());
+        transcript.append_point(&v2.into_group());
+
+        Ok(DoryProof {
+            c,
+            d2,
+            e1,
+            rounds,
+            v1,
+            v2,
+        })
+    }
+
+    pub fn verify(
+        srs: &DorySRS<P>,
+        C: &DoryCommitment<P>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        proof: &DoryProof<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let (row_vars, col_vars) = matrix_dims(point.len());
+        let cols = 1 << col_vars;
+        if cols > srs.max_num_cols() {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: srs.max_num_cols(),
+                required_powers: cols,
+            });
+        }
+        if proof.rounds.len() != col_vars {
+            return Err(ProofVerifyError::InvalidInputLength(
+                col_vars,
+                proof.rounds.len(),
+            ));
+        }
+
+        let mut s1 = EqPolynomial::evals(&point[row_vars..]);
+        let mut s2 = EqPolynomial::evals(&point[..row_vars]);
+        s2.resize(cols, P::ScalarField::zero());
+
+        append_claim(transcript, C, point, eval);
+        append_gt(transcript, &proof.c);
+        append_gt(transcript, &proof.d2);
+        transcript.append_point(&proof.e1.into_group());
+
+        // The evaluation vector committed to in G2 is the one L opens the rows to
+        if P::pairing(proof.e1, srs.h2) != proof.d2 {
+            return Err(DoryError::InvalidEvaluationVector.into());
+        }
+
+        let mut c = proof.c;
+        let mut d1 = C.0;
+        let mut d2 = proof.d2;
+        let mut e1: P::G1 = proof.e1.into();
+        let mut e2: P::G2 = srs.h2 * eval;
+
+        for (round, j) in proof.rounds.iter().zip((1..=col_vars).rev()) {
+            let half = 1 << (j - 1);
+
+            round.append_first_message(transcript);
+            let beta: P::ScalarField = transcript.challenge_scalar();
+            let beta_inv = beta.inverse().ok_or(DoryError::ZeroChallenge)?;
+            c += srs.chi[j] + d2 * beta + d1 * beta_inv;
+            e1 += msm::<P::G1>(&srs.g1[..2 * half], &s2) * beta;
+            e2 += msm::<P::G2>(&srs.g2[..2 * half], &s1) * beta_inv;
+
+            round.append_second_message(transcript);
+            let alpha: P::ScalarField = transcript.challenge_scalar();
+            let alpha_inv = alpha.inverse().ok_or(DoryError::ZeroChallenge)?;
+            c += round.c_plus * alpha + round.c_minus * alpha_inv;
+            d1 = round.d1_left * alpha
+                + round.d1_right
+                + srs.chi[j - 1] * (alpha * beta)
+                + srs.delta_1r[j] * beta;
+            d2 = round.d2_left * alpha_inv
+                + round.d2_right
+                + srs.chi[j - 1] * (alpha_inv * beta_inv)
+                + srs.delta_2r[j] * beta_inv;
+            e1 += round.e1_plus * alpha + round.e1_minus * alpha_inv;
+            e2 += round.e2_plus * alpha + round.e2_minus * alpha_inv;
+            fold_scalars(&mut s1, alpha);
+            fold_scalars(&mut s2, alpha_inv);
+        }
+
+        transcript.append_point(&proof.v1.into_group());
+        transcript.append_point(&proof.v2.into_group());
+
+        if c != P::pairing(proof.v1, proof.v2) {
+            return Err(DoryError::InvalidFinalC.into());
+        }
+        if d1 != P::pairing(proof.v1, srs.g2[0]) {
+            return Err(DoryError::InvalidFinalD1.into());
+        }
+        if d2 != P::pairing(srs.g1[0], proof.v2) {
+            return Err(DoryError::InvalidFinalD2.into());
+        }
+        if e1 != proof.v1 * s2[0] {
+            return Err(DoryError::InvalidFinalE1.into());
+        }
+        if e2 != proof.v2 * s1[0] {
+            return Err(DoryError::InvalidFinalE2.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transcript::KeccakTranscript;
+    use ark_bn254::{Bn254, Fr};
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_dory_eval() {
+        // Test with poly(X1, X2) = 1 + X1 + X2 + X1*X2
+        let srs = DorySRS::<Bn254>::setup(2);
+
+        // poly is in eval. representation; evaluated at [(0,0), (0,1), (1,0), (1,1)]
+        let poly = DensePolynomial::new(vec![Fr::from(1), Fr::from(2), Fr::from(2), Fr::from(4)]);
+
+        let C = Dory::<_, KeccakTranscript>::commit(&srs, &poly).unwrap();
+
+        let test_inner = |point: Vec<Fr>, eval: Fr| -> Result<(), ProofVerifyError> {
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            let claimed_eval = if cfg!(debug_assertions) {
+                poly.evaluate(&point)
+            } else {
+                eval
+            };
+            let proof = Dory::open(&srs, &poly, &point, &claimed_eval, &mut tr).unwrap();
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            Dory::verify(&srs, &C, &point, &eval, &proof, &mut tr)
+        };
+
+        // Call the prover with a (point, eval) pair. In debug builds the prover checks the
+        // pair, so incorrect evaluations are only passed to the verifier
+        let point = vec![Fr::from(0), Fr::from(0)];
+        let eval = Fr::from(1);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(0), Fr::from(1)];
+        let eval = Fr::from(2);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(1), Fr::from(1)];
+        let eval = Fr::from(4);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(0), Fr::from(2)];
+        let eval = Fr::from(3);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(2), Fr::from(2)];
+        let eval = Fr::from(9);
+        assert!(test_inner(point, eval).is_ok());
+
+        // Try a couple incorrect evaluations and expect failure
+        let point = vec![Fr::from(2), Fr::from(2)];
+        let eval = Fr::from(50);
+        assert!(test_inner(point, eval).is_err());
+
+        let point = vec![Fr::from(0), Fr::from(2)];
+        let eval = Fr::from(4);
+        assert!(test_inner(point, eval).is_err());
+    }
+
+    #[test]
+    fn test_dory_small() {
+        // poly = [1, 2, 1, 4]
+        let poly = DensePolynomial::new(vec![Fr::from(1), Fr::from(2), Fr::from(1), Fr::from(4)]);
+
+        // point = [4,3]
+        let point = vec![Fr::from(4), Fr::from(3)];
+
+        // eval = 28
+        let eval = Fr::from(28);
+
+        let srs = DorySRS::<Bn254>::setup(2);
+
+        // make a commitment
+        let C = Dory::<_, KeccakTranscript>::commit(&srs, &poly).unwrap();
+
+        // prove an evaluation
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let proof = Dory::open(&srs, &poly, &point, &eval, &mut tr).unwrap();
+        let post_c_p = tr.challenge_scalar::<Fr>();
+
+        // verify the evaluation
+        let mut verifier_transcript = KeccakTranscript::new(b"TestEval");
+        assert!(Dory::verify(&srs, &C, &point, &eval, &proof, &mut verifier_transcript).is_ok());
+        let post_c_v = verifier_transcript.challenge_scalar::<Fr>();
+
+        // check if the prover transcript and verifier transcript are kept in the same state
+        assert_eq!(post_c_p, post_c_v);
+
+        // Change the proof and expect verification to fail
+        let mut bad_proof = proof.clone();
+        bad_proof.rounds[0].c_plus = bad_proof.rounds[0].c_minus;
+        let mut verifier_transcript2 = KeccakTranscript::new(b"TestEval");
+        assert!(matches!(
+            Dory::verify(
+                &srs,
+                &C,
+                &point,
+                &eval,
+                &bad_proof,
+                &mut verifier_transcript2
+            ),
+            Err(ProofVerifyError::DoryError(DoryError::InvalidFinalC))
+        ));
+
+        let mut bad_proof = proof.clone();
+        bad_proof.e1 = (bad_proof.e1 + srs.g1[0]).into_affine();
+        let mut verifier_transcript3 = KeccakTranscript::new(b"TestEval");
+        assert!(matches!(
+            Dory::verify(
+                &srs,
+                &C,
+                &point,
+                &eval,
+                &bad_proof,
+                &mut verifier_transcript3
+            ),
+            Err(ProofVerifyError::DoryError(
+                DoryError::InvalidEvaluationVector
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_dory_binds_claim() {
+        // `open` and `verify` absorb the commitment, the point and the evaluation before the
+        // first challenge, so a proof does not carry over to another claim
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(262);
+        let srs = DorySRS::<Bn254>::setup(4);
+        let poly = DensePolynomial::new((0..16).map(|_| Fr::random(&mut rng)).collect());
+        let point = (0..4).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let C = Dory::<_, KeccakTranscript>::commit(&srs, &poly).unwrap();
+
+        let mut prover_tr = KeccakTranscript::new(b"TestBinding");
+        let proof = Dory::open(&srs, &poly, &point, &eval, &mut prover_tr).unwrap();
+        let post_c_p = prover_tr.challenge_scalar::<Fr>();
+        let mut verifier_tr = KeccakTranscript::new(b"TestBinding");
+        assert!(Dory::verify(&srs, &C, &point, &eval, &proof, &mut verifier_tr).is_ok());
+        assert_eq!(post_c_p, verifier_tr.challenge_scalar::<Fr>());
+
+        // The same proof for another point, or the commitment to another polynomial
+        let mut other_point = point.clone();
+        other_point[3] += Fr::from(1);
+        let mut verifier_tr = KeccakTranscript::new(b"TestBinding");
+        assert!(matches!(
+            Dory::verify(&srs, &C, &other_point, &eval, &proof, &mut verifier_tr),
+            Err(ProofVerifyError::DoryError(_))
+        ));
+        let other_poly = DensePolynomial::new((0..16).map(|_| Fr::random(&mut rng)).collect());
+        let other_C = Dory::<_, KeccakTranscript>::commit(&srs, &other_poly).unwrap();
+        let mut verifier_tr = KeccakTranscript::new(b"TestBinding");
+        assert!(matches!(
+            Dory::verify(&srs, &other_C, &point, &eval, &proof, &mut verifier_tr),
+            Err(ProofVerifyError::DoryError(_))
+        ));
+    }
+
+    #[test]
+    fn test_dory_large() {
+        // Odd numbers of variables give matrices with fewer rows than columns
+        for ell in [0, 1, 4, 5, 6, 7] {
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(261 + ell as u64);
+
+            let n = 1 << ell;
+            let poly = DensePolynomial::new((0..n).map(|_| Fr::random(&mut rng)).collect());
+            let point = (0..ell).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+            let eval = poly.evaluate(&point);
+
+            let srs = DorySRS::<Bn254>::setup(ell);
+            let C = Dory::<_, KeccakTranscript>::commit(&srs, &poly).unwrap();
+
+            let mut prover_transcript = KeccakTranscript::new(b"TestEval");
+            let proof = Dory::open(&srs, &poly, &point, &eval, &mut prover_transcript).unwrap();
+
+            let mut verifier_tr = KeccakTranscript::new(b"TestEval");
+            assert!(Dory::verify(&srs, &C, &point, &eval, &proof, &mut verifier_tr).is_ok());
+
+            // Change the claimed evaluation and expect verification to fail
+            let mut verifier_tr2 = KeccakTranscript::new(b"TestEval");
+            let bad_eval = eval + Fr::from(1);
+            assert!(Dory::verify(&srs, &C, &point, &bad_eval, &proof, &mut verifier_tr2).is_err());
+
+            // Change the proof and expect verification to fail
+            let mut bad_proof = proof.clone();
+            bad_proof.v1 = (bad_proof.v1 + srs.g1[0]).into_affine();
+            let mut verifier_tr3 = KeccakTranscript::new(b"TestEval");
+            assert!(Dory::verify(&srs, &C, &point, &eval, &bad_proof, &mut verifier_tr3).is_err());
+        }
+    }
+
+    #[test]
+    fn test_dory_key_too_small() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(261);
+        let srs = DorySRS::<Bn254>::setup(3);
+        let poly = DensePolynomial::new((0..1 << 6).map(|_| Fr::random(&mut rng)).collect());
+        assert!(matches!(
+            Dory::<_, KeccakTranscript>::commit(&srs, &poly),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers: 4,
+                required_powers: 8
+            })
+        ));
+    }
+}
@@ -9,28 +9,49 @@
 //! and within the KZG commitment scheme implementation itself).
 use super::{
     commitment_scheme::CommitmentScheme,
-    kzg::{KZGProverKey, KZGVerifierKey, UnivariateKZG},
+    kzg::{CommitKey, KZGProverKey, KZGVerifierKey, UnivariateKZG},
 };
 use crate::field::JoltField;
 use crate::poly::multilinear_polynomial::{MultilinearPolynomial, PolynomialEvaluation};
 use crate::utils::transcript::Transcript;
+use crate::{maybe_into_par_iter, maybe_join, maybe_par_iter, maybe_par_iter_mut};
 use crate::{
-    msm::{Icicle, VariableBaseMSM},
-    poly::{commitment::kzg::SRS, dense_mlpoly::DensePolynomial, unipoly::UniPoly},
+    msm::{GpuBaseType, Icicle, MsmBackend, VariableBaseMSM},
+    poly::{
+        boolean_poly::{set_bits, BooleanPolynomial},
+        commitment::{kzg::SRS, ptau::read_powers_of_tau},
+        dense_mlpoly::DensePolynomial,
+        unipoly::UniPoly,
+    },
     utils::{errors::ProofVerifyError, transcript::AppendToTranscript},
 };
 use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use ark_std::{One, Zero};
+#[cfg(not(feature = "parallel"))]
+use itertools::Itertools;
 use rand_chacha::ChaCha20Rng;
 use rand_core::{CryptoRng, RngCore, SeedableRng};
+#[cfg(feature = "parallel")]
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
     IntoParallelRefMutIterator, ParallelIterator,
 };
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
-use std::{marker::PhantomData, sync::Arc};
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+#[cfg(feature = "prover-timings")]
+use std::path::Path;
+use std::time::Instant;
+use std::{
+    marker::PhantomData,
+    sync::{Arc, OnceLock},
+};
 
+#[derive(Clone)]
 pub struct HyperKZGSRS<P: Pairing>(Arc<SRS<P>>)
 where
     P::G1: Icicle;
@@ -46,9 +67,61 @@ where
         Self(Arc::new(SRS::setup(rng, max_degree, 2)))
     }
 
+    /// Same as [`HyperKZGSRS::setup`] with a `ChaCha20Rng` seeded with `seed`, so that a seed
+    /// always gives the same SRS, e.g. to pin test vectors shared with other
+    /// implementations. Its trapdoor is derived from the seed, so it is only fit for tests.
+    pub fn setup_from_seed(seed: [u8; 32], max_degree: usize) -> Self
+    where
+        P::ScalarField: JoltField,
+    {
+        Self::setup(&mut ChaCha20Rng::from_seed(seed), max_degree)
+    }
+
+    /// Exports the G1 powers followed by the G2 powers, each as a compressed `Vec` (a
+    /// little-endian `u64` length, then the compressed points). Read back with
+    /// [`HyperKZGSRS::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            self.0.g1_powers.compressed_size() + self.0.g2_powers.compressed_size(),
+        );
+        self.0.g1_powers.serialize_compressed(&mut bytes).unwrap();
+        self.0.g2_powers.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Imports an SRS exported by [`HyperKZGSRS::to_bytes`], checking that each point is on
+    /// its curve and in the prime-order subgroup. A length prefix promising more points than
+    /// the remaining bytes could hold is rejected before anything is allocated for it.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, ProofVerifyError> {
+        let max_g1_powers = bytes.len() / P::G1Affine::zero().compressed_size();
+        let g1_powers: Vec<P::G1Affine> =
+            read_vec(&mut bytes, Compress::Yes, Validate::Yes, max_g1_powers)?;
+        let max_g2_powers = bytes.len() / P::G2Affine::zero().compressed_size();
+        let g2_powers: Vec<P::G2Affine> =
+            read_vec(&mut bytes, Compress::Yes, Validate::Yes, max_g2_powers)?;
+        if !bytes.is_empty() {
+            return Err(ProofVerifyError::InvalidInputLength(0, bytes.len()));
+        }
+        Ok(Self(Arc::new(SRS::from_powers(g1_powers, g2_powers))))
+    }
+
     pub fn trim(self, max_degree: usize) -> (HyperKZGProverKey<P>, HyperKZGVerifierKey<P>) {
         let (kzg_pk, kzg_vk) = SRS::trim(self.0, max_degree);
-        (HyperKZGProverKey { kzg_pk }, HyperKZGVerifierKey { kzg_vk })
+        (
+            HyperKZGProverKey::new(kzg_pk),
+            HyperKZGVerifierKey { kzg_vk },
+        )
+    }
+}
+
+impl HyperKZGSRS<ark_bn254::Bn254> {
+    /// Loads the SRS from the output of a powers-of-tau ceremony in the `.ptau` format,
+    /// supporting polynomials of up to `max_degree + 1` evaluations. Only a prefix of the
+    /// file is read, and the powers are checked to be consistent before being used; see
+    /// [`read_powers_of_tau`].
+    pub fn from_reader<R: Read>(reader: R, max_degree: usize) -> Result<Self, ProofVerifyError> {
+        let (g1_powers, g2_powers) = read_powers_of_tau(reader, max_degree + 1, 2)?;
+        Ok(Self(Arc::new(SRS::from_powers(g1_powers, g2_powers))))
     }
 }
 
@@ -58,16 +131,202 @@ where
     P::G1: Icicle,
 {
     pub kzg_pk: KZGProverKey<P>,
+    /// MSM backend used by `commit`, chosen on first use (see `msm_backend`)
+    msm_backend: OnceLock<MsmBackend>,
+    /// Pippenger window of the CPU MSMs of `commit` and `open` (see `msm_window_bits`)
+    msm_window_bits: Option<usize>,
+}
+
+impl<P: Pairing> HyperKZGProverKey<P>
+where
+    P::ScalarField: JoltField,
+    P::G1: Icicle,
+{
+    /// Number of bases used by the micro-benchmark that selects the MSM backend.
+    const MSM_BENCHMARK_SIZE: usize = 1 << 12;
+
+    pub fn new(kzg_pk: KZGProverKey<P>) -> Self {
+        Self {
+            kzg_pk,
+            msm_backend: OnceLock::new(),
+            msm_window_bits: None,
+        }
+    }
+
+    /// Length of the longest polynomial this key can commit to and open, i.e. the number
+    /// of G1 powers it holds. Longer polynomials are rejected with
+    /// [`ProofVerifyError::KeyLengthError`].
+    pub fn max_poly_len(&self) -> usize {
+        self.kzg_pk.g1_powers().len()
+    }
+
+    /// The MSM backend used to commit with this key. Unless overridden with
+    /// `set_msm_backend`, this is selected on first use by timing a sample commitment
+    /// with each backend available on this host, and cached thereafter.
+    pub fn msm_backend(&self) -> MsmBackend {
+        *self.msm_backend.get_or_init(|| self.select_msm_backend())
+    }
+
+    pub fn set_msm_backend(&mut self, backend: MsmBackend) {
+        self.msm_backend = OnceLock::from(backend);
+    }
+
+    /// The Pippenger window, in bits, of the CPU MSMs this key commits and opens with. None
+    /// (the default) sizes each window from the number of bases of its MSM.
+    pub fn msm_window_bits(&self) -> Option<usize> {
+        self.msm_window_bits
+    }
+
+    /// Overrides the Pippenger window of this key's CPU MSMs. The window must be between 1
+    /// and [`MAX_WINDOW_BITS`](crate::msm::MAX_WINDOW_BITS), or the MSMs fail with [`ProofVerifyError::InvalidWindowBits`].
+    pub fn set_msm_window_bits(&mut self, window_bits: Option<usize>) {
+        self.msm_window_bits = window_bits;
+    }
+
+    fn select_msm_backend(&self) -> MsmBackend {
+        let candidates = MsmBackend::available();
+        if candidates.len() == 1 {
+            return candidates[0];
+        }
+
+        let n = Self::MSM_BENCHMARK_SIZE.min(self.kzg_pk.g1_powers().len());
+        let mut rng = ChaCha20Rng::seed_from_u64(n as u64);
+        let poly = MultilinearPolynomial::from(
+            (0..n)
+                .map(|_| P::ScalarField::random(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        candidates
+            .into_iter()
+            .min_by_key(|backend| {
+                let start = Instant::now();
+                let _ = UnivariateKZG::commit_as_univariate_with_backend(
+                    &self.kzg_pk,
+                    &poly,
+                    *backend,
+                    self.msm_window_bits,
+                );
+                start.elapsed()
+            })
+            .unwrap()
+    }
+}
+
+impl<P: Pairing> CommitKey<P> for HyperKZGProverKey<P>
+where
+    P::ScalarField: JoltField,
+    P::G1: Icicle,
+{
+    fn g1_powers(&self) -> &[P::G1Affine] {
+        self.kzg_pk.g1_powers()
+    }
+
+    fn gpu_g1(&self) -> Option<&[GpuBaseType<P::G1>]> {
+        self.kzg_pk.gpu_g1()
+    }
+
+    fn msm_backend(&self) -> MsmBackend {
+        HyperKZGProverKey::msm_backend(self)
+    }
+
+    fn msm_window_bits(&self) -> Option<usize> {
+        HyperKZGProverKey::msm_window_bits(self)
+    }
+}
+
+/// A prover key that can only commit: it owns a copy of the G1 powers of a
+/// [`HyperKZGProverKey`] and drops the rest of the SRS, so it is much cheaper to hand
+/// out to parties that never open. Obtained with [`HyperKZGProverKey::commit_only_key`].
+#[derive(Clone, Debug)]
+pub struct CommitOnlyKey<P: Pairing>
+where
+    P::G1: Icicle,
+{
+    g1_powers: Vec<P::G1Affine>,
+    gpu_g1: Option<Vec<GpuBaseType<P::G1>>>,
+    msm_backend: MsmBackend,
+    msm_window_bits: Option<usize>,
+}
+
+impl<P: Pairing> HyperKZGProverKey<P>
+where
+    P::ScalarField: JoltField,
+    P::G1: Icicle,
+{
+    pub fn commit_only_key(&self) -> CommitOnlyKey<P> {
+        CommitOnlyKey {
+            g1_powers: self.kzg_pk.g1_powers().to_vec(),
+            gpu_g1: self.kzg_pk.gpu_g1().map(|gpu_g1| gpu_g1.to_vec()),
+            msm_backend: self.msm_backend(),
+            msm_window_bits: self.msm_window_bits(),
+        }
+    }
+}
+
+impl<P: Pairing> CommitKey<P> for CommitOnlyKey<P>
+where
+    P::G1: Icicle,
+{
+    fn g1_powers(&self) -> &[P::G1Affine] {
+        &self.g1_powers
+    }
+
+    fn gpu_g1(&self) -> Option<&[GpuBaseType<P::G1>]> {
+        self.gpu_g1.as_deref()
+    }
+
+    fn msm_backend(&self) -> MsmBackend {
+        self.msm_backend
+    }
+
+    fn msm_window_bits(&self) -> Option<usize> {
+        self.msm_window_bits
+    }
 }
 
+/// Only holds `g1`, `g2` and `beta_g2`, which do not depend on the degree the SRS was
+/// trimmed to: a verifier key from any `trim` of an SRS verifies proofs made with a prover
+/// key from any other `trim` of the same SRS.
 #[derive(Copy, Clone, Debug)]
 pub struct HyperKZGVerifierKey<P: Pairing> {
     pub kzg_vk: KZGVerifierKey<P>,
 }
 
+impl<P: Pairing> HyperKZGVerifierKey<P> {
+    /// Precomputes the G2 side of the final pairing check, which only depends on the key,
+    /// for verifiers that check many proofs with [`HyperKZG::verify_prepared`].
+    pub fn prepare(&self) -> PreparedHyperKZGVerifierKey<P> {
+        PreparedHyperKZGVerifierKey {
+            vk: *self,
+            g2: self.kzg_vk.g2.into(),
+            beta_g2: self.kzg_vk.beta_g2.into(),
+        }
+    }
+}
+
+/// A [`HyperKZGVerifierKey`] along with its G2 elements prepared for pairing.
+#[derive(Clone, Debug)]
+pub struct PreparedHyperKZGVerifierKey<P: Pairing> {
+    pub vk: HyperKZGVerifierKey<P>,
+    g2: P::G2Prepared,
+    beta_g2: P::G2Prepared,
+}
+
 #[derive(Debug, Clone, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct HyperKZGCommitment<P: Pairing>(pub P::G1Affine);
 
+impl<P: Pairing> Eq for HyperKZGCommitment<P> {}
+
+/// Hashes the compressed encoding of the commitment, which is unique to the point, so that
+/// commitments can key a cache of committed polynomials.
+impl<P: Pairing> Hash for HyperKZGCommitment<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut bytes = Vec::with_capacity(self.0.compressed_size());
+        self.0.serialize_compressed(&mut bytes).unwrap();
+        bytes.hash(state);
+    }
+}
+
 impl<P: Pairing> Default for HyperKZGCommitment<P> {
     fn default() -> Self {
         Self(P::G1Affine::zero())
@@ -87,64 +346,315 @@ pub struct HyperKZGProof<P: Pairing> {
     pub v: Vec<Vec<P::ScalarField>>,
 }
 
-// On input f(x) and u compute the witness polynomial used to prove
-// that f(u) = v. The main part of this is to compute the
-// division (f(x) - f(u)) / (x - u), but we don't use a general
-// division algorithm, we make use of the fact that the division
-// never has a remainder, and that the denominator is always a linear
-// polynomial. The cost is (d-1) mults + (d-1) adds in P::ScalarField, where
-// d is the degree of f.
-//
-// We use the fact that if we compute the quotient of f(x)/(x-u),
-// there will be a remainder, but it'll be v = f(u).  Put another way
-// the quotient of f(x)/(x-u) and (f(x) - f(v))/(x-u) is the
-// same.  One advantage is that computing f(u) could be decoupled
-// from kzg_open, it could be done later or separate from computing W.
+/// A [`HyperKZGProof`] along with a commitment to the batched polynomial
+/// B = sum_i q^i * P_i opened in its last phase, as produced by
+/// [`HyperKZG::open_with_batch_commitment`]. Kept apart from `HyperKZGProof` so that
+/// proofs without it keep their serialization.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+pub struct HyperKZGProofWithBatchCommitment<P: Pairing> {
+    pub proof: HyperKZGProof<P>,
+    pub b_com: P::G1Affine,
+}
+
+impl<P: Pairing> HyperKZGProofWithBatchCommitment<P> {
+    /// The length of the compressed serialization: the proof's, then the point.
+    pub fn serialized_size(&self) -> usize {
+        self.proof.serialized_size() + self.b_com.compressed_size()
+    }
+}
+
+/// Reads a `u64` length prefix, rejecting lengths above `max_len`.
+fn read_len<R: Read>(
+    reader: &mut R,
+    compress: Compress,
+    validate: Validate,
+    max_len: usize,
+) -> Result<usize, ProofVerifyError> {
+    let len = u64::deserialize_with_mode(reader, compress, validate)?;
+    if len > max_len as u64 {
+        return Err(ProofVerifyError::InputTooLarge);
+    }
+    Ok(len as usize)
+}
+
+/// Reads a vector in the format of the derived `CanonicalDeserialize`, rejecting it before
+/// allocating anything if its length prefix is above `max_len`.
+fn read_vec<T: CanonicalDeserialize, R: Read>(
+    reader: &mut R,
+    compress: Compress,
+    validate: Validate,
+    max_len: usize,
+) -> Result<Vec<T>, ProofVerifyError> {
+    let len = read_len(reader, compress, validate, max_len)?;
+    (0..len)
+        .map(|_| Ok(T::deserialize_with_mode(&mut *reader, compress, validate)?))
+        .collect()
+}
+
+impl<P: Pairing> HyperKZGProof<P> {
+    /// The length of the proof's compressed serialization, computed from its dimensions
+    /// alone: each vector is a `u64` length followed by its elements.
+    pub fn serialized_size(&self) -> usize {
+        const LEN_SIZE: usize = 8;
+        let point_size = P::G1Affine::zero().compressed_size();
+        let scalar_size = P::ScalarField::zero().compressed_size();
+        let points_size = |n: usize| LEN_SIZE + n * point_size;
+        points_size(self.com.len())
+            + points_size(self.w.len())
+            + LEN_SIZE
+            + self
+                .v
+                .iter()
+                .map(|v_i| LEN_SIZE + v_i.len() * scalar_size)
+                .sum::<usize>()
+    }
+
+    /// Deserializes a proof for a polynomial of at most `max_num_vars` variables, in the
+    /// same format as the derived `CanonicalDeserialize`. Every length prefix is checked
+    /// against the bound before anything is allocated for it, so a malicious length
+    /// cannot cause a huge allocation.
+    pub fn deserialize_bounded<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+        max_num_vars: usize,
+    ) -> Result<Self, ProofVerifyError> {
+        // com holds one commitment per fold, w and v one entry per opening point
+        let com = read_vec(
+            &mut reader,
+            compress,
+            validate,
+            max_num_vars.saturating_sub(1),
+        )?;
+        let w = read_vec(&mut reader, compress, validate, 3)?;
+        let num_points = read_len(&mut reader, compress, validate, 3)?;
+        let v = (0..num_points)
+            .map(|_| read_vec(&mut reader, compress, validate, max_num_vars))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { com, w, v })
+    }
+
+    /// Builds a proof from its parts, as returned by
+    /// [`Self::commitments`], [`Self::witnesses`] and [`Self::evaluations`]. There must be
+    /// one witness and one vector of evaluations per opening point, i.e. three of each.
+    pub fn from_parts(
+        com: Vec<P::G1Affine>,
+        w: Vec<P::G1Affine>,
+        v: Vec<Vec<P::ScalarField>>,
+    ) -> Result<Self, ProofVerifyError> {
+        if v.len() != 3 {
+            return Err(ProofVerifyError::InvalidInputLength(3, v.len()));
+        }
+        if w.len() != 3 {
+            return Err(ProofVerifyError::InvalidInputLength(3, w.len()));
+        }
+        Ok(Self { com, w, v })
+    }
+
+    /// Commitments to the folded polynomials.
+    pub fn commitments(&self) -> &[P::G1Affine] {
+        &self.com
+    }
+
+    /// KZG witnesses for the batched opening, one per opening point.
+    pub fn witnesses(&self) -> &[P::G1Affine] {
+        &self.w
+    }
+
+    /// Evaluations of every folded polynomial, one vector per opening point.
+    pub fn evaluations(&self) -> &[Vec<P::ScalarField>] {
+        &self.v
+    }
+}
+
+/// Commits to the witness polynomials of the openings of `f` at each of `u`.
+///
+/// The witness for `f(u) = v` is `(f(x) - v) / (x - u)`. Rather than a general division
+/// algorithm this uses synthetic division by the linear `x - u`, which costs (d-1) mults +
+/// (d-1) adds in P::ScalarField for `f` of degree d: the quotient of `f(x) / (x - u)` is the
+/// same as that of `(f(x) - v) / (x - u)`, with remainder `v`, so `v` need not be known here.
+///
+/// HyperKZG opens at `r`, `-r` and `r^2`, and the witnesses for `r` and `-r` could share a
+/// division of `f` by `x^2 - r^2` (see `benches/hyperkzg_witness.rs`). That division and
+/// the products with `r` it then needs take as many multiplications as two synthetic
+/// divisions, and the divisions for the three points already run in parallel, so each point
+/// is divided separately.
 fn kzg_batch_open_no_rem<P: Pairing>(
     f: &MultilinearPolynomial<P::ScalarField>,
     u: &[P::ScalarField],
     pk: &HyperKZGProverKey<P>,
-) -> Vec<P::G1Affine>
+) -> Result<Vec<P::G1Affine>, ProofVerifyError>
 where
     <P as Pairing>::ScalarField: JoltField,
     <P as Pairing>::G1: Icicle,
 {
     let f: &DensePolynomial<P::ScalarField> = f.try_into().unwrap();
-    let h = u
-        .par_iter()
+    let h = maybe_par_iter!(u)
         .map(|ui| {
-            let h = compute_witness_polynomial::<P>(&f.evals(), *ui);
+            let h = UnivariateKZG::<P>::witness_polynomial(&f.evals(), *ui);
             MultilinearPolynomial::from(h)
         })
         .collect::<Vec<_>>();
 
-    UnivariateKZG::commit_batch(&pk.kzg_pk, &h).unwrap()
+    UnivariateKZG::commit_batch(&pk.kzg_pk, &h)
+}
+
+/// Fixes the last variable of `evals` (a polynomial in evaluation form, whose last variable is
+/// the least significant bit of the index) to `r`, halving its size.
+///
+/// A constant `r` of 0 or 1 makes the fold a plain select of the even or odd evaluations, so
+/// those rounds skip the field arithmetic. The folded polynomial still has to be committed
+/// to: the verifier checks every round against its commitment, whatever `r` is.
+fn fold<F: JoltField>(evals: &[F], r: F) -> Vec<F> {
+    let len = evals.len() / 2;
+    if r.is_zero() || r.is_one() {
+        let offset = r.is_one() as usize;
+        return evals[..2 * len]
+            .iter()
+            .skip(offset)
+            .step_by(2)
+            .copied()
+            .collect();
+    }
+    fold_uniform(evals, r)
+}
+
+/// Same as [`fold`], but always computes `r * (odd - even) + even`, whatever `r` is.
+fn fold_uniform<F: JoltField>(evals: &[F], r: F) -> Vec<F> {
+    DensePolynomial::fold_bottom_evals(evals, r)
+}
+
+/// Number of consecutive folds [`fold_levels`] computes per pass over a tile of
+/// `2^FOLD_TILE_LEVELS` evaluations.
+const FOLD_TILE_LEVELS: usize = 8;
+
+/// Below this many evaluations, [`fold_levels`] folds one level at a time, as the
+/// polynomial already fits in cache.
+const FOLD_TILE_THRESHOLD: usize = 1 << 16;
+
+/// Successively folds `evals` by `rs[0]`, `rs[1]`, ..., returning every fold, i.e. the same
+/// polynomials as repeatedly applying [`fold`].
+///
+/// Each fold only combines adjacent evaluations, so a tile of `2^k` contiguous evaluations
+/// folds `k` times independently of the rest of the polynomial. Large polynomials are folded
+/// `FOLD_TILE_LEVELS` levels at a time, tile by tile, so each pass reads the previous level
+/// once instead of once per level.
+pub fn fold_levels<F: JoltField>(evals: &[F], rs: &[F]) -> Vec<Vec<F>> {
+    fold_levels_inner(evals, rs, false)
+}
+
+fn fold_levels_inner<F: JoltField>(evals: &[F], rs: &[F], uniform: bool) -> Vec<Vec<F>> {
+    assert!(
+        evals.len() >> rs.len() >= 1,
+        "cannot fold {} times",
+        rs.len()
+    );
+    let fold_level = |evals: &[F], r: F| {
+        if uniform {
+            fold_uniform(evals, r)
+        } else {
+            fold(evals, r)
+        }
+    };
+
+    let mut levels: Vec<Vec<F>> = Vec::with_capacity(rs.len());
+    for rs in rs.chunks(FOLD_TILE_LEVELS) {
+        let current = levels.last().map_or(evals, |level| level.as_slice());
+        if current.len() < FOLD_TILE_THRESHOLD {
+            for r in rs {
+                let current = levels.last().map_or(evals, |level| level.as_slice());
+                let next = fold_level(current, *r);
+                levels.push(next);
+            }
+            continue;
+        }
+
+        let tile_len = 1 << rs.len();
+        let tiles: Vec<Vec<Vec<F>>> = maybe_into_par_iter!(0..current.len() / tile_len)
+            .map(|t| {
+                let mut tile_levels: Vec<Vec<F>> = Vec::with_capacity(rs.len());
+                for r in rs {
+                    let tile = tile_levels
+                        .last()
+                        .map_or(&current[t * tile_len..(t + 1) * tile_len], |level| {
+                            level.as_slice()
+                        });
+                    let next = fold_level(tile, *r);
+                    tile_levels.push(next);
+                }
+                tile_levels
+            })
+            .collect();
+        let block: Vec<Vec<F>> = maybe_into_par_iter!(0..rs.len())
+            .map(|k| {
+                tiles
+                    .iter()
+                    .flat_map(|tile_levels| tile_levels[k].iter().copied())
+                    .collect()
+            })
+            .collect();
+        levels.extend(block);
+    }
+    levels
 }
 
-fn compute_witness_polynomial<P: Pairing>(
-    f: &[P::ScalarField],
-    u: P::ScalarField,
-) -> Vec<P::ScalarField>
+fn kzg_open_batch<P: Pairing, ProofTranscript: Transcript>(
+    f: &[MultilinearPolynomial<P::ScalarField>],
+    u: &[P::ScalarField],
+    pk: &HyperKZGProverKey<P>,
+    transcript: &mut ProofTranscript,
+    commit_to_B: bool,
+) -> Result<
+    (
+        Vec<P::G1Affine>,
+        Vec<Vec<P::ScalarField>>,
+        Option<P::G1Affine>,
+    ),
+    ProofVerifyError,
+>
 where
     <P as Pairing>::ScalarField: JoltField,
+    <P as Pairing>::G1: Icicle,
 {
-    let d = f.len();
-
-    // Compute h(x) = f(x)/(x - u)
-    let mut h = vec![P::ScalarField::zero(); d];
-    for i in (1..d).rev() {
-        h[i - 1] = f[i] + h[i] * u;
-    }
+    kzg_open_batch_inner(f, u, pk, transcript, commit_to_B, false)
+}
 
-    h
+/// `sum_i coefficients[i] * polynomials[i]`, computed with a multiplication per evaluation
+/// regardless of its value (unlike [`MultilinearPolynomial::linear_combination`], which
+/// skips multiplications by 0 and 1).
+fn linear_combination_uniform<F: JoltField>(
+    polynomials: &[MultilinearPolynomial<F>],
+    coefficients: &[F],
+) -> MultilinearPolynomial<F> {
+    let len = polynomials.iter().map(|poly| poly.len()).max().unwrap();
+    let combined: Vec<F> = maybe_into_par_iter!(0..len)
+        .map(|j| {
+            polynomials
+                .iter()
+                .zip(coefficients)
+                .filter(|(poly, _)| j < poly.len())
+                .map(|(poly, coeff)| poly.get_coeff(j) * *coeff)
+                .sum()
+        })
+        .collect();
+    MultilinearPolynomial::from(combined)
 }
 
-fn kzg_open_batch<P: Pairing, ProofTranscript: Transcript>(
+fn kzg_open_batch_inner<P: Pairing, ProofTranscript: Transcript>(
     f: &[MultilinearPolynomial<P::ScalarField>],
     u: &[P::ScalarField],
     pk: &HyperKZGProverKey<P>,
     transcript: &mut ProofTranscript,
-) -> (Vec<P::G1Affine>, Vec<Vec<P::ScalarField>>)
+    commit_to_B: bool,
+    uniform: bool,
+) -> Result<
+    (
+        Vec<P::G1Affine>,
+        Vec<Vec<P::ScalarField>>,
+        Option<P::G1Affine>,
+    ),
+    ProofVerifyError,
+>
 where
     <P as Pairing>::ScalarField: JoltField,
     <P as Pairing>::G1: Icicle,
@@ -155,9 +665,9 @@ where
     // The verifier needs f_i(u_j), so we compute them here
     // (V will compute B(u_j) itself)
     let mut v = vec![vec!(P::ScalarField::zero(); k); t];
-    v.par_iter_mut().enumerate().for_each(|(i, v_i)| {
+    maybe_par_iter_mut!(v).enumerate().for_each(|(i, v_i)| {
         // for each point u
-        v_i.par_iter_mut().zip_eq(f).for_each(|(v_ij, f)| {
+        maybe_par_iter_mut!(v_i).zip_eq(f).for_each(|(v_ij, f)| {
             // for each poly f
             *v_ij = UniPoly::eval_as_univariate(f, &u[i]);
         });
@@ -167,17 +677,131 @@ where
     let scalars = v.iter().flatten().collect::<Vec<&P::ScalarField>>();
     transcript.append_scalars::<P::ScalarField>(&scalars);
     let q_powers: Vec<P::ScalarField> = transcript.challenge_scalar_powers(f.len());
-    let B = MultilinearPolynomial::linear_combination(&f.iter().collect::<Vec<_>>(), &q_powers);
+    let B = if uniform {
+        linear_combination_uniform(f, &q_powers)
+    } else {
+        MultilinearPolynomial::linear_combination(&f.iter().collect::<Vec<_>>(), &q_powers)
+    };
+
+    // B and its witnesses (of degree B.len() - 2) are committed to with the prover key, so
+    // it must hold at least B.len() powers
+    let available_powers = pk.kzg_pk.g1_powers().len();
+    if available_powers < B.len() {
+        return Err(ProofVerifyError::KeyLengthError {
+            available_powers,
+            required_powers: B.len(),
+        });
+    }
+    let B_com = commit_to_B
+        .then(|| UnivariateKZG::commit_as_univariate(&pk.kzg_pk, &B))
+        .transpose()?;
 
     // Now open B at u0, ..., u_{t-1}
-    let w = kzg_batch_open_no_rem(&B, u, pk);
+    let w = kzg_batch_open_no_rem(&B, u, pk)?;
 
     // The prover computes the challenge to keep the transcript in the same
     // state as that of the verifier
     transcript.append_points(&w.iter().map(|g| g.into_group()).collect::<Vec<P::G1>>());
     let _d_0: P::ScalarField = transcript.challenge_scalar();
 
-    (w, v)
+    Ok((w, v, B_com))
+}
+
+/// A transcript interaction performed by [`HyperKZG::verify`].
+#[derive(Clone, Debug)]
+pub enum TranscriptOp<P: Pairing> {
+    AbsorbPoints(Vec<P::G1Affine>),
+    AbsorbScalars(Vec<P::ScalarField>),
+    /// Draws a single challenge.
+    SqueezeScalar,
+    /// Draws a challenge `q` and expands it to `(1, q, ..., q^(n-1))`.
+    SqueezeScalarPowers(usize),
+}
+
+/// The transcript interactions of [`HyperKZG::verify`], in order. Everything the verifier
+/// absorbs comes from the proof, so the script is fixed before any challenge is drawn.
+/// The native verifier runs it against its transcript; a recursion backend can instead
+/// reinterpret each operation (e.g. as in-circuit hashing) and pass the squeezed values
+/// to [`HyperKZG::verify_with_challenges`].
+#[derive(Clone, Debug)]
+pub struct VerifierScript<P: Pairing> {
+    pub ops: Vec<TranscriptOp<P>>,
+}
+
+impl<P: Pairing> VerifierScript<P>
+where
+    P::ScalarField: JoltField,
+{
+    /// Runs the script against `transcript`, returning the output of each squeeze in order.
+    pub fn run<ProofTranscript: Transcript>(
+        &self,
+        transcript: &mut ProofTranscript,
+    ) -> Vec<Vec<P::ScalarField>> {
+        self.ops
+            .iter()
+            .filter_map(|op| match op {
+                TranscriptOp::AbsorbPoints(points) => {
+                    transcript.append_points(
+                        &points
+                            .iter()
+                            .map(|g| g.into_group())
+                            .collect::<Vec<P::G1>>(),
+                    );
+                    None
+                }
+                TranscriptOp::AbsorbScalars(scalars) => {
+                    transcript.append_scalars::<P::ScalarField>(scalars);
+                    None
+                }
+                TranscriptOp::SqueezeScalar => Some(vec![transcript.challenge_scalar()]),
+                TranscriptOp::SqueezeScalarPowers(n) => {
+                    Some(transcript.challenge_scalar_powers(*n))
+                }
+            })
+            .collect()
+    }
+}
+
+// The transcript interactions of a batched KZG opening of k polynomials, shared by
+// `kzg_open_batch` and `kzg_verify_batch`:
+//   1. absorb the evaluations v (row by row),
+//   2. squeeze q, expanded to the k powers (1, q, ..., q^(k-1)) that batch the polynomials,
+//   3. absorb the witnesses W,
+//   4. squeeze d_0, which batches the pairing checks.
+// The prover only learns W after step 2, so it performs these steps inline.
+fn kzg_verify_batch_script<P: Pairing>(
+    W: &[P::G1Affine],
+    v: &[Vec<P::ScalarField>],
+    k: usize,
+) -> Vec<TranscriptOp<P>> {
+    vec![
+        TranscriptOp::AbsorbScalars(v.iter().flatten().copied().collect()),
+        TranscriptOp::SqueezeScalarPowers(k),
+        TranscriptOp::AbsorbPoints(W.to_vec()),
+        TranscriptOp::SqueezeScalar,
+    ]
+}
+
+/// Derives the batching challenges `(q_powers, d_0)` of a batched KZG opening with
+/// evaluations `v` of `k` polynomials and witnesses `W`, in the order described on
+/// `kzg_verify_batch_script`.
+fn kzg_batch_challenges<P: Pairing, ProofTranscript: Transcript>(
+    transcript: &mut ProofTranscript,
+    W: &[P::G1Affine],
+    v: &[Vec<P::ScalarField>],
+    k: usize,
+) -> (Vec<P::ScalarField>, P::ScalarField)
+where
+    <P as Pairing>::ScalarField: JoltField,
+    <P as Pairing>::G1: Icicle,
+{
+    let script = VerifierScript {
+        ops: kzg_verify_batch_script(W, v, k),
+    };
+    let mut squeezes = script.run(transcript).into_iter();
+    let q_powers = squeezes.next().unwrap();
+    let d_0 = squeezes.next().unwrap()[0];
+    (q_powers, d_0)
 }
 
 // vk is hashed in transcript already, so we do not add it here
@@ -187,25 +811,102 @@ fn kzg_verify_batch<P: Pairing, ProofTranscript: Transcript>(
     W: &[P::G1Affine],
     u: &[P::ScalarField],
     v: &[Vec<P::ScalarField>],
+    B_com: Option<&P::G1Affine>,
     transcript: &mut ProofTranscript,
 ) -> bool
+where
+    <P as Pairing>::ScalarField: JoltField,
+    <P as Pairing>::G1: Icicle,
+{
+    let (q_powers, d_0) = kzg_batch_challenges(transcript, W, v, C.len());
+    kzg_verify_batch_with_challenges(vk, C, W, u, v, B_com, &q_powers, d_0, None)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn kzg_verify_batch_with_challenges<P: Pairing>(
+    vk: &HyperKZGVerifierKey<P>,
+    C: &[P::G1Affine],
+    W: &[P::G1Affine],
+    u: &[P::ScalarField],
+    v: &[Vec<P::ScalarField>],
+    B_com: Option<&P::G1Affine>,
+    q_powers: &[P::ScalarField],
+    d_0: P::ScalarField,
+    mut timings: Option<&mut VerifyTimings>,
+) -> bool
+where
+    <P as Pairing>::ScalarField: JoltField,
+    <P as Pairing>::G1: Icicle,
+{
+    let Some((L, R)) = kzg_batch_pairing_inputs(vk, C, W, u, v, B_com, q_powers, d_0, &mut timings)
+    else {
+        return false;
+    };
+
+    // Check that e(L, vk.H) == e(R, vk.tau_H)
+    let start = VerifyPhaseStart::now();
+    let accepted = P::multi_pairing([L, -R], [vk.kzg_vk.g2, vk.kzg_vk.beta_g2]).is_zero();
+    VerifyTimings::record(&mut timings, VerifyTimings::PAIRING, start);
+    accepted
+}
+
+// `scalar * base` by a Montgomery ladder over all `MODULUS_BIT_SIZE` bits of `scalar`: every
+// step does one addition and one doubling, and the bits only select which of the two
+// registers is doubled, so the sequence of group operations does not depend on `scalar`.
+fn ladder_mul<G: CurveGroup>(base: G, scalar: &G::ScalarField) -> G {
+    let bits = scalar.into_bigint();
+    let mut registers = [G::zero(), base];
+    for i in (0..G::ScalarField::MODULUS_BIT_SIZE as usize).rev() {
+        let bit = bits.get_bit(i) as usize;
+        let sum = registers[0] + registers[1];
+        registers[bit] = registers[bit].double();
+        registers[1 - bit] = sum;
+    }
+    registers[0]
+}
+
+// The points (L, R) of the pairing check e(L, vk.H) == e(R, vk.tau_H) that a batched KZG
+// opening reduces to, or None if one of the checks before it fails.
+#[allow(clippy::too_many_arguments)]
+fn kzg_batch_pairing_inputs<P: Pairing>(
+    vk: &HyperKZGVerifierKey<P>,
+    C: &[P::G1Affine],
+    W: &[P::G1Affine],
+    u: &[P::ScalarField],
+    v: &[Vec<P::ScalarField>],
+    B_com: Option<&P::G1Affine>,
+    q_powers: &[P::ScalarField],
+    d_0: P::ScalarField,
+    timings: &mut Option<&mut VerifyTimings>,
+) -> Option<(P::G1, P::G1)>
 where
     <P as Pairing>::ScalarField: JoltField,
     <P as Pairing>::G1: Icicle,
 {
     let k = C.len();
     let t = u.len();
+    if q_powers.len() != k {
+        return None;
+    }
+    let start = VerifyPhaseStart::now();
 
-    let scalars = v.iter().flatten().collect::<Vec<&P::ScalarField>>();
-    transcript.append_scalars::<P::ScalarField>(&scalars);
-    let q_powers: Vec<P::ScalarField> = transcript.challenge_scalar_powers(k);
+    // By linearity, a commitment to B = sum_i q^i * f_i must equal sum_i q^i * C_i
+    if let Some(B_com) = B_com {
+        let expected =
+            <P::G1 as VariableBaseMSM>::msm_field_elements(C, None, q_powers, None, false).unwrap();
+        if expected.into_affine() != *B_com {
+            return None;
+        }
+    }
 
-    transcript.append_points(&W.iter().map(|g| g.into_group()).collect::<Vec<P::G1>>());
-    let d_0: P::ScalarField = transcript.challenge_scalar();
+    if W.len() != t || v.len() != t {
+        return None;
+    }
+    if t != 3 {
+        return kzg_batch_pairing_inputs_generic(vk, C, W, u, v, q_powers, d_0);
+    }
     let d_1 = d_0 * d_0;
 
-    assert_eq!(t, 3);
-    assert_eq!(W.len(), 3);
     // We write a special case for t=3, since this what is required for
     // hyperkzg. Following the paper directly, we must compute:
     // let L0 = C_B - vk.G * B_u[0] + W[0] * u[0];
@@ -226,18 +927,16 @@ where
 
     let q_power_multiplier: P::ScalarField = P::ScalarField::one() + d_0 + d_1;
 
-    let q_powers_multiplied: Vec<P::ScalarField> = q_powers
-        .par_iter()
+    let q_powers_multiplied: Vec<P::ScalarField> = maybe_par_iter!(q_powers)
         .map(|q_power| *q_power * q_power_multiplier)
         .collect();
 
     // Compute the batched openings
     // compute B(u_i) = v[i][0] + q*v[i][1] + ... + q^(t-1) * v[i][t-1]
-    let B_u = v
-        .into_par_iter()
+    let B_u = maybe_into_par_iter!(v)
         .map(|v_i| {
-            v_i.into_par_iter()
-                .zip(q_powers.par_iter())
+            maybe_into_par_iter!(v_i)
+                .zip_eq(maybe_par_iter!(q_powers))
                 .map(|(a, b)| *a * *b)
                 .sum()
         })
@@ -262,86 +961,748 @@ where
     .unwrap();
 
     let R = W[0] + W[1] * d_0 + W[2] * d_1;
-
-    // Check that e(L, vk.H) == e(R, vk.tau_H)
-    P::multi_pairing([L, -R], [vk.kzg_vk.g2, vk.kzg_vk.beta_g2]).is_zero()
-}
-
-#[derive(Clone)]
-pub struct HyperKZG<P: Pairing, ProofTranscript: Transcript> {
-    _phantom: PhantomData<(P, ProofTranscript)>,
+    VerifyTimings::record(timings, VerifyTimings::MSM, start);
+    Some((L, R))
 }
 
-impl<P: Pairing, ProofTranscript: Transcript> HyperKZG<P, ProofTranscript>
+// Batched KZG verification for an arbitrary number of points t, following the
+// paper directly with d = (1, d_0, d_0^2, ..., d_0^(t-1)):
+// L = sum_i d_i * (C_B - vk.G * B_u[i] + W[i] * u[i])
+// R = sum_i d_i * W[i]
+// HyperKZG only ever uses t=3, see the specialized path in `kzg_batch_pairing_inputs`.
+fn kzg_batch_pairing_inputs_generic<P: Pairing>(
+    vk: &HyperKZGVerifierKey<P>,
+    C: &[P::G1Affine],
+    W: &[P::G1Affine],
+    u: &[P::ScalarField],
+    v: &[Vec<P::ScalarField>],
+    q_powers: &[P::ScalarField],
+    d_0: P::ScalarField,
+) -> Option<(P::G1, P::G1)>
 where
     <P as Pairing>::ScalarField: JoltField,
     <P as Pairing>::G1: Icicle,
 {
-    pub fn protocol_name() -> &'static [u8] {
-        b"HyperKZG"
+    let k = C.len();
+    let t = u.len();
+    if v.iter().any(|v_i| v_i.len() != k) {
+        return None;
     }
 
-    pub fn commit(
-        pp: &HyperKZGProverKey<P>,
-        poly: &MultilinearPolynomial<P::ScalarField>,
-    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
-        if pp.kzg_pk.g1_powers().len() < poly.len() {
-            return Err(ProofVerifyError::KeyLengthError(
-                pp.kzg_pk.g1_powers().len(),
-                poly.len(),
-            ));
-        }
-        Ok(HyperKZGCommitment(UnivariateKZG::commit_as_univariate(
-            &pp.kzg_pk, poly,
-        )?))
+    let mut d = Vec::with_capacity(t);
+    d.push(P::ScalarField::one());
+    for i in 1..t {
+        d.push(d[i - 1] * d_0);
     }
+    let d_sum: P::ScalarField = d.iter().sum();
 
-    #[tracing::instrument(skip_all, name = "HyperKZG::open")]
-    pub fn open(
-        pk: &HyperKZGProverKey<P>,
-        poly: &MultilinearPolynomial<P::ScalarField>,
-        point: &[P::ScalarField],
-        _eval: &P::ScalarField,
-        transcript: &mut ProofTranscript,
-    ) -> Result<HyperKZGProof<P>, ProofVerifyError> {
-        let ell = point.len();
-        let n = poly.len();
-        assert_eq!(n, 1 << ell); // Below we assume that n is a power of two
-
-        // Phase 1  -- create commitments com_1, ..., com_\ell
-        // We do not compute final Pi (and its commitment) as it is constant and equals to 'eval'
-        // also known to verifier, so can be derived on its side as well
-        let mut polys: Vec<MultilinearPolynomial<P::ScalarField>> = Vec::new();
-        polys.push(poly.clone());
-        for i in 0..ell - 1 {
-            let previous_poly: &DensePolynomial<P::ScalarField> = (&polys[i]).try_into().unwrap();
-            let Pi_len = previous_poly.len() / 2;
-            let mut Pi = vec![P::ScalarField::zero(); Pi_len];
-            Pi.par_iter_mut().enumerate().for_each(|(j, Pi_j)| {
-                *Pi_j = point[ell - i - 1] * (previous_poly[2 * j + 1] - previous_poly[2 * j])
-                    + previous_poly[2 * j];
-            });
+    // compute B(u_i) = v[i][0] + q*v[i][1] + ... + q^(k-1) * v[i][k-1]
+    let B_u = maybe_par_iter!(v)
+        .map(|v_i| {
+            maybe_par_iter!(v_i)
+                .zip_eq(maybe_par_iter!(q_powers))
+                .map(|(a, b)| *a * *b)
+                .sum()
+        })
+        .collect::<Vec<P::ScalarField>>();
+    let B_u_batched: P::ScalarField = B_u.iter().zip(d.iter()).map(|(b, d)| *b * d).sum();
 
-            polys.push(MultilinearPolynomial::from(Pi));
+    let scalars = [
+        q_powers
+            .iter()
+            .map(|q_power| *q_power * d_sum)
+            .collect::<Vec<_>>(),
+        u.iter().zip(d.iter()).map(|(u, d)| *u * d).collect(),
+        vec![-B_u_batched],
+    ]
+    .concat();
+    let L = <P::G1 as VariableBaseMSM>::msm_field_elements(
+        &[C, W, &[vk.kzg_vk.g1]].concat(),
+        None,
+        &scalars,
+        None,
+        false,
+    )
+    .unwrap();
+
+    let R = <P::G1 as VariableBaseMSM>::msm_field_elements(W, None, &d, None, false).unwrap();
+    Some((L, R))
+}
+
+/// Profile of a single [`HyperKZG::open`] call, for tracking prover performance
+/// across commits. Serializes to JSON with a fixed set of keys. The phase timings are only
+/// recorded with the `prover-timings` feature, so that `open` does not read the clock
+/// otherwise; that feature also adds the JSON export.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HyperKZGOpenProfile {
+    pub num_vars: usize,
+    /// Lengths of the polynomials P_0, ..., P_{ell-1} folded in phase 1
+    pub poly_sizes: Vec<usize>,
+    /// Number of MSMs computed (one per commitment in the proof)
+    pub msm_count: usize,
+    /// Wall-clock time of each phase in microseconds, keyed by phase name
+    pub phase_timings_us: BTreeMap<String, u64>,
+}
+
+impl HyperKZGOpenProfile {
+    pub const FOLD: &'static str = "fold";
+    pub const COMMIT: &'static str = "commit";
+    pub const CHALLENGE: &'static str = "challenge";
+    pub const BATCH_OPEN: &'static str = "batch_open";
+
+    #[cfg(feature = "prover-timings")]
+    fn record_phase(&mut self, phase: &str, start: Instant) {
+        self.phase_timings_us
+            .insert(phase.to_string(), start.elapsed().as_micros() as u64);
+    }
+
+    #[cfg(feature = "prover-timings")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    #[cfg(feature = "prover-timings")]
+    pub fn write_json<Q: AsRef<Path>>(&self, path: Q) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+}
+
+/// Wall-clock breakdown of a single [`HyperKZG::verify`] call, in microseconds per phase;
+/// see `HyperKZG::verify_with_timings`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VerifyTimings {
+    pub total_us: u64,
+    pub phase_timings_us: BTreeMap<String, u64>,
+}
+
+impl VerifyTimings {
+    pub const TRANSCRIPT: &'static str = "transcript";
+    pub const CONSISTENCY: &'static str = "consistency";
+    pub const MSM: &'static str = "msm";
+    pub const PAIRING: &'static str = "pairing";
+
+    #[cfg(feature = "verify-timings")]
+    fn record(timings: &mut Option<&mut Self>, phase: &str, start: VerifyPhaseStart) {
+        if let Some(timings) = timings {
+            timings
+                .phase_timings_us
+                .insert(phase.to_string(), start.0.elapsed().as_micros() as u64);
+        }
+    }
+
+    #[cfg(not(feature = "verify-timings"))]
+    fn record(_: &mut Option<&mut Self>, _: &str, _: VerifyPhaseStart) {}
+}
+
+/// Start of a verifier phase timed into [`VerifyTimings`]. The clock is only read with the
+/// `verify-timings` feature; otherwise this is empty and `verify` never reads it.
+#[derive(Clone, Copy)]
+struct VerifyPhaseStart(#[cfg(feature = "verify-timings")] Instant);
+
+impl VerifyPhaseStart {
+    #[cfg(feature = "verify-timings")]
+    fn now() -> Self {
+        Self(Instant::now())
+    }
+
+    #[cfg(not(feature = "verify-timings"))]
+    fn now() -> Self {
+        Self()
+    }
+}
+
+#[derive(Clone)]
+pub struct HyperKZG<P: Pairing, ProofTranscript: Transcript> {
+    _phantom: PhantomData<(P, ProofTranscript)>,
+}
+
+impl<P: Pairing, ProofTranscript: Transcript> HyperKZG<P, ProofTranscript>
+where
+    <P as Pairing>::ScalarField: JoltField,
+    <P as Pairing>::G1: Icicle,
+{
+    pub fn protocol_name() -> &'static [u8] {
+        b"HyperKZG"
+    }
+
+    /// Commits to `poly` with either a full [`HyperKZGProverKey`] or a [`CommitOnlyKey`].
+    pub fn commit(
+        pp: &impl CommitKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
+        if pp.g1_powers().len() < poly.len() {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pp.g1_powers().len(),
+                required_powers: poly.len(),
+            });
+        }
+        Ok(HyperKZGCommitment(
+            UnivariateKZG::commit_as_univariate_with_backend(
+                pp,
+                poly,
+                pp.msm_backend(),
+                pp.msm_window_bits(),
+            )?,
+        ))
+    }
+
+    /// Commits to a dense polynomial by reference; equal to [`HyperKZG::commit`] of
+    /// `MultilinearPolynomial::LargeScalars(poly.clone())`, without the clone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ark_bn254::{Bn254, Fr};
+    /// use jolt_core::poly::commitment::hyperkzg::{HyperKZG, HyperKZGSRS};
+    /// use jolt_core::poly::dense_mlpoly::DensePolynomial;
+    /// use jolt_core::utils::transcript::KeccakTranscript;
+    /// use rand_core::SeedableRng;
+    ///
+    /// let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+    /// let (pk, _vk) = HyperKZGSRS::<Bn254>::setup(&mut rng, 4).trim(4);
+    /// let poly = DensePolynomial::new(vec![Fr::from(1u64), Fr::from(2u64), Fr::from(3u64), Fr::from(4u64)]);
+    /// let commitment = HyperKZG::<Bn254, KeccakTranscript>::commit_dense(&pk, &poly).unwrap();
+    /// ```
+    pub fn commit_dense(
+        pp: &impl CommitKey<P>,
+        poly: &DensePolynomial<P::ScalarField>,
+    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
+        let n = poly.len();
+        if pp.g1_powers().len() < n {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pp.g1_powers().len(),
+                required_powers: n,
+            });
+        }
+        let c = <P::G1 as VariableBaseMSM>::msm_field_elements_with_backend(
+            &pp.g1_powers()[..n],
+            pp.gpu_g1().map(|gpu_g1| &gpu_g1[..n]),
+            &poly.evals_ref()[..n],
+            None,
+            pp.msm_backend(),
+            pp.msm_window_bits(),
+        )?;
+        Ok(HyperKZGCommitment(c.into_affine()))
+    }
+
+    /// Commits to the multilinear polynomial with evaluations `evals` zero-padded to the next
+    /// power of two, without materializing the padding: the padded evaluations contribute
+    /// nothing, so the MSM runs over `evals` alone. The commitment equals the one
+    /// [`HyperKZG::commit`] gives for the padded polynomial.
+    pub fn commit_evals(
+        pp: &impl CommitKey<P>,
+        evals: &[P::ScalarField],
+    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
+        let n = evals.len();
+        if pp.g1_powers().len() < n {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pp.g1_powers().len(),
+                required_powers: n,
+            });
+        }
+        let c = <P::G1 as VariableBaseMSM>::msm_field_elements_with_backend(
+            &pp.g1_powers()[..n],
+            pp.gpu_g1().map(|gpu_g1| &gpu_g1[..n]),
+            evals,
+            None,
+            pp.msm_backend(),
+            pp.msm_window_bits(),
+        )?;
+        Ok(HyperKZGCommitment(c.into_affine()))
+    }
+
+    /// Commits to each of `polys`, in parallel across polynomials. If some polynomial is
+    /// longer than the key, returns the `KeyLengthError` for the first such polynomial.
+    #[tracing::instrument(skip_all, name = "HyperKZG::commit_batch")]
+    pub fn commit_batch(
+        pp: &impl CommitKey<P>,
+        polys: &[DensePolynomial<P::ScalarField>],
+    ) -> Result<Vec<HyperKZGCommitment<P>>, ProofVerifyError> {
+        let available_powers = pp.g1_powers().len();
+        if let Some(poly) = polys.iter().find(|poly| poly.len() > available_powers) {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers,
+                required_powers: poly.len(),
+            });
+        }
+
+        maybe_par_iter!(polys)
+            .map(|poly| Self::commit_dense(pp, poly))
+            .collect()
+    }
+
+    /// Commits to `poly` and also returns the commitment's compressed encoding, for callers
+    /// that persist commitments as soon as they are made.
+    pub fn commit_and_serialize(
+        pp: &impl CommitKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+    ) -> Result<(HyperKZGCommitment<P>, Vec<u8>), ProofVerifyError> {
+        let commitment = Self::commit(pp, poly)?;
+        let mut bytes = Vec::with_capacity(commitment.compressed_size());
+        commitment.serialize_compressed(&mut bytes)?;
+        Ok((commitment, bytes))
+    }
+
+    /// Commits to `poly` like [`Self::commit`], with the MSM accumulation blinded by
+    /// randomness drawn from `rng`: a random `blind` is added to the first scalar, and
+    /// `blind` times the first power in `pp` is subtracted from the MSM's result with a
+    /// Montgomery ladder. Only the first scalar is blinded, so the partial sums that include
+    /// the first power are randomized while the others still depend on `poly` alone. The
+    /// commitment is unchanged. Fails if `poly` is empty, as there is no scalar to blind.
+    pub fn commit_blinded_msm<R: RngCore + CryptoRng>(
+        pp: &impl CommitKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        rng: &mut R,
+    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
+        let n = poly.len();
+        if n == 0 {
+            return Err(ProofVerifyError::InvalidInputLength(1, 0));
+        }
+        if pp.g1_powers().len() < n {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pp.g1_powers().len(),
+                required_powers: n,
+            });
+        }
+
+        let blind = P::ScalarField::random(rng);
+        let mut scalars: Vec<P::ScalarField> = (0..n).map(|i| poly.get_coeff(i)).collect();
+        scalars[0] += blind;
+        let blinded = <P::G1 as VariableBaseMSM>::msm_field_elements_with_backend(
+            &pp.g1_powers()[..n],
+            pp.gpu_g1().map(|gpu_g1| &gpu_g1[..n]),
+            &scalars,
+            None,
+            pp.msm_backend(),
+            pp.msm_window_bits(),
+        )?;
+        let unblinded = blinded - ladder_mul(pp.g1_powers()[0].into_group(), &blind);
+        Ok(HyperKZGCommitment(unblinded.into_affine()))
+    }
+
+    /// Updates `commitment` to a polynomial after the evaluations at some indices changed,
+    /// given as `(index, old_value, new_value)`, without recommitting: by linearity the new
+    /// commitment is `commitment + sum (new_value - old_value) * g1_powers[index]`.
+    pub fn update_commitment(
+        pp: &impl CommitKey<P>,
+        commitment: &HyperKZGCommitment<P>,
+        updates: &[(usize, P::ScalarField, P::ScalarField)],
+    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
+        let g1_powers = pp.g1_powers();
+        if let Some(&(index, _, _)) = updates.iter().max_by_key(|(index, _, _)| *index) {
+            if index >= g1_powers.len() {
+                return Err(ProofVerifyError::KeyLengthError {
+                    available_powers: g1_powers.len(),
+                    required_powers: index + 1,
+                });
+            }
+        } else {
+            return Ok(commitment.clone());
+        }
+
+        let (bases, deltas): (Vec<P::G1Affine>, Vec<P::ScalarField>) = updates
+            .iter()
+            .map(|(index, old_value, new_value)| (g1_powers[*index], *new_value - *old_value))
+            .unzip();
+        let delta =
+            <P::G1 as VariableBaseMSM>::msm_field_elements(&bases, None, &deltas, None, false)?;
+        Ok(HyperKZGCommitment((commitment.0 + delta).into_affine()))
+    }
+
+    /// Commits to `poly` and also returns the univariate polynomial that was actually
+    /// committed to, i.e. the one whose coefficients are `poly`'s evaluations over the
+    /// Boolean hypercube.
+    pub fn commit_with_unipoly(
+        pp: &HyperKZGProverKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+    ) -> Result<(HyperKZGCommitment<P>, UniPoly<P::ScalarField>), ProofVerifyError> {
+        let commitment = Self::commit(pp, poly)?;
+        let coeffs = match poly {
+            MultilinearPolynomial::LargeScalars(poly) => poly.evals(),
+            _ => maybe_into_par_iter!(0..poly.len())
+                .map(|i| poly.get_coeff(i))
+                .collect(),
+        };
+        Ok((commitment, UniPoly::from_coeff(coeffs)))
+    }
+
+    /// Commits to a 0/1-valued polynomial. Since every coefficient is 0 or 1, the
+    /// commitment is just the sum of the SRS powers at the set bits, so no MSM is needed.
+    #[tracing::instrument(skip_all, name = "HyperKZG::commit_boolean")]
+    pub fn commit_boolean(
+        pp: &HyperKZGProverKey<P>,
+        poly: &BooleanPolynomial,
+    ) -> Result<HyperKZGCommitment<P>, ProofVerifyError> {
+        let g1_powers = pp.kzg_pk.g1_powers();
+        if g1_powers.len() < poly.len() {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: g1_powers.len(),
+                required_powers: poly.len(),
+            });
+        }
+        let c: P::G1 = maybe_par_iter!(poly.words())
+            .enumerate()
+            .map(|(word_index, word)| {
+                let base = word_index * u64::BITS as usize;
+                set_bits(*word).fold(P::G1::zero(), |acc, bit| acc + g1_powers[base + bit])
+            })
+            .sum();
+        Ok(HyperKZGCommitment(c.into_affine()))
+    }
+
+    /// Commits to each of `polys` (e.g. the columns of a witness matrix) and to their
+    /// aggregate `C_agg = sum_i gamma^i * C_i`, where gamma is drawn from `transcript`
+    /// after absorbing the individual commitments. `C_agg` is then absorbed too, so openings
+    /// made with `transcript` afterwards are bound to it.
+    #[tracing::instrument(skip_all, name = "HyperKZG::commit_aggregate")]
+    pub fn commit_aggregate(
+        pp: &HyperKZGProverKey<P>,
+        polys: &[MultilinearPolynomial<P::ScalarField>],
+        transcript: &mut ProofTranscript,
+    ) -> Result<(Vec<HyperKZGCommitment<P>>, HyperKZGCommitment<P>), ProofVerifyError> {
+        let commitments = polys
+            .iter()
+            .map(|poly| Self::commit(pp, poly))
+            .collect::<Result<Vec<_>, _>>()?;
+        let aggregate = Self::aggregate_commitments(&commitments, transcript);
+        Ok((commitments, aggregate))
+    }
+
+    fn aggregate_commitments(
+        commitments: &[HyperKZGCommitment<P>],
+        transcript: &mut ProofTranscript,
+    ) -> HyperKZGCommitment<P> {
+        let points: Vec<P::G1> = commitments.iter().map(|c| c.0.into_group()).collect();
+        transcript.append_points(&points);
+        let gamma_powers: Vec<P::ScalarField> =
+            transcript.challenge_scalar_powers(commitments.len());
+        let bases: Vec<P::G1Affine> = commitments.iter().map(|c| c.0).collect();
+        let aggregate = <P::G1 as VariableBaseMSM>::msm_field_elements(
+            &bases,
+            None,
+            &gamma_powers,
+            None,
+            false,
+        )
+        .unwrap();
+        transcript.append_point(&aggregate);
+        HyperKZGCommitment(aggregate.into_affine())
+    }
+
+    /// Verifies an opening of column `index` (produced by `open` on that column) against
+    /// its own commitment, after checking that `commitments` aggregate to `aggregate`.
+    /// `aggregate` is recomputed from `commitments` with the challenges `commit_aggregate`
+    /// drew, so only the columns it was committed from pass, and the opening's challenges
+    /// are drawn after absorbing it. `transcript` must be in the same state as it was when
+    /// `commit_aggregate` was called.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_column(
+        vk: &HyperKZGVerifierKey<P>,
+        commitments: &[HyperKZGCommitment<P>],
+        aggregate: &HyperKZGCommitment<P>,
+        index: usize,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        if index >= commitments.len() {
+            return Err(ProofVerifyError::InvalidInputLength(
+                commitments.len(),
+                index + 1,
+            ));
+        }
+        if Self::aggregate_commitments(commitments, transcript) != *aggregate {
+            return Err(ProofVerifyError::InternalError);
+        }
+        Self::verify(vk, &commitments[index], point, P_of_x, pi, transcript)
+    }
+
+    /// Proves the evaluation of `poly` at `point`. A `poly` with fewer than `2^point.len()`
+    /// evaluations is treated as zero-padded to that length. This needs `2^point.len()` G1
+    /// powers; with fewer it returns a `KeyLengthError`.
+    /// `eval` must be `poly` evaluated at `point`; this is only checked in debug builds.
+    ///
+    /// Coordinates of `point` equal to 0 or 1 make their fold a select, which skips its field
+    /// arithmetic, but not its commitment: the proof has one commitment per fold whatever the
+    /// point is. Dropping the commitments of the constant folds would need the verifier to
+    /// open `poly` at the `2^(k+1)` roots of `r^(2^(k+1))` for `k` such folds, which costs
+    /// more witness MSMs than the dropped commitments save.
+    #[tracing::instrument(skip_all, name = "HyperKZG::open")]
+    pub fn open(
+        pk: &HyperKZGProverKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<HyperKZGProof<P>, ProofVerifyError> {
+        Self::open_with_profile(pk, poly, point, eval, transcript).map(|(proof, _)| proof)
+    }
+
+    /// Proves the evaluation at `point` of the polynomial committed to by
+    /// [`HyperKZG::commit_evals`], i.e. `evals` zero-padded to `2^point.len()` evaluations.
+    /// The proof is checked with [`HyperKZG::verify`].
+    pub fn open_evals(
+        pk: &HyperKZGProverKey<P>,
+        evals: &[P::ScalarField],
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<HyperKZGProof<P>, ProofVerifyError> {
+        let n = 1 << point.len();
+        if evals.len() > n {
+            return Err(ProofVerifyError::InvalidInputLength(n, evals.len()));
+        }
+        let mut padded = evals.to_vec();
+        padded.resize(n, P::ScalarField::zero());
+        Self::open(
+            pk,
+            &MultilinearPolynomial::from(padded),
+            point,
+            eval,
+            transcript,
+        )
+    }
+
+    /// Same as [`HyperKZG::open`], but also commits to the batched polynomial B opened in
+    /// the last phase, so that composed protocols can bind to it. For a zero-variable
+    /// polynomial, B is the polynomial itself. The proof is checked with
+    /// [`HyperKZG::verify_with_batch_commitment`].
+    pub fn open_with_batch_commitment(
+        pk: &HyperKZGProverKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<HyperKZGProofWithBatchCommitment<P>, ProofVerifyError> {
+        let (proof, b_com, _) = Self::open_inner(pk, poly, point, eval, transcript, true, false)?;
+        Ok(HyperKZGProofWithBatchCommitment {
+            proof,
+            b_com: b_com.unwrap(),
+        })
+    }
+
+    /// Same as [`HyperKZG::open`], but also returns a [`HyperKZGOpenProfile`] of the
+    /// polynomial sizes, the MSM count and, with the `prover-timings` feature, the phase
+    /// timings.
+    pub fn open_with_profile(
+        pk: &HyperKZGProverKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(HyperKZGProof<P>, HyperKZGOpenProfile), ProofVerifyError> {
+        Self::open_inner(pk, poly, point, eval, transcript, false, false)
+            .map(|(proof, _, profile)| (proof, profile))
+    }
+
+    /// Same as [`HyperKZG::open`], and produces the same proof, but with fewer timing
+    /// side channels on the polynomial's evaluations, for provers that open secret
+    /// polynomials on behalf of others:
+    /// - a compact (small-scalar) polynomial is first converted to field elements, so the
+    ///   MSM algorithm is not chosen by the size of its evaluations;
+    /// - every fold multiplies by the point's coordinate, even when it is 0 or 1;
+    /// - the batched polynomial B is combined without skipping evaluations equal to 0 or 1.
+    ///
+    /// This is not constant-time. The MSMs still skip zero scalars and zero windows of each
+    /// scalar, and the field arithmetic (e.g. the final subtraction of Montgomery
+    /// multiplication) is data-dependent.
+    pub fn open_ct(
+        pk: &HyperKZGProverKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<HyperKZGProof<P>, ProofVerifyError> {
+        let poly = match poly {
+            MultilinearPolynomial::LargeScalars(_) => poly.clone(),
+            _ => MultilinearPolynomial::from(
+                (0..poly.len())
+                    .map(|i| poly.get_coeff(i))
+                    .collect::<Vec<_>>(),
+            ),
+        };
+        Self::open_inner(pk, &poly, point, eval, transcript, false, true).map(|(proof, _, _)| proof)
+    }
+
+    fn open_inner(
+        pk: &HyperKZGProverKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+        commit_to_B: bool,
+        uniform: bool,
+    ) -> Result<(HyperKZGProof<P>, Option<P::G1Affine>, HyperKZGOpenProfile), ProofVerifyError>
+    {
+        let ell = point.len();
+        let n = poly.len();
+        if n > 1 << ell {
+            // The point only reaches the first 2^ell powers of the committed polynomial
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: 1 << ell,
+                required_powers: n,
+            });
+        }
+        if n != 1 << ell {
+            // A shorter polynomial is opened as if zero-padded to 2^ell evaluations, which
+            // leaves its commitment unchanged
+            let padded = MultilinearPolynomial::from(
+                (0..1 << ell)
+                    .map(|i| {
+                        if i < n {
+                            poly.get_coeff(i)
+                        } else {
+                            P::ScalarField::zero()
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            return Self::open_inner(pk, &padded, point, eval, transcript, commit_to_B, uniform);
+        }
+        let mut profile = HyperKZGOpenProfile {
+            num_vars: ell,
+            ..Default::default()
+        };
+        if ell == 0 {
+            // A zero-variable polynomial is a constant, so there is nothing to open;
+            // see the corresponding case in `verify`.
+            debug_assert_eq!(poly.get_coeff(0), *eval, "`eval` is not `poly` at `point`");
+            profile.poly_sizes = vec![n];
+            let proof = HyperKZGProof {
+                com: vec![],
+                w: vec![],
+                v: vec![],
+            };
+            let b_com = commit_to_B
+                .then(|| UnivariateKZG::commit_as_univariate(&pk.kzg_pk, poly))
+                .transpose()?;
+            return Ok((proof, b_com, profile));
+        }
+        // The witness polynomial for B and B itself have as many coefficients as `poly`, so
+        // opening needs exactly as many powers as committing.
+        if pk.kzg_pk.g1_powers().len() < n {
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pk.kzg_pk.g1_powers().len(),
+                required_powers: n,
+            });
+        }
+
+        // Phase 1  -- create commitments com_1, ..., com_\ell
+        // We do not compute final Pi (and its commitment) as it is constant and equals to 'eval'
+        // also known to verifier, so can be derived on its side as well
+        //
+        // Each fold depends on the previous one, so the folds are computed in blocks of
+        // `FOLD_TILE_LEVELS` levels (see `fold_levels`). The commitments to a block do not
+        // depend on the next block, so we commit to the previous block while folding the next.
+        // The FOLD phase therefore also covers all commitments but those to the last block.
+        #[cfg(feature = "prover-timings")]
+        let start = Instant::now();
+        let mut polys: Vec<MultilinearPolynomial<P::ScalarField>> = Vec::with_capacity(ell);
+        let mut com: Vec<P::G1Affine> = Vec::with_capacity(ell - 1);
+        polys.push(poly.clone());
+        // We do not need to commit to the first polynomial as it is already committed.
+        let mut num_committed = 1;
+        let rs: Vec<P::ScalarField> = point[1..].iter().rev().copied().collect();
+        for rs in rs.chunks(FOLD_TILE_LEVELS) {
+            let previous_poly: &DensePolynomial<P::ScalarField> =
+                polys.last().unwrap().try_into().unwrap();
+            let (block, previous_com) = maybe_join!(
+                || fold_levels_inner(previous_poly.evals_ref(), rs, uniform),
+                || {
+                    polys[num_committed..]
+                        .iter()
+                        .map(|previous_poly| {
+                            UnivariateKZG::commit_as_univariate_with_backend(
+                                pk,
+                                previous_poly,
+                                pk.msm_backend(),
+                                pk.msm_window_bits(),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                },
+            );
+            com.extend(previous_com?);
+            num_committed = polys.len();
+            polys.extend(block.into_iter().map(MultilinearPolynomial::from));
         }
 
         assert_eq!(polys.len(), ell);
         assert_eq!(polys[ell - 1].len(), 2);
+        #[cfg(debug_assertions)]
+        {
+            let last = &polys[ell - 1];
+            let (even, odd) = (last.get_coeff(0), last.get_coeff(1));
+            let final_fold = even + point[0] * (odd - even);
+            debug_assert_eq!(final_fold, *eval, "`eval` is not `poly` at `point`");
+        }
+        #[cfg(feature = "prover-timings")]
+        profile.record_phase(HyperKZGOpenProfile::FOLD, start);
 
-        // We do not need to commit to the first polynomial as it is already committed.
-        let com: Vec<P::G1Affine> = UnivariateKZG::commit_variable_batch(&pk.kzg_pk, &polys[1..])?;
+        #[cfg(feature = "prover-timings")]
+        let start = Instant::now();
+        for poly in polys[num_committed..].iter() {
+            com.push(UnivariateKZG::commit_as_univariate_with_backend(
+                pk,
+                poly,
+                pk.msm_backend(),
+                pk.msm_window_bits(),
+            )?);
+        }
+        #[cfg(feature = "prover-timings")]
+        profile.record_phase(HyperKZGOpenProfile::COMMIT, start);
 
         // Phase 2
         // We do not need to add x to the transcript, because in our context x was obtained from the transcript.
         // We also do not need to absorb `C` and `eval` as they are already absorbed by the transcript by the caller
+        // (see `open_standalone` for when this does not hold)
+        #[cfg(feature = "prover-timings")]
+        let start = Instant::now();
         transcript.append_points(&com.iter().map(|g| g.into_group()).collect::<Vec<P::G1>>());
         let r: <P as Pairing>::ScalarField = transcript.challenge_scalar();
         let u = vec![r, -r, r * r];
+        #[cfg(feature = "prover-timings")]
+        profile.record_phase(HyperKZGOpenProfile::CHALLENGE, start);
 
         // Phase 3 -- create response
-        let (w, v) = kzg_open_batch(&polys, &u, pk, transcript);
+        #[cfg(feature = "prover-timings")]
+        let start = Instant::now();
+        let (w, v, b_com) = kzg_open_batch_inner(&polys, &u, pk, transcript, commit_to_B, uniform)?;
+        #[cfg(feature = "prover-timings")]
+        profile.record_phase(HyperKZGOpenProfile::BATCH_OPEN, start);
+
+        profile.poly_sizes = polys.iter().map(|poly| poly.len()).collect();
+        profile.msm_count = com.len() + w.len() + usize::from(b_com.is_some());
 
-        Ok(HyperKZGProof { com, w, v })
+        Ok((HyperKZGProof { com, w, v }, b_com, profile))
+    }
+
+    /// Same as [`HyperKZG::open`], but first absorbs the claim (`C`, `point`, `eval`) into the
+    /// transcript. `open` assumes the caller has already bound the claim, as is the case inside
+    /// Jolt where `point` is itself a transcript challenge; use this variant when the point is
+    /// chosen freely, otherwise a prover can pick the point after seeing the challenges.
+    pub fn open_standalone(
+        pk: &HyperKZGProverKey<P>,
+        C: &HyperKZGCommitment<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) -> Result<HyperKZGProof<P>, ProofVerifyError> {
+        Self::absorb_claim(C, point, eval, transcript);
+        Self::open(pk, poly, point, eval, transcript)
+    }
+
+    fn absorb_claim(
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        eval: &P::ScalarField,
+        transcript: &mut ProofTranscript,
+    ) {
+        C.append_to_transcript(transcript);
+        transcript.append_scalars::<P::ScalarField>(point);
+        transcript.append_scalar(eval);
     }
 
     /// A method to verify purported evaluations of a batch of polynomials
@@ -353,207 +1714,2577 @@ where
         pi: &HyperKZGProof<P>,
         transcript: &mut ProofTranscript,
     ) -> Result<(), ProofVerifyError> {
-        let y = P_of_x;
+        let squeezes = Self::verifier_script(point, pi).run(transcript);
+        Self::verify_with_challenges(vk, C, point, P_of_x, pi, &squeezes)
+    }
+
+    /// Same as [`HyperKZG::verify`], but with a verifier key whose G2 elements are already
+    /// prepared for pairing, which saves preparing them on every call.
+    pub fn verify_prepared(
+        pvk: &PreparedHyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let squeezes = Self::verifier_script(point, pi).run(transcript);
+        let Some((L, R)) =
+            Self::pairing_inputs(&pvk.vk, C, point, P_of_x, pi, None, &squeezes, &mut None)?
+        else {
+            return Ok(());
+        };
+
+        // Check that e(L, vk.H) == e(R, vk.tau_H)
+        if !P::multi_pairing([L, -R], [pvk.g2.clone(), pvk.beta_g2.clone()]).is_zero() {
+            return Err(ProofVerifyError::InternalError);
+        }
+        Ok(())
+    }
+
+    /// Same as [`HyperKZG::verify`], but also returns how long each phase took: running the
+    /// transcript, the consistency checks of the claimed evaluations, the MSMs, and the
+    /// final pairing check.
+    #[cfg(feature = "verify-timings")]
+    pub fn verify_with_timings(
+        vk: &HyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<VerifyTimings, ProofVerifyError> {
+        let total = Instant::now();
+        let mut timings = VerifyTimings::default();
+        let start = VerifyPhaseStart::now();
+        let squeezes = Self::verifier_script(point, pi).run(transcript);
+        VerifyTimings::record(&mut Some(&mut timings), VerifyTimings::TRANSCRIPT, start);
+        Self::verify_with_challenges_timed(
+            vk,
+            C,
+            point,
+            P_of_x,
+            pi,
+            None,
+            &squeezes,
+            Some(&mut timings),
+        )?;
+        timings.total_us = total.elapsed().as_micros() as u64;
+        Ok(timings)
+    }
+
+    /// [`Self::verify`] against a commitment given by its compressed encoding. The encoding
+    /// is validated, including the subgroup check, before verifying.
+    pub fn verify_with_commitment_bytes(
+        vk: &HyperKZGVerifierKey<P>,
+        commitment_bytes: &[u8],
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let C = HyperKZGCommitment::deserialize_compressed(commitment_bytes)?;
+        Self::verify(vk, &C, point, P_of_x, pi, transcript)
+    }
+
+    /// The transcript interactions performed by `verify` on `pi`.
+    pub fn verifier_script(point: &[P::ScalarField], pi: &HyperKZGProof<P>) -> VerifierScript<P> {
+        if point.is_empty() {
+            return VerifierScript { ops: vec![] };
+        }
+        // we do not need to add x to the transcript, because in our context x was
+        // obtained from the transcript (see `verify_standalone` for when this does not hold)
+        let mut ops = vec![
+            TranscriptOp::AbsorbPoints(pi.com.clone()),
+            TranscriptOp::SqueezeScalar,
+        ];
+        ops.extend(kzg_verify_batch_script(&pi.w, &pi.v, pi.com.len() + 1));
+        VerifierScript { ops }
+    }
+
+    /// The checks of `verify`, given the output of each squeeze of
+    /// [`HyperKZG::verifier_script`] in order: `r`, the powers of `q`, and `d_0`.
+    pub fn verify_with_challenges(
+        vk: &HyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        squeezes: &[Vec<P::ScalarField>],
+    ) -> Result<(), ProofVerifyError> {
+        Self::verify_with_challenges_timed(vk, C, point, P_of_x, pi, None, squeezes, None)
+    }
+
+    /// Verifies a proof produced by [`HyperKZG::open_with_batch_commitment`]: the proof
+    /// itself as in [`HyperKZG::verify`], and that its batch commitment equals
+    /// sum_i q^i * com_i, which holds for the batched polynomial by linearity.
+    pub fn verify_with_batch_commitment(
+        vk: &HyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProofWithBatchCommitment<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let squeezes = Self::verifier_script(point, &pi.proof).run(transcript);
+        Self::verify_with_challenges_timed(
+            vk,
+            C,
+            point,
+            P_of_x,
+            &pi.proof,
+            Some(&pi.b_com),
+            &squeezes,
+            None,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_with_challenges_timed(
+        vk: &HyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        b_com: Option<&P::G1Affine>,
+        squeezes: &[Vec<P::ScalarField>],
+        mut timings: Option<&mut VerifyTimings>,
+    ) -> Result<(), ProofVerifyError> {
+        let Some((L, R)) =
+            Self::pairing_inputs(vk, C, point, P_of_x, pi, b_com, squeezes, &mut timings)?
+        else {
+            return Ok(());
+        };
+
+        // Check that e(L, vk.H) == e(R, vk.tau_H)
+        let start = VerifyPhaseStart::now();
+        let accepted = P::multi_pairing([L, -R], [vk.kzg_vk.g2, vk.kzg_vk.beta_g2]).is_zero();
+        VerifyTimings::record(&mut timings, VerifyTimings::PAIRING, start);
+        if !accepted {
+            return Err(ProofVerifyError::InternalError);
+        }
+        Ok(())
+    }
+
+    // All checks of `verify` but the final pairing check, whose inputs (L, R) are returned.
+    // Returns None for a zero-variable claim, which is checked without a pairing.
+    #[allow(clippy::too_many_arguments)]
+    fn pairing_inputs(
+        vk: &HyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        b_com: Option<&P::G1Affine>,
+        squeezes: &[Vec<P::ScalarField>],
+        timings: &mut Option<&mut VerifyTimings>,
+    ) -> Result<Option<(P::G1, P::G1)>, ProofVerifyError> {
+        let y = P_of_x;
+
+        let ell = point.len();
+
+        if ell == 0 {
+            // A zero-variable polynomial is the constant Z[0], committed to as Z[0] * G,
+            // so the claim P_of_x == Z[0] is checked against C directly.
+            if !pi.com.is_empty() || !pi.w.is_empty() || !pi.v.is_empty() {
+                return Err(ProofVerifyError::InternalError);
+            }
+            if (vk.kzg_vk.g1.into_group() * *y).into_affine() != C.0 {
+                return Err(ProofVerifyError::InternalError);
+            }
+            // The batched polynomial is the polynomial itself
+            if b_com.is_some_and(|b_com| *b_com != C.0) {
+                return Err(ProofVerifyError::InternalError);
+            }
+            return Ok(None);
+        }
+
+        if pi.com.len() != ell - 1 {
+            return Err(ProofVerifyError::InternalError);
+        }
+        let [r, q_powers, d_0] = squeezes else {
+            return Err(ProofVerifyError::InternalError);
+        };
+        let (Some(&r), Some(&d_0)) = (r.first(), d_0.first()) else {
+            return Err(ProofVerifyError::InternalError);
+        };
+
+        if r == P::ScalarField::zero() || C.0 == P::G1Affine::zero() {
+            return Err(ProofVerifyError::InternalError);
+        }
+        let mut com = pi.com.clone();
+        com.insert(0, C.0); // set com_0 = C, shifts other commitments to the right
+
+        let u = vec![r, -r, r * r];
+
+        // Setup vectors (Y, ypos, yneg) from pi.v
+        let v = &pi.v;
+        if v.len() != 3 {
+            return Err(ProofVerifyError::InternalError);
+        }
+        if v[0].len() != ell || v[1].len() != ell || v[2].len() != ell {
+            return Err(ProofVerifyError::InternalError);
+        }
+        let ypos = &v[0];
+        let yneg = &v[1];
+        let mut Y = v[2].to_vec();
+        Y.push(*y);
+
+        // Check consistency of (Y, ypos, yneg)
+        let start = VerifyPhaseStart::now();
+        let two = P::ScalarField::from(2u64);
+        for i in 0..ell {
+            if two * r * Y[i + 1]
+                != r * (P::ScalarField::one() - point[ell - i - 1]) * (ypos[i] + yneg[i])
+                    + point[ell - i - 1] * (ypos[i] - yneg[i])
+            {
+                return Err(ProofVerifyError::InternalError);
+            }
+            // Note that we don't make any checks about Y[0] here, but our batching
+            // check below requires it
+        }
+        VerifyTimings::record(timings, VerifyTimings::CONSISTENCY, start);
+
+        // Check commitments to (Y, ypos, yneg) are valid
+        kzg_batch_pairing_inputs(vk, &com, &pi.w, &u, &pi.v, b_com, q_powers, d_0, timings)
+            .map(Some)
+            .ok_or(ProofVerifyError::InternalError)
+    }
+
+    /// Verifies the claims `(commitments[i], points[i], evals[i])` with `proofs[i]`, for
+    /// proofs produced one after the other on the same transcript. All checks but the
+    /// final pairing check are done per proof; the pairing checks are then combined into
+    /// one, with coefficients drawn from the transcript after every proof has been
+    /// absorbed. The transcript is therefore left in a different state than after
+    /// verifying each proof with [`HyperKZG::verify`].
+    pub fn batch_verify(
+        vk: &HyperKZGVerifierKey<P>,
+        commitments: &[HyperKZGCommitment<P>],
+        points: &[Vec<P::ScalarField>],
+        evals: &[P::ScalarField],
+        proofs: &[HyperKZGProof<P>],
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        let n = commitments.len();
+        if points.len() != n || evals.len() != n || proofs.len() != n {
+            return Err(ProofVerifyError::InternalError);
+        }
+
+        let mut L = vec![];
+        let mut R = vec![];
+        for i in 0..n {
+            let squeezes = Self::verifier_script(&points[i], &proofs[i]).run(transcript);
+            if let Some((L_i, R_i)) = Self::pairing_inputs(
+                vk,
+                &commitments[i],
+                &points[i],
+                &evals[i],
+                &proofs[i],
+                None,
+                &squeezes,
+                &mut None,
+            )? {
+                L.push(L_i);
+                R.push(R_i);
+            }
+        }
+        if L.is_empty() {
+            return Ok(());
+        }
+
+        // sum_i rho^i * e(L_i, vk.H) / e(R_i, vk.tau_H) vanishes for a random rho only if
+        // every term does, except with probability n / |F|
+        let rho_powers: Vec<P::ScalarField> = transcript.challenge_scalar_powers(L.len());
+        let L = <P::G1 as VariableBaseMSM>::msm_mixed(&[], &L, &rho_powers)?;
+        let R = <P::G1 as VariableBaseMSM>::msm_mixed(&[], &R, &rho_powers)?;
+        if !P::multi_pairing([L, -R], [vk.kzg_vk.g2, vk.kzg_vk.beta_g2]).is_zero() {
+            return Err(ProofVerifyError::InternalError);
+        }
+        Ok(())
+    }
+
+    /// Verifies a proof produced by [`HyperKZG::open_standalone`].
+    pub fn verify_standalone(
+        vk: &HyperKZGVerifierKey<P>,
+        C: &HyperKZGCommitment<P>,
+        point: &[P::ScalarField],
+        P_of_x: &P::ScalarField,
+        pi: &HyperKZGProof<P>,
+        transcript: &mut ProofTranscript,
+    ) -> Result<(), ProofVerifyError> {
+        Self::absorb_claim(C, point, P_of_x, transcript);
+        Self::verify(vk, C, point, P_of_x, pi, transcript)
+    }
+}
+
+impl<P: Pairing, ProofTranscript: Transcript> CommitmentScheme<ProofTranscript>
+    for HyperKZG<P, ProofTranscript>
+where
+    <P as Pairing>::ScalarField: JoltField,
+    <P as Pairing>::G1: Icicle,
+{
+    type Field = P::ScalarField;
+    type Setup = (HyperKZGProverKey<P>, HyperKZGVerifierKey<P>);
+    type Commitment = HyperKZGCommitment<P>;
+    type Proof = HyperKZGProof<P>;
+    type BatchedProof = HyperKZGProof<P>;
+
+    fn setup(max_poly_len: usize) -> Self::Setup {
+        HyperKZGSRS(Arc::new(SRS::setup(
+            &mut ChaCha20Rng::from_seed(*b"HyperKZG_POLY_COMMITMENT_SCHEMEE"),
+            max_poly_len,
+            2,
+        )))
+        .trim(max_poly_len)
+    }
+
+    #[tracing::instrument(skip_all, name = "HyperKZG::commit")]
+    fn commit(poly: &MultilinearPolynomial<Self::Field>, setup: &Self::Setup) -> Self::Commitment {
+        assert!(
+            setup.0.kzg_pk.g1_powers().len() >= poly.len(),
+            "COMMIT KEY LENGTH ERROR {}, {}",
+            setup.0.kzg_pk.g1_powers().len(),
+            poly.len()
+        );
+        HyperKZG::<P, ProofTranscript>::commit(&setup.0, poly).unwrap()
+    }
+
+    #[tracing::instrument(skip_all, name = "HyperKZG::batch_commit")]
+    fn batch_commit<U>(polys: &[U], gens: &Self::Setup) -> Vec<Self::Commitment>
+    where
+        U: Borrow<MultilinearPolynomial<Self::Field>> + Sync,
+    {
+        maybe_into_par_iter!(UnivariateKZG::commit_batch(&gens.0.kzg_pk, polys).unwrap())
+            .map(|c| HyperKZGCommitment(c))
+            .collect()
+    }
+
+    fn prove(
+        setup: &Self::Setup,
+        poly: &MultilinearPolynomial<Self::Field>,
+        opening_point: &[Self::Field], // point at which the polynomial is evaluated
+        transcript: &mut ProofTranscript,
+    ) -> Self::Proof {
+        let eval = poly.evaluate(opening_point);
+        HyperKZG::<P, ProofTranscript>::open(&setup.0, poly, opening_point, &eval, transcript)
+            .unwrap()
+    }
+
+    fn combine_commitments(
+        commitments: &[&Self::Commitment],
+        coeffs: &[Self::Field],
+    ) -> Self::Commitment {
+        let combined_commitment: P::G1 = commitments
+            .iter()
+            .zip(coeffs.iter())
+            .map(|(commitment, coeff)| commitment.0 * coeff)
+            .sum();
+        HyperKZGCommitment(combined_commitment.into_affine())
+    }
+
+    fn verify(
+        proof: &Self::Proof,
+        setup: &Self::Setup,
+        transcript: &mut ProofTranscript,
+        opening_point: &[Self::Field], // point at which the polynomial is evaluated
+        opening: &Self::Field,         // evaluation \widetilde{Z}(r)
+        commitment: &Self::Commitment,
+    ) -> Result<(), ProofVerifyError> {
+        HyperKZG::<P, ProofTranscript>::verify(
+            &setup.1,
+            commitment,
+            opening_point,
+            opening,
+            proof,
+            transcript,
+        )
+    }
+
+    fn protocol_name() -> &'static [u8] {
+        b"hyperkzg"
+    }
+}
+
+/// Deterministic HyperKZG instances shared by the tests and the fuzz targets.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing {
+    use super::*;
+
+    pub type Fixture<P> = (
+        HyperKZGProverKey<P>,
+        HyperKZGVerifierKey<P>,
+        DensePolynomial<<P as Pairing>::ScalarField>,
+        Vec<<P as Pairing>::ScalarField>,
+        <P as Pairing>::ScalarField,
+    );
+
+    /// Builds a random instance with `ell` variables, derived entirely from `seed`: keys
+    /// sized for a `2^ell`-evaluation polynomial, the polynomial, an opening point, and the
+    /// polynomial's evaluation at that point.
+    pub fn fixture<P: Pairing>(ell: usize, seed: u64) -> Fixture<P>
+    where
+        P::ScalarField: JoltField,
+        P::G1: Icicle,
+    {
+        let mut rng = ChaCha20Rng::seed_from_u64(seed);
+        let n = 1 << ell;
+
+        let poly = DensePolynomial::new((0..n).map(|_| P::ScalarField::random(&mut rng)).collect());
+        let point = (0..ell)
+            .map(|_| P::ScalarField::random(&mut rng))
+            .collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk) = srs.trim(n);
+        (pk, vk, poly, point, eval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transcript::{KeccakTranscript, Transcript};
+    use ark_bn254::{Bn254, Fr};
+    use ark_std::UniformRand;
+    use proptest::prelude::{any, prop, prop_assert, proptest, Just, ProptestConfig, Strategy};
+    use proptest::sample::Index;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_hyperkzg_eval() {
+        // Test with poly(X1, X2) = 1 + X1 + X2 + X1*X2
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let srs = HyperKZGSRS::setup(&mut rng, 3);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(3);
+
+        // poly is in eval. representation; evaluated at [(0,0), (0,1), (1,0), (1,1)]
+        let poly =
+            MultilinearPolynomial::from(vec![Fr::from(1), Fr::from(2), Fr::from(2), Fr::from(4)]);
+
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        let test_inner = |point: Vec<Fr>, eval: Fr| -> Result<(), ProofVerifyError> {
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            let claimed_eval = if cfg!(debug_assertions) {
+                poly.evaluate(&point)
+            } else {
+                eval
+            };
+            let proof = HyperKZG::open(&pk, &poly, &point, &claimed_eval, &mut tr).unwrap();
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr)
+        };
+
+        // Call the prover with a (point, eval) pair. In debug builds the prover checks the
+        // pair, so incorrect evaluations are only passed to the verifier
+        let point = vec![Fr::from(0), Fr::from(0)];
+        let eval = Fr::from(1);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(0), Fr::from(1)];
+        let eval = Fr::from(2);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(1), Fr::from(1)];
+        let eval = Fr::from(4);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(0), Fr::from(2)];
+        let eval = Fr::from(3);
+        assert!(test_inner(point, eval).is_ok());
+
+        let point = vec![Fr::from(2), Fr::from(2)];
+        let eval = Fr::from(9);
+        assert!(test_inner(point, eval).is_ok());
+
+        // Try a couple incorrect evaluations and expect failure
+        let point = vec![Fr::from(2), Fr::from(2)];
+        let eval = Fr::from(50);
+        assert!(test_inner(point, eval).is_err());
+
+        let point = vec![Fr::from(0), Fr::from(2)];
+        let eval = Fr::from(4);
+        assert!(test_inner(point, eval).is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_commit_dense() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(236);
+        let (pk, _) = HyperKZGSRS::<Bn254>::setup(&mut rng, 1 << 5).trim(1 << 5);
+        let poly = DensePolynomial::<Fr>::random(5, &mut rng);
+        assert_eq!(
+            HyperKZG::<_, KeccakTranscript>::commit_dense(&pk, &poly).unwrap(),
+            HyperKZG::<_, KeccakTranscript>::commit(
+                &pk,
+                &MultilinearPolynomial::LargeScalars(poly.clone())
+            )
+            .unwrap()
+        );
+
+        let too_long = DensePolynomial::<Fr>::random(6, &mut rng);
+        assert!(matches!(
+            HyperKZG::<_, KeccakTranscript>::commit_dense(&pk, &too_long),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers: 33,
+                required_powers: 64,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_hyperkzg_commit_with_unipoly() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let ell = 3;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let polys = [
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>()),
+            MultilinearPolynomial::from((0..n as u8).map(|i| i * 3).collect::<Vec<_>>()),
+        ];
+        for poly in polys.iter() {
+            let (C, unipoly) =
+                HyperKZG::<_, KeccakTranscript>::commit_with_unipoly(&pk, poly).unwrap();
+            assert_eq!(
+                C,
+                HyperKZG::<_, KeccakTranscript>::commit(&pk, poly).unwrap()
+            );
+            assert_eq!(unipoly.coeffs.len(), n);
+
+            // The i-th coefficient is the evaluation at the i-th vertex of the hypercube
+            for i in 0..n {
+                let vertex: Vec<Fr> = (0..ell)
+                    .map(|j| {
+                        if (i >> (ell - j - 1)) & 1 == 1 {
+                            Fr::one()
+                        } else {
+                            Fr::zero()
+                        }
+                    })
+                    .collect();
+                assert_eq!(unipoly[i], poly.evaluate(&vertex));
+            }
+
+            let r = Fr::rand(&mut rng);
+            assert_eq!(unipoly.evaluate(&r), UniPoly::eval_as_univariate(poly, &r));
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_commit_boolean() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let srs = HyperKZGSRS::setup(&mut rng, 1 << 8);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(1 << 8);
+
+        for len in [1, 63, 64, 65, 200, 1 << 8] {
+            let evals: Vec<bool> = (0..len).map(|_| rng.next_u32() % 2 == 0).collect();
+            let poly = BooleanPolynomial::from_bools(&evals);
+            let dense = MultilinearPolynomial::from(
+                evals
+                    .iter()
+                    .map(|bit| if *bit { Fr::one() } else { Fr::zero() })
+                    .collect::<Vec<_>>(),
+            );
+
+            let C = HyperKZG::<_, KeccakTranscript>::commit_boolean(&pk, &poly).unwrap();
+            let expected = HyperKZG::<_, KeccakTranscript>::commit(&pk, &dense).unwrap();
+            assert_eq!(C, expected);
+        }
+
+        // All-zero polynomial commits to the identity
+        let C = HyperKZG::<_, KeccakTranscript>::commit_boolean(&pk, &BooleanPolynomial::new(16))
+            .unwrap();
+        assert_eq!(C, HyperKZGCommitment::default());
+
+        // Key too short
+        assert!(HyperKZG::<_, KeccakTranscript>::commit_boolean(
+            &pk,
+            &BooleanPolynomial::new((1 << 8) + 1)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_kzg_batch_arbitrary_t() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let n = 16;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let f = (0..3)
+            .map(|_| {
+                MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let C = f
+            .iter()
+            .map(|f| HyperKZG::<_, KeccakTranscript>::commit(&pk, f).unwrap().0)
+            .collect::<Vec<_>>();
+
+        for t in [1, 2, 3, 4, 5] {
+            let u = (0..t).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+            let mut prover_transcript = KeccakTranscript::new(b"TestBatch");
+            let (w, v, _) = kzg_open_batch(&f, &u, &pk, &mut prover_transcript, false).unwrap();
+
+            let mut verifier_transcript = KeccakTranscript::new(b"TestBatch");
+            assert!(kzg_verify_batch(
+                &vk,
+                &C,
+                &w,
+                &u,
+                &v,
+                None,
+                &mut verifier_transcript
+            ));
+
+            // Tampering with a claimed evaluation must be caught
+            let mut bad_v = v.clone();
+            bad_v[t - 1][0] += Fr::one();
+            let mut verifier_transcript = KeccakTranscript::new(b"TestBatch");
+            assert!(!kzg_verify_batch(
+                &vk,
+                &C,
+                &w,
+                &u,
+                &bad_v,
+                None,
+                &mut verifier_transcript
+            ));
+
+            // Mismatched number of witnesses is rejected rather than panicking
+            let mut verifier_transcript = KeccakTranscript::new(b"TestBatch");
+            assert!(!kzg_verify_batch(
+                &vk,
+                &C,
+                &w[..t - 1],
+                &u,
+                &v,
+                None,
+                &mut verifier_transcript
+            ));
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_open_profile() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let ell = 4;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let poly =
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        let mut tr = KeccakTranscript::new(b"TestProfile");
+        let (proof, profile) =
+            HyperKZG::open_with_profile(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        let mut tr = KeccakTranscript::new(b"TestProfile");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr).is_ok());
+
+        assert_eq!(profile.num_vars, ell);
+        assert_eq!(profile.poly_sizes, vec![16, 8, 4, 2]);
+        assert_eq!(profile.msm_count, (ell - 1) + 3);
+        #[cfg(not(feature = "prover-timings"))]
+        assert!(profile.phase_timings_us.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "prover-timings")]
+    fn test_hyperkzg_open_profile_json() {
+        let (pk, _, poly, point, eval) = testing::fixture::<Bn254>(4, 207);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let mut tr = KeccakTranscript::new(b"TestProfile");
+        let (_, profile) = HyperKZG::open_with_profile(&pk, &poly, &point, &eval, &mut tr).unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&profile.to_json()).unwrap();
+        for key in ["num_vars", "poly_sizes", "msm_count", "phase_timings_us"] {
+            assert!(json.get(key).is_some(), "missing key {key}");
+        }
+        for phase in [
+            HyperKZGOpenProfile::FOLD,
+            HyperKZGOpenProfile::COMMIT,
+            HyperKZGOpenProfile::CHALLENGE,
+            HyperKZGOpenProfile::BATCH_OPEN,
+        ] {
+            assert!(json["phase_timings_us"].get(phase).is_some());
+        }
+
+        let path = std::env::temp_dir().join("hyperkzg_open_profile_test.json");
+        profile.write_json(&path).unwrap();
+        let written: HyperKZGOpenProfile =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written, profile);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_hyperkzg_zero_vars() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let srs = HyperKZGSRS::setup(&mut rng, 2);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(2);
+
+        for z in [Fr::zero(), Fr::one(), Fr::rand(&mut rng)] {
+            let poly = MultilinearPolynomial::from(vec![z]);
+            let point: Vec<Fr> = vec![];
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+            let mut tr = KeccakTranscript::new(b"TestZeroVars");
+            let proof = HyperKZG::open(&pk, &poly, &point, &z, &mut tr).unwrap();
+            assert!(proof.com.is_empty() && proof.w.is_empty() && proof.v.is_empty());
+
+            let mut tr = KeccakTranscript::new(b"TestZeroVars");
+            assert!(HyperKZG::verify(&vk, &C, &point, &z, &proof, &mut tr).is_ok());
+
+            // Wrong evaluation
+            let mut tr = KeccakTranscript::new(b"TestZeroVars");
+            assert!(HyperKZG::verify(&vk, &C, &point, &(z + Fr::one()), &proof, &mut tr).is_err());
+
+            // Non-empty proof components are not accepted for the trivial claim
+            let mut bad_proof = proof.clone();
+            bad_proof.v = vec![vec![]; 3];
+            let mut tr = KeccakTranscript::new(b"TestZeroVars");
+            assert!(HyperKZG::verify(&vk, &C, &point, &z, &bad_proof, &mut tr).is_err());
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_srs_from_seed() {
+        let seed = [29u8; 32];
+        let bytes = HyperKZGSRS::<Bn254>::setup_from_seed(seed, 8).to_bytes();
+        assert_eq!(
+            bytes,
+            HyperKZGSRS::<Bn254>::setup_from_seed(seed, 8).to_bytes()
+        );
+        assert_eq!(
+            bytes,
+            HyperKZGSRS::<Bn254>::setup(&mut ChaCha20Rng::from_seed(seed), 8).to_bytes()
+        );
+        assert_ne!(
+            bytes,
+            HyperKZGSRS::<Bn254>::setup_from_seed([96u8; 32], 8).to_bytes()
+        );
+        // 9 G1 powers of 32 bytes and 3 G2 powers of 64 bytes, each with a length prefix
+        assert_eq!(bytes.len(), 8 + 9 * 32 + 8 + 3 * 64);
+        assert_eq!(bytes[..8], 9u64.to_le_bytes());
+
+        // Commitments under the imported SRS match those under the original
+        let srs = HyperKZGSRS::<Bn254>::from_bytes(&bytes).unwrap();
+        assert_eq!(srs.to_bytes(), bytes);
+        let poly = MultilinearPolynomial::from((0..8).map(Fr::from_u64).collect::<Vec<_>>());
+        let (pk, _) = srs.trim(8);
+        let (original_pk, _) = HyperKZGSRS::<Bn254>::setup_from_seed(seed, 8).trim(8);
+        assert_eq!(
+            HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap(),
+            HyperKZG::<_, KeccakTranscript>::commit(&original_pk, &poly).unwrap()
+        );
+
+        assert!(HyperKZGSRS::<Bn254>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(HyperKZGSRS::<Bn254>::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+
+        // A length prefix past the end of the input is rejected without reading on
+        let mut huge_len = bytes.clone();
+        huge_len[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            HyperKZGSRS::<Bn254>::from_bytes(&huge_len),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
+        let mut g1_too_long = bytes.clone();
+        g1_too_long[..8].copy_from_slice(&((bytes.len() / 32 + 1) as u64).to_le_bytes());
+        assert!(matches!(
+            HyperKZGSRS::<Bn254>::from_bytes(&g1_too_long),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
+    }
+
+    #[test]
+    fn test_hyperkzg_max_poly_len() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(295);
+        let srs = HyperKZGSRS::setup(&mut rng, 16);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(8);
+        assert_eq!(pk.max_poly_len(), pk.kzg_pk.g1_powers().len());
+
+        for len in 1..=16 {
+            let poly = MultilinearPolynomial::from(
+                (0..len).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>(),
+            );
+            let result = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly);
+            assert_eq!(
+                result.is_ok(),
+                pk.max_poly_len() >= poly.len(),
+                "length {len}"
+            );
+            if let Err(err) = result {
+                assert!(matches!(
+                    err,
+                    ProofVerifyError::KeyLengthError {
+                        available_powers,
+                        required_powers,
+                    } if available_powers == pk.max_poly_len() && required_powers == len
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_commitment_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+
+        fn hash_of(C: &HyperKZGCommitment<Bn254>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            C.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let (pk, _, poly, _, _) = testing::fixture::<Bn254>(4, 293);
+        let polys: Vec<MultilinearPolynomial<Fr>> = vec![
+            MultilinearPolynomial::LargeScalars(poly.clone()),
+            MultilinearPolynomial::from(poly.evals_ref().iter().rev().copied().collect::<Vec<_>>()),
+            MultilinearPolynomial::from(vec![Fr::zero(); 16]),
+        ];
+        let commitments: Vec<_> = polys
+            .iter()
+            .map(|poly| HyperKZG::<_, KeccakTranscript>::commit(&pk, poly).unwrap())
+            .collect();
+
+        let same = HyperKZG::<_, KeccakTranscript>::commit(&pk, &polys[0]).unwrap();
+        assert_eq!(same, commitments[0]);
+        assert_eq!(hash_of(&same), hash_of(&commitments[0]));
+        for i in 0..commitments.len() {
+            for j in i + 1..commitments.len() {
+                assert_ne!(commitments[i], commitments[j]);
+                assert_ne!(hash_of(&commitments[i]), hash_of(&commitments[j]));
+            }
+        }
+
+        // Identical committed polynomials share a cache entry
+        let mut cache = HashMap::new();
+        for (index, C) in commitments.iter().chain([&same]).enumerate() {
+            cache.entry(C.clone()).or_insert(index);
+        }
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache[&same], 0);
+    }
+
+    #[test]
+    fn test_hyperkzg_constant_poly_open_paths() {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(0, 292);
+        assert_eq!(poly.len(), 1);
+        assert!(point.is_empty());
+        let evals = poly.evals();
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        type Open = fn(
+            &HyperKZGProverKey<Bn254>,
+            &MultilinearPolynomial<Fr>,
+            &[Fr],
+            &Fr,
+            &mut KeccakTranscript,
+        ) -> Result<HyperKZGProof<Bn254>, ProofVerifyError>;
+        let open_paths: [Open; 2] = [HyperKZG::open, HyperKZG::open_ct];
+        let mut proofs: Vec<_> = open_paths
+            .iter()
+            .map(|open| {
+                let mut tr = KeccakTranscript::new(b"TestConstantPoly");
+                open(&pk, &poly, &point, &eval, &mut tr).unwrap()
+            })
+            .collect();
+        let mut tr = KeccakTranscript::new(b"TestConstantPoly");
+        proofs.push(HyperKZG::open_evals(&pk, &evals, &point, &eval, &mut tr).unwrap());
+
+        // The batched polynomial of a constant is the constant itself
+        let mut tr = KeccakTranscript::new(b"TestConstantPoly");
+        let batch_proof =
+            HyperKZG::open_with_batch_commitment(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        assert_eq!(batch_proof.b_com, C.0);
+        let mut tr = KeccakTranscript::new(b"TestConstantPoly");
+        assert!(HyperKZG::verify_with_batch_commitment(
+            &vk,
+            &C,
+            &point,
+            &eval,
+            &batch_proof,
+            &mut tr
+        )
+        .is_ok());
+        proofs.push(batch_proof.proof);
+
+        for proof in proofs {
+            assert!(proof.com.is_empty() && proof.w.is_empty() && proof.v.is_empty());
+            let mut tr = KeccakTranscript::new(b"TestConstantPoly");
+            assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_one_var() {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(1, 291);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        // There are no intermediate folds to commit to, so only the batched opening of the
+        // polynomial itself remains
+        let mut tr = KeccakTranscript::new(b"TestOneVar");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        assert!(proof.com.is_empty());
+        assert_eq!(proof.w.len(), 3);
+        assert!(proof.v.iter().all(|v_i| v_i.len() == 1));
+
+        let mut tr = KeccakTranscript::new(b"TestOneVar");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr).is_ok());
+
+        let mut tr = KeccakTranscript::new(b"TestOneVar");
+        assert!(HyperKZG::verify(&vk, &C, &point, &(eval + Fr::one()), &proof, &mut tr).is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_msm_backend_selection() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let n = 1 << 6;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (mut pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let polys = [
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>()),
+            MultilinearPolynomial::from((0..n).map(|_| rng.next_u64()).collect::<Vec<_>>()),
+        ];
+
+        let selected = pk.msm_backend();
+        assert!(MsmBackend::available().contains(&selected));
+        // The selection is cached
+        assert_eq!(pk.msm_backend(), selected);
+
+        for poly in polys.iter() {
+            let reference = UnivariateKZG::commit_as_univariate(&pk.kzg_pk, poly).unwrap();
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, poly).unwrap();
+            assert_eq!(C.0, reference);
+        }
+
+        pk.set_msm_backend(MsmBackend::Cpu);
+        assert_eq!(pk.msm_backend(), MsmBackend::Cpu);
+        for poly in polys.iter() {
+            let reference = UnivariateKZG::commit_as_univariate(&pk.kzg_pk, poly).unwrap();
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, poly).unwrap();
+            assert_eq!(C.0, reference);
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_msm_window() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let ell = 6;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (mut pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+        pk.set_msm_backend(MsmBackend::Cpu);
+
+        let poly =
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+        let dense: &DensePolynomial<Fr> = (&poly).try_into().unwrap();
+        let point: Vec<Fr> = (0..ell).map(|_| Fr::rand(&mut rng)).collect();
+        let eval = poly.evaluate(&point);
+
+        let reference = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut tr = KeccakTranscript::new(b"TestWindow");
+        let reference_proof =
+            HyperKZG::<_, KeccakTranscript>::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+
+        for window_bits in [1, 2, 5, 8, 13] {
+            pk.set_msm_window_bits(Some(window_bits));
+            assert_eq!(pk.msm_window_bits(), Some(window_bits));
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+            assert_eq!(C, reference, "window of {window_bits} bits");
+            assert_eq!(
+                HyperKZG::<_, KeccakTranscript>::commit_dense(&pk, dense).unwrap(),
+                reference
+            );
+            assert_eq!(
+                HyperKZG::<_, KeccakTranscript>::commit(&pk.commit_only_key(), &poly).unwrap(),
+                reference
+            );
+
+            let mut tr = KeccakTranscript::new(b"TestWindow");
+            let proof =
+                HyperKZG::<_, KeccakTranscript>::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+            assert_eq!(
+                (&proof.com, &proof.w, &proof.v),
+                (&reference_proof.com, &reference_proof.w, &reference_proof.v),
+                "window of {window_bits} bits"
+            );
+            let mut tr = KeccakTranscript::new(b"TestWindow");
+            assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr).is_ok());
+        }
+
+        pk.set_msm_window_bits(Some(crate::msm::MAX_WINDOW_BITS + 1));
+        assert!(matches!(
+            HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly),
+            Err(ProofVerifyError::InvalidWindowBits(_))
+        ));
+    }
+
+    #[test]
+    fn test_hyperkzg_batch_commitment() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let ell = 4;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let poly =
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        let mut tr = KeccakTranscript::new(b"TestBatchCommitment");
+        let proof =
+            HyperKZG::open_with_batch_commitment(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        let b_com = proof.b_com;
+        let proof_com = &proof.proof.com;
+
+        // Replay the verifier's transcript to recover the batching challenge q
+        let mut tr = KeccakTranscript::new(b"TestBatchCommitment");
+        tr.append_points(&proof_com.iter().map(|g| g.into_group()).collect::<Vec<_>>());
+        let _r: Fr = tr.challenge_scalar();
+        tr.append_scalars::<Fr>(&proof.proof.v.iter().flatten().collect::<Vec<_>>());
+        let q_powers: Vec<Fr> = tr.challenge_scalar_powers(ell);
+        let expected = [vec![C.0], proof_com.clone()]
+            .concat()
+            .iter()
+            .zip(q_powers.iter())
+            .map(|(com, q_power)| com.into_group() * q_power)
+            .sum::<<Bn254 as Pairing>::G1>();
+        assert_eq!(b_com, expected.into_affine());
+
+        let mut tr = KeccakTranscript::new(b"TestBatchCommitment");
+        assert!(
+            HyperKZG::verify_with_batch_commitment(&vk, &C, &point, &eval, &proof, &mut tr).is_ok()
+        );
+
+        // The proof is otherwise identical to the one without the batch commitment
+        let mut tr = KeccakTranscript::new(b"TestBatchCommitment");
+        let plain_proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        assert_eq!(plain_proof.com, proof.proof.com);
+        assert_eq!(plain_proof.w, proof.proof.w);
+        let mut tr = KeccakTranscript::new(b"TestBatchCommitment");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof.proof, &mut tr).is_ok());
+
+        let mut bad_proof = proof.clone();
+        bad_proof.b_com = (b_com.into_group() + vk.kzg_vk.g1).into_affine();
+        let mut tr = KeccakTranscript::new(b"TestBatchCommitment");
+        assert!(HyperKZG::verify_with_batch_commitment(
+            &vk, &C, &point, &eval, &bad_proof, &mut tr
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_zero_eval() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let ell = 4;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let prove_and_verify = |evals: Vec<Fr>, point: Vec<Fr>| -> Result<(), ProofVerifyError> {
+            let poly = MultilinearPolynomial::from(evals);
+            let eval = poly.evaluate(&point);
+            assert_eq!(eval, Fr::zero());
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+            let mut tr = KeccakTranscript::new(b"TestZeroEval");
+            let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+            let mut tr = KeccakTranscript::new(b"TestZeroEval");
+            HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr)?;
+
+            // A nonzero claim for the same opening must not verify
+            let mut tr = KeccakTranscript::new(b"TestZeroEval");
+            assert!(HyperKZG::verify(&vk, &C, &point, &Fr::one(), &proof, &mut tr).is_err());
+            Ok(())
+        };
+
+        // Random point: shift the evaluations so that poly(point) = 0
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut evals = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = MultilinearPolynomial::from(evals.clone()).evaluate(&point);
+        evals.iter_mut().for_each(|z| *z -= eval);
+        assert!(prove_and_verify(evals, point).is_ok());
+
+        // Hypercube vertex at which the evaluation is zero
+        let mut evals = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        evals[0b0110] = Fr::zero();
+        let point = vec![Fr::zero(), Fr::one(), Fr::one(), Fr::zero()];
+        assert!(prove_and_verify(evals, point).is_ok());
+
+        // The all-zero polynomial commits to the identity, which `verify` rejects outright
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        assert!(matches!(
+            prove_and_verify(vec![Fr::zero(); n], point),
+            Err(ProofVerifyError::InternalError)
+        ));
+    }
+
+    #[test]
+    fn test_hyperkzg_aggregate_commitment() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
+        let ell = 3;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let columns = (0..3)
+            .map(|_| {
+                MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = columns[1].evaluate(&point);
+
+        let mut prover_tr = KeccakTranscript::new(b"TestAggregate");
+        let (commitments, aggregate) =
+            HyperKZG::commit_aggregate(&pk, &columns, &mut prover_tr).unwrap();
+        for (commitment, column) in commitments.iter().zip(columns.iter()) {
+            assert_eq!(
+                *commitment,
+                HyperKZG::<_, KeccakTranscript>::commit(&pk, column).unwrap()
+            );
+        }
+        let proof = HyperKZG::open(&pk, &columns[1], &point, &eval, &mut prover_tr).unwrap();
+
+        let verify = |commitments: &[HyperKZGCommitment<Bn254>],
+                      aggregate: &HyperKZGCommitment<Bn254>,
+                      index: usize,
+                      eval: &Fr| {
+            let mut tr = KeccakTranscript::new(b"TestAggregate");
+            HyperKZG::verify_column(
+                &vk,
+                commitments,
+                aggregate,
+                index,
+                &point,
+                eval,
+                &proof,
+                &mut tr,
+            )
+        };
+        assert!(verify(&commitments, &aggregate, 1, &eval).is_ok());
+
+        // Wrong column, wrong evaluation, or out-of-range index
+        assert!(verify(&commitments, &aggregate, 0, &eval).is_err());
+        assert!(verify(&commitments, &aggregate, 1, &(eval + Fr::one())).is_err());
+        assert!(verify(&commitments, &aggregate, 3, &eval).is_err());
+
+        // Aggregate inconsistent with the columns
+        let bad_aggregate = HyperKZGCommitment((aggregate.0 + vk.kzg_vk.g1).into_affine());
+        assert!(verify(&commitments, &bad_aggregate, 1, &eval).is_err());
+        let mut swapped = commitments.clone();
+        swapped.swap(0, 2);
+        assert!(verify(&swapped, &aggregate, 1, &eval).is_err());
+
+        // The opening is bound to the aggregate, so it does not verify on its own
+        let mut tr = KeccakTranscript::new(b"TestAggregate");
+        tr.append_points(
+            &commitments
+                .iter()
+                .map(|c| c.0.into_group())
+                .collect::<Vec<_>>(),
+        );
+        let _: Vec<Fr> = tr.challenge_scalar_powers(commitments.len());
+        assert!(HyperKZG::verify(&vk, &commitments[1], &point, &eval, &proof, &mut tr).is_err());
+    }
+
+    const ROUND_TRIP_MAX_ELL: usize = 16;
+
+    fn round_trip_keys() -> &'static (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) {
+        static KEYS: std::sync::OnceLock<(HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>)> =
+            std::sync::OnceLock::new();
+        KEYS.get_or_init(|| {
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(218);
+            HyperKZGSRS::setup(&mut rng, 1 << ROUND_TRIP_MAX_ELL).trim(1 << ROUND_TRIP_MAX_ELL)
+        })
+    }
+
+    /// `ell`, the evaluations of an `ell`-variate polynomial, a point, and the (byte index,
+    /// bit) pairs to flip in the serialized proof
+    fn round_trip_case() -> impl Strategy<Value = (usize, Vec<Fr>, Vec<Fr>, Vec<(Index, u8)>)> {
+        let field = || any::<[u8; 32]>().prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes));
+        (1..=ROUND_TRIP_MAX_ELL).prop_flat_map(move |ell| {
+            (
+                Just(ell),
+                prop::collection::vec(field(), 1 << ell),
+                prop::collection::vec(field(), ell),
+                prop::collection::vec((any::<Index>(), 0..8u8), 8),
+            )
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(12))]
+
+        // For any `ell`, polynomial and point, `open` must produce a proof that `verify`
+        // accepts, and flipping any bit of the serialized proof must make it either fail to
+        // deserialize or fail to verify.
+        #[test]
+        fn test_hyperkzg_round_trip_random((ell, evals, point, flips) in round_trip_case()) {
+            let (pk, vk) = round_trip_keys();
+            let poly = MultilinearPolynomial::from(evals);
+            let eval = poly.evaluate(&point);
+            let C = HyperKZG::<_, KeccakTranscript>::commit(pk, &poly).unwrap();
+
+            let mut prover_tr = KeccakTranscript::new(b"TestRoundTrip");
+            let proof = HyperKZG::open(pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+            let mut verifier_tr = KeccakTranscript::new(b"TestRoundTrip");
+            prop_assert!(
+                HyperKZG::verify(vk, &C, &point, &eval, &proof, &mut verifier_tr).is_ok(),
+                "ell {}: honest proof rejected",
+                ell
+            );
+
+            let mut bytes = Vec::new();
+            proof.serialize_compressed(&mut bytes).unwrap();
+            for (index, bit) in flips {
+                let index = index.index(bytes.len());
+                let mut mutated = bytes.clone();
+                mutated[index] ^= 1 << bit;
+
+                let Ok(bad_proof) = HyperKZGProof::<Bn254>::deserialize_compressed(&mutated[..])
+                else {
+                    continue;
+                };
+                let mut verifier_tr = KeccakTranscript::new(b"TestRoundTrip");
+                prop_assert!(
+                    HyperKZG::verify(vk, &C, &point, &eval, &bad_proof, &mut verifier_tr).is_err(),
+                    "ell {}: proof with bit {} of byte {} flipped was accepted",
+                    ell,
+                    bit,
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_standalone_binds_point() {
+        // Since `open`/`verify` do not absorb the point, a prover who may choose the point
+        // after seeing r can "prove" any evaluation of a committed polynomial: commit to
+        // arbitrary folded polynomials, derive r, and then solve each fold equation in
+        // `verify` for the corresponding coordinate of the point.
+        fn forge(
+            pk: &HyperKZGProverKey<Bn254>,
+            poly: &MultilinearPolynomial<Fr>,
+            ell: usize,
+            y: Fr,
+            rng: &mut rand_chacha::ChaCha20Rng,
+        ) -> (Vec<Fr>, HyperKZGProof<Bn254>) {
+            let mut polys = vec![poly.clone()];
+            for i in 1..ell {
+                polys.push(MultilinearPolynomial::from(
+                    (0..1 << (ell - i))
+                        .map(|_| Fr::rand(rng))
+                        .collect::<Vec<_>>(),
+                ));
+            }
+            let com = UnivariateKZG::commit_variable_batch(&pk.kzg_pk, &polys[1..]).unwrap();
+
+            let mut transcript = KeccakTranscript::new(b"TestStandalone");
+            transcript.append_points(&com.iter().map(|g| g.into_group()).collect::<Vec<_>>());
+            let r: Fr = transcript.challenge_scalar();
+            let u = vec![r, -r, r * r];
+            let (w, v, _) = kzg_open_batch(&polys, &u, pk, &mut transcript, false).unwrap();
+
+            let mut Y = v[2].clone();
+            Y.push(y);
+            let mut point = vec![Fr::zero(); ell];
+            for i in 0..ell {
+                let (sum, diff) = (v[0][i] + v[1][i], v[0][i] - v[1][i]);
+                point[ell - i - 1] =
+                    (Fr::from(2u64) * r * Y[i + 1] - r * sum) * (diff - r * sum).inverse().unwrap();
+            }
+            (point, HyperKZGProof { com, w, v })
+        }
+
+        let ell = 4;
+        let n = 1 << ell;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(219);
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let poly =
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        let y = Fr::from(1234u64);
+        let (point, forged) = forge(&pk, &poly, ell, y, &mut rng);
+        assert_ne!(poly.evaluate(&point), y);
+
+        let mut verifier_tr = KeccakTranscript::new(b"TestStandalone");
+        assert!(HyperKZG::verify(&vk, &C, &point, &y, &forged, &mut verifier_tr).is_ok());
+        let mut verifier_tr = KeccakTranscript::new(b"TestStandalone");
+        assert!(
+            HyperKZG::verify_standalone(&vk, &C, &point, &y, &forged, &mut verifier_tr).is_err()
+        );
+
+        // Honest standalone proofs still verify, and only against the claimed point
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let mut prover_tr = KeccakTranscript::new(b"TestStandalone");
+        let proof =
+            HyperKZG::open_standalone(&pk, &C, &poly, &point, &eval, &mut prover_tr).unwrap();
+        let mut verifier_tr = KeccakTranscript::new(b"TestStandalone");
+        assert!(
+            HyperKZG::verify_standalone(&vk, &C, &point, &eval, &proof, &mut verifier_tr).is_ok()
+        );
+        let mut other_point = point.clone();
+        other_point[0] += Fr::one();
+        let mut verifier_tr = KeccakTranscript::new(b"TestStandalone");
+        assert!(HyperKZG::verify_standalone(
+            &vk,
+            &C,
+            &other_point,
+            &poly.evaluate(&other_point),
+            &proof,
+            &mut verifier_tr
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_commit_only_key() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(220);
+        let n = 1 << 6;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+        let commit_key = pk.commit_only_key();
+        assert_eq!(commit_key.g1_powers(), pk.kzg_pk.g1_powers());
+        assert_eq!(CommitKey::msm_backend(&commit_key), pk.msm_backend());
+
+        for len in [1, 2, n / 2, n] {
+            let poly = MultilinearPolynomial::from(
+                (0..len).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>(),
+            );
+            assert_eq!(
+                HyperKZG::<_, KeccakTranscript>::commit(&commit_key, &poly).unwrap(),
+                HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap()
+            );
+        }
+
+        let too_long =
+            MultilinearPolynomial::from((0..2 * n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+        assert!(matches!(
+            HyperKZG::<_, KeccakTranscript>::commit(&commit_key, &too_long),
+            Err(ProofVerifyError::KeyLengthError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hyperkzg_pipelined_fold() {
+        // The folds (sequential and parallel on either side of the threshold in
+        // `DensePolynomial::fold_bottom_evals`) and the
+        // commitments pipelined with them must match a plain fold-then-commit.
+        let ell = 12;
+        let n = 1 << ell;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(223);
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let evals = (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let poly = MultilinearPolynomial::from(evals.clone());
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+
+        let mut folds = vec![];
+        let mut current = evals;
+        for i in 0..ell - 1 {
+            current = (0..current.len() / 2)
+                .map(|j| {
+                    current[2 * j] + point[ell - i - 1] * (current[2 * j + 1] - current[2 * j])
+                })
+                .collect();
+            folds.push(MultilinearPolynomial::from(current.clone()));
+        }
+        let expected_com = UnivariateKZG::commit_variable_batch(&pk.kzg_pk, &folds).unwrap();
+
+        let mut prover_tr = KeccakTranscript::new(b"TestPipelinedFold");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+        assert_eq!(proof.com, expected_com);
+
+        let mut verifier_tr = KeccakTranscript::new(b"TestPipelinedFold");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut verifier_tr).is_ok());
+    }
+
+    #[test]
+    fn test_hyperkzg_verifier_script() {
+        // A stand-in for a recursion backend: it interprets the script op by op on its
+        // own transcript, recording what it did, and then checks the claim with the
+        // squeezed values only.
+        fn interpret(
+            vk: &HyperKZGVerifierKey<Bn254>,
+            C: &HyperKZGCommitment<Bn254>,
+            point: &[Fr],
+            eval: &Fr,
+            proof: &HyperKZGProof<Bn254>,
+        ) -> (bool, Vec<&'static str>) {
+            let mut transcript = KeccakTranscript::new(b"TestScript");
+            let mut trace = vec![];
+            let mut squeezes: Vec<Vec<Fr>> = vec![];
+            for op in HyperKZG::<Bn254, KeccakTranscript>::verifier_script(point, proof).ops {
+                match op {
+                    TranscriptOp::AbsorbPoints(points) => {
+                        trace.push("absorb");
+                        let points = points.iter().map(|g| g.into_group()).collect::<Vec<_>>();
+                        transcript.append_points(&points);
+                    }
+                    TranscriptOp::AbsorbScalars(scalars) => {
+                        trace.push("absorb");
+                        transcript.append_scalars::<Fr>(&scalars);
+                    }
+                    TranscriptOp::SqueezeScalar => {
+                        trace.push("squeeze");
+                        squeezes.push(vec![transcript.challenge_scalar()]);
+                    }
+                    TranscriptOp::SqueezeScalarPowers(n) => {
+                        trace.push("squeeze");
+                        squeezes.push(transcript.challenge_scalar_powers(n));
+                    }
+                }
+            }
+            let accepted = HyperKZG::<Bn254, KeccakTranscript>::verify_with_challenges(
+                vk, C, point, eval, proof, &squeezes,
+            )
+            .is_ok();
+            (accepted, trace)
+        }
+
+        let ell = 5;
+        let n = 1 << ell;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(224);
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+
+        let poly =
+            MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut prover_tr = KeccakTranscript::new(b"TestScript");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+
+        let mut bad_eval_proof = proof.clone();
+        bad_eval_proof.v[2][1] += Fr::one();
+        let mut bad_witness_proof = proof.clone();
+        bad_witness_proof.w.swap(0, 1);
+        let cases = [
+            (&point[..], eval, &proof, true),
+            (&point[..], eval + Fr::one(), &proof, false),
+            (&point[..], eval, &bad_eval_proof, false),
+            (&point[..], eval, &bad_witness_proof, false),
+        ];
+        for (point, eval, proof, expected) in cases {
+            let mut verifier_tr = KeccakTranscript::new(b"TestScript");
+            let native = HyperKZG::verify(&vk, &C, point, &eval, proof, &mut verifier_tr).is_ok();
+            let (interpreted, trace) = interpret(&vk, &C, point, &eval, proof);
+            assert_eq!(native, expected);
+            assert_eq!(interpreted, expected);
+            assert_eq!(
+                trace,
+                ["absorb", "squeeze", "absorb", "squeeze", "absorb", "squeeze"]
+            );
+        }
+
+        // Challenges that do not come from the script are rejected
+        let mut verifier_tr = KeccakTranscript::new(b"TestScript");
+        let mut squeezes = HyperKZG::<Bn254, KeccakTranscript>::verifier_script(&point, &proof)
+            .run(&mut verifier_tr);
+        assert!(HyperKZG::<Bn254, KeccakTranscript>::verify_with_challenges(
+            &vk, &C, &point, &eval, &proof, &squeezes
+        )
+        .is_ok());
+        squeezes[2][0] += Fr::one();
+        assert!(HyperKZG::<Bn254, KeccakTranscript>::verify_with_challenges(
+            &vk, &C, &point, &eval, &proof, &squeezes
+        )
+        .is_err());
+        assert!(HyperKZG::<Bn254, KeccakTranscript>::verify_with_challenges(
+            &vk,
+            &C,
+            &point,
+            &eval,
+            &proof,
+            &[]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_minimal_srs_size() {
+        // `trim(max_degree)` keeps max_degree + 1 powers; both commit and open need exactly
+        // as many powers as the polynomial has evaluations.
+        for ell in [1, 3, 6] {
+            let n = 1 << ell;
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(227 + ell as u64);
+            let poly =
+                MultilinearPolynomial::from((0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+            let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let eval = poly.evaluate(&point);
+
+            let srs = HyperKZGSRS::setup(&mut rng, n);
+            let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n - 1);
+            assert_eq!(pk.kzg_pk.g1_powers().len(), n);
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+            for commit_to_B in [false, true] {
+                let mut prover_tr = KeccakTranscript::new(b"TestMinimalSRS");
+                let mut verifier_tr = KeccakTranscript::new(b"TestMinimalSRS");
+                let verified = if commit_to_B {
+                    let proof = HyperKZG::open_with_batch_commitment(
+                        &pk,
+                        &poly,
+                        &point,
+                        &eval,
+                        &mut prover_tr,
+                    )
+                    .unwrap();
+                    HyperKZG::verify_with_batch_commitment(
+                        &vk,
+                        &C,
+                        &point,
+                        &eval,
+                        &proof,
+                        &mut verifier_tr,
+                    )
+                } else {
+                    let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+                    HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut verifier_tr)
+                };
+                assert!(verified.is_ok());
+            }
+
+            let (small_pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+                HyperKZGSRS::setup(&mut rng, n).trim(n - 2);
+            assert!(matches!(
+                HyperKZG::<_, KeccakTranscript>::commit(&small_pk, &poly),
+                Err(ProofVerifyError::KeyLengthError {
+                    available_powers,
+                    required_powers,
+                }) if available_powers == n - 1 && required_powers == n
+            ));
+            for commit_to_B in [false, true] {
+                let mut prover_tr = KeccakTranscript::new(b"TestMinimalSRS");
+                let result = if commit_to_B {
+                    HyperKZG::open_with_batch_commitment(
+                        &small_pk,
+                        &poly,
+                        &point,
+                        &eval,
+                        &mut prover_tr,
+                    )
+                    .map(|proof| proof.proof)
+                } else {
+                    HyperKZG::open(&small_pk, &poly, &point, &eval, &mut prover_tr)
+                };
+                assert!(matches!(
+                    result,
+                    Err(ProofVerifyError::KeyLengthError {
+                        available_powers,
+                        required_powers,
+                    }) if available_powers == n - 1 && required_powers == n
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_mixed_trim_sizes() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(230);
+        let srs = HyperKZGSRS::setup(&mut rng, 1 << 10);
+        let (large_pk, large_vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+            srs.clone().trim(1 << 10);
+        let (small_pk, small_vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+            srs.trim(1);
+
+        let ell = 4;
+        let poly = MultilinearPolynomial::from(
+            (0..1 << ell)
+                .map(|_| Fr::rand(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+
+        // The small prover key from the same SRS cannot even commit to this polynomial...
+        assert!(HyperKZG::<_, KeccakTranscript>::commit(&small_pk, &poly).is_err());
+        // ...but the small verifier key verifies proofs made with the large prover key.
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&large_pk, &poly).unwrap();
+        let mut prover_tr = KeccakTranscript::new(b"TestMixedTrim");
+        let proof = HyperKZG::open(&large_pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+        for vk in [&large_vk, &small_vk] {
+            let mut verifier_tr = KeccakTranscript::new(b"TestMixedTrim");
+            assert!(HyperKZG::verify(vk, &C, &point, &eval, &proof, &mut verifier_tr).is_ok());
+            let mut verifier_tr = KeccakTranscript::new(b"TestMixedTrim");
+            assert!(HyperKZG::verify(
+                vk,
+                &C,
+                &point,
+                &(eval + Fr::one()),
+                &proof,
+                &mut verifier_tr
+            )
+            .is_err());
+        }
+
+        // A verifier key from a different SRS does not
+        let (_, other_vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+            HyperKZGSRS::setup(&mut rng, 4).trim(1);
+        let mut verifier_tr = KeccakTranscript::new(b"TestMixedTrim");
+        assert!(HyperKZG::verify(&other_vk, &C, &point, &eval, &proof, &mut verifier_tr).is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_commitment_scheme() {
+        // The Jolt VM only uses HyperKZG through `CommitmentScheme`, so exercise that
+        // surface generically: commit, batch commit, combine, prove and verify. The VM's own
+        // proof flow over it is `rv32i_vm::tests::small_e2e_hyperkzg_commitment_scheme`.
+        fn round_trip<PCS: CommitmentScheme<KeccakTranscript, Field = Fr>>() {
+            let ell = 5;
+            let n = 1 << ell;
+            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(231);
+            let setup = PCS::setup(n);
+            let polys = (0..3)
+                .map(|_| {
+                    MultilinearPolynomial::from(
+                        (0..n).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let commitments = PCS::batch_commit(&polys, &setup);
+            for (poly, commitment) in polys.iter().zip(commitments.iter()) {
+                assert_eq!(&PCS::commit(poly, &setup), commitment);
+            }
+
+            let coeffs = (0..polys.len())
+                .map(|_| Fr::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let combined_poly = MultilinearPolynomial::from(
+                (0..n)
+                    .map(|i| {
+                        polys
+                            .iter()
+                            .zip(coeffs.iter())
+                            .map(|(poly, coeff)| poly.get_coeff(i) * coeff)
+                            .sum::<Fr>()
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            let combined =
+                PCS::combine_commitments(&commitments.iter().collect::<Vec<_>>(), &coeffs);
+            assert_eq!(combined, PCS::commit(&combined_poly, &setup));
+
+            let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let eval = combined_poly.evaluate(&point);
+            let mut prover_tr = KeccakTranscript::new(b"TestPCS");
+            let proof = PCS::prove(&setup, &combined_poly, &point, &mut prover_tr);
+            let mut verifier_tr = KeccakTranscript::new(b"TestPCS");
+            assert!(
+                PCS::verify(&proof, &setup, &mut verifier_tr, &point, &eval, &combined).is_ok()
+            );
+            let mut verifier_tr = KeccakTranscript::new(b"TestPCS");
+            assert!(PCS::verify(
+                &proof,
+                &setup,
+                &mut verifier_tr,
+                &point,
+                &(eval + Fr::one()),
+                &combined
+            )
+            .is_err());
+        }
+
+        round_trip::<HyperKZG<Bn254, KeccakTranscript>>();
+    }
+
+    #[test]
+    fn test_hyperkzg_fixture_is_deterministic() {
+        let commit = |(pk, _, poly, point, eval): testing::Fixture<Bn254>| {
+            let poly = MultilinearPolynomial::LargeScalars(poly);
+            assert_eq!(poly.evaluate(&point), eval);
+            (
+                HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap(),
+                point,
+            )
+        };
+        let (C, point) = commit(testing::fixture(6, 232));
+        assert_eq!((C, point.clone()), commit(testing::fixture(6, 232)));
+        assert_ne!(C, commit(testing::fixture(6, 233)).0);
+        assert_eq!(point.len(), 6);
+    }
+
+    #[test]
+    fn test_hyperkzg_rejects_mutated_group_elements() {
+        for ell in 4..=6 {
+            let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(ell, 233 + ell as u64);
+            let poly = MultilinearPolynomial::LargeScalars(poly);
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+            assert_eq!(proof.com.len(), ell - 1);
+            assert_eq!(proof.w.len(), 3);
+
+            let rejects = |bad_proof: &HyperKZGProof<Bn254>| {
+                let mut tr = KeccakTranscript::new(b"TestEval");
+                HyperKZG::verify(&vk, &C, &point, &eval, bad_proof, &mut tr).is_err()
+            };
+            let shift = |g: &mut ark_bn254::G1Affine| {
+                *g = (*g + ark_bn254::G1Affine::generator()).into_affine();
+            };
+
+            for i in 0..proof.com.len() {
+                let mut bad_proof = proof.clone();
+                shift(&mut bad_proof.com[i]);
+                assert!(
+                    rejects(&bad_proof),
+                    "ell = {ell}: mutated com[{i}] accepted"
+                );
+            }
+            for i in 0..proof.w.len() {
+                let mut bad_proof = proof.clone();
+                shift(&mut bad_proof.w[i]);
+                assert!(rejects(&bad_proof), "ell = {ell}: mutated w[{i}] accepted");
+            }
+            // Reordering the fold commitments must be caught as well
+            for i in 1..proof.com.len() {
+                let mut bad_proof = proof.clone();
+                bad_proof.com.swap(i - 1, i);
+                assert!(
+                    rejects(&bad_proof),
+                    "ell = {ell}: swapped com[{i}] accepted"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_commit_and_serialize() {
+        let (pk, _, poly, _, _) = testing::fixture::<Bn254>(5, 234);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let (C, bytes) = HyperKZG::<_, KeccakTranscript>::commit_and_serialize(&pk, &poly).unwrap();
+        assert_eq!(
+            C,
+            HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap()
+        );
+        assert_eq!(bytes.len(), C.compressed_size());
+        let decoded = HyperKZGCommitment::<Bn254>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded, C);
+    }
+
+    #[test]
+    fn test_hyperkzg_constant_coordinates() {
+        // Coordinates fixed to 0 or 1 take the select path of `fold`, and are otherwise
+        // proven like any other coordinate
+        let ell = 3;
+        let (pk, vk, poly, _, _) = testing::fixture::<Bn254>(ell, 235);
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(235);
+        let point = vec![Fr::rand(&mut rng), Fr::zero(), Fr::one()];
+
+        let evens = fold(poly.evals_ref(), Fr::zero());
+        let odds = fold(poly.evals_ref(), Fr::one());
+        for j in 0..poly.len() / 2 {
+            assert_eq!(evens[j], poly[2 * j]);
+            assert_eq!(odds[j], poly[2 * j + 1]);
+        }
+
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let eval = poly.evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let (proof, profile) =
+            HyperKZG::open_with_profile(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        // Every fold is still committed to, so the proof keeps its shape and its MSM count
+        assert_eq!(proof.com.len(), ell - 1);
+        let random_point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let (_, random_profile) = HyperKZG::open_with_profile(
+            &pk,
+            &poly,
+            &random_point,
+            &poly.evaluate(&random_point),
+            &mut tr,
+        )
+        .unwrap();
+        assert_eq!(profile.msm_count, random_profile.msm_count);
+        assert_eq!(profile.poly_sizes, random_profile.poly_sizes);
+
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr).is_ok());
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        assert!(HyperKZG::verify(&vk, &C, &point, &(eval + Fr::one()), &proof, &mut tr).is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_commit_blinded_msm() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(239);
+        let (pk, _, poly, _, _) = testing::fixture::<Bn254>(6, 239);
+        let n = poly.len();
+        let polys = [
+            MultilinearPolynomial::LargeScalars(poly),
+            MultilinearPolynomial::from((0..n as u64).collect::<Vec<_>>()),
+            MultilinearPolynomial::from(vec![Fr::zero(); n]),
+        ];
+        for poly in polys.iter() {
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, poly).unwrap();
+            for _ in 0..3 {
+                let blinded =
+                    HyperKZG::<_, KeccakTranscript>::commit_blinded_msm(&pk, poly, &mut rng)
+                        .unwrap();
+                assert_eq!(blinded, C);
+            }
+        }
+
+        let (short_pk, _) = HyperKZGSRS::setup(&mut rng, n / 2).trim(n / 2);
+        assert!(matches!(
+            HyperKZG::<Bn254, KeccakTranscript>::commit_blinded_msm(&short_pk, &polys[0], &mut rng),
+            Err(ProofVerifyError::KeyLengthError { .. })
+        ));
+        let empty = MultilinearPolynomial::LargeScalars(DensePolynomial::default());
+        assert!(matches!(
+            HyperKZG::<Bn254, KeccakTranscript>::commit_blinded_msm(&pk, &empty, &mut rng),
+            Err(ProofVerifyError::InvalidInputLength(1, 0))
+        ));
+
+        let base = pk.g1_powers()[1].into_group();
+        for scalar in [Fr::zero(), Fr::one(), -Fr::one(), Fr::rand(&mut rng)] {
+            assert_eq!(ladder_mul(base, &scalar), base * scalar);
+        }
+    }
+
+    #[test]
+    fn test_kzg_open_batch_exact_key() {
+        // A key with exactly B.len() powers suffices to commit to B and its witnesses
+        let (_, _, poly, _, _) = testing::fixture::<Bn254>(5, 240);
+        let f = vec![MultilinearPolynomial::LargeScalars(poly)];
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(240);
+        // `trim(d)` keeps d + 1 powers
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+            HyperKZGSRS::setup(&mut rng, f[0].len()).trim(f[0].len() - 1);
+        assert_eq!(pk.kzg_pk.g1_powers().len(), f[0].len());
+        let C = vec![
+            HyperKZG::<_, KeccakTranscript>::commit(&pk, &f[0])
+                .unwrap()
+                .0,
+        ];
+
+        let u = (0..3).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut prover_transcript = KeccakTranscript::new(b"TestBatch");
+        let (w, v, b_com) = kzg_open_batch(&f, &u, &pk, &mut prover_transcript, true).unwrap();
+        assert!(b_com.is_some());
+        let mut verifier_transcript = KeccakTranscript::new(b"TestBatch");
+        assert!(kzg_verify_batch(
+            &vk,
+            &C,
+            &w,
+            &u,
+            &v,
+            b_com.as_ref(),
+            &mut verifier_transcript
+        ));
+    }
+
+    #[test]
+    fn test_kzg_open_batch_short_key() {
+        let (_, _, poly, _, _) = testing::fixture::<Bn254>(5, 240);
+        let f = vec![MultilinearPolynomial::LargeScalars(poly)];
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(240);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+            HyperKZGSRS::setup(&mut rng, f[0].len()).trim(f[0].len() - 2);
+        assert_eq!(pk.kzg_pk.g1_powers().len(), f[0].len() - 1);
+
+        let u = (0..3).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut prover_transcript = KeccakTranscript::new(b"TestBatch");
+        assert!(matches!(
+            kzg_open_batch(&f, &u, &pk, &mut prover_transcript, false),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers: 31,
+                required_powers: 32,
+            })
+        ));
+    }
+
+    #[test]
+    fn test_kzg_batch_challenges() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(241);
+        let (pk, _, poly, _, _) = testing::fixture::<Bn254>(4, 241);
+        let f = vec![
+            MultilinearPolynomial::LargeScalars(poly),
+            MultilinearPolynomial::from((0..16).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>()),
+        ];
+        let u = (0..3).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let mut prover_transcript = KeccakTranscript::new(b"TestBatch");
+        let (w, v, _) = kzg_open_batch(&f, &u, &pk, &mut prover_transcript, false).unwrap();
+
+        // The derivation performed inline by the prover
+        let mut inline_transcript = KeccakTranscript::new(b"TestBatch");
+        inline_transcript.append_scalars::<Fr>(&v.iter().flatten().collect::<Vec<_>>());
+        let q_powers: Vec<Fr> = inline_transcript.challenge_scalar_powers(f.len());
+        inline_transcript.append_points(&w.iter().map(|g| g.into_group()).collect::<Vec<_>>());
+        let d_0: Fr = inline_transcript.challenge_scalar();
+
+        let mut transcript = KeccakTranscript::new(b"TestBatch");
+        assert_eq!(
+            kzg_batch_challenges::<Bn254, _>(&mut transcript, &w, &v, f.len()),
+            (q_powers, d_0)
+        );
+        // All three transcripts are left in the same state
+        let next: Fr = transcript.challenge_scalar();
+        assert_eq!(next, inline_transcript.challenge_scalar::<Fr>());
+        assert_eq!(next, prover_transcript.challenge_scalar::<Fr>());
+    }
+
+    #[test]
+    fn test_hyperkzg_verify_with_commitment_bytes() {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(5, 242);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let (_, bytes) = HyperKZG::<_, KeccakTranscript>::commit_and_serialize(&pk, &poly).unwrap();
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+
+        let verify = |bytes: &[u8], eval: &Fr| {
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            HyperKZG::verify_with_commitment_bytes(&vk, bytes, &point, eval, &proof, &mut tr)
+        };
+        assert!(verify(&bytes, &eval).is_ok());
+        assert!(verify(&bytes, &(eval + Fr::one())).is_err());
+        assert!(matches!(
+            verify(&bytes[..bytes.len() - 1], &eval),
+            Err(ProofVerifyError::SerializationError(_))
+        ));
+    }
+
+    /// An unoptimized HyperKZG verifier that follows the paper directly, computing the
+    /// batched KZG check from `L0, L1, L2` and `R0, R1, R2` without grouping the MSMs.
+    /// Only for differential testing against `HyperKZG::verify`.
+    fn verify_reference(
+        vk: &HyperKZGVerifierKey<Bn254>,
+        C: &HyperKZGCommitment<Bn254>,
+        point: &[Fr],
+        eval: &Fr,
+        pi: &HyperKZGProof<Bn254>,
+        transcript: &mut KeccakTranscript,
+    ) -> bool {
+        use ark_bn254::G1Projective;
+
+        let ell = point.len();
+        let squeezes =
+            HyperKZG::<Bn254, KeccakTranscript>::verifier_script(point, pi).run(transcript);
+        if ell == 0
+            || pi.com.len() != ell - 1
+            || pi.w.len() != 3
+            || pi.v.len() != 3
+            || pi.v.iter().any(|v_i| v_i.len() != ell)
+        {
+            return false;
+        }
+        let (r, q_powers, d_0) = (squeezes[0][0], &squeezes[1], squeezes[2][0]);
+        let u = [r, -r, r * r];
+
+        // Consistency of the claimed evaluations of consecutive folds
+        let (ypos, yneg) = (&pi.v[0], &pi.v[1]);
+        let mut Y = pi.v[2].clone();
+        Y.push(*eval);
+        // Y[i + 1] = (1 - x) * even + x * odd, for the even and odd parts of fold i
+        // recovered from its evaluations at r and -r
+        for i in 0..ell {
+            let x = point[ell - i - 1];
+            let even = (ypos[i] + yneg[i]) / Fr::from(2u64);
+            let odd = (ypos[i] - yneg[i]) / (Fr::from(2u64) * r);
+            if Y[i + 1] != (Fr::one() - x) * even + x * odd {
+                return false;
+            }
+        }
+
+        // C_B = sum_i q^i * C_i and B(u_j) = sum_i q^i * v[j][i]
+        let com = [&[C.0], &pi.com[..]].concat();
+        let C_B: G1Projective = com.iter().zip(q_powers.iter()).map(|(c, q)| *c * q).sum();
+        let B_u =
+            pi.v.iter()
+                .map(|v_j| v_j.iter().zip(q_powers.iter()).map(|(v, q)| *v * q).sum())
+                .collect::<Vec<Fr>>();
+
+        let G = vk.kzg_vk.g1;
+        let W = &pi.w;
+        let L0 = C_B - G * B_u[0] + W[0] * u[0];
+        let L1 = C_B - G * B_u[1] + W[1] * u[1];
+        let L2 = C_B - G * B_u[2] + W[2] * u[2];
+        let R0 = -W[0].into_group();
+        let R1 = -W[1].into_group();
+        let R2 = -W[2].into_group();
+        let d_1 = d_0 * d_0;
+        let L = L0 + L1 * d_0 + L2 * d_1;
+        let R = R0 + R1 * d_0 + R2 * d_1;
+
+        // e(L, H) == e(-R, tau H)
+        Bn254::pairing(L, vk.kzg_vk.g2) == Bn254::pairing(-R, vk.kzg_vk.beta_g2)
+    }
+
+    #[test]
+    fn test_hyperkzg_verify_matches_reference() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(244);
+        for ell in 1..=6 {
+            let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(ell, 244 + ell as u64);
+            let poly = MultilinearPolynomial::LargeScalars(poly);
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+            let mut tr = KeccakTranscript::new(b"TestEval");
+            let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+
+            let check = |proof: &HyperKZGProof<Bn254>, eval: &Fr| {
+                let mut tr = KeccakTranscript::new(b"TestEval");
+                let optimized = HyperKZG::verify(&vk, &C, &point, eval, proof, &mut tr).is_ok();
+                let mut tr = KeccakTranscript::new(b"TestEval");
+                let reference = verify_reference(&vk, &C, &point, eval, proof, &mut tr);
+                assert_eq!(optimized, reference, "ell = {ell}");
+                optimized
+            };
+
+            assert!(check(&proof, &eval));
+            assert!(!check(&proof, &Fr::rand(&mut rng)));
+            for _ in 0..8 {
+                let mut bad_proof = proof.clone();
+                match rng.next_u32() % 3 {
+                    0 => {
+                        let j = rng.next_u32() as usize % 3;
+                        let i = rng.next_u32() as usize % ell;
+                        bad_proof.v[j][i] = Fr::rand(&mut rng);
+                    }
+                    1 => {
+                        let j = rng.next_u32() as usize % 3;
+                        bad_proof.w[j] = (bad_proof.w[j] * Fr::rand(&mut rng)).into_affine();
+                    }
+                    _ if !bad_proof.com.is_empty() => {
+                        let i = rng.next_u32() as usize % bad_proof.com.len();
+                        bad_proof.com[i] = (bad_proof.com[i] * Fr::rand(&mut rng)).into_affine();
+                    }
+                    _ => bad_proof.v.swap(0, 1),
+                }
+                assert!(!check(&bad_proof, &eval));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_deserialize_bounded() {
+        let ell = 5;
+        let (pk, _, poly, point, eval) = testing::fixture::<Bn254>(ell, 245);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+        let mut bytes = vec![];
+        proof.serialize_compressed(&mut bytes).unwrap();
+
+        let deserialize = |bytes: &[u8], max_num_vars| {
+            HyperKZGProof::<Bn254>::deserialize_bounded(
+                bytes,
+                Compress::Yes,
+                Validate::Yes,
+                max_num_vars,
+            )
+        };
+        for max_num_vars in [ell, 20] {
+            let decoded = deserialize(&bytes, max_num_vars).unwrap();
+            let mut reencoded = vec![];
+            decoded.serialize_compressed(&mut reencoded).unwrap();
+            assert_eq!(reencoded, bytes);
+        }
+        assert!(matches!(
+            deserialize(&bytes, ell - 1),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
 
-        let ell = point.len();
+        // A length prefix claiming 2^60 fold commitments is rejected before allocating
+        let mut crafted = vec![];
+        (1u64 << 60).serialize_compressed(&mut crafted).unwrap();
+        crafted.extend_from_slice(&bytes[8..]);
+        assert!(matches!(
+            deserialize(&crafted, 32),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
 
-        let mut com = pi.com.clone();
+        // As are oversized inner lengths of v
+        // v comes last in the proof
+        let first_v_len = bytes.len() - 3 * (8 + ell * 32);
+        let mut crafted = bytes.clone();
+        crafted[first_v_len..first_v_len + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            deserialize(&crafted, 32),
+            Err(ProofVerifyError::InputTooLarge)
+        ));
+    }
 
-        // we do not need to add x to the transcript, because in our context x was
-        // obtained from the transcript
-        transcript.append_points(&com.iter().map(|g| g.into_group()).collect::<Vec<P::G1>>());
-        let r: <P as Pairing>::ScalarField = transcript.challenge_scalar();
+    #[test]
+    fn test_hyperkzg_update_commitment() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(246);
+        let (pk, _, poly, _, _) = testing::fixture::<Bn254>(6, 246);
+        let n = poly.len();
+        let mut evals = poly.evals();
+        let C = HyperKZG::<_, KeccakTranscript>::commit(
+            &pk,
+            &MultilinearPolynomial::LargeScalars(poly),
+        )
+        .unwrap();
 
-        if r == P::ScalarField::zero() || C.0 == P::G1Affine::zero() {
-            return Err(ProofVerifyError::InternalError);
+        // Sparse updates, including the first and last evaluation, an index updated twice
+        // and an unchanged value
+        let mut updates = vec![];
+        for index in [0, 5, 17, 5, n - 1, 40] {
+            let new_value = if index == 40 {
+                evals[index]
+            } else {
+                Fr::rand(&mut rng)
+            };
+            updates.push((index, evals[index], new_value));
+            evals[index] = new_value;
         }
-        com.insert(0, C.0); // set com_0 = C, shifts other commitments to the right
+        let updated =
+            HyperKZG::<_, KeccakTranscript>::update_commitment(&pk, &C, &updates).unwrap();
+        let fresh =
+            HyperKZG::<_, KeccakTranscript>::commit(&pk, &MultilinearPolynomial::from(evals))
+                .unwrap();
+        assert_eq!(updated, fresh);
+        assert_eq!(
+            HyperKZG::<_, KeccakTranscript>::update_commitment(&pk, &C, &[]).unwrap(),
+            C
+        );
 
-        let u = vec![r, -r, r * r];
+        let available_powers = pk.kzg_pk.g1_powers().len();
+        assert!(matches!(
+            HyperKZG::<_, KeccakTranscript>::update_commitment(
+                &pk,
+                &C,
+                &[(available_powers, Fr::zero(), Fr::one())]
+            ),
+            Err(ProofVerifyError::KeyLengthError { .. })
+        ));
+    }
 
-        // Setup vectors (Y, ypos, yneg) from pi.v
-        let v = &pi.v;
-        if v.len() != 3 {
-            return Err(ProofVerifyError::InternalError);
-        }
-        if v[0].len() != ell || v[1].len() != ell || v[2].len() != ell {
-            return Err(ProofVerifyError::InternalError);
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "`eval` is not `poly` at `point`")]
+    fn test_hyperkzg_open_checks_eval() {
+        let (pk, _, poly, point, eval) = testing::fixture::<Bn254>(4, 247);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let _ = HyperKZG::open(&pk, &poly, &point, &(eval + Fr::one()), &mut tr);
+    }
+
+    #[test]
+    #[cfg(feature = "verify-timings")]
+    fn test_hyperkzg_verify_timings() {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(12, 249);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        let timings =
+            HyperKZG::verify_with_timings(&vk, &C, &point, &eval, &proof, &mut tr).unwrap();
+        for phase in [
+            VerifyTimings::TRANSCRIPT,
+            VerifyTimings::CONSISTENCY,
+            VerifyTimings::MSM,
+            VerifyTimings::PAIRING,
+        ] {
+            assert!(
+                timings.phase_timings_us.contains_key(phase),
+                "{phase} missing"
+            );
         }
-        let ypos = &v[0];
-        let yneg = &v[1];
-        let mut Y = v[2].to_vec();
-        Y.push(*y);
+        assert_eq!(timings.phase_timings_us.len(), 4);
+        // The phases cover all of verify but a few allocations
+        let sum: u64 = timings.phase_timings_us.values().sum();
+        assert!(sum <= timings.total_us);
+        assert!(2 * sum >= timings.total_us, "{timings:?}");
 
-        // Check consistency of (Y, ypos, yneg)
-        let two = P::ScalarField::from(2u64);
-        for i in 0..ell {
-            if two * r * Y[i + 1]
-                != r * (P::ScalarField::one() - point[ell - i - 1]) * (ypos[i] + yneg[i])
-                    + point[ell - i - 1] * (ypos[i] - yneg[i])
-            {
-                return Err(ProofVerifyError::InternalError);
-            }
-            // Note that we don't make any checks about Y[0] here, but our batching
-            // check below requires it
+        let mut tr = KeccakTranscript::new(b"TestEval");
+        assert!(HyperKZG::verify_with_timings(
+            &vk,
+            &C,
+            &point,
+            &(eval + Fr::one()),
+            &proof,
+            &mut tr
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hyperkzg_batch_verify() {
+        const N: usize = 8;
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(251);
+        let srs = HyperKZGSRS::setup(&mut rng, 1 << 6);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(1 << 6);
+
+        // Claims about polynomials of different sizes, proven one after the other
+        let mut commitments = vec![];
+        let mut points = vec![];
+        let mut evals = vec![];
+        let mut proofs = vec![];
+        let mut prover_tr = KeccakTranscript::new(b"TestBatchVerify");
+        for i in 0..N {
+            let ell = 1 + i % 6;
+            let poly = MultilinearPolynomial::from(
+                (0..1 << ell)
+                    .map(|_| Fr::rand(&mut rng))
+                    .collect::<Vec<_>>(),
+            );
+            let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let eval = poly.evaluate(&point);
+            commitments.push(HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap());
+            proofs.push(HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap());
+            points.push(point);
+            evals.push(eval);
         }
 
-        // Check commitments to (Y, ypos, yneg) are valid
-        if !kzg_verify_batch(vk, &com, &pi.w, &u, &pi.v, transcript) {
-            return Err(ProofVerifyError::InternalError);
+        let batch_verify = |proofs: &[HyperKZGProof<Bn254>], evals: &[Fr]| {
+            let mut tr = KeccakTranscript::new(b"TestBatchVerify");
+            HyperKZG::batch_verify(&vk, &commitments, &points, evals, proofs, &mut tr)
+        };
+        assert!(batch_verify(&proofs, &evals).is_ok());
+
+        // Corrupting the witnesses of a single proof only affects the combined pairing check
+        for i in [0, N / 2, N - 1] {
+            let mut bad_proofs = proofs.clone();
+            bad_proofs[i].w[1] =
+                (bad_proofs[i].w[1] + ark_bn254::G1Affine::generator()).into_affine();
+            assert!(batch_verify(&bad_proofs, &evals).is_err());
         }
+        let mut bad_evals = evals.clone();
+        bad_evals[3] += Fr::one();
+        assert!(batch_verify(&proofs, &bad_evals).is_err());
 
-        Ok(())
+        assert!(matches!(
+            batch_verify(&proofs[1..], &evals),
+            Err(ProofVerifyError::InternalError)
+        ));
     }
-}
 
-impl<P: Pairing, ProofTranscript: Transcript> CommitmentScheme<ProofTranscript>
-    for HyperKZG<P, ProofTranscript>
-where
-    <P as Pairing>::ScalarField: JoltField,
-    <P as Pairing>::G1: Icicle,
-{
-    type Field = P::ScalarField;
-    type Setup = (HyperKZGProverKey<P>, HyperKZGVerifierKey<P>);
-    type Commitment = HyperKZGCommitment<P>;
-    type Proof = HyperKZGProof<P>;
-    type BatchedProof = HyperKZGProof<P>;
+    #[test]
+    fn test_hyperkzg_non_power_of_two_length() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(254);
+        let srs = HyperKZGSRS::setup(&mut rng, 8);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(8);
 
-    fn setup(max_poly_len: usize) -> Self::Setup {
-        HyperKZGSRS(Arc::new(SRS::setup(
-            &mut ChaCha20Rng::from_seed(*b"HyperKZG_POLY_COMMITMENT_SCHEMEE"),
-            max_poly_len,
-            2,
-        )))
-        .trim(max_poly_len)
-    }
+        let evals = (0..6).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let point = (0..3).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let padded = MultilinearPolynomial::from([evals.clone(), vec![Fr::zero(); 2]].concat());
+        let eval = padded.evaluate(&point);
 
-    #[tracing::instrument(skip_all, name = "HyperKZG::commit")]
-    fn commit(poly: &MultilinearPolynomial<Self::Field>, setup: &Self::Setup) -> Self::Commitment {
-        assert!(
-            setup.0.kzg_pk.g1_powers().len() >= poly.len(),
-            "COMMIT KEY LENGTH ERROR {}, {}",
-            setup.0.kzg_pk.g1_powers().len(),
-            poly.len()
+        let C = HyperKZG::<_, KeccakTranscript>::commit_evals(&pk, &evals).unwrap();
+        assert_eq!(
+            C,
+            HyperKZG::<_, KeccakTranscript>::commit(&pk, &padded).unwrap()
         );
-        HyperKZGCommitment(UnivariateKZG::commit_as_univariate(&setup.0.kzg_pk, poly).unwrap())
-    }
+        let mut prover_tr = KeccakTranscript::new(b"TestUnpadded");
+        let proof = HyperKZG::open_evals(&pk, &evals, &point, &eval, &mut prover_tr).unwrap();
+        let mut verifier_tr = KeccakTranscript::new(b"TestUnpadded");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut verifier_tr).is_ok());
+        let mut verifier_tr = KeccakTranscript::new(b"TestUnpadded");
+        assert!(HyperKZG::verify(
+            &vk,
+            &C,
+            &point,
+            &(eval + Fr::one()),
+            &proof,
+            &mut verifier_tr
+        )
+        .is_err());
 
-    #[tracing::instrument(skip_all, name = "HyperKZG::batch_commit")]
-    fn batch_commit<U>(polys: &[U], gens: &Self::Setup) -> Vec<Self::Commitment>
-    where
-        U: Borrow<MultilinearPolynomial<Self::Field>> + Sync,
-    {
-        UnivariateKZG::commit_batch(&gens.0.kzg_pk, polys)
-            .unwrap()
-            .into_par_iter()
-            .map(|c| HyperKZGCommitment(c))
-            .collect()
+        // A polynomial with fewer variables than the point is padded by `open` itself
+        let short = MultilinearPolynomial::from(evals[..4].to_vec());
+        let short_eval =
+            MultilinearPolynomial::from([evals[..4].to_vec(), vec![Fr::zero(); 4]].concat())
+                .evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &short).unwrap();
+        let mut prover_tr = KeccakTranscript::new(b"TestUnpadded");
+        let proof = HyperKZG::open(&pk, &short, &point, &short_eval, &mut prover_tr).unwrap();
+        let mut verifier_tr = KeccakTranscript::new(b"TestUnpadded");
+        assert!(HyperKZG::verify(&vk, &C, &point, &short_eval, &proof, &mut verifier_tr).is_ok());
+
+        let mut prover_tr = KeccakTranscript::new(b"TestUnpadded");
+        assert!(matches!(
+            HyperKZG::open_evals(&pk, &evals, &point[..2], &eval, &mut prover_tr),
+            Err(ProofVerifyError::InvalidInputLength(4, 6))
+        ));
+        let mut prover_tr = KeccakTranscript::new(b"TestUnpadded");
+        assert!(matches!(
+            HyperKZG::open(&pk, &padded, &point[..2], &eval, &mut prover_tr),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers: 4,
+                required_powers: 8,
+            })
+        ));
     }
 
-    fn prove(
-        setup: &Self::Setup,
-        poly: &MultilinearPolynomial<Self::Field>,
-        opening_point: &[Self::Field], // point at which the polynomial is evaluated
-        transcript: &mut ProofTranscript,
-    ) -> Self::Proof {
-        let eval = poly.evaluate(opening_point);
-        HyperKZG::<P, ProofTranscript>::open(&setup.0, poly, opening_point, &eval, transcript)
+    #[test]
+    fn test_hyperkzg_commit_batch() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(255);
+        let srs = HyperKZGSRS::setup(&mut rng, 1 << 8);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(1 << 8);
+
+        let polys: Vec<DensePolynomial<Fr>> = (0..16)
+            .map(|i| DensePolynomial::random(i % 9, &mut rng))
+            .collect();
+        let commitments = HyperKZG::<_, KeccakTranscript>::commit_batch(&pk, &polys).unwrap();
+        assert_eq!(commitments.len(), polys.len());
+        for (poly, commitment) in polys.iter().zip(commitments.iter()) {
+            let poly = MultilinearPolynomial::LargeScalars(poly.clone());
+            assert_eq!(
+                *commitment,
+                HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap()
+            );
+        }
+        assert!(HyperKZG::<_, KeccakTranscript>::commit_batch(&pk, &[])
             .unwrap()
-    }
+            .is_empty());
 
-    fn combine_commitments(
-        commitments: &[&Self::Commitment],
-        coeffs: &[Self::Field],
-    ) -> Self::Commitment {
-        let combined_commitment: P::G1 = commitments
-            .iter()
-            .zip(coeffs.iter())
-            .map(|(commitment, coeff)| commitment.0 * coeff)
-            .sum();
-        HyperKZGCommitment(combined_commitment.into_affine())
+        // The first polynomial that does not fit determines the error
+        let too_long = [
+            DensePolynomial::random(2, &mut rng),
+            DensePolynomial::random(9, &mut rng),
+            DensePolynomial::random(10, &mut rng),
+        ];
+        assert!(matches!(
+            HyperKZG::<_, KeccakTranscript>::commit_batch(&pk, &too_long),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers,
+                required_powers,
+            }) if available_powers == pk.kzg_pk.g1_powers().len() && required_powers == 1 << 9
+        ));
     }
 
-    fn verify(
-        proof: &Self::Proof,
-        setup: &Self::Setup,
-        transcript: &mut ProofTranscript,
-        opening_point: &[Self::Field], // point at which the polynomial is evaluated
-        opening: &Self::Field,         // evaluation \widetilde{Z}(r)
-        commitment: &Self::Commitment,
-    ) -> Result<(), ProofVerifyError> {
-        HyperKZG::<P, ProofTranscript>::verify(
-            &setup.1,
-            commitment,
-            opening_point,
-            opening,
-            proof,
-            transcript,
+    #[test]
+    fn test_hyperkzg_proof_from_parts() {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(5, 256);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut prover_tr = KeccakTranscript::new(b"TestFromParts");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+
+        let rebuilt = HyperKZGProof::<Bn254>::from_parts(
+            proof.commitments().to_vec(),
+            proof.witnesses().to_vec(),
+            proof.evaluations().to_vec(),
         )
+        .unwrap();
+        let (mut bytes, mut rebuilt_bytes) = (vec![], vec![]);
+        proof.serialize_compressed(&mut bytes).unwrap();
+        rebuilt.serialize_compressed(&mut rebuilt_bytes).unwrap();
+        assert_eq!(bytes, rebuilt_bytes);
+        let mut verifier_tr = KeccakTranscript::new(b"TestFromParts");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &rebuilt, &mut verifier_tr).is_ok());
+
+        assert!(matches!(
+            HyperKZGProof::<Bn254>::from_parts(
+                proof.com.clone(),
+                proof.w.clone(),
+                proof.v[..2].to_vec()
+            ),
+            Err(ProofVerifyError::InvalidInputLength(3, 2))
+        ));
+        assert!(matches!(
+            HyperKZGProof::<Bn254>::from_parts(proof.com.clone(), vec![], proof.v.clone()),
+            Err(ProofVerifyError::InvalidInputLength(3, 0))
+        ));
     }
 
-    fn protocol_name() -> &'static [u8] {
-        b"hyperkzg"
+    #[test]
+    fn test_hyperkzg_srs_from_ptau() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(259);
+        let file = crate::poly::commitment::ptau::test::synthetic_ptau(Fr::rand(&mut rng), 3);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) =
+            HyperKZGSRS::from_reader(&file[..], 8).unwrap().trim(8);
+
+        let ell = 3;
+        let poly = MultilinearPolynomial::from(
+            (0..1 << ell)
+                .map(|_| Fr::rand(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+        let point = (0..ell).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+        let eval = poly.evaluate(&point);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut prover_tr = KeccakTranscript::new(b"TestPtau");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+        let mut verifier_tr = KeccakTranscript::new(b"TestPtau");
+        assert!(HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut verifier_tr).is_ok());
+
+        // A ceremony of power 3 has 15 G1 powers
+        assert!(matches!(
+            HyperKZGSRS::from_reader(&file[..], 15),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers: 15,
+                required_powers: 16,
+            })
+        ));
+        assert!(HyperKZGSRS::from_reader(&file[..100], 8).is_err());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::utils::transcript::{KeccakTranscript, Transcript};
-    use ark_bn254::{Bn254, Fr};
-    use ark_std::UniformRand;
-    use rand_core::SeedableRng;
+    #[test]
+    fn test_hyperkzg_verify_prepared() {
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(10, 260);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let pvk = vk.prepare();
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut prover_tr = KeccakTranscript::new(b"TestPrepared");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_tr).unwrap();
+        let mut bad_proof = proof.clone();
+        bad_proof.w[0] = (bad_proof.w[0] + ark_bn254::G1Affine::generator()).into_affine();
+
+        for (proof, eval, accepted) in [
+            (&proof, eval, true),
+            (&proof, eval + Fr::one(), false),
+            (&bad_proof, eval, false),
+        ] {
+            let mut tr = KeccakTranscript::new(b"TestPrepared");
+            let result = HyperKZG::verify(&vk, &C, &point, &eval, proof, &mut tr);
+            assert_eq!(result.is_ok(), accepted);
+
+            let mut prepared_tr = KeccakTranscript::new(b"TestPrepared");
+            let result =
+                HyperKZG::verify_prepared(&pvk, &C, &point, &eval, proof, &mut prepared_tr);
+            assert_eq!(result.is_ok(), accepted);
+            assert_eq!(tr.state, prepared_tr.state);
+        }
+    }
 
     #[test]
-    fn test_hyperkzg_eval() {
-        // Test with poly(X1, X2) = 1 + X1 + X2 + X1*X2
-        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(0);
-        let srs = HyperKZGSRS::setup(&mut rng, 3);
-        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(3);
+    fn test_hyperkzg_open_ct() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(262);
+        let ell = 6;
+        let n = 1 << ell;
+        let srs = HyperKZGSRS::setup(&mut rng, n);
+        let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
 
-        // poly is in eval. representation; evaluated at [(0,0), (0,1), (1,0), (1,1)]
-        let poly =
-            MultilinearPolynomial::from(vec![Fr::from(1), Fr::from(2), Fr::from(2), Fr::from(4)]);
+        let dense =
+            MultilinearPolynomial::from((0..n).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>());
+        // A compact polynomial with zero and one evaluations
+        let compact =
+            MultilinearPolynomial::from((0..n).map(|i| (i % 3) as u8).collect::<Vec<_>>());
+        let random_point = (0..ell).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+        // A point with boolean coordinates, for which `open` skips the fold arithmetic
+        let mut boolean_point = random_point.clone();
+        boolean_point[1] = Fr::zero();
+        boolean_point[4] = Fr::one();
 
-        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        for poly in [&dense, &compact] {
+            let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, poly).unwrap();
+            for point in [&random_point, &boolean_point] {
+                let eval = poly.evaluate(point);
 
-        let test_inner = |point: Vec<Fr>, eval: Fr| -> Result<(), ProofVerifyError> {
-            let mut tr = KeccakTranscript::new(b"TestEval");
-            let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
-            let mut tr = KeccakTranscript::new(b"TestEval");
-            HyperKZG::verify(&vk, &C, &point, &eval, &proof, &mut tr)
-        };
+                let mut tr = KeccakTranscript::new(b"TestEval");
+                let proof = HyperKZG::open(&pk, poly, point, &eval, &mut tr).unwrap();
+                let mut tr_ct = KeccakTranscript::new(b"TestEval");
+                let proof_ct = HyperKZG::open_ct(&pk, poly, point, &eval, &mut tr_ct).unwrap();
 
-        // Call the prover with a (point, eval) pair.
-        // The prover does not recompute so it may produce a proof, but it should not verify
-        let point = vec![Fr::from(0), Fr::from(0)];
-        let eval = Fr::from(1);
-        assert!(test_inner(point, eval).is_ok());
+                let mut bytes = vec![];
+                proof.serialize_compressed(&mut bytes).unwrap();
+                let mut bytes_ct = vec![];
+                proof_ct.serialize_compressed(&mut bytes_ct).unwrap();
+                assert_eq!(bytes, bytes_ct);
+                assert_eq!(tr.state, tr_ct.state);
 
-        let point = vec![Fr::from(0), Fr::from(1)];
-        let eval = Fr::from(2);
-        assert!(test_inner(point, eval).is_ok());
+                let mut tr = KeccakTranscript::new(b"TestEval");
+                assert!(HyperKZG::verify(&vk, &C, point, &eval, &proof_ct, &mut tr).is_ok());
+            }
+        }
+    }
 
-        let point = vec![Fr::from(1), Fr::from(1)];
-        let eval = Fr::from(4);
-        assert!(test_inner(point, eval).is_ok());
+    #[test]
+    fn test_hyperkzg_fold_levels() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(263);
+        for ell in [10, 18] {
+            let evals: Vec<Fr> = (0..1 << ell).map(|_| Fr::random(&mut rng)).collect();
+            let mut rs: Vec<Fr> = (0..ell - 1).map(|_| Fr::random(&mut rng)).collect();
+            // Constant coordinates take the select path of `fold`
+            rs[2] = Fr::zero();
+            rs[ell - 2] = Fr::one();
 
-        let point = vec![Fr::from(0), Fr::from(2)];
-        let eval = Fr::from(3);
-        assert!(test_inner(point, eval).is_ok());
+            // Fold one level at a time
+            let mut expected: Vec<Vec<Fr>> = vec![];
+            for r in rs.iter() {
+                let previous = expected.last().map_or(&evals, |level| level);
+                expected.push(fold(previous, *r));
+            }
 
-        let point = vec![Fr::from(2), Fr::from(2)];
-        let eval = Fr::from(9);
-        assert!(test_inner(point, eval).is_ok());
+            assert_eq!(fold_levels(&evals, &rs), expected);
+            assert_eq!(fold_levels_inner(&evals, &rs, true), expected);
+        }
+    }
 
-        // Try a couple incorrect evaluations and expect failure
-        let point = vec![Fr::from(2), Fr::from(2)];
-        let eval = Fr::from(50);
-        assert!(test_inner(point, eval).is_err());
+    #[test]
+    fn test_hyperkzg_malformed_proof_shapes() {
+        // Proofs of the wrong shape are rejected with an error rather than a panic
+        let (pk, vk, poly, point, eval) = testing::fixture::<Bn254>(6, 282);
+        let poly = MultilinearPolynomial::LargeScalars(poly);
+        let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
+        let mut tr = KeccakTranscript::new(b"TestMalformed");
+        let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
 
-        let point = vec![Fr::from(0), Fr::from(2)];
-        let eval = Fr::from(4);
-        assert!(test_inner(point, eval).is_err());
+        let mut short_w = proof.clone();
+        short_w.w.truncate(2);
+        let mut long_w = proof.clone();
+        long_w.w.push(long_w.w[0]);
+        let mut short_v = proof.clone();
+        short_v.v.truncate(2);
+        let mut short_row = proof.clone();
+        short_row.v[1].pop();
+        let mut short_com = proof.clone();
+        short_com.com.pop();
+
+        for bad_proof in [&short_w, &long_w, &short_v, &short_row, &short_com] {
+            let mut tr = KeccakTranscript::new(b"TestMalformed");
+            assert!(matches!(
+                HyperKZG::verify(&vk, &C, &point, &eval, bad_proof, &mut tr),
+                Err(ProofVerifyError::InternalError)
+            ));
+            let mut tr = KeccakTranscript::new(b"TestMalformed");
+            assert!(HyperKZG::batch_verify(
+                &vk,
+                &[C.clone(), C.clone()],
+                &[point.clone(), point.clone()],
+                &[eval, eval],
+                &[proof.clone(), bad_proof.clone()],
+                &mut tr
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn test_hyperkzg_serialized_size() {
+        for ell in [1, 2, 5, 8] {
+            let (pk, _, poly, point, eval) = testing::fixture::<Bn254>(ell, 284 + ell as u64);
+            let poly = MultilinearPolynomial::LargeScalars(poly);
+            let mut tr = KeccakTranscript::new(b"TestSize");
+            let proof = HyperKZG::open(&pk, &poly, &point, &eval, &mut tr).unwrap();
+            let mut bytes = vec![];
+            proof.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(proof.serialized_size(), bytes.len(), "ell = {ell}");
+            assert_eq!(proof.compressed_size(), bytes.len());
+
+            let mut tr = KeccakTranscript::new(b"TestSize");
+            let proof =
+                HyperKZG::open_with_batch_commitment(&pk, &poly, &point, &eval, &mut tr).unwrap();
+            let mut bytes = vec![];
+            proof.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(proof.serialized_size(), bytes.len(), "ell = {ell}");
+            assert_eq!(proof.compressed_size(), bytes.len());
+        }
     }
 
     #[test]
@@ -594,6 +4325,7 @@ mod tests {
         let mut proof_bytes = Vec::new();
         proof.serialize_compressed(&mut proof_bytes).unwrap();
         assert_eq!(proof_bytes.len(), 368);
+        assert_eq!(proof.serialized_size(), proof_bytes.len());
 
         // Change the proof and expect verification to fail
         let mut bad_proof = proof.clone();
@@ -611,33 +4343,22 @@ mod tests {
         .is_err());
     }
 
-    #[test]
-    fn test_hyperkzg_large() {
+    fn hyperkzg_large_test<P: Pairing>()
+    where
+        P::ScalarField: JoltField,
+        P::G1: Icicle,
+    {
         // test the hyperkzg prover and verifier with random instances (derived from a seed)
         for ell in [4, 5, 6] {
-            let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(ell as u64);
-
-            let n = 1 << ell; // n = 2^ell
-
-            let poly = MultilinearPolynomial::from(
-                (0..n)
-                    .map(|_| <Bn254 as Pairing>::ScalarField::rand(&mut rng))
-                    .collect::<Vec<_>>(),
-            );
-            let point = (0..ell)
-                .map(|_| <Bn254 as Pairing>::ScalarField::rand(&mut rng))
-                .collect::<Vec<_>>();
-            let eval = poly.evaluate(&point);
-
-            let srs = HyperKZGSRS::setup(&mut rng, n);
-            let (pk, vk): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(n);
+            let (pk, vk, poly, point, eval) = testing::fixture::<P>(ell, ell as u64);
+            let poly = MultilinearPolynomial::LargeScalars(poly);
 
             // make a commitment
             let C = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap();
 
             // prove an evaluation
             let mut prover_transcript = KeccakTranscript::new(b"TestEval");
-            let proof: HyperKZGProof<Bn254> =
+            let proof: HyperKZGProof<P> =
                 HyperKZG::open(&pk, &poly, &point, &eval, &mut prover_transcript).unwrap();
 
             // verify the evaluation
@@ -654,4 +4375,15 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_hyperkzg_large() {
+        hyperkzg_large_test::<Bn254>();
+    }
+
+    #[cfg(not(feature = "icicle"))]
+    #[test]
+    fn test_hyperkzg_large_bls12_381() {
+        hyperkzg_large_test::<ark_bls12_381::Bls12_381>();
+    }
 }
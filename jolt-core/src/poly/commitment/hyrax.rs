@@ -14,10 +14,17 @@ use crate::poly::multilinear_polynomial::MultilinearPolynomial;
 use crate::utils::errors::ProofVerifyError;
 use crate::utils::math::Math;
 use crate::utils::transcript::{AppendToTranscript, Transcript};
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
 use crate::utils::{compute_dotproduct, mul_0_1_optimized};
+use crate::{
+    maybe_into_par_iter, maybe_par_chunks, maybe_par_iter, utils::thread::current_num_threads,
+    utils::MaybeParReduce,
+};
 use ark_ec::CurveGroup;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use num_integer::Roots;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use tracing::trace_span;
 
@@ -71,9 +78,7 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
         assert_eq!(L_size * R_size, n);
 
         let gens = CurveGroup::normalize_batch(&generators.generators[..R_size]);
-        let row_commitments = poly
-            .Z
-            .par_chunks(R_size)
+        let row_commitments = maybe_par_chunks!(poly.evals_ref(), R_size)
             .map(|row| PedersenCommitment::commit_vector(row, &gens))
             .collect();
         Self { row_commitments }
@@ -95,13 +100,12 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
 
         let gens = CurveGroup::normalize_batch(&generators.generators[..R_size]);
 
-        let rows = batch.par_iter().flat_map(|poly| poly.par_chunks(R_size));
+        let rows = maybe_par_iter!(batch).flat_map(|poly| maybe_par_chunks!(poly, R_size));
         let row_commitments: Vec<G> = rows
             .map(|row| PedersenCommitment::commit_vector(row, &gens))
             .collect();
 
-        row_commitments
-            .par_chunks(L_size)
+        maybe_par_chunks!(row_commitments, L_size)
             .map(|chunk| Self {
                 row_commitments: chunk.to_vec(),
             })
@@ -195,15 +199,14 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
     ) -> Vec<G::ScalarField> {
         let (_, R_size) = matrix_dimensions(poly.get_num_vars(), ratio);
 
-        poly.evals_ref()
-            .par_chunks(R_size)
+        maybe_par_chunks!(poly.evals_ref(), R_size)
             .enumerate()
             .map(|(i, row)| {
                 row.iter()
                     .map(|x| mul_0_1_optimized(&L[i], x))
                     .collect::<Vec<G::ScalarField>>()
             })
-            .reduce(
+            .reduce_with_identity(
                 || vec![G::ScalarField::zero(); R_size],
                 |mut acc: Vec<_>, row| {
                     acc.iter_mut().zip(row).for_each(|(x, y)| *x += y);
@@ -240,12 +243,11 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
 
         let poly_len = polynomials[0].len();
 
-        let num_chunks = rayon::current_num_threads().next_power_of_two();
+        let num_chunks = current_num_threads().next_power_of_two();
         let chunk_size = poly_len / num_chunks;
 
         let rlc_poly = if chunk_size > 0 {
-            (0..num_chunks)
-                .into_par_iter()
+            maybe_into_par_iter!(0..num_chunks)
                 .flat_map_iter(|chunk_index| {
                     let mut chunk = vec![G::ScalarField::zero(); chunk_size];
                     for (coeff, poly) in rlc_coefficients.iter().zip(polynomials.iter()) {
@@ -260,11 +262,10 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
                 })
                 .collect::<Vec<_>>()
         } else {
-            rlc_coefficients
-                .par_iter()
-                .zip(polynomials.par_iter())
+            maybe_par_iter!(rlc_coefficients)
+                .zip(maybe_par_iter!(polynomials))
                 .map(|(coeff, poly)| poly.evals_ref().iter().map(|eval| *coeff * *eval).collect())
-                .reduce(
+                .reduce_with_identity(
                     || vec![G::ScalarField::zero(); poly_len],
                     |running, new| {
                         debug_assert_eq!(running.len(), new.len());
@@ -314,9 +315,8 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
 
         let rlc_eval = compute_dotproduct(&rlc_coefficients, openings);
 
-        let rlc_commitment = rlc_coefficients
-            .par_iter()
-            .zip(commitments.par_iter())
+        let rlc_commitment = maybe_par_iter!(rlc_coefficients)
+            .zip(maybe_par_iter!(commitments))
             .map(|(coeff, commitment)| {
                 commitment
                     .row_commitments
@@ -324,7 +324,7 @@ impl<const RATIO: usize, F: JoltField, G: CurveGroup<ScalarField = F> + Icicle>
                     .map(|row_commitment| *row_commitment * coeff)
                     .collect()
             })
-            .reduce(
+            .reduce_with_identity(
                 || vec![G::zero(); L_size],
                 |running, new| {
                     debug_assert_eq!(running.len(), new.len());
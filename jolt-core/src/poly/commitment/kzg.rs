@@ -1,15 +1,19 @@
 use crate::field::JoltField;
-use crate::msm::{use_icicle, GpuBaseType, Icicle, VariableBaseMSM};
+use crate::msm::{use_icicle, GpuBaseType, Icicle, MsmBackend, VariableBaseMSM};
 use crate::poly::multilinear_polynomial::MultilinearPolynomial;
-use crate::poly::unipoly::UniPoly;
+use crate::poly::unipoly::{synthetic_division, UniPoly};
 use crate::utils::errors::ProofVerifyError;
+use crate::{maybe_into_par_iter, maybe_join, maybe_par_iter};
 use ark_ec::scalar_mul::fixed_base::FixedBase;
 use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
 use ark_ff::PrimeField;
-use ark_std::{One, UniformRand, Zero};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{UniformRand, Zero};
 use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::borrow::Borrow;
+use std::io::Write;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -48,7 +52,7 @@ where
         let g1_table = FixedBase::get_window_table(scalar_bits, g1_window_size, g1);
         let g2_table = FixedBase::get_window_table(scalar_bits, g2_window_size, g2);
 
-        let (g1_powers_projective, g2_powers_projective) = rayon::join(
+        let (g1_powers_projective, g2_powers_projective) = maybe_join!(
             || {
                 let beta_powers: Vec<P::ScalarField> = (0..=num_g1_powers)
                     .scan(beta, |acc, _| {
@@ -71,15 +75,21 @@ where
             },
         );
 
-        let (g1_powers, g2_powers) = rayon::join(
-            || P::G1::normalize_batch(&g1_powers_projective),
-            || P::G2::normalize_batch(&g2_powers_projective),
-        );
+        let (g1_powers, g2_powers) =
+            maybe_join!(|| P::G1::normalize_batch(&g1_powers_projective), || {
+                P::G2::normalize_batch(&g2_powers_projective)
+            });
+        Self::from_powers(g1_powers, g2_powers)
+    }
 
+    /// Builds an SRS from externally computed powers `g1_powers[i] = tau^i * G1` and
+    /// `g2_powers[i] = tau^i * G2`, e.g. from a trusted setup ceremony. The powers are not
+    /// checked to be consistent.
+    pub fn from_powers(g1_powers: Vec<P::G1Affine>, g2_powers: Vec<P::G2Affine>) -> Self {
         // Precompute a commitment to each power-of-two length vector of ones, which is just the sum of each power-of-two length prefix of the SRS
         let num_powers = (g1_powers.len() as f64).log2().floor() as usize + 1;
-        let all_ones_coeffs: Vec<u8> = vec![1; num_g1_powers + 1];
-        let powers_of_2 = (0..num_powers).into_par_iter().map(|i| 1usize << i);
+        let all_ones_coeffs: Vec<u8> = vec![1; g1_powers.len()];
+        let powers_of_2 = maybe_into_par_iter!(0..num_powers).map(|i| 1usize << i);
         let g_products = powers_of_2
             .map(|power| {
                 <P::G1 as VariableBaseMSM>::msm_u8(
@@ -94,8 +104,7 @@ where
 
         #[cfg(feature = "icicle")]
         let gpu_g1 = Some(
-            g1_powers
-                .par_iter()
+            maybe_par_iter!(g1_powers)
                 .map(<P::G1 as Icicle>::from_ark_affine)
                 .collect::<Vec<_>>(),
         );
@@ -110,6 +119,101 @@ where
         }
     }
 
+    /// Same as [`SRS::setup`], and identical to it for the same `rng`, but computes the G1
+    /// powers `chunk_size` at a time rather than all at once. If `sink` is given, each G1
+    /// power is written to it (uncompressed, in order) as soon as its chunk is computed
+    /// instead of being kept, so the returned SRS has empty `g1_powers` (and no `gpu_g1`)
+    /// and at most one chunk of powers is held in memory.
+    pub fn setup_chunked<R: RngCore + CryptoRng, W: Write>(
+        mut rng: &mut R,
+        num_g1_powers: usize,
+        num_g2_powers: usize,
+        chunk_size: usize,
+        mut sink: Option<&mut W>,
+    ) -> Result<Self, ProofVerifyError>
+    where
+        P::ScalarField: JoltField,
+    {
+        assert!(chunk_size > 0, "chunk_size must be positive");
+        let beta = P::ScalarField::rand(&mut rng);
+        let g1 = P::G1::rand(&mut rng);
+        let g2 = P::G2::rand(&mut rng);
+
+        let scalar_bits = P::ScalarField::MODULUS_BIT_SIZE as usize;
+
+        let g2_window_size = FixedBase::get_mul_window_size(num_g2_powers);
+        let g2_table = FixedBase::get_window_table(scalar_bits, g2_window_size, g2);
+        let beta_powers: Vec<P::ScalarField> = (0..=num_g2_powers)
+            .scan(beta, |acc, _| {
+                let val = *acc;
+                *acc *= beta;
+                Some(val)
+            })
+            .collect();
+        let g2_powers = P::G2::normalize_batch(&FixedBase::msm(
+            scalar_bits,
+            g2_window_size,
+            &g2_table,
+            &beta_powers,
+        ));
+
+        let g1_window_size = FixedBase::get_mul_window_size(num_g1_powers);
+        let g1_table = FixedBase::get_window_table(scalar_bits, g1_window_size, g1);
+        let mut g1_powers = vec![];
+        // The commitment to each power-of-two length vector of ones, as in `setup`, is
+        // accumulated as the powers are computed
+        let mut g_products = vec![];
+        let mut prefix_sum = P::G1::zero();
+        let mut beta_power = beta;
+        for start in (0..=num_g1_powers).step_by(chunk_size) {
+            let end = (start + chunk_size).min(num_g1_powers + 1);
+            let beta_powers: Vec<P::ScalarField> = (start..end)
+                .map(|_| {
+                    let val = beta_power;
+                    beta_power *= beta;
+                    val
+                })
+                .collect();
+            let chunk = P::G1::normalize_batch(&FixedBase::msm(
+                scalar_bits,
+                g1_window_size,
+                &g1_table,
+                &beta_powers,
+            ));
+
+            for (i, power) in (start..end).zip(chunk.iter()) {
+                prefix_sum += power;
+                if (i + 1).is_power_of_two() {
+                    g_products.push(prefix_sum.into_affine());
+                }
+            }
+            match sink.as_mut() {
+                Some(sink) => {
+                    for power in chunk.iter() {
+                        power.serialize_uncompressed(&mut **sink)?;
+                    }
+                }
+                None => g1_powers.extend(chunk),
+            }
+        }
+
+        #[cfg(feature = "icicle")]
+        let gpu_g1 = sink.is_none().then(|| {
+            maybe_par_iter!(g1_powers)
+                .map(<P::G1 as Icicle>::from_ark_affine)
+                .collect::<Vec<_>>()
+        });
+        #[cfg(not(feature = "icicle"))]
+        let gpu_g1 = None;
+
+        Ok(Self {
+            g1_powers,
+            g2_powers,
+            g_products,
+            gpu_g1,
+        })
+    }
+
     pub fn trim(params: Arc<Self>, max_degree: usize) -> (KZGProverKey<P>, KZGVerifierKey<P>) {
         assert!(!params.g1_powers.is_empty(), "max_degree is 0");
         assert!(
@@ -168,6 +272,40 @@ where
     }
 }
 
+/// The part of a prover key that is needed to commit: the G1 powers of the SRS
+/// (optionally mirrored in icicle's GPU types), and the MSM backend and window to commit with.
+pub trait CommitKey<P: Pairing>
+where
+    P::G1: Icicle,
+{
+    fn g1_powers(&self) -> &[P::G1Affine];
+
+    fn gpu_g1(&self) -> Option<&[GpuBaseType<P::G1>]>;
+
+    fn msm_backend(&self) -> MsmBackend {
+        MsmBackend::default()
+    }
+
+    /// Pippenger window for the CPU MSMs committing with this key, or None to size it from
+    /// the number of bases.
+    fn msm_window_bits(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<P: Pairing> CommitKey<P> for KZGProverKey<P>
+where
+    P::G1: Icicle,
+{
+    fn g1_powers(&self) -> &[P::G1Affine] {
+        KZGProverKey::g1_powers(self)
+    }
+
+    fn gpu_g1(&self) -> Option<&[GpuBaseType<P::G1>]> {
+        KZGProverKey::gpu_g1(self)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct KZGVerifierKey<P: Pairing> {
     pub g1: P::G1Affine,
@@ -197,19 +335,17 @@ where
         let gpu_g1 = pk.gpu_g1();
 
         // batch commit requires all batches to have the same length
-        assert!(polys
-            .par_iter()
-            .all(|s| s.borrow().len() == polys[0].borrow().len()));
+        assert!(maybe_par_iter!(polys).all(|s| s.borrow().len() == polys[0].borrow().len()));
         assert!(polys[0].borrow().len() <= g1_powers.len());
 
         if let Some(invalid) = polys
             .iter()
             .find(|coeffs| (*coeffs).borrow().len() > g1_powers.len())
         {
-            return Err(ProofVerifyError::KeyLengthError(
-                g1_powers.len(),
-                invalid.borrow().len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: g1_powers.len(),
+                required_powers: invalid.borrow().len(),
+            });
         }
 
         let msm_size = polys[0].borrow().len();
@@ -232,10 +368,10 @@ where
 
         // batch commit requires all batches be less than the bases in size
         if let Some(invalid) = polys.iter().find(|poly| poly.len() > g1_powers.len()) {
-            return Err(ProofVerifyError::KeyLengthError(
-                g1_powers.len(),
-                invalid.len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: g1_powers.len(),
+                required_powers: invalid.len(),
+            });
         }
 
         let commitments = <P::G1 as VariableBaseMSM>::variable_batch_msm(g1_powers, gpu_g1, polys);
@@ -255,10 +391,10 @@ where
             .iter()
             .find(|poly| poly.coeffs.len() > g1_powers.len())
         {
-            return Err(ProofVerifyError::KeyLengthError(
-                g1_powers.len(),
-                invalid.coeffs.len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: g1_powers.len(),
+                required_powers: invalid.coeffs.len(),
+            });
         }
 
         let commitments =
@@ -287,19 +423,30 @@ where
     pub fn commit_as_univariate(
         pk: &KZGProverKey<P>,
         poly: &MultilinearPolynomial<P::ScalarField>,
+    ) -> Result<P::G1Affine, ProofVerifyError> {
+        Self::commit_as_univariate_with_backend(pk, poly, MsmBackend::default(), None)
+    }
+
+    pub fn commit_as_univariate_with_backend(
+        pk: &impl CommitKey<P>,
+        poly: &MultilinearPolynomial<P::ScalarField>,
+        backend: MsmBackend,
+        window_bits: Option<usize>,
     ) -> Result<P::G1Affine, ProofVerifyError> {
         if pk.g1_powers().len() < poly.len() {
-            return Err(ProofVerifyError::KeyLengthError(
-                pk.g1_powers().len(),
-                poly.len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pk.g1_powers().len(),
+                required_powers: poly.len(),
+            });
         }
 
-        let c = <P::G1 as VariableBaseMSM>::msm(
+        let c = <P::G1 as VariableBaseMSM>::msm_with_backend(
             &pk.g1_powers()[..poly.original_len()],
             pk.gpu_g1().map(|g| &g[..poly.original_len()]),
             poly,
             None,
+            backend,
+            window_bits,
         )?;
         Ok(c.into_affine())
     }
@@ -312,10 +459,10 @@ where
         offset: usize,
     ) -> Result<P::G1Affine, ProofVerifyError> {
         if pk.g1_powers().len() < coeffs.len() {
-            return Err(ProofVerifyError::KeyLengthError(
-                pk.g1_powers().len(),
-                coeffs.len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pk.g1_powers().len(),
+                required_powers: coeffs.len(),
+            });
         }
 
         let c = <P::G1 as VariableBaseMSM>::msm_field_elements(
@@ -329,6 +476,13 @@ where
         Ok(c.into_affine())
     }
 
+    /// The witness polynomial `h(x) = (f(x) - f(u)) / (x - u)` of an opening of `f` at `u`,
+    /// with `f` and `h` given by their coefficients, lowest degree first. See
+    /// [`synthetic_division`].
+    pub fn witness_polynomial(coeffs: &[P::ScalarField], u: P::ScalarField) -> Vec<P::ScalarField> {
+        synthetic_division(coeffs, u)
+    }
+
     #[tracing::instrument(skip_all, name = "KZG::open")]
     pub fn open(
         pk: &KZGProverKey<P>,
@@ -338,12 +492,11 @@ where
     where
         <P as Pairing>::ScalarField: JoltField,
     {
-        let divisor = UniPoly::from_coeff(vec![-*point, P::ScalarField::one()]);
-        let (witness_poly, _) = poly.divide_with_remainder(&divisor).unwrap();
+        let witness_poly = Self::witness_polynomial(&poly.coeffs, *point);
         let proof = <P::G1 as VariableBaseMSM>::msm_field_elements(
-            &pk.g1_powers()[..witness_poly.coeffs.len()],
-            pk.gpu_g1().map(|g| &g[..witness_poly.coeffs.len()]),
-            witness_poly.coeffs.as_slice(),
+            &pk.g1_powers()[..witness_poly.len()],
+            pk.gpu_g1().map(|g| &g[..witness_poly.len()]),
+            witness_poly.as_slice(),
             None,
             use_icicle(),
         )?;
@@ -377,6 +530,71 @@ mod test {
     use rand_chacha::ChaCha20Rng;
     use rand_core::SeedableRng;
 
+    #[test]
+    fn witness_polynomial() {
+        let mut rng = ChaCha20Rng::seed_from_u64(252);
+        for degree in [0, 1, 2, 7, 64] {
+            let f = UniPoly::from_coeff((0..=degree).map(|_| Fr::rand(&mut rng)).collect());
+            let u = Fr::rand(&mut rng);
+            let h = UnivariateKZG::<Bn254>::witness_polynomial(&f.coeffs, u);
+            assert_eq!(h.len(), f.coeffs.len());
+
+            // f(x) = h(x) * (x - u) + f(u)
+            let mut product = vec![Fr::zero(); h.len() + 1];
+            for (i, h_i) in h.iter().enumerate() {
+                product[i + 1] += h_i;
+                product[i] -= *h_i * u;
+            }
+            assert_eq!(product.pop(), Some(Fr::zero()));
+            let remainder: Vec<Fr> = f
+                .coeffs
+                .iter()
+                .zip(&product)
+                .map(|(f_i, p_i)| *f_i - p_i)
+                .collect();
+            assert_eq!(remainder[0], f.evaluate(&u));
+            assert!(remainder[1..].iter().all(|r| r.is_zero()));
+        }
+    }
+
+    #[test]
+    fn setup_chunked_matches_setup() {
+        const NUM_G1_POWERS: usize = 37;
+        let srs = SRS::<Bn254>::setup(&mut ChaCha20Rng::seed_from_u64(258), NUM_G1_POWERS, 2);
+        let mut g1_bytes = vec![];
+        for g in srs.g1_powers.iter() {
+            g.serialize_uncompressed(&mut g1_bytes).unwrap();
+        }
+
+        for chunk_size in [1, 5, 16, NUM_G1_POWERS + 1, 100] {
+            let chunked = SRS::<Bn254>::setup_chunked::<_, Vec<u8>>(
+                &mut ChaCha20Rng::seed_from_u64(258),
+                NUM_G1_POWERS,
+                2,
+                chunk_size,
+                None,
+            )
+            .unwrap();
+            assert_eq!(chunked.g1_powers, srs.g1_powers);
+            assert_eq!(chunked.g2_powers, srs.g2_powers);
+            assert_eq!(chunked.g_products, srs.g_products);
+
+            let mut sink = vec![];
+            let streamed = SRS::<Bn254>::setup_chunked(
+                &mut ChaCha20Rng::seed_from_u64(258),
+                NUM_G1_POWERS,
+                2,
+                chunk_size,
+                Some(&mut sink),
+            )
+            .unwrap();
+            assert!(streamed.g1_powers.is_empty());
+            assert_eq!(streamed.g2_powers, srs.g2_powers);
+            assert_eq!(streamed.g_products, srs.g_products);
+            assert_eq!(sink, g1_bytes);
+        }
+    }
+
     fn run_kzg_test<F>(degree_generator: F) -> Result<(), ProofVerifyError>
     where
         F: Fn(&mut ChaCha20Rng) -> usize,
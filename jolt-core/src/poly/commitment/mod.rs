@@ -1,9 +1,11 @@
 pub mod binius;
 pub mod commitment_scheme;
+pub mod dory;
 pub mod hyperkzg;
 pub mod hyrax;
 pub mod kzg;
 pub mod pedersen;
+pub mod ptau;
 pub mod zeromorph;
 
 #[cfg(test)]
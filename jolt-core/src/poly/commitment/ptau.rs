@@ -0,0 +1,372 @@
+//! Reading powers of tau from a `.ptau` file, the format used by snarkjs and the Perpetual
+//! Powers of Tau ceremony, so that the SRS of a real trusted setup can be used instead of one
+//! generated from an RNG. Only BN254 files are supported.
+//!
+//! A `.ptau` file is the magic `ptau`, a `u32` version and a `u32` number of sections, each
+//! of which is a `u32` type, a `u64` size in bytes and the section's contents. The header
+//! section holds the byte length of a base field element, the base field modulus and the
+//! power of the ceremony; the tau sections hold `tau^i * G1` and `tau^i * G2` in order. Points
+//! are uncompressed affine coordinates, each stored little-endian in Montgomery form. All
+//! integers are little-endian.
+
+use std::io::{self, Read};
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{BigInt, Fp, PrimeField};
+use ark_serialize::SerializationError;
+use ark_std::Zero;
+
+use crate::msm::VariableBaseMSM;
+use crate::utils::errors::ProofVerifyError;
+use crate::utils::transcript::{KeccakTranscript, Transcript};
+
+const MAGIC: &[u8; 4] = b"ptau";
+const HEADER_SECTION: u32 = 1;
+const TAU_G1_SECTION: u32 = 2;
+const TAU_G2_SECTION: u32 = 3;
+/// Byte length of a BN254 base field element
+const N8: usize = 32;
+const G1_SIZE: usize = 2 * N8;
+const G2_SIZE: usize = 4 * N8;
+
+fn invalid_data() -> ProofVerifyError {
+    SerializationError::InvalidData.into()
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, ProofVerifyError> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64, ProofVerifyError> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_limbs(bytes: &[u8]) -> BigInt<4> {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    BigInt::new(limbs)
+}
+
+fn read_fq(bytes: &[u8]) -> Result<Fq, ProofVerifyError> {
+    let repr = read_limbs(bytes);
+    if repr >= Fq::MODULUS {
+        return Err(invalid_data());
+    }
+    // The Montgomery form is also arkworks' internal representation
+    Ok(Fp::new_unchecked(repr))
+}
+
+fn read_g1(bytes: &[u8]) -> Result<G1Affine, ProofVerifyError> {
+    let point = G1Affine::new_unchecked(read_fq(&bytes[..N8])?, read_fq(&bytes[N8..])?);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(invalid_data());
+    }
+    Ok(point)
+}
+
+fn read_g2(bytes: &[u8]) -> Result<G2Affine, ProofVerifyError> {
+    let x = Fq2::new(read_fq(&bytes[..N8])?, read_fq(&bytes[N8..2 * N8])?);
+    let y = Fq2::new(read_fq(&bytes[2 * N8..3 * N8])?, read_fq(&bytes[3 * N8..])?);
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(invalid_data());
+    }
+    Ok(point)
+}
+
+/// Reads the first `num_points` points of a section of `size` bytes holding points of
+/// `point_size` bytes each, and skips the rest of the section.
+fn read_points<R: Read, T>(
+    reader: &mut R,
+    size: u64,
+    point_size: usize,
+    num_points: usize,
+    read_point: impl Fn(&[u8]) -> Result<T, ProofVerifyError>,
+) -> Result<Vec<T>, ProofVerifyError> {
+    if size % point_size as u64 != 0 {
+        return Err(invalid_data());
+    }
+    let available_points = size / point_size as u64;
+    if available_points < num_points as u64 {
+        return Err(ProofVerifyError::KeyLengthError {
+            available_powers: available_points as usize,
+            required_powers: num_points,
+        });
+    }
+
+    let mut bytes = vec![0u8; point_size];
+    let points = (0..num_points)
+        .map(|_| {
+            reader.read_exact(&mut bytes)?;
+            read_point(&bytes)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    skip(reader, size - (num_points * point_size) as u64)?;
+    Ok(points)
+}
+
+fn skip<R: Read>(reader: &mut R, len: u64) -> Result<(), ProofVerifyError> {
+    let skipped = io::copy(&mut reader.take(len), &mut io::sink())?;
+    if skipped != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    Ok(())
+}
+
+/// Reads `tau^i * G1` for `i < num_g1_powers` and `tau^i * G2` for `i < num_g2_powers` from a
+/// `.ptau` file. Reading stops as soon as both are read, so only a prefix of a large
+/// ceremony file is consumed.
+///
+/// Besides checking that every point is on the curve and in the prime-order subgroup, this
+/// checks that the powers are powers of a single tau: with `rho` drawn from a transcript of
+/// the powers, `e(sum_i rho^i * g1[i + 1], g2[0]) == e(sum_i rho^i * g1[i], g2[1])`, which
+/// only holds for inconsistent G1 powers with probability `num_g1_powers / |F|`, and
+/// `e(g1[1], g2[i]) == e(g1[0], g2[i + 1])` for the G2 powers.
+pub fn read_powers_of_tau<R: Read>(
+    mut reader: R,
+    num_g1_powers: usize,
+    num_g2_powers: usize,
+) -> Result<(Vec<G1Affine>, Vec<G2Affine>), ProofVerifyError> {
+    // The consistency checks below need at least tau^0 and tau^1 in each group
+    for num_powers in [num_g1_powers, num_g2_powers] {
+        if num_powers < 2 {
+            return Err(ProofVerifyError::InvalidInputLength(2, num_powers));
+        }
+    }
+
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(invalid_data());
+    }
+    let _version = read_u32(&mut reader)?;
+    let num_sections = read_u32(&mut reader)?;
+
+    let mut header_read = false;
+    let mut g1_powers = None;
+    let mut g2_powers = None;
+    for _ in 0..num_sections {
+        if g1_powers.is_some() && g2_powers.is_some() {
+            break;
+        }
+        let section = read_u32(&mut reader)?;
+        let size = read_u64(&mut reader)?;
+        match section {
+            HEADER_SECTION => {
+                if size < 4 + N8 as u64 {
+                    return Err(invalid_data());
+                }
+                let n8 = read_u32(&mut reader)?;
+                let mut modulus = [0u8; N8];
+                reader.read_exact(&mut modulus)?;
+                if n8 as usize != N8 || read_limbs(&modulus) != Fq::MODULUS {
+                    return Err(invalid_data());
+                }
+                skip(&mut reader, size - 4 - N8 as u64)?;
+                header_read = true;
+            }
+            TAU_G1_SECTION if header_read => {
+                g1_powers = Some(read_points(
+                    &mut reader,
+                    size,
+                    G1_SIZE,
+                    num_g1_powers,
+                    read_g1,
+                )?);
+            }
+            TAU_G2_SECTION if header_read => {
+                g2_powers = Some(read_points(
+                    &mut reader,
+                    size,
+                    G2_SIZE,
+                    num_g2_powers,
+                    read_g2,
+                )?);
+            }
+            TAU_G1_SECTION | TAU_G2_SECTION => return Err(invalid_data()),
+            _ => skip(&mut reader, size)?,
+        }
+    }
+    let (Some(g1_powers), Some(g2_powers)) = (g1_powers, g2_powers) else {
+        return Err(invalid_data());
+    };
+
+    let mut transcript = KeccakTranscript::new(b"ptau");
+    transcript.append_points(
+        &g1_powers
+            .iter()
+            .map(|g| g.into_group())
+            .collect::<Vec<G1Projective>>(),
+    );
+    let rho_powers: Vec<Fr> = transcript.challenge_scalar_powers(num_g1_powers - 1);
+    let lower = G1Projective::msm_field_elements(
+        &g1_powers[..num_g1_powers - 1],
+        None,
+        &rho_powers,
+        None,
+        false,
+    )?;
+    let upper = G1Projective::msm_field_elements(&g1_powers[1..], None, &rho_powers, None, false)?;
+    let mut consistent =
+        Bn254::multi_pairing([upper, -lower], [g2_powers[0], g2_powers[1]]).is_zero();
+    for i in 0..num_g2_powers - 1 {
+        consistent &= Bn254::multi_pairing(
+            [g1_powers[1].into_group(), -g1_powers[0].into_group()],
+            [g2_powers[i], g2_powers[i + 1]],
+        )
+        .is_zero();
+    }
+    if !consistent || g1_powers[0].is_zero() || g2_powers[0].is_zero() {
+        return Err(ProofVerifyError::InternalError);
+    }
+
+    Ok((g1_powers, g2_powers))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use ark_ec::CurveGroup;
+    use ark_ff::Field;
+    use ark_std::UniformRand;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn write_fq(bytes: &mut Vec<u8>, x: &Fq) {
+        for limb in x.0 .0 {
+            bytes.extend(limb.to_le_bytes());
+        }
+    }
+
+    fn write_section(file: &mut Vec<u8>, section: u32, contents: &[u8]) {
+        file.extend(section.to_le_bytes());
+        file.extend((contents.len() as u64).to_le_bytes());
+        file.extend(contents);
+    }
+
+    /// A synthetic `.ptau` file for a ceremony of power `power` with secret `tau`, preceded
+    /// by an unrelated section to check that it is skipped.
+    pub(crate) fn synthetic_ptau(tau: Fr, power: u32) -> Vec<u8> {
+        let mut file = MAGIC.to_vec();
+        file.extend(1u32.to_le_bytes());
+        file.extend(5u32.to_le_bytes());
+
+        let mut header = (N8 as u32).to_le_bytes().to_vec();
+        for limb in Fq::MODULUS.0 {
+            header.extend(limb.to_le_bytes());
+        }
+        header.extend(power.to_le_bytes());
+        header.extend(power.to_le_bytes());
+        write_section(&mut file, HEADER_SECTION, &header);
+
+        write_section(&mut file, 7, &[0xab; 13]);
+
+        let mut tau_g1 = vec![];
+        for i in 0..(2u64 << power) - 1 {
+            let point = (G1Affine::generator() * tau.pow([i])).into_affine();
+            write_fq(&mut tau_g1, &point.x);
+            write_fq(&mut tau_g1, &point.y);
+        }
+        write_section(&mut file, TAU_G1_SECTION, &tau_g1);
+
+        let mut tau_g2 = vec![];
+        for i in 0..1u64 << power {
+            let point = (G2Affine::generator() * tau.pow([i])).into_affine();
+            for coordinate in [point.x.c0, point.x.c1, point.y.c0, point.y.c1] {
+                write_fq(&mut tau_g2, &coordinate);
+            }
+        }
+        write_section(&mut file, TAU_G2_SECTION, &tau_g2);
+
+        write_section(&mut file, 4, &[0; G1_SIZE]);
+        file
+    }
+
+    #[test]
+    fn read_synthetic_ptau() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(259);
+        let tau = Fr::rand(&mut rng);
+        let file = synthetic_ptau(tau, 3);
+
+        let (g1_powers, g2_powers) = read_powers_of_tau(&file[..], 10, 2).unwrap();
+        assert_eq!(g1_powers.len(), 10);
+        assert_eq!(g1_powers[0], G1Affine::generator());
+        assert_eq!(
+            g1_powers[9],
+            (G1Affine::generator() * tau.pow([9])).into_affine()
+        );
+        assert_eq!(g2_powers[1], (G2Affine::generator() * tau).into_affine());
+
+        // A ceremony of power 3 has 15 G1 powers
+        assert!(read_powers_of_tau(&file[..], 15, 8).is_ok());
+        assert!(matches!(
+            read_powers_of_tau(&file[..], 16, 2),
+            Err(ProofVerifyError::KeyLengthError {
+                available_powers: 15,
+                required_powers: 16,
+            })
+        ));
+    }
+
+    #[test]
+    fn read_malformed_ptau() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(259);
+        let file = synthetic_ptau(Fr::rand(&mut rng), 2);
+        assert!(read_powers_of_tau(&file[..], 4, 2).is_ok());
+        assert!(matches!(
+            read_powers_of_tau(&file[..], 1, 2),
+            Err(ProofVerifyError::InvalidInputLength(2, 1))
+        ));
+        assert!(matches!(
+            read_powers_of_tau(&file[..], 4, 0),
+            Err(ProofVerifyError::InvalidInputLength(2, 0))
+        ));
+
+        // Truncated inside the G1 powers
+        assert!(matches!(
+            read_powers_of_tau(&file[..200], 4, 2),
+            Err(ProofVerifyError::IoError(_))
+        ));
+
+        let mut bad_magic = file.clone();
+        bad_magic[0] = b'x';
+        assert!(read_powers_of_tau(&bad_magic[..], 4, 2).is_err());
+
+        // The header, a 13-byte section and the G1 section header precede the first point
+        let first_point = 12 + (12 + 4 + N8 + 8) + (12 + 13) + 12;
+        // A coordinate that is not a field element
+        let mut bad_coordinate = file.clone();
+        bad_coordinate[first_point..first_point + N8].fill(0xff);
+        assert!(read_powers_of_tau(&bad_coordinate[..], 4, 2).is_err());
+        // A point that is not on the curve
+        let mut off_curve = file.clone();
+        off_curve[first_point] ^= 1;
+        assert!(read_powers_of_tau(&off_curve[..], 4, 2).is_err());
+
+        // Powers of a different tau in G2 fail the pairing check
+        let other = synthetic_ptau(Fr::rand(&mut rng), 2);
+        let g2_section = file.len() - (12 + G1_SIZE) - 4 * G2_SIZE;
+        let mut mixed = file.clone();
+        mixed[g2_section..].copy_from_slice(&other[g2_section..]);
+        assert!(matches!(
+            read_powers_of_tau(&mixed[..], 4, 2),
+            Err(ProofVerifyError::InternalError)
+        ));
+
+        // As does a single G1 power that is swapped out
+        let mut swapped = file.clone();
+        let third_point = first_point + 2 * G1_SIZE;
+        swapped.copy_within(first_point..first_point + G1_SIZE, third_point);
+        assert!(matches!(
+            read_powers_of_tau(&swapped[..], 4, 2),
+            Err(ProofVerifyError::InternalError)
+        ));
+    }
+}
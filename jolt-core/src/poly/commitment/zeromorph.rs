@@ -24,6 +24,8 @@ use super::{
     kzg::{KZGProverKey, KZGVerifierKey, UnivariateKZG, SRS},
 };
 use crate::field::JoltField;
+use crate::{maybe_par_iter, maybe_par_iter_mut};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 pub struct ZeromorphSRS<P: Pairing>(Arc<SRS<P>>)
@@ -104,7 +106,7 @@ where
     let num_var = poly.get_num_vars();
     assert_eq!(num_var, point.len());
 
-    let mut remainder = poly.Z.to_vec();
+    let mut remainder = poly.evals();
     let mut quotients: Vec<_> = point
         .iter()
         .enumerate()
@@ -112,16 +114,14 @@ where
             let (remainder_lo, remainder_hi) = remainder.split_at_mut(1 << (num_var - 1 - i));
             let mut quotient = vec![P::ScalarField::zero(); remainder_lo.len()];
 
-            quotient
-                .par_iter_mut()
+            maybe_par_iter_mut!(quotient)
                 .zip(&*remainder_lo)
                 .zip(&*remainder_hi)
                 .for_each(|((q, r_lo), r_hi)| {
                     *q = *r_hi - *r_lo;
                 });
 
-            remainder_lo
-                .par_iter_mut()
+            maybe_par_iter_mut!(remainder_lo)
                 .zip(remainder_hi)
                 .for_each(|(r_lo, r_hi)| {
                     *r_lo += (*r_hi - *r_lo) * *x_i;
@@ -154,7 +154,7 @@ where
     let q_hat = quotients.iter().enumerate().fold(
         vec![P::ScalarField::zero(); 1 << num_vars],
         |mut q_hat, (idx, q)| {
-            let q_hat_iter = q_hat[(1 << num_vars) - (1 << idx)..].par_iter_mut();
+            let q_hat_iter = maybe_par_iter_mut!(q_hat[(1 << num_vars) - (1 << idx)..]);
             q_hat_iter.zip(&q.coeffs).for_each(|(q_hat, q)| {
                 *q_hat += scalar * *q;
             });
@@ -251,10 +251,10 @@ where
         poly: &MultilinearPolynomial<P::ScalarField>,
     ) -> Result<ZeromorphCommitment<P>, ProofVerifyError> {
         if pp.commit_pp.g1_powers().len() < poly.len() {
-            return Err(ProofVerifyError::KeyLengthError(
-                pp.commit_pp.g1_powers().len(),
-                poly.len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pp.commit_pp.g1_powers().len(),
+                required_powers: poly.len(),
+            });
         }
         Ok(ZeromorphCommitment(
             UnivariateKZG::commit_as_univariate(&pp.commit_pp, poly).unwrap(),
@@ -276,10 +276,10 @@ where
         let poly: &DensePolynomial<P::ScalarField> = poly.try_into().unwrap();
 
         if pp.commit_pp.g1_powers().len() < poly.len() {
-            return Err(ProofVerifyError::KeyLengthError(
-                pp.commit_pp.g1_powers().len(),
-                poly.len(),
-            ));
+            return Err(ProofVerifyError::KeyLengthError {
+                available_powers: pp.commit_pp.g1_powers().len(),
+                required_powers: poly.len(),
+            });
         }
 
         assert_eq!(poly.evaluate(point), *eval);
@@ -290,7 +290,7 @@ where
         assert_eq!(remainder, *eval);
 
         let q_k_com = UnivariateKZG::commit_variable_batch_univariate(&pp.commit_pp, &quotients)?;
-        let q_comms: Vec<P::G1> = q_k_com.par_iter().map(|c| c.into_group()).collect();
+        let q_comms: Vec<P::G1> = maybe_par_iter!(q_k_com).map(|c| c.into_group()).collect();
         // Compute the multilinear quotients q_k = q_k(X_0, ..., X_{k-1})
         // let quotient_slices: Vec<&[P::ScalarField]> =
         //     quotients.iter().map(|q| q.coeffs.as_slice()).collect();
@@ -321,7 +321,7 @@ where
             (Vec<P::ScalarField>, Vec<P::ScalarField>),
         ) = eval_and_quotient_scalars::<P>(y_challenge, x_challenge, z_challenge, point);
         // f = z * poly.Z + q_hat + (-z * Φ_n(x) * e) + ∑_k (q_scalars_k * q_k)
-        let mut f = UniPoly::from_coeff(poly.Z.clone());
+        let mut f = UniPoly::from_coeff(poly.evals());
         f *= &z_challenge;
         f += &q_hat;
         f[0] += eval_scalar * *eval;
@@ -4,10 +4,12 @@ use super::multilinear_polynomial::{BindingOrder, PolynomialBinding};
 use crate::utils::math::Math;
 use crate::utils::thread::unsafe_allocate_zero_vec;
 use crate::{field::JoltField, utils};
+use crate::{maybe_into_par_iter, maybe_par_iter, maybe_par_iter_mut};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
 };
 use num_integer::Integer;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 pub trait SmallScalar: Copy + Integer + Sync {
@@ -146,7 +148,7 @@ impl<T: SmallScalar, F: JoltField> CompactPolynomial<T, F> {
     }
 
     pub fn coeffs_as_field_elements(&self) -> Vec<F> {
-        self.coeffs.par_iter().map(|x| x.to_field()).collect()
+        maybe_par_iter!(self.coeffs).map(|x| x.to_field()).collect()
     }
 }
 
@@ -236,8 +238,8 @@ impl<T: SmallScalar, F: JoltField> PolynomialBinding<F> for CompactPolynomial<T,
                 }
                 BindingOrder::HighToLow => {
                     let (left, right) = self.bound_coeffs.split_at_mut(n);
-                    left.par_iter_mut()
-                        .zip(right.par_iter())
+                    maybe_par_iter_mut!(left)
+                        .zip(maybe_par_iter!(right))
                         .filter(|(a, b)| a != b)
                         .for_each(|(a, b)| {
                             *a += r * (*b - *a);
@@ -249,8 +251,7 @@ impl<T: SmallScalar, F: JoltField> PolynomialBinding<F> for CompactPolynomial<T,
             let one_minus_r_r2 = (F::one() - r) * F::montgomery_r2().unwrap_or(F::one());
             match order {
                 BindingOrder::LowToHigh => {
-                    self.bound_coeffs = (0..n)
-                        .into_par_iter()
+                    self.bound_coeffs = maybe_into_par_iter!(0..n)
                         .map(|i| {
                             if self.coeffs[2 * i] == self.coeffs[2 * i + 1] {
                                 self.coeffs[2 * i].to_field()
@@ -263,9 +264,8 @@ impl<T: SmallScalar, F: JoltField> PolynomialBinding<F> for CompactPolynomial<T,
                 }
                 BindingOrder::HighToLow => {
                     let (left, right) = self.coeffs.split_at(n);
-                    self.bound_coeffs = left
-                        .par_iter()
-                        .zip(right.par_iter())
+                    self.bound_coeffs = maybe_par_iter!(left)
+                        .zip(maybe_par_iter!(right))
                         .map(|(&a, &b)| {
                             if a == b {
                                 a.to_field()
@@ -6,7 +6,11 @@ use crate::{
     },
     utils::{thread::unsafe_allocate_zero_vec, transcript::Transcript},
 };
+use crate::{maybe_par_chunks, maybe_par_chunks_mut, maybe_par_iter, utils::MaybeParReduce};
+#[cfg(feature = "parallel")]
 use rayon::{prelude::*, slice::Chunks};
+#[cfg(not(feature = "parallel"))]
+use std::slice::Chunks;
 
 #[cfg(test)]
 use super::dense_mlpoly::DensePolynomial;
@@ -66,7 +70,7 @@ impl<F: JoltField> DenseInterleavedPolynomial<F> {
     }
 
     pub fn par_chunks(&self, chunk_size: usize) -> Chunks<'_, F> {
-        self.coeffs[..self.len].par_chunks(chunk_size)
+        maybe_par_chunks!(self.coeffs[..self.len], chunk_size)
     }
 
     #[cfg(test)]
@@ -95,8 +99,7 @@ impl<F: JoltField> DenseInterleavedPolynomial<F> {
     }
 
     pub fn layer_output(&self) -> Self {
-        let output = self
-            .par_chunks(2)
+        let output = self.par_chunks(2)
             .map(|chunk| chunk[0] * chunk[1])
             .collect();
         Self::new(output)
@@ -122,9 +125,8 @@ impl<F: JoltField> Bindable<F> for DenseInterleavedPolynomial<F> {
         // In order to parallelize binding while obeying Rust ownership rules, we
         // must write to a different vector than we are reading from. `binding_scratch_space`
         // serves this purpose.
-        self.binding_scratch_space
-            .par_chunks_mut(2)
-            .zip(self.coeffs[..self.len].par_chunks(4))
+        maybe_par_chunks_mut!(self.binding_scratch_space, 2)
+            .zip(maybe_par_chunks!(self.coeffs[..self.len], 4))
             .for_each(|(bound_chunk, unbound_chunk)| {
                 let unbound_chunk = [
                     *unbound_chunk.first().unwrap_or(&F::zero()),
@@ -170,8 +172,8 @@ pub fn bind_left_and_right<F: JoltField>(left: &mut Vec<F>, right: &mut Vec<F>,
     left_poly.bound_poly_var_bot(&r);
     right_poly.bound_poly_var_bot(&r);
 
-    *left = left_poly.Z[..left.len() / 2].to_vec();
-    *right = right_poly.Z[..right.len() / 2].to_vec();
+    *left = left_poly.evals_ref()[..left.len() / 2].to_vec();
+    *right = right_poly.evals_ref()[..right.len() / 2].to_vec();
 }
 
 impl<F: JoltField, ProofTranscript: Transcript> BatchedGrandProductLayer<F, ProofTranscript>
@@ -217,7 +219,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
             // would without the Dao-Thaler optimization, using the standard linear-time
             // sumcheck algorithm.
             self.par_chunks(4)
-                .zip(eq_poly.E2.par_chunks(2))
+                .zip(maybe_par_chunks!(eq_poly.E2, 2))
                 .map(|(layer_chunk, eq_chunk)| {
                     let eq_evals = {
                         let eval_point_0 = eq_chunk[0];
@@ -250,7 +252,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                         eq_evals.2 * left_eval_3 * right_eval_3,
                     )
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 )
@@ -271,8 +273,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
 
             // We start by computing the E1 evals:
             // (1 - j) * E1[0, x1] + j * E1[1, x1]
-            let E1_evals: Vec<_> = eq_poly.E1[..eq_poly.E1_len]
-                .par_chunks(2)
+            let E1_evals: Vec<_> = maybe_par_chunks!(eq_poly.E1[..eq_poly.E1_len], 2)
                 .map(|E1_chunk| {
                     let eval_point_0 = E1_chunk[0];
                     let m_eq = E1_chunk[1] - E1_chunk[0];
@@ -283,8 +284,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                 .collect();
 
             let chunk_size = (self.len.next_power_of_two() / eq_poly.E2_len).max(1);
-            eq_poly.E2[..eq_poly.E2_len]
-                .par_iter()
+            maybe_par_iter!(eq_poly.E2[..eq_poly.E2_len])
                 .zip(self.par_chunks(chunk_size))
                 .map(|(E2_eval, P_x2)| {
                     // The for-loop below corresponds to the inner sum:
@@ -320,7 +320,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                         *E2_eval * inner_sum.2,
                     )
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 )
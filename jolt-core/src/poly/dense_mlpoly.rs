@@ -4,17 +4,27 @@ use crate::utils::thread::{drop_in_background_thread, unsafe_allocate_zero_vec};
 use crate::utils::{self, compute_dotproduct, compute_dotproduct_low_optimized};
 
 use crate::field::JoltField;
+use crate::utils::errors::ProofVerifyError;
 use crate::utils::math::Math;
+use crate::{maybe_into_par_iter, maybe_par_iter, maybe_par_iter_mut};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use core::ops::Index;
 use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// Below this many output evaluations, a fold is computed sequentially, as rayon's
+/// overhead outweighs the work.
+const PAR_FOLD_THRESHOLD: usize = 1 << 10;
+
 #[derive(Default, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct DensePolynomial<F: JoltField> {
     num_vars: usize, // the number of variables in the multilinear polynomial
     len: usize,
     pub Z: Vec<F>, // evaluations of the polynomial in all the 2^num_vars Boolean inputs
+    // the number of evaluations before padding to `len`; the rest of them are zero. Last, so
+    // that the serialized polynomial starts with the fields it had before.
+    logical_len: usize,
 }
 
 impl<F: JoltField> DensePolynomial<F> {
@@ -28,11 +38,15 @@ impl<F: JoltField> DensePolynomial<F> {
         DensePolynomial {
             num_vars: Z.len().log_2(),
             len: Z.len(),
+            logical_len: Z.len(),
             Z,
         }
     }
 
+    /// Pads `evals` with zeros up to the next power of two. The number of evaluations
+    /// given is kept as [`Self::logical_len`], while [`Self::len`] is the padded length.
     pub fn new_padded(evals: Vec<F>) -> Self {
+        let logical_len = evals.len();
         // Pad non-power-2 evaluations to fill out the dense multilinear polynomial
         let mut poly_evals = evals;
         while !(utils::is_power_of_two(poly_evals.len())) {
@@ -42,10 +56,27 @@ impl<F: JoltField> DensePolynomial<F> {
         DensePolynomial {
             num_vars: poly_evals.len().log_2(),
             len: poly_evals.len(),
+            logical_len,
             Z: poly_evals,
         }
     }
 
+    /// Checks the invariant assumed when committing to the polynomial: it has a nonzero
+    /// power-of-two number of evaluations.
+    pub fn validate(&self) -> Result<(), ProofVerifyError> {
+        if !utils::is_power_of_two(self.len) {
+            return Err(ProofVerifyError::InvalidInputLength(
+                self.len.next_power_of_two(),
+                self.len,
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_valid_for_commitment(&self) -> bool {
+        self.validate().is_ok()
+    }
+
     pub fn get_num_vars(&self) -> usize {
         self.num_vars
     }
@@ -58,6 +89,13 @@ impl<F: JoltField> DensePolynomial<F> {
         self.len == 0
     }
 
+    /// Number of leading evaluations that may be nonzero: those given to
+    /// [`Self::new_padded`] before padding, or [`Self::len`] for an unpadded polynomial.
+    /// Binding variables shrinks it along with `len`.
+    pub fn logical_len(&self) -> usize {
+        self.logical_len
+    }
+
     pub fn is_bound(&self) -> bool {
         self.len != self.Z.len()
     }
@@ -72,6 +110,7 @@ impl<F: JoltField> DensePolynomial<F> {
 
         self.num_vars -= 1;
         self.len = n;
+        self.logical_len = self.logical_len.min(n);
     }
 
     pub fn bound_poly_var_top_many_ones(&mut self, r: &F) {
@@ -92,6 +131,7 @@ impl<F: JoltField> DensePolynomial<F> {
 
         self.num_vars -= 1;
         self.len = n;
+        self.logical_len = self.logical_len.min(n);
     }
 
     /// Bounds the polynomial's most significant index bit to 'r' optimized for a
@@ -102,8 +142,8 @@ impl<F: JoltField> DensePolynomial<F> {
 
         let (left, right) = self.Z.split_at_mut(n);
 
-        left.par_iter_mut()
-            .zip(right.par_iter())
+        maybe_par_iter_mut!(left)
+            .zip(maybe_par_iter!(right))
             .filter(|(&mut a, &b)| a != b)
             .for_each(|(a, b)| {
                 *a += *r * (*b - *a);
@@ -111,6 +151,7 @@ impl<F: JoltField> DensePolynomial<F> {
 
         self.num_vars -= 1;
         self.len = n;
+        self.logical_len = self.logical_len.min(n);
     }
 
     #[tracing::instrument(skip_all)]
@@ -133,6 +174,7 @@ impl<F: JoltField> DensePolynomial<F> {
         Self {
             num_vars,
             len,
+            logical_len: self.logical_len.min(n),
             Z: new_evals,
         }
     }
@@ -173,6 +215,7 @@ impl<F: JoltField> DensePolynomial<F> {
         Self {
             num_vars,
             len,
+            logical_len: self.logical_len.min(n),
             Z: new_evals,
         }
     }
@@ -187,12 +230,13 @@ impl<F: JoltField> DensePolynomial<F> {
 
         self.num_vars -= 1;
         self.len = n;
+        self.logical_len = self.logical_len.div_ceil(2);
     }
 
     pub fn bound_poly_var_bot_01_optimized(&mut self, r: &F) {
         let n = self.len() / 2;
         let mut new_z = unsafe_allocate_zero_vec(n);
-        new_z.par_iter_mut().enumerate().for_each(|(i, z)| {
+        maybe_par_iter_mut!(new_z).enumerate().for_each(|(i, z)| {
             let m = self.Z[2 * i + 1] - self.Z[2 * i];
             *z = if m.is_zero() {
                 self.Z[2 * i]
@@ -208,6 +252,43 @@ impl<F: JoltField> DensePolynomial<F> {
 
         self.num_vars -= 1;
         self.len = n;
+        self.logical_len = self.logical_len.div_ceil(2);
+    }
+
+    /// Returns the polynomial with its first variable (the most significant bit of the
+    /// evaluation index) bound to `r`, i.e. `Z'[i] = Z[i] + r * (Z[i + n/2] - Z[i])`.
+    pub fn fold_top(&self, r: F) -> Self {
+        let n = self.len() / 2;
+        let (low, high) = self.Z[..self.len()].split_at(n);
+        let fold_pair = |i: usize| low[i] + r * (high[i] - low[i]);
+        let Z = if n < PAR_FOLD_THRESHOLD {
+            (0..n).map(fold_pair).collect()
+        } else {
+            maybe_into_par_iter!(0..n).map(fold_pair).collect()
+        };
+        let mut poly = Self::new(Z);
+        poly.logical_len = self.logical_len.min(n);
+        poly
+    }
+
+    /// Returns the polynomial with its last variable (the least significant bit of the
+    /// evaluation index) bound to `r`, i.e. `Z'[i] = Z[2i] + r * (Z[2i + 1] - Z[2i])`.
+    pub fn fold_bottom(&self, r: F) -> Self {
+        let mut poly = Self::new(Self::fold_bottom_evals(&self.Z[..self.len()], r));
+        poly.logical_len = self.logical_len.div_ceil(2);
+        poly
+    }
+
+    /// [`DensePolynomial::fold_bottom`] on a slice of evaluations, e.g. a tile of a larger
+    /// polynomial, as HyperKZG folds them.
+    pub fn fold_bottom_evals(evals: &[F], r: F) -> Vec<F> {
+        let n = evals.len() / 2;
+        let fold_pair = |i: usize| evals[2 * i] + r * (evals[2 * i + 1] - evals[2 * i]);
+        if n < PAR_FOLD_THRESHOLD {
+            (0..n).map(fold_pair).collect()
+        } else {
+            maybe_into_par_iter!(0..n).map(fold_pair).collect()
+        }
     }
 
     // returns Z(r) in O(n) time
@@ -219,6 +300,13 @@ impl<F: JoltField> DensePolynomial<F> {
         compute_dotproduct(&self.Z, &chis)
     }
 
+    /// Evaluates the polynomial at each of `points`, in parallel across points. Each
+    /// evaluation builds its own eq-table, so this takes O(k * n) time and O(n) memory per
+    /// point evaluated concurrently, for k points and n evaluations.
+    pub fn evaluate_batch(&self, points: &[Vec<F>]) -> Vec<F> {
+        maybe_par_iter!(points).map(|r| self.evaluate(r)).collect()
+    }
+
     pub fn evaluate_at_chi(&self, chis: &[F]) -> F {
         compute_dotproduct(&self.Z, chis)
     }
@@ -261,7 +349,9 @@ impl<F: JoltField> DensePolynomial<F> {
 
 impl<F: JoltField> Clone for DensePolynomial<F> {
     fn clone(&self) -> Self {
-        Self::new(self.Z[0..self.len].to_vec())
+        let mut poly = Self::new(self.Z[0..self.len].to_vec());
+        poly.logical_len = self.logical_len;
+        poly
     }
 }
 
@@ -339,4 +429,147 @@ mod tests {
             Fr::from(8)
         );
     }
+
+    #[test]
+    fn evaluate_batch() {
+        let mut rng = test_rng();
+        let num_vars = 6;
+        let poly = DensePolynomial::<Fr>::random(num_vars, &mut rng);
+        let points: Vec<Vec<Fr>> = (0..8)
+            .map(|_| (0..num_vars).map(|_| Fr::random(&mut rng)).collect())
+            .collect();
+
+        let evals = poly.evaluate_batch(&points);
+        assert_eq!(evals.len(), points.len());
+        for (point, eval) in points.iter().zip(evals.iter()) {
+            assert_eq!(poly.evaluate(point), *eval);
+        }
+        assert!(poly.evaluate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn fold_all_variables() {
+        let mut rng = test_rng();
+        for num_vars in [1, 4, 11] {
+            let poly = DensePolynomial::<Fr>::random(num_vars, &mut rng);
+            let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+            let eval = poly.evaluate(&r);
+
+            let top = r.iter().fold(poly.clone(), |poly, r_i| poly.fold_top(*r_i));
+            assert_eq!(top.len(), 1);
+            assert_eq!(top[0], eval);
+
+            let bottom = r
+                .iter()
+                .rev()
+                .fold(poly.clone(), |poly, r_i| poly.fold_bottom(*r_i));
+            assert_eq!(bottom.len(), 1);
+            assert_eq!(bottom[0], eval);
+
+            // Folding agrees with binding in place
+            let mut bound = poly.clone();
+            bound.bound_poly_var_top(&r[0]);
+            assert_eq!(poly.fold_top(r[0]).evals_ref(), bound.evals_ref());
+            let mut bound = poly.clone();
+            bound.bound_poly_var_bot(&r[0]);
+            assert_eq!(poly.fold_bottom(r[0]).evals_ref(), bound.evals_ref());
+        }
+    }
+
+    #[test]
+    fn validate() {
+        let poly = DensePolynomial::new(vec![Fr::from(1); 8]);
+        assert!(poly.is_valid_for_commitment());
+        assert!(poly.validate().is_ok());
+        // Binding keeps the evaluations past `len` around, which does not invalidate it
+        let mut bound = poly.clone();
+        bound.bound_poly_var_top(&Fr::from(2));
+        assert!(bound.is_bound());
+        assert!(bound.validate().is_ok());
+
+        let empty = DensePolynomial::<Fr>::default();
+        assert!(!empty.is_valid_for_commitment());
+        assert!(matches!(
+            empty.validate(),
+            Err(ProofVerifyError::InvalidInputLength(1, 0))
+        ));
+
+        // `new` rejects a length that is not a power of two, but a deserialized polynomial
+        // can still carry one
+        let mut bytes = vec![];
+        (3usize, 6usize, vec![Fr::from(1); 6], 6usize)
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        let six = DensePolynomial::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(six.len(), 6);
+        assert!(!six.is_valid_for_commitment());
+        assert!(matches!(
+            six.validate(),
+            Err(ProofVerifyError::InvalidInputLength(8, 6))
+        ));
+    }
+
+    #[test]
+    fn new_padded() {
+        let mut rng = test_rng();
+        for num_evals in [1, 5, 8, 13] {
+            let evals: Vec<Fr> = (0..num_evals).map(|_| Fr::random(&mut rng)).collect();
+            let padded = DensePolynomial::new_padded(evals.clone());
+            assert_eq!(padded.logical_len(), num_evals);
+            assert_eq!(padded.len(), num_evals.next_power_of_two());
+            assert_eq!(padded.clone().logical_len(), num_evals);
+
+            let mut zero_padded = evals.clone();
+            zero_padded.resize(padded.len(), Fr::from_u64(0));
+            let unpadded = DensePolynomial::new(zero_padded);
+            assert_eq!(unpadded.logical_len(), unpadded.len());
+            // The padding boundary is compared, and serialized after the evaluations
+            assert_eq!(padded.Z, unpadded.Z);
+            assert_eq!(padded == unpadded, num_evals == padded.len());
+            let mut bytes = vec![];
+            padded.serialize_compressed(&mut bytes).unwrap();
+            let mut expected_bytes = vec![];
+            (padded.get_num_vars(), padded.len(), &padded.Z, num_evals)
+                .serialize_compressed(&mut expected_bytes)
+                .unwrap();
+            assert_eq!(bytes, expected_bytes);
+            let deserialized = DensePolynomial::<Fr>::deserialize_compressed(&bytes[..]).unwrap();
+            assert_eq!(deserialized, padded);
+            assert_eq!(deserialized.logical_len(), num_evals);
+
+            let num_vars = padded.get_num_vars();
+            for _ in 0..4 {
+                let r: Vec<Fr> = (0..num_vars).map(|_| Fr::random(&mut rng)).collect();
+                // The multilinear extension of the given evaluations alone
+                let expected: Fr = evals
+                    .iter()
+                    .zip(EqPolynomial::evals(&r))
+                    .map(|(eval, chi)| *eval * chi)
+                    .sum();
+                assert_eq!(padded.evaluate(&r), expected);
+                assert_eq!(unpadded.evaluate(&r), expected);
+            }
+
+            if num_vars > 0 {
+                let r = Fr::random(&mut rng);
+                let mut top = padded.clone();
+                top.bound_poly_var_top(&r);
+                assert_eq!(top.logical_len(), num_evals.min(top.len()));
+                let mut bot = padded.clone();
+                bot.bound_poly_var_bot(&r);
+                assert_eq!(bot.logical_len(), num_evals.div_ceil(2));
+                let point: Vec<Fr> = (0..num_vars - 1).map(|_| Fr::random(&mut rng)).collect();
+                let padded_top = padded.new_poly_from_bound_poly_var_top(&r);
+                assert_eq!(padded_top.logical_len(), top.logical_len());
+                assert_eq!(padded.fold_top(r).logical_len(), top.logical_len());
+                assert_eq!(padded.fold_bottom(r).logical_len(), bot.logical_len());
+                assert_eq!(
+                    padded_top.evaluate(&point),
+                    unpadded
+                        .new_poly_from_bound_poly_var_top(&r)
+                        .evaluate(&point)
+                );
+            }
+        }
+    }
 }
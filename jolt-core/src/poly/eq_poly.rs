@@ -1,4 +1,6 @@
 use crate::field::JoltField;
+use crate::{maybe_into_par_iter, maybe_par_chunks_mut, maybe_par_iter_mut};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 use crate::utils::{math::Math, thread::unsafe_allocate_zero_vec};
@@ -86,9 +88,8 @@ impl<F: JoltField> EqPolynomial<F> {
             let (evals_left, evals_right) = evals.split_at_mut(size);
             let (evals_right, _) = evals_right.split_at_mut(size);
 
-            evals_left
-                .par_iter_mut()
-                .zip(evals_right.par_iter_mut())
+            maybe_par_iter_mut!(evals_left)
+                .zip(maybe_par_iter_mut!(evals_right))
                 .for_each(|(x, y)| {
                     *y = *x * *r;
                     *x -= *y;
@@ -121,8 +122,7 @@ impl<F: JoltField> EqPlusOnePolynomial<F> {
             Then, the next bit in x is 0 and the next bit in y is 1.
             The remaining higher bits are the same in x and y.
         */
-        (0..l)
-            .into_par_iter()
+        maybe_into_par_iter!(0..l)
             .map(|k| {
                 let lower_bits_product = (0..k)
                     .map(|i| x[l - 1 - i] * (F::one() - y[l - 1 - i]))
@@ -150,9 +150,9 @@ impl<F: JoltField> EqPlusOnePolynomial<F> {
             debug_assert!(i != 0);
             let step = 1 << (ell - i); // step = (full / size)/2
 
-            let mut selected: Vec<_> = eq_evals.par_iter_mut().step_by(step).collect();
+            let mut selected: Vec<_> = maybe_par_iter_mut!(eq_evals).step_by(step).collect();
 
-            selected.par_chunks_mut(2).for_each(|chunk| {
+            maybe_par_chunks_mut!(selected, 2).for_each(|chunk| {
                 *chunk[1] = *chunk[0] * r[i - 1];
                 *chunk[0] -= *chunk[1];
             });
@@ -164,8 +164,7 @@ impl<F: JoltField> EqPlusOnePolynomial<F> {
 
             let r_lower_product = (F::one() - r[i]) * r.iter().skip(i + 1).copied().product::<F>();
 
-            eq_plus_one_evals
-                .par_iter_mut()
+            maybe_par_iter_mut!(eq_plus_one_evals)
                 .enumerate()
                 .skip(half_step)
                 .step_by(step)
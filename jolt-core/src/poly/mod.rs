@@ -1,3 +1,4 @@
+pub mod boolean_poly;
 pub mod commitment;
 pub mod compact_polynomial;
 pub mod dense_interleaved_poly;
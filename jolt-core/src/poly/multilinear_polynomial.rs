@@ -1,7 +1,13 @@
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
 use crate::utils::{compute_dotproduct, math::Math};
+use crate::{maybe_into_par_iter, maybe_par_iter, utils::thread::current_num_threads};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
 };
+#[cfg(not(feature = "parallel"))]
+use itertools::Itertools;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use strum_macros::EnumIter;
 
@@ -44,7 +50,7 @@ impl<F: JoltField> MultilinearPolynomial<F> {
     /// The length of the polynomial before it was bound
     pub fn original_len(&self) -> usize {
         match self {
-            MultilinearPolynomial::LargeScalars(poly) => poly.Z.len(),
+            MultilinearPolynomial::LargeScalars(poly) => poly.evals_ref().len(),
             MultilinearPolynomial::U8Scalars(poly) => poly.coeffs.len(),
             MultilinearPolynomial::U16Scalars(poly) => poly.coeffs.len(),
             MultilinearPolynomial::U32Scalars(poly) => poly.coeffs.len(),
@@ -80,9 +86,7 @@ impl<F: JoltField> MultilinearPolynomial<F> {
     #[tracing::instrument(skip_all)]
     pub fn max_num_bits(&self) -> usize {
         match self {
-            MultilinearPolynomial::LargeScalars(poly) => poly
-                .evals_ref()
-                .par_iter()
+            MultilinearPolynomial::LargeScalars(poly) => maybe_par_iter!(poly.evals_ref())
                 .map(|s| s.num_bits())
                 .max()
                 .unwrap() as usize,
@@ -115,13 +119,10 @@ impl<F: JoltField> MultilinearPolynomial<F> {
             .map(|poly| poly.original_len())
             .max()
             .unwrap();
-        let num_chunks = rayon::current_num_threads()
-            .next_power_of_two()
-            .min(max_length);
+        let num_chunks = current_num_threads().next_power_of_two().min(max_length);
         let chunk_size = (max_length / num_chunks).max(1);
 
-        let lc_coeffs: Vec<F> = (0..num_chunks)
-            .into_par_iter()
+        let lc_coeffs: Vec<F> = maybe_into_par_iter!(0..num_chunks)
             .flat_map_iter(|chunk_index| {
                 let index = chunk_index * chunk_size;
                 let mut chunk = unsafe_allocate_zero_vec::<F>(chunk_size);
@@ -275,36 +276,26 @@ impl<F: JoltField> MultilinearPolynomial<F> {
     pub fn dot_product(&self, other: Option<&[F]>, other_r2_adjusted: Option<&[F]>) -> F {
         match self {
             MultilinearPolynomial::LargeScalars(poly) => {
-                compute_dotproduct(&poly.Z, other.unwrap())
+                compute_dotproduct(poly.evals_ref(), other.unwrap())
             }
-            MultilinearPolynomial::U8Scalars(poly) => poly
-                .coeffs
-                .par_iter()
-                .zip_eq(other_r2_adjusted.unwrap().par_iter())
+            MultilinearPolynomial::U8Scalars(poly) => maybe_par_iter!(poly.coeffs)
+                .zip_eq(maybe_par_iter!(other_r2_adjusted.unwrap()))
                 .map(|(a, b)| a.field_mul(*b))
                 .sum(),
-            MultilinearPolynomial::U16Scalars(poly) => poly
-                .coeffs
-                .par_iter()
-                .zip_eq(other_r2_adjusted.unwrap().par_iter())
+            MultilinearPolynomial::U16Scalars(poly) => maybe_par_iter!(poly.coeffs)
+                .zip_eq(maybe_par_iter!(other_r2_adjusted.unwrap()))
                 .map(|(a, b)| a.field_mul(*b))
                 .sum(),
-            MultilinearPolynomial::U32Scalars(poly) => poly
-                .coeffs
-                .par_iter()
-                .zip_eq(other_r2_adjusted.unwrap().par_iter())
+            MultilinearPolynomial::U32Scalars(poly) => maybe_par_iter!(poly.coeffs)
+                .zip_eq(maybe_par_iter!(other_r2_adjusted.unwrap()))
                 .map(|(a, b)| a.field_mul(*b))
                 .sum(),
-            MultilinearPolynomial::U64Scalars(poly) => poly
-                .coeffs
-                .par_iter()
-                .zip_eq(other_r2_adjusted.unwrap().par_iter())
+            MultilinearPolynomial::U64Scalars(poly) => maybe_par_iter!(poly.coeffs)
+                .zip_eq(maybe_par_iter!(other_r2_adjusted.unwrap()))
                 .map(|(a, b)| a.field_mul(*b))
                 .sum(),
-            MultilinearPolynomial::I64Scalars(poly) => poly
-                .coeffs
-                .par_iter()
-                .zip_eq(other_r2_adjusted.unwrap().par_iter())
+            MultilinearPolynomial::I64Scalars(poly) => maybe_par_iter!(poly.coeffs)
+                .zip_eq(maybe_par_iter!(other_r2_adjusted.unwrap()))
                 .map(|(a, b)| a.field_mul(*b))
                 .sum(),
         }
@@ -313,7 +304,7 @@ impl<F: JoltField> MultilinearPolynomial<F> {
     /// Multiplies the polynomial's coefficient at `index` by a field element.
     pub fn scale_coeff(&self, index: usize, scaling_factor: F, scaling_factor_r2_adjusted: F) -> F {
         match self {
-            MultilinearPolynomial::LargeScalars(poly) => poly.Z[index] * scaling_factor,
+            MultilinearPolynomial::LargeScalars(poly) => poly.evals_ref()[index] * scaling_factor,
             MultilinearPolynomial::U8Scalars(poly) => {
                 poly.coeffs[index].field_mul(scaling_factor_r2_adjusted)
             }
@@ -542,7 +533,7 @@ impl<F: JoltField> PolynomialBinding<F> for MultilinearPolynomial<F> {
         match self {
             MultilinearPolynomial::LargeScalars(poly) => {
                 assert_eq!(poly.len(), 1);
-                poly.Z[0]
+                poly.evals_ref()[0]
             }
             MultilinearPolynomial::U8Scalars(poly) => poly.final_sumcheck_claim(),
             MultilinearPolynomial::U16Scalars(poly) => poly.final_sumcheck_claim(),
@@ -576,8 +567,7 @@ impl<F: JoltField> PolynomialEvaluation<F> for MultilinearPolynomial<F> {
             // If any of the polynomials contain non-Montgomery form coefficients,
             // we need to compute the R^2-adjusted EQ table.
             let eq_r2 = EqPolynomial::evals_with_r2(r);
-            let evals: Vec<F> = polys
-                .into_par_iter()
+            let evals: Vec<F> = maybe_into_par_iter!(polys)
                 .map(|&poly| match poly {
                     MultilinearPolynomial::LargeScalars(poly) => {
                         poly.evaluate_at_chi_low_optimized(&eq)
@@ -587,8 +577,7 @@ impl<F: JoltField> PolynomialEvaluation<F> for MultilinearPolynomial<F> {
                 .collect();
             (evals, eq)
         } else {
-            let evals: Vec<F> = polys
-                .into_par_iter()
+            let evals: Vec<F> = maybe_into_par_iter!(polys)
                 .map(|&poly| {
                     let poly: &DensePolynomial<F> = poly.try_into().unwrap();
                     poly.evaluate_at_chi_low_optimized(&eq)
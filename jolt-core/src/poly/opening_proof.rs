@@ -4,7 +4,9 @@
 //! can use a sumcheck to reduce multiple opening proofs (multiple polynomials, not
 //! necessarily of the same size, each opened at a different point) into a single opening.
 
+use crate::{maybe_join, maybe_par_iter, maybe_par_iter_mut};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::marker::PhantomData;
 
@@ -188,7 +190,7 @@ impl<F: JoltField, ProofTranscript: Transcript> ProverOpeningAccumulator<F, Proo
 
             let expected_eq_poly = EqPolynomial::evals(&opening_point);
             assert!(
-                eq_poly.Z == expected_eq_poly,
+                eq_poly.evals_ref() == expected_eq_poly,
                 "eq_poly and opening point are inconsistent"
             );
 
@@ -304,9 +306,8 @@ impl<F: JoltField, ProofTranscript: Transcript> ProverOpeningAccumulator<F, Proo
 
         // Compute random linear combination of the claims, accounting for the fact that the
         // polynomials may be of different sizes
-        let mut e: F = coeffs
-            .par_iter()
-            .zip(self.openings.par_iter())
+        let mut e: F = maybe_par_iter!(coeffs)
+            .zip(maybe_par_iter!(self.openings))
             .map(|(coeff, opening)| {
                 let scaled_claim = if opening.polynomial.get_num_vars() != max_num_vars {
                     F::from_u64(1 << (max_num_vars - opening.polynomial.get_num_vars()))
@@ -331,9 +332,9 @@ impl<F: JoltField, ProofTranscript: Transcript> ProverOpeningAccumulator<F, Proo
             let r_j = transcript.challenge_scalar();
             r.push(r_j);
 
-            self.openings.par_iter_mut().for_each(|opening| {
+            maybe_par_iter_mut!(self.openings).for_each(|opening| {
                 if remaining_rounds <= opening.opening_point.len() {
-                    rayon::join(
+                    maybe_join!(
                         || opening.eq_poly.bind(r_j, BindingOrder::HighToLow),
                         || opening.polynomial.bind(r_j, BindingOrder::HighToLow),
                     );
@@ -362,9 +363,7 @@ impl<F: JoltField, ProofTranscript: Transcript> ProverOpeningAccumulator<F, Proo
         remaining_sumcheck_rounds: usize,
         previous_round_claim: F,
     ) -> UniPoly<F> {
-        let evals: Vec<(F, F)> = self
-            .openings
-            .par_iter()
+        let evals: Vec<(F, F)> = maybe_par_iter!(self.openings)
             .map(|opening| {
                 if remaining_sumcheck_rounds <= opening.opening_point.len() {
                     let mle_half = opening.polynomial.len() / 2;
@@ -634,9 +633,8 @@ where
         sumcheck_proof: &SumcheckInstanceProof<F, ProofTranscript>,
         transcript: &mut ProofTranscript,
     ) -> Result<(F, Vec<F>), ProofVerifyError> {
-        let combined_claim: F = coeffs
-            .par_iter()
-            .zip(self.openings.par_iter())
+        let combined_claim: F = maybe_par_iter!(coeffs)
+            .zip(maybe_par_iter!(self.openings))
             .map(|(coeff, opening)| {
                 let scaled_claim = if opening.opening_point.len() != num_sumcheck_rounds {
                     F::from_u64(1 << (num_sumcheck_rounds - opening.opening_point.len()))
@@ -10,6 +10,10 @@ use crate::{
     },
     utils::{math::Math, transcript::Transcript},
 };
+use crate::{
+    maybe_par_chunk_by, maybe_par_chunks, maybe_par_iter, maybe_par_iter_mut, utils::MaybeParReduce,
+};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
@@ -214,9 +218,7 @@ impl<F: JoltField> SparseInterleavedPolynomial<F> {
                 coalesced: Some(coalesced.layer_output()),
             }
         } else {
-            let coeffs: Vec<Vec<_>> = self
-                .coeffs
-                .par_iter()
+            let coeffs: Vec<Vec<_>> = maybe_par_iter!(self.coeffs)
                 .map(|segment| {
                     let mut output_segment: Vec<SparseCoefficient<F>> =
                         Vec::with_capacity(segment.len());
@@ -281,108 +283,106 @@ impl<F: JoltField> Bindable<F> for SparseInterleavedPolynomial<F> {
             coalesced.bind(r);
             self.dense_len = padded_len / 2;
         } else {
-            self.coeffs
-                .par_iter_mut()
-                .for_each(|segment: &mut Vec<SparseCoefficient<F>>| {
-                    let mut next_left_node_to_process = 0;
-                    let mut next_right_node_to_process = 0;
-                    let mut bound_index = 0;
-
-                    for j in 0..segment.len() {
-                        let current = segment[j];
-                        if current.index % 2 == 0 && current.index < next_left_node_to_process {
-                            // This left node was already bound with its sibling in a previous iteration
-                            continue;
-                        }
-                        if current.index % 2 == 1 && current.index < next_right_node_to_process {
-                            // This right node was already bound with its sibling in a previous iteration
-                            continue;
-                        }
+            maybe_par_iter_mut!(self.coeffs).for_each(|segment: &mut Vec<SparseCoefficient<F>>| {
+                let mut next_left_node_to_process = 0;
+                let mut next_right_node_to_process = 0;
+                let mut bound_index = 0;
+
+                for j in 0..segment.len() {
+                    let current = segment[j];
+                    if current.index % 2 == 0 && current.index < next_left_node_to_process {
+                        // This left node was already bound with its sibling in a previous iteration
+                        continue;
+                    }
+                    if current.index % 2 == 1 && current.index < next_right_node_to_process {
+                        // This right node was already bound with its sibling in a previous iteration
+                        continue;
+                    }
 
-                        let neighbors = [
-                            segment
-                                .get(j + 1)
-                                .cloned()
-                                .unwrap_or((current.index + 1, F::one()).into()),
-                            segment
-                                .get(j + 2)
-                                .cloned()
-                                .unwrap_or((current.index + 2, F::one()).into()),
-                        ];
-                        let find_neighbor = |query_index: usize| {
-                            neighbors
-                                .iter()
-                                .find_map(|neighbor| {
-                                    if neighbor.index == query_index {
-                                        Some(neighbor.value)
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .unwrap_or(F::one())
-                        };
-
-                        match current.index % 4 {
-                            0 => {
-                                // Find sibling left node
-                                let sibling_value: F = find_neighbor(current.index + 2);
-                                segment[bound_index] = (
-                                    current.index / 2,
-                                    current.value + r * (sibling_value - current.value),
-                                )
-                                    .into();
-                                next_left_node_to_process = current.index + 4;
-                            }
-                            1 => {
-                                // Edge case: If this right node's neighbor is not 1 and has _not_
-                                // been bound yet, we need to bind the neighbor first to preserve
-                                // the monotonic ordering of the bound layer.
-                                if next_left_node_to_process <= current.index + 1 {
-                                    let left_neighbor: F = find_neighbor(current.index + 1);
-                                    if !left_neighbor.is_one() {
-                                        segment[bound_index] = (
-                                            current.index / 2,
-                                            F::one() + r * (left_neighbor - F::one()),
-                                        )
-                                            .into();
-                                        bound_index += 1;
-                                    }
-                                    next_left_node_to_process = current.index + 3;
+                    let neighbors = [
+                        segment
+                            .get(j + 1)
+                            .cloned()
+                            .unwrap_or((current.index + 1, F::one()).into()),
+                        segment
+                            .get(j + 2)
+                            .cloned()
+                            .unwrap_or((current.index + 2, F::one()).into()),
+                    ];
+                    let find_neighbor = |query_index: usize| {
+                        neighbors
+                            .iter()
+                            .find_map(|neighbor| {
+                                if neighbor.index == query_index {
+                                    Some(neighbor.value)
+                                } else {
+                                    None
                                 }
-
-                                // Find sibling right node
-                                let sibling_value: F = find_neighbor(current.index + 2);
-                                segment[bound_index] = (
-                                    current.index / 2 + 1,
-                                    current.value + r * (sibling_value - current.value),
-                                )
-                                    .into();
-                                next_right_node_to_process = current.index + 4;
-                            }
-                            2 => {
-                                // Sibling left node wasn't encountered in previous iteration,
-                                // so sibling must have value 1.
-                                segment[bound_index] = (
-                                    current.index / 2 - 1,
-                                    F::one() + r * (current.value - F::one()),
-                                )
-                                    .into();
-                                next_left_node_to_process = current.index + 2;
-                            }
-                            3 => {
-                                // Sibling right node wasn't encountered in previous iteration,
-                                // so sibling must have value 1.
-                                segment[bound_index] =
-                                    (current.index / 2, F::one() + r * (current.value - F::one()))
+                            })
+                            .unwrap_or(F::one())
+                    };
+
+                    match current.index % 4 {
+                        0 => {
+                            // Find sibling left node
+                            let sibling_value: F = find_neighbor(current.index + 2);
+                            segment[bound_index] = (
+                                current.index / 2,
+                                current.value + r * (sibling_value - current.value),
+                            )
+                                .into();
+                            next_left_node_to_process = current.index + 4;
+                        }
+                        1 => {
+                            // Edge case: If this right node's neighbor is not 1 and has _not_
+                            // been bound yet, we need to bind the neighbor first to preserve
+                            // the monotonic ordering of the bound layer.
+                            if next_left_node_to_process <= current.index + 1 {
+                                let left_neighbor: F = find_neighbor(current.index + 1);
+                                if !left_neighbor.is_one() {
+                                    segment[bound_index] = (
+                                        current.index / 2,
+                                        F::one() + r * (left_neighbor - F::one()),
+                                    )
                                         .into();
-                                next_right_node_to_process = current.index + 2;
+                                    bound_index += 1;
+                                }
+                                next_left_node_to_process = current.index + 3;
                             }
-                            _ => unreachable!("?_?"),
+
+                            // Find sibling right node
+                            let sibling_value: F = find_neighbor(current.index + 2);
+                            segment[bound_index] = (
+                                current.index / 2 + 1,
+                                current.value + r * (sibling_value - current.value),
+                            )
+                                .into();
+                            next_right_node_to_process = current.index + 4;
+                        }
+                        2 => {
+                            // Sibling left node wasn't encountered in previous iteration,
+                            // so sibling must have value 1.
+                            segment[bound_index] = (
+                                current.index / 2 - 1,
+                                F::one() + r * (current.value - F::one()),
+                            )
+                                .into();
+                            next_left_node_to_process = current.index + 2;
+                        }
+                        3 => {
+                            // Sibling right node wasn't encountered in previous iteration,
+                            // so sibling must have value 1.
+                            segment[bound_index] =
+                                (current.index / 2, F::one() + r * (current.value - F::one()))
+                                    .into();
+                            next_right_node_to_process = current.index + 2;
                         }
-                        bound_index += 1;
+                        _ => unreachable!("?_?"),
                     }
-                    segment.truncate(bound_index);
-                });
+                    bound_index += 1;
+                }
+                segment.truncate(bound_index);
+            });
 
             self.dense_len /= 2;
             if (self.dense_len / self.batch_size()) == 2 {
@@ -464,9 +464,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
             // would without the Dao-Thaler optimization, using the standard linear-time
             // sumcheck algorithm with optimizations for sparsity.
 
-            let eq_evals: Vec<(F, F, F)> = eq_poly
-                .E2
-                .par_chunks(2)
+            let eq_evals: Vec<(F, F, F)> = maybe_par_chunks!(eq_poly.E2, 2)
                 .take(self.dense_len / 4)
                 .map(|eq_chunk| {
                     let eval_point_0 = eq_chunk[0];
@@ -478,25 +476,17 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                 .collect();
             // This is what Σ eq(r, x) * left(x) * right(x) would be if
             // `left` and `right` were both all ones.
-            let eq_eval_sums: (F, F, F) = eq_evals
-                .par_iter()
-                .fold(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                )
-                .reduce(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                );
+            let eq_eval_sums: (F, F, F) = maybe_par_iter!(eq_evals).fold_reduce(
+                || (F::zero(), F::zero(), F::zero()),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+            );
             // Now we compute the deltas, correcting `eq_eval_sums` for the
             // elements of `left` and `right` that aren't ones.
-            let deltas: (F, F, F) = self
-                .coeffs
-                .par_iter()
+            let deltas: (F, F, F) = maybe_par_iter!(self.coeffs)
                 .flat_map(|segment| {
-                    segment
-                        .par_chunk_by(|x, y| x.index / 4 == y.index / 4)
-                        .map(|sparse_block| {
+                    maybe_par_chunk_by!(segment, |x, y| x.index / 4 == y.index / 4).map(
+                        |sparse_block| {
                             let block_index = sparse_block[0].index / 4;
                             let mut block = [F::one(); 4];
                             for coeff in sparse_block {
@@ -523,9 +513,10 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                                 eq_evals.1 * (left_eval_2 * right_eval_2 - F::one()),
                                 eq_evals.2 * (left_eval_3 * right_eval_3 - F::one()),
                             )
-                        })
+                        },
+                    )
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -541,8 +532,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
 
             // We start by computing the E1 evals:
             // (1 - j) * E1[0, x1] + j * E1[1, x1]
-            let E1_evals: Vec<_> = eq_poly.E1[..eq_poly.E1_len]
-                .par_chunks(2)
+            let E1_evals: Vec<_> = maybe_par_chunks!(eq_poly.E1[..eq_poly.E1_len], 2)
                 .map(|E1_chunk| {
                     let eval_point_0 = E1_chunk[0];
                     let m_eq = E1_chunk[1] - E1_chunk[0];
@@ -552,76 +542,68 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                 })
                 .collect();
             // Now compute \sum_x1 ((1 - j) * E1[0, x1] + j * E1[1, x1])
-            let E1_eval_sums: (F, F, F) = E1_evals
-                .par_iter()
-                .fold(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                )
-                .reduce(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                );
+            let E1_eval_sums: (F, F, F) = maybe_par_iter!(E1_evals).fold_reduce(
+                || (F::zero(), F::zero(), F::zero()),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+            );
 
             let num_x1_bits = eq_poly.E1_len.log_2() - 1;
             let x1_bitmask = (1 << num_x1_bits) - 1;
 
             // Iterate over the non-one coefficients and compute the deltas (relative to
             // what the cubic would be if all the coefficients were ones).
-            let deltas = self
-                .coeffs
-                .par_iter()
+            let deltas = maybe_par_iter!(self.coeffs)
                 .flat_map(|segment| {
-                    segment
-                        .par_chunk_by(|a, b| {
-                            // Group by x2
-                            let a_x2 = (a.index / 4) >> num_x1_bits;
-                            let b_x2 = (b.index / 4) >> num_x1_bits;
-                            a_x2 == b_x2
-                        })
-                        .map(|chunk| {
-                            let mut inner_sum = (F::zero(), F::zero(), F::zero());
-                            for sparse_block in chunk.chunk_by(|x, y| x.index / 4 == y.index / 4) {
-                                let block_index = sparse_block[0].index / 4;
-                                let mut block = [F::one(); 4];
-                                for coeff in sparse_block {
-                                    block[coeff.index % 4] = coeff.value;
-                                }
-
-                                let left = (block[0], block[2]);
-                                let right = (block[1], block[3]);
-
-                                let m_left = left.1 - left.0;
-                                let m_right = right.1 - right.0;
-
-                                let left_eval_2 = left.1 + m_left;
-                                let left_eval_3 = left_eval_2 + m_left;
-
-                                let right_eval_2 = right.1 + m_right;
-                                let right_eval_3 = right_eval_2 + m_right;
-
-                                let x1 = block_index & x1_bitmask;
-                                let delta = (
-                                    E1_evals[x1].0.mul_0_optimized(
-                                        left.0.mul_1_optimized(right.0) - F::one(),
-                                    ),
-                                    E1_evals[x1].1 * (left_eval_2 * right_eval_2 - F::one()),
-                                    E1_evals[x1].2 * (left_eval_3 * right_eval_3 - F::one()),
-                                );
-                                inner_sum.0 += delta.0;
-                                inner_sum.1 += delta.1;
-                                inner_sum.2 += delta.2;
+                    maybe_par_chunk_by!(segment, |a, b| {
+                        // Group by x2
+                        let a_x2 = (a.index / 4) >> num_x1_bits;
+                        let b_x2 = (b.index / 4) >> num_x1_bits;
+                        a_x2 == b_x2
+                    })
+                    .map(|chunk| {
+                        let mut inner_sum = (F::zero(), F::zero(), F::zero());
+                        for sparse_block in chunk.chunk_by(|x, y| x.index / 4 == y.index / 4) {
+                            let block_index = sparse_block[0].index / 4;
+                            let mut block = [F::one(); 4];
+                            for coeff in sparse_block {
+                                block[coeff.index % 4] = coeff.value;
                             }
 
-                            let x2 = (chunk[0].index / 4) >> num_x1_bits;
-                            (
-                                eq_poly.E2[x2] * inner_sum.0,
-                                eq_poly.E2[x2] * inner_sum.1,
-                                eq_poly.E2[x2] * inner_sum.2,
-                            )
-                        })
+                            let left = (block[0], block[2]);
+                            let right = (block[1], block[3]);
+
+                            let m_left = left.1 - left.0;
+                            let m_right = right.1 - right.0;
+
+                            let left_eval_2 = left.1 + m_left;
+                            let left_eval_3 = left_eval_2 + m_left;
+
+                            let right_eval_2 = right.1 + m_right;
+                            let right_eval_3 = right_eval_2 + m_right;
+
+                            let x1 = block_index & x1_bitmask;
+                            let delta = (
+                                E1_evals[x1]
+                                    .0
+                                    .mul_0_optimized(left.0.mul_1_optimized(right.0) - F::one()),
+                                E1_evals[x1].1 * (left_eval_2 * right_eval_2 - F::one()),
+                                E1_evals[x1].2 * (left_eval_3 * right_eval_3 - F::one()),
+                            );
+                            inner_sum.0 += delta.0;
+                            inner_sum.1 += delta.1;
+                            inner_sum.2 += delta.2;
+                        }
+
+                        let x2 = (chunk[0].index / 4) >> num_x1_bits;
+                        (
+                            eq_poly.E2[x2] * inner_sum.0,
+                            eq_poly.E2[x2] * inner_sum.1,
+                            eq_poly.E2[x2] * inner_sum.2,
+                        )
+                    })
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -666,14 +648,10 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                     // e.g. 1 1 1 1 1 1 1 1 0 0 0 0
                     //
                     // This handles this last chunk:
-                    let last_chunk_evals = E1_evals[..(self.dense_len % chunk_size) / 4]
-                        .par_iter()
-                        .fold(
+                    let last_chunk_evals =
+                        maybe_par_iter!(E1_evals[..(self.dense_len % chunk_size) / 4]).fold_reduce(
                             || (F::zero(), F::zero(), F::zero()),
                             |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                        )
-                        .reduce(
-                            || (F::zero(), F::zero(), F::zero()),
                             |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                         );
                     (
@@ -737,8 +715,8 @@ pub fn bind_left_and_right<F: JoltField>(left: &mut Vec<F>, right: &mut Vec<F>,
     left_poly.bound_poly_var_bot(&r);
     right_poly.bound_poly_var_bot(&r);
 
-    *left = left_poly.Z[..left.len() / 2].to_vec();
-    *right = right_poly.Z[..right.len() / 2].to_vec();
+    *left = left_poly.evals_ref()[..left.len() / 2].to_vec();
+    *right = right_poly.evals_ref()[..right.len() / 2].to_vec();
 }
 
 #[cfg(test)]
@@ -8,6 +8,8 @@ use super::{
 use crate::poly::dense_mlpoly::DensePolynomial;
 #[cfg(test)]
 use crate::r1cs::inputs::JoltR1CSInputs;
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
 use crate::{
     field::{JoltField, OptimizedMul},
     r1cs::builder::{eval_offset_lc, Constraint, OffsetEqConstraint},
@@ -16,7 +18,14 @@ use crate::{
         transcript::{AppendToTranscript, Transcript},
     },
 };
+use crate::{
+    maybe_into_par_iter, maybe_par_chunk_by, maybe_par_chunks, maybe_par_iter,
+    utils::thread::current_num_threads, utils::MaybeParReduce,
+};
 use ark_ff::Zero;
+#[cfg(not(feature = "parallel"))]
+use itertools::Itertools;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 #[derive(Default, Debug, Clone)]
@@ -49,11 +58,10 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
     ) -> Self {
         let num_steps = flattened_polynomials[0].len();
 
-        let num_chunks = rayon::current_num_threads().next_power_of_two() * 4;
+        let num_chunks = current_num_threads().next_power_of_two() * 4;
         let chunk_size = num_steps.div_ceil(num_chunks);
 
-        let unbound_coeffs: Vec<SparseCoefficient<i128>> = (0..num_chunks)
-            .into_par_iter()
+        let unbound_coeffs: Vec<SparseCoefficient<i128>> = maybe_into_par_iter!(0..num_chunks)
             .flat_map_iter(|chunk_index| {
                 let mut coeffs = Vec::with_capacity(chunk_size * padded_num_constraints * 3);
                 for step_index in chunk_size * chunk_index..chunk_size * (chunk_index + 1) {
@@ -243,17 +251,16 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         let block_size = self
             .unbound_coeffs
             .len()
-            .div_ceil(rayon::current_num_threads())
+            .div_ceil(current_num_threads())
             .next_multiple_of(6);
-        let chunks: Vec<_> = self
-            .unbound_coeffs
-            .par_chunk_by(|x, y| x.index / block_size == y.index / block_size)
-            .collect();
+        let chunks: Vec<_> = maybe_par_chunk_by!(self.unbound_coeffs, |x, y| {
+            x.index / block_size == y.index / block_size
+        })
+        .collect();
 
         // We start by computing the E1 evals:
         // (1 - j) * E1[0, x1] + j * E1[1, x1]
-        let E1_evals: Vec<_> = eq_poly.E1[..eq_poly.E1_len]
-            .par_chunks(2)
+        let E1_evals: Vec<_> = maybe_par_chunks!(eq_poly.E1[..eq_poly.E1_len], 2)
             .map(|E1_chunk| {
                 let eval_point_0 = E1_chunk[0];
                 let m_eq = E1_chunk[1] - E1_chunk[0];
@@ -266,8 +273,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         let num_x1_bits = eq_poly.E1_len.log_2() - 1;
         let x1_bitmask = (1 << num_x1_bits) - 1;
 
-        let evals: (F, F, F) = chunks
-            .par_iter()
+        let evals: (F, F, F) = maybe_par_iter!(chunks)
             .map(|chunk| {
                 let mut eval_point_0 = F::zero();
                 let mut eval_point_2 = F::zero();
@@ -325,7 +331,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
 
                 (eval_point_0, eval_point_2, eval_point_3)
             })
-            .reduce(
+            .reduce_with_identity(
                 || (F::zero(), F::zero(), F::zero()),
                 |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
             );
@@ -354,8 +360,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
 
         // Compute the number of non-zero bound coefficients that will be produced
         // per chunk.
-        let output_sizes: Vec<_> = chunks
-            .par_iter()
+        let output_sizes: Vec<_> = maybe_par_iter!(chunks)
             .map(|chunk| Self::binding_output_length(chunk))
             .collect();
 
@@ -374,9 +379,8 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         }
         debug_assert_eq!(remainder.len(), 0);
 
-        chunks
-            .par_iter()
-            .zip_eq(output_slices.into_par_iter())
+        maybe_par_iter!(chunks)
+            .zip_eq(maybe_into_par_iter!(output_slices))
             .for_each(|(unbound_coeffs, output_slice)| {
                 let mut output_index = 0;
                 for block in unbound_coeffs.chunk_by(|x, y| x.index / 6 == y.index / 6) {
@@ -442,9 +446,9 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
                     println!("{i} {} != {}", az_bound[i], az[i]);
                 }
             }
-            assert!(az_bound.Z[..az_bound.len()] == az.Z[..az.len()]);
-            assert!(bz_bound.Z[..bz_bound.len()] == bz.Z[..bz.len()]);
-            assert!(cz_bound.Z[..cz_bound.len()] == cz.Z[..cz.len()]);
+            assert!(az_bound.evals_ref()[..az_bound.len()] == az.evals_ref()[..az.len()]);
+            assert!(bz_bound.evals_ref()[..bz_bound.len()] == bz.evals_ref()[..bz.len()]);
+            assert!(cz_bound.evals_ref()[..cz_bound.len()] == cz.evals_ref()[..cz.len()]);
         }
     }
 
@@ -466,16 +470,15 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         let block_size = self
             .bound_coeffs
             .len()
-            .div_ceil(rayon::current_num_threads())
+            .div_ceil(current_num_threads())
             .next_multiple_of(6);
-        let chunks: Vec<_> = self
-            .bound_coeffs
-            .par_chunk_by(|x, y| x.index / block_size == y.index / block_size)
-            .collect();
+        let chunks: Vec<_> = maybe_par_chunk_by!(self.bound_coeffs, |x, y| {
+            x.index / block_size == y.index / block_size
+        })
+        .collect();
 
         let cubic_poly = if eq_poly.E1_len == 1 {
-            let eq_evals: Vec<(F, F, F)> = eq_poly.E2[..eq_poly.E2_len]
-                .par_chunks(2)
+            let eq_evals: Vec<(F, F, F)> = maybe_par_chunks!(eq_poly.E2[..eq_poly.E2_len], 2)
                 .map(|eq_chunk| {
                     let eval_point_0 = eq_chunk[0];
                     let m_eq = eq_chunk[1] - eq_chunk[0];
@@ -485,8 +488,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
                 })
                 .collect();
 
-            let evals: (F, F, F) = chunks
-                .par_iter()
+            let evals: (F, F, F) = maybe_par_iter!(chunks)
                 .flat_map_iter(|chunk| {
                     chunk
                         .chunk_by(|x, y| x.index / 6 == y.index / 6)
@@ -529,7 +531,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
                             )
                         })
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -539,8 +541,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         } else {
             // We start by computing the E1 evals:
             // (1 - j) * E1[0, x1] + j * E1[1, x1]
-            let E1_evals: Vec<_> = eq_poly.E1[..eq_poly.E1_len]
-                .par_chunks(2)
+            let E1_evals: Vec<_> = maybe_par_chunks!(eq_poly.E1[..eq_poly.E1_len], 2)
                 .map(|E1_chunk| {
                     let eval_point_0 = E1_chunk[0];
                     let m_eq = E1_chunk[1] - E1_chunk[0];
@@ -553,8 +554,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
             let num_x1_bits = eq_poly.E1_len.log_2() - 1;
             let x1_bitmask = (1 << num_x1_bits) - 1;
 
-            let evals: (F, F, F) = chunks
-                .par_iter()
+            let evals: (F, F, F) = maybe_par_iter!(chunks)
                 .map(|chunk| {
                     let mut eval_point_0 = F::zero();
                     let mut eval_point_2 = F::zero();
@@ -617,7 +617,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
 
                     (eval_point_0, eval_point_2, eval_point_3)
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -645,8 +645,7 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         #[cfg(test)]
         let (mut az, mut bz, mut cz) = self.uninterleave();
 
-        let output_sizes: Vec<_> = chunks
-            .par_iter()
+        let output_sizes: Vec<_> = maybe_par_iter!(chunks)
             .map(|chunk| Self::binding_output_length(chunk))
             .collect();
 
@@ -667,9 +666,8 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
         }
         debug_assert_eq!(remainder.len(), 0);
 
-        chunks
-            .par_iter()
-            .zip_eq(output_slices.into_par_iter())
+        maybe_par_iter!(chunks)
+            .zip_eq(maybe_into_par_iter!(output_slices))
             .for_each(|(coeffs, output_slice)| {
                 let mut output_index = 0;
                 for block in coeffs.chunk_by(|x, y| x.index / 6 == y.index / 6) {
@@ -732,9 +730,9 @@ impl<F: JoltField> SpartanInterleavedPolynomial<F> {
             az.bound_poly_var_bot(&r_i);
             bz.bound_poly_var_bot(&r_i);
             cz.bound_poly_var_bot(&r_i);
-            assert!(az_bound.Z[..az_bound.len()] == az.Z[..az.len()]);
-            assert!(bz_bound.Z[..bz_bound.len()] == bz.Z[..bz.len()]);
-            assert!(cz_bound.Z[..cz_bound.len()] == cz.Z[..cz.len()]);
+            assert!(az_bound.evals_ref()[..az_bound.len()] == az.evals_ref()[..az.len()]);
+            assert!(bz_bound.evals_ref()[..bz_bound.len()] == bz.evals_ref()[..bz.len()]);
+            assert!(cz_bound.evals_ref()[..cz_bound.len()] == cz.evals_ref()[..cz.len()]);
         }
     }
 
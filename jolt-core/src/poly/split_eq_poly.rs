@@ -2,7 +2,7 @@
 //! https://eprint.iacr.org/2024/1210.pdf
 #[cfg(test)]
 use super::dense_mlpoly::DensePolynomial;
-use crate::{field::JoltField, poly::eq_poly::EqPolynomial};
+use crate::{field::JoltField, maybe_join, poly::eq_poly::EqPolynomial};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SplitEqPolynomial<F> {
@@ -18,7 +18,7 @@ impl<F: JoltField> SplitEqPolynomial<F> {
     pub fn new(w: &[F]) -> Self {
         let m = w.len() / 2;
         let (w2, w1) = w.split_at(m);
-        let (E2, E1) = rayon::join(|| EqPolynomial::evals(w2), || EqPolynomial::evals(w1));
+        let (E2, E1) = maybe_join!(|| EqPolynomial::evals(w2), || EqPolynomial::evals(w1));
         let E1_len = E1.len();
         let E2_len = E2.len();
         Self {
@@ -109,7 +109,10 @@ mod tests {
             split_eq.bind(r);
 
             let merged = split_eq.merge();
-            assert_eq!(regular_eq.Z[..regular_eq.len()], merged.Z[..merged.len()]);
+            assert_eq!(
+                regular_eq.evals_ref()[..regular_eq.len()],
+                merged.evals_ref()[..merged.len()]
+            );
         }
     }
 }
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 use crate::field::JoltField;
+use crate::{maybe_into_par_iter, maybe_par_iter, maybe_par_iter_mut};
 use std::cmp::Ordering;
 use std::ops::{AddAssign, Index, IndexMut, Mul, MulAssign};
 
@@ -7,6 +8,7 @@ use crate::utils::gaussian_elimination::gaussian_elimination;
 use crate::utils::transcript::{AppendToTranscript, Transcript};
 use ark_serialize::*;
 use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 use super::compact_polynomial::SmallScalar;
@@ -38,6 +40,43 @@ impl<F: JoltField> UniPoly<F> {
         }
     }
 
+    /// Interpolates the polynomial of degree less than `domain.len()` taking the value
+    /// `evals[i]` at `domain[i]`, for an arbitrary domain of distinct points. Unlike
+    /// [`UniPoly::from_evals`], which assumes the domain `0, 1, ..., n - 1`. Uses Lagrange
+    /// interpolation in O(n^2) field operations.
+    ///
+    /// Panics if `evals` and `domain` differ in length or `domain` has repeated points.
+    pub fn from_evals_over(evals: &[F], domain: &[F]) -> Self {
+        assert_eq!(evals.len(), domain.len());
+        let n = domain.len();
+
+        // Z(x) = prod_j (x - domain[j])
+        let mut vanishing = vec![F::zero(); n + 1];
+        vanishing[0] = F::one();
+        for (j, point) in domain.iter().enumerate() {
+            for k in (1..=j + 1).rev() {
+                vanishing[k] = vanishing[k - 1] - vanishing[k] * point;
+            }
+            vanishing[0] = -(vanishing[0] * point);
+        }
+
+        let mut coeffs = vec![F::zero(); n];
+        for (eval, point) in evals.iter().zip(domain.iter()) {
+            // Z(x) / (x - domain[i]) is the i'th Lagrange basis polynomial, up to the
+            // scalar prod_{j != i} (domain[i] - domain[j]), which is its value at domain[i]
+            let basis = &synthetic_division(&vanishing, *point)[..n];
+            let denominator = Self::eval_with_coeffs(basis, point);
+            let scale = *eval
+                * denominator
+                    .inverse()
+                    .expect("interpolation domain has repeated points");
+            for (coeff, basis_coeff) in coeffs.iter_mut().zip(basis.iter()) {
+                *coeff += scale * basis_coeff;
+            }
+        }
+        UniPoly { coeffs }
+    }
+
     fn vandermonde_interpolation(evals: &[F]) -> Vec<F> {
         let n = evals.len();
         let xs: Vec<F> = (0..n).map(|x| F::from_u64(x as u64)).collect();
@@ -124,6 +163,13 @@ impl<F: JoltField> UniPoly<F> {
         Self::eval_with_coeffs(&self.coeffs, r)
     }
 
+    /// Evaluates the polynomial at each of `xs`, in parallel across points.
+    pub fn evaluate_batch(&self, xs: &[F]) -> Vec<F> {
+        maybe_par_iter!(xs)
+            .map(|x| Self::eval_with_coeffs(&self.coeffs, x))
+            .collect()
+    }
+
     #[tracing::instrument(skip_all, name = "UniPoly::eval_with_coeffs")]
     pub fn eval_with_coeffs(coeffs: &[F], r: &F) -> F {
         let mut eval = coeffs[0];
@@ -139,7 +185,7 @@ impl<F: JoltField> UniPoly<F> {
     pub fn eval_as_univariate(poly: &MultilinearPolynomial<F>, r: &F) -> F {
         match poly {
             MultilinearPolynomial::LargeScalars(poly) => {
-                let mut eval = poly.Z[0];
+                let mut eval = poly.evals_ref()[0];
                 let mut power = *r;
                 for coeff in poly.evals_ref()[1..].iter() {
                     eval += power * coeff;
@@ -212,10 +258,25 @@ impl<F: JoltField> UniPoly<F> {
     }
 
     pub fn shift_coefficients(&mut self, rhs: &F) {
-        self.coeffs.par_iter_mut().for_each(|c| *c += *rhs);
+        maybe_par_iter_mut!(self.coeffs).for_each(|c| *c += *rhs);
     }
 }
 
+/// Divides the polynomial with coefficients `f` (lowest degree first) by `(x - u)` using
+/// synthetic division, discarding the remainder `f(u)`. Since `f(x) - f(u)` is divisible by
+/// `(x - u)`, the result is also the KZG witness polynomial for the opening `f(u)`.
+///
+/// The quotient has degree `f.len() - 2`; it is returned padded with a zero leading
+/// coefficient so that it has the same length as `f`. Costs `f.len() - 1` multiplications.
+pub fn synthetic_division<F: JoltField>(f: &[F], u: F) -> Vec<F> {
+    let d = f.len();
+    let mut h = vec![F::zero(); d];
+    for i in (1..d).rev() {
+        h[i - 1] = f[i] + h[i] * u;
+    }
+    h
+}
+
 impl<F: JoltField> AddAssign<&Self> for UniPoly<F> {
     fn add_assign(&mut self, rhs: &Self) {
         let ordering = self.coeffs.len().cmp(&rhs.coeffs.len());
@@ -234,7 +295,7 @@ impl<F: JoltField> Mul<F> for UniPoly<F> {
     type Output = Self;
 
     fn mul(self, rhs: F) -> Self {
-        let iter = self.coeffs.into_par_iter();
+        let iter = maybe_into_par_iter!(self.coeffs);
         Self::from_coeff(iter.map(|c| c * rhs).collect::<Vec<_>>())
     }
 }
@@ -243,7 +304,7 @@ impl<F: JoltField> Mul<&F> for UniPoly<F> {
     type Output = Self;
 
     fn mul(self, rhs: &F) -> Self {
-        let iter = self.coeffs.into_par_iter();
+        let iter = maybe_into_par_iter!(self.coeffs);
         Self::from_coeff(iter.map(|c| c * *rhs).collect::<Vec<_>>())
     }
 }
@@ -264,7 +325,7 @@ impl<F: JoltField> IndexMut<usize> for UniPoly<F> {
 
 impl<F: JoltField> MulAssign<&F> for UniPoly<F> {
     fn mul_assign(&mut self, rhs: &F) {
-        self.coeffs.par_iter_mut().for_each(|c| *c *= *rhs);
+        maybe_par_iter_mut!(self.coeffs).for_each(|c| *c *= *rhs);
     }
 }
 
@@ -321,6 +382,7 @@ impl<F: JoltField> AppendToTranscript for CompressedUniPoly<F> {
 mod tests {
     use super::*;
     use ark_bn254::Fr;
+    use ark_std::{One, Zero};
     use rand_chacha::ChaCha20Rng;
     use rand_core::SeedableRng;
 
@@ -387,6 +449,54 @@ mod tests {
         assert_eq!(poly.evaluate(&F::from_u64(4u64)), e4);
     }
 
+    #[test]
+    fn test_from_evals_over() {
+        let rng = &mut ChaCha20Rng::from_seed([2u8; 32]);
+        for n in 1..20 {
+            let domain: Vec<Fr> = (0..n).map(|_| Fr::random(rng)).collect();
+            let evals: Vec<Fr> = (0..n).map(|_| Fr::random(rng)).collect();
+            let poly = UniPoly::from_evals_over(&evals, &domain);
+            assert_eq!(poly.coeffs.len(), n);
+            for (point, eval) in domain.iter().zip(evals.iter()) {
+                assert_eq!(poly.evaluate(point), *eval);
+            }
+        }
+
+        // Agrees with `from_evals` on the domain 0, 1, ..., n - 1
+        let evals: Vec<Fr> = (0..5).map(|_| Fr::random(rng)).collect();
+        let domain: Vec<Fr> = (0..5).map(Fr::from_u64).collect();
+        assert_eq!(
+            UniPoly::from_evals_over(&evals, &domain),
+            UniPoly::from_evals(&evals)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_batch() {
+        let rng = &mut ChaCha20Rng::from_seed([3u8; 32]);
+        for degree in 0..20 {
+            let poly = UniPoly::<Fr>::random(degree, rng);
+            let r = Fr::random(rng);
+            let points = [r, -r, r * r];
+            let expected: Vec<Fr> = points
+                .iter()
+                .map(|x| {
+                    let mut eval = Fr::zero();
+                    let mut power = Fr::one();
+                    for coeff in poly.coeffs.iter() {
+                        eval += *coeff * power;
+                        power *= *x;
+                    }
+                    eval
+                })
+                .collect();
+            assert_eq!(poly.evaluate_batch(&points), expected);
+        }
+        assert!(UniPoly::<Fr>::from_coeff(vec![Fr::one()])
+            .evaluate_batch(&[])
+            .is_empty());
+    }
+
     pub fn naive_mul<F: JoltField>(ours: &UniPoly<F>, other: &UniPoly<F>) -> UniPoly<F> {
         if ours.is_zero() || other.is_zero() {
             UniPoly::zero()
@@ -401,6 +511,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_synthetic_division() {
+        // (x^2 + 3x + 2) / (x + 1) = x + 2
+        let f = [Fr::from_u64(2), Fr::from_u64(3), Fr::from_u64(1)];
+        assert_eq!(
+            synthetic_division(&f, -Fr::one()),
+            vec![Fr::from_u64(2), Fr::one(), Fr::zero()]
+        );
+        assert!(synthetic_division::<Fr>(&[], Fr::one()).is_empty());
+        assert_eq!(
+            synthetic_division(&[Fr::from_u64(7)], Fr::one()),
+            vec![Fr::zero()]
+        );
+
+        let rng = &mut ChaCha20Rng::from_seed([1u8; 32]);
+        for degree in 1..40 {
+            let f = UniPoly::<Fr>::random(degree, rng);
+            let u = Fr::random(rng);
+            let h = synthetic_division(&f.coeffs, u);
+            assert_eq!(h.len(), f.coeffs.len());
+
+            let divisor = UniPoly::from_coeff(vec![-u, Fr::one()]);
+            let (quotient, remainder) = f.divide_with_remainder(&divisor).unwrap();
+            assert_eq!(h[..quotient.coeffs.len()], quotient.coeffs[..]);
+            assert!(h[quotient.coeffs.len()..].iter().all(|c| c.is_zero()));
+            assert!(remainder.coeffs.iter().all(|c| *c == f.evaluate(&u)));
+        }
+    }
+
     #[test]
     fn test_divide_poly() {
         let rng = &mut ChaCha20Rng::from_seed([0u8; 32]);
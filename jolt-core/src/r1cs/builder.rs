@@ -7,10 +7,13 @@ use crate::poly::multilinear_polynomial::MultilinearPolynomial;
 use crate::{
     field::JoltField,
     jolt::vm::JoltPolynomials,
+    maybe_par_chunks_mut,
     poly::spartan_interleaved_poly::SpartanInterleavedPolynomial,
     r1cs::key::{SparseConstraints, UniformR1CS},
+    utils::thread::current_num_threads,
 };
 use ark_ff::One;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::{
     collections::BTreeMap,
@@ -136,12 +139,11 @@ impl<F: JoltField> AuxComputation<F> {
             .collect();
 
         let mut aux_poly: Vec<i64> = vec![0; poly_len];
-        let num_threads = rayon::current_num_threads();
+        let num_threads = current_num_threads();
         let chunk_size = poly_len.div_ceil(num_threads);
         let contains_negative_values = AtomicBool::new(false);
 
-        aux_poly
-            .par_chunks_mut(chunk_size)
+        maybe_par_chunks_mut!(aux_poly, chunk_size)
             .enumerate()
             .for_each(|(chunk_index, chunk)| {
                 chunk.iter_mut().enumerate().for_each(|(offset, result)| {
@@ -1,13 +1,16 @@
-use common::{constants::REGISTER_COUNT, rv_trace::CircuitFlags};
+use common::{
+    constants::{INSTRUCTION_ALIGNMENT, REGISTER_COUNT},
+    rv_trace::CircuitFlags,
+};
 use strum::IntoEnumIterator;
 
 use crate::{
     field::JoltField,
     jolt::{
         instruction::{
-            add::ADDInstruction, mul::MULInstruction, mulhu::MULHUInstruction,
-            mulu::MULUInstruction, sll::SLLInstruction, sra::SRAInstruction, srl::SRLInstruction,
-            sub::SUBInstruction,
+            add::ADDInstruction, auipc::AUIPCInstruction, lui::LUIInstruction, mul::MULInstruction,
+            mulhu::MULHUInstruction, mulu::MULUInstruction, sll::SLLInstruction,
+            sra::SRAInstruction, srl::SRLInstruction, sub::SUBInstruction,
             virtual_assert_aligned_memory_access::AssertAlignedMemoryAccessInstruction,
             virtual_move::MOVEInstruction, virtual_movsign::MOVSIGNInstruction,
         },
@@ -22,7 +25,9 @@ use super::{
 };
 
 pub const PC_START_ADDRESS: i64 = 0x80000000;
-const PC_NOOP_SHIFT: i64 = 4;
+// Bytecode addresses are compressed as `1 + (pc - PC_START_ADDRESS) / INSTRUCTION_ALIGNMENT`,
+// so `2 * ELFAddress + PC_START_ADDRESS` is the real PC shifted by this amount.
+const PC_NOOP_SHIFT: i64 = INSTRUCTION_ALIGNMENT as i64;
 const LOG_M: usize = 16;
 const OPERAND_SIZE: usize = LOG_M / 2;
 
@@ -74,7 +79,7 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
         cs.constrain_pack_be(flags, JoltR1CSInputs::Bytecode_Bitflags, 1);
 
         let real_pc =
-            4i64 * JoltR1CSInputs::Bytecode_ELFAddress + (PC_START_ADDRESS - PC_NOOP_SHIFT);
+            2i64 * JoltR1CSInputs::Bytecode_ELFAddress + (PC_START_ADDRESS - PC_NOOP_SHIFT);
         let x = cs.allocate_if_else(
             JoltR1CSInputs::Aux(AuxVariable::LeftLookupOperand),
             JoltR1CSInputs::OpFlags(CircuitFlags::LeftOperandIsPC),
@@ -122,6 +127,7 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
         // For the `AssertAlignedMemoryAccessInstruction` lookups, we add the `rs1` and `imm` values
         // to obtain the memory address being accessed.
         let add_operands = JoltR1CSInputs::InstructionFlags(ADDInstruction::default().into())
+            + JoltR1CSInputs::InstructionFlags(AUIPCInstruction::default().into())
             + JoltR1CSInputs::InstructionFlags(
                 AssertAlignedMemoryAccessInstruction::<32, 2>::default().into(),
             )
@@ -150,6 +156,11 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
             packed_query.clone(),
             x,
         );
+        cs.constrain_eq_conditional(
+            JoltR1CSInputs::InstructionFlags(LUIInstruction::default().into()),
+            packed_query.clone(),
+            y,
+        );
 
         cs.constrain_eq_conditional(
             JoltR1CSInputs::OpFlags(CircuitFlags::Assert),
@@ -205,7 +216,7 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
             JoltR1CSInputs::RD_Write,
             JoltR1CSInputs::LookupOutput,
         );
-        // if (rd != 0 && is_jump_instr == 1) constrain(rd_val == 4 * PC)
+        // if (rd != 0 && is_jump_instr == 1) constrain(rd_val == PC + instruction length)
         let rd_nonzero_and_jmp = cs.allocate_prod(
             JoltR1CSInputs::Aux(AuxVariable::WritePCtoRD),
             JoltR1CSInputs::Bytecode_RD,
@@ -213,15 +224,17 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
         );
         cs.constrain_eq_conditional(
             rd_nonzero_and_jmp,
-            4 * JoltR1CSInputs::Bytecode_ELFAddress + PC_START_ADDRESS,
+            2 * JoltR1CSInputs::Bytecode_ELFAddress + PC_START_ADDRESS + 2
+                - 2 * JoltR1CSInputs::OpFlags(CircuitFlags::IsCompressed),
             JoltR1CSInputs::RD_Write,
         );
 
         let next_pc_jump = cs.allocate_if_else(
             JoltR1CSInputs::Aux(AuxVariable::NextPCJump),
             JoltR1CSInputs::OpFlags(CircuitFlags::Jump),
-            JoltR1CSInputs::LookupOutput + 4,
-            4 * JoltR1CSInputs::Bytecode_ELFAddress + PC_START_ADDRESS + 4
+            JoltR1CSInputs::LookupOutput + PC_NOOP_SHIFT,
+            2 * JoltR1CSInputs::Bytecode_ELFAddress + PC_START_ADDRESS + 4
+                - 2 * JoltR1CSInputs::OpFlags(CircuitFlags::IsCompressed)
                 - 4 * JoltR1CSInputs::OpFlags(CircuitFlags::DoNotUpdatePC),
         );
 
@@ -233,7 +246,7 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
         let _next_pc = cs.allocate_if_else(
             JoltR1CSInputs::Aux(AuxVariable::NextPC),
             should_branch,
-            4 * JoltR1CSInputs::Bytecode_ELFAddress
+            2 * JoltR1CSInputs::Bytecode_ELFAddress
                 + PC_START_ADDRESS
                 + JoltR1CSInputs::Bytecode_Imm,
             next_pc_jump,
@@ -247,7 +260,7 @@ impl<const C: usize, F: JoltField> R1CSConstraints<C, F> for JoltRV32IMConstrain
             (JoltR1CSInputs::Bytecode_ELFAddress, true),
             (JoltR1CSInputs::Aux(AuxVariable::NextPC), false),
             (
-                4 * JoltR1CSInputs::Bytecode_ELFAddress + PC_START_ADDRESS,
+                2 * JoltR1CSInputs::Bytecode_ELFAddress + PC_START_ADDRESS,
                 true,
             ),
         );
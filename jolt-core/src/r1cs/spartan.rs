@@ -31,6 +31,10 @@ use crate::{
 use super::builder::CombinedUniformBuilder;
 use super::inputs::ConstraintInput;
 
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
+use crate::{maybe_into_par_iter, maybe_par_iter, maybe_par_iter_mut};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 #[derive(Clone, Debug, Eq, PartialEq, Error)]
@@ -194,9 +198,8 @@ where
         let mut bind_z = vec![F::zero(); num_vars_uniform * 2];
         let mut bind_shift_z = vec![F::zero(); num_vars_uniform * 2];
 
-        flattened_polys
-            .par_iter()
-            .zip(bind_z.par_iter_mut().zip(bind_shift_z.par_iter_mut()))
+        maybe_par_iter!(flattened_polys)
+            .zip(maybe_par_iter_mut!(bind_z).zip(maybe_par_iter_mut!(bind_shift_z)))
             .for_each(|(poly, (eval, eval_shifted))| {
                 *eval = poly.dot_product(Some(&eq_rx_step), Some(&eq_rx_step_r2));
                 *eval_shifted =
@@ -249,8 +252,8 @@ where
         let span = span!(Level::INFO, "bind_z_ry_var");
         let _guard = span.enter();
         let num_steps_unpadded = constraint_builder.uniform_repeat();
-        (0..num_steps_unpadded) // unpadded number of steps is sufficient
-            .into_par_iter()
+        // unpadded number of steps is sufficient
+        maybe_into_par_iter!(0..num_steps_unpadded)
             .map(|t| {
                 flattened_polys
                     .iter()
@@ -270,8 +273,7 @@ where
             MultilinearPolynomial::from(eq_plus_one_rx_step),
         ];
 
-        let shift_sumcheck_claim = (0..1 << num_rounds_shift_sumcheck)
-            .into_par_iter()
+        let shift_sumcheck_claim = maybe_into_par_iter!(0..1 << num_rounds_shift_sumcheck)
             .map(|i| {
                 let params: Vec<F> = shift_sumcheck_polys
                     .iter()
@@ -279,7 +281,7 @@ where
                     .collect();
                 comb_func(&params)
             })
-            .reduce(|| F::zero(), |acc, x| acc + x);
+            .sum::<F>();
 
         let (shift_sumcheck_proof, shift_sumcheck_r, _shift_sumcheck_claims) =
             SumcheckInstanceProof::prove_arbitrary(
@@ -9,8 +9,12 @@ use crate::poly::split_eq_poly::SplitEqPolynomial;
 use crate::utils::math::Math;
 use crate::utils::thread::drop_in_background_thread;
 use crate::utils::transcript::Transcript;
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
+use crate::{maybe_into_par_iter, maybe_par_iter};
 use ark_serialize::*;
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 #[derive(CanonicalSerialize, CanonicalDeserialize)]
@@ -216,8 +220,7 @@ where
         transcript.append_scalar(&left_claim);
         transcript.append_scalar(&right_claim);
 
-        r_sumcheck
-            .into_par_iter()
+        maybe_into_par_iter!(r_sumcheck)
             .rev()
             .collect_into_vec(r_grand_product);
 
@@ -342,7 +345,7 @@ mod tests {
             .take(batch_size)
             .collect();
 
-            let expected_product: Fr = leaves.par_iter().flatten().product();
+            let expected_product: Fr = maybe_par_iter!(leaves).flatten().product();
 
             let batched_circuit = <BatchedDenseGrandProduct<Fr> as BatchedGrandProduct<
                 Fr,
@@ -351,7 +354,10 @@ mod tests {
             >>::construct((leaves.concat(), batch_size));
 
             for layer in &batched_circuit.layers {
-                assert_eq!(layer.coeffs.par_iter().product::<Fr>(), expected_product);
+                assert_eq!(
+                    maybe_par_iter!(layer.coeffs).product::<Fr>(),
+                    expected_product
+                );
             }
 
             let claimed_outputs: Vec<Fr> = <BatchedDenseGrandProduct<Fr> as BatchedGrandProduct<
@@ -3,6 +3,7 @@ use super::grand_product::{
 };
 use super::sumcheck::SumcheckInstanceProof;
 use crate::field::JoltField;
+use crate::maybe_par_chunks;
 use crate::poly::commitment::commitment_scheme::CommitmentScheme;
 use crate::poly::dense_interleaved_poly::DenseInterleavedPolynomial;
 use crate::poly::dense_mlpoly::DensePolynomial;
@@ -15,6 +16,7 @@ use crate::utils::transcript::{AppendToTranscript, Transcript};
 use ark_serialize::*;
 use ark_std::{One, Zero};
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::marker::PhantomData;
 use thiserror::Error;
@@ -119,14 +121,12 @@ where
     fn claimed_outputs(&self) -> Vec<F> {
         if let Some(quark_poly) = &self.quark_poly {
             let chunk_size = quark_poly.len() / self.batch_size;
-            quark_poly
-                .par_chunks(chunk_size)
+            maybe_par_chunks!(quark_poly, chunk_size)
                 .map(|chunk| chunk.iter().product())
                 .collect()
         } else {
             let top_layer = &self.base_layers[self.base_layers.len() - 1];
-            top_layer
-                .par_chunks(2)
+            maybe_par_chunks!(top_layer, 2)
                 .map(|chunk| chunk[0] * chunk[1])
                 .collect()
         }
@@ -568,7 +568,7 @@ fn v_into_f<F: JoltField>(
 ) {
     let v_length = v.len();
     let mut f_evals = vec![F::zero(); 2 * v_length];
-    let (evals, _) = v.Z.split_at(v.len());
+    let (evals, _) = v.evals_ref().split_at(v.len());
     f_evals[..v_length].clone_from_slice(evals);
 
     for i in v_length..2 * v_length {
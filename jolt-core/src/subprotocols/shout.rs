@@ -16,6 +16,11 @@ use crate::{
         transcript::{AppendToTranscript, Transcript},
     },
 };
+use crate::{
+    maybe_into_par_iter, maybe_join, maybe_par_chunks, maybe_par_iter, maybe_par_iter_mut,
+    utils::thread::current_num_threads, utils::MaybeParReduce,
+};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 pub struct ShoutProof<F: JoltField, ProofTranscript: Transcript> {
@@ -49,12 +54,11 @@ impl<F: JoltField, ProofTranscript: Transcript> ShoutProof<F, ProofTranscript> {
         let span = tracing::span!(tracing::Level::INFO, "compute F");
         let _guard = span.enter();
 
-        let num_chunks = rayon::current_num_threads()
+        let num_chunks = current_num_threads()
             .next_power_of_two()
             .min(read_addresses.len());
         let chunk_size = (read_addresses.len() / num_chunks).max(1);
-        let F: Vec<_> = read_addresses
-            .par_chunks(chunk_size)
+        let F: Vec<_> = maybe_par_chunks!(read_addresses, chunk_size)
             .enumerate()
             .map(|(chunk_index, addresses)| {
                 let mut result: Vec<F> = unsafe_allocate_zero_vec(K);
@@ -65,12 +69,11 @@ impl<F: JoltField, ProofTranscript: Transcript> ShoutProof<F, ProofTranscript> {
                 }
                 result
             })
-            .reduce(
+            .reduce_with_identity(
                 || unsafe_allocate_zero_vec(K),
                 |mut running, new| {
-                    running
-                        .par_iter_mut()
-                        .zip(new.into_par_iter())
+                    maybe_par_iter_mut!(running)
+                        .zip(maybe_into_par_iter!(new))
                         .for_each(|(x, y)| *x += y);
                     running
                 },
@@ -78,9 +81,8 @@ impl<F: JoltField, ProofTranscript: Transcript> ShoutProof<F, ProofTranscript> {
         drop(_guard);
         drop(span);
 
-        let rv_claim: F = F
-            .par_iter()
-            .zip(lookup_table.par_iter())
+        let rv_claim: F = maybe_par_iter!(F)
+            .zip(maybe_par_iter!(lookup_table))
             .map(|(&ra, &val)| ra * val)
             .sum();
         // Linear combination of the core PIOP claim and the Hamming weight claim (which is 1)
@@ -100,8 +102,7 @@ impl<F: JoltField, ProofTranscript: Transcript> ShoutProof<F, ProofTranscript> {
             let inner_span = tracing::span!(tracing::Level::INFO, "Compute univariate poly");
             let _inner_guard = inner_span.enter();
 
-            let univariate_poly_evals: [F; 2] = (0..ra.len() / 2)
-                .into_par_iter()
+            let univariate_poly_evals: [F; 2] = maybe_into_par_iter!(0..ra.len() / 2)
                 .map(|i| {
                     let ra_evals = ra.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
                     let val_evals = val.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
@@ -111,7 +112,7 @@ impl<F: JoltField, ProofTranscript: Transcript> ShoutProof<F, ProofTranscript> {
                         ra_evals[1] * (z + val_evals[1]),
                     ]
                 })
-                .reduce(
+                .reduce_with_identity(
                     || [F::zero(); 2],
                     |running, new| [running[0] + new[0], running[1] + new[1]],
                 );
@@ -135,10 +136,9 @@ impl<F: JoltField, ProofTranscript: Transcript> ShoutProof<F, ProofTranscript> {
             previous_claim = univariate_poly.evaluate(&r_j);
 
             // Bind polynomials
-            rayon::join(
-                || ra.bind_parallel(r_j, BindingOrder::LowToHigh),
-                || val.bind_parallel(r_j, BindingOrder::LowToHigh),
-            );
+            maybe_join!(|| ra.bind_parallel(r_j, BindingOrder::LowToHigh), || {
+                val.bind_parallel(r_j, BindingOrder::LowToHigh)
+            });
         }
 
         drop(_guard);
@@ -222,8 +222,7 @@ pub fn prove_core_shout_piop<F: JoltField, ProofTranscript: Transcript>(
     let mut r_address: Vec<F> = Vec::with_capacity(num_rounds);
 
     let E: Vec<F> = EqPolynomial::evals(&r_cycle);
-    let F: Vec<_> = (0..K)
-        .into_par_iter()
+    let F: Vec<_> = maybe_into_par_iter!(0..K)
         .map(|k| {
             read_addresses
                 .iter()
@@ -233,9 +232,8 @@ pub fn prove_core_shout_piop<F: JoltField, ProofTranscript: Transcript>(
         })
         .collect();
 
-    let sumcheck_claim: F = F
-        .par_iter()
-        .zip(lookup_table.par_iter())
+    let sumcheck_claim: F = maybe_par_iter!(F)
+        .zip(maybe_par_iter!(lookup_table))
         .map(|(&ra, &val)| ra * val)
         .sum();
     let mut previous_claim = sumcheck_claim;
@@ -246,15 +244,14 @@ pub fn prove_core_shout_piop<F: JoltField, ProofTranscript: Transcript>(
     const DEGREE: usize = 2;
     let mut compressed_polys: Vec<CompressedUniPoly<F>> = Vec::with_capacity(num_rounds);
     for _ in 0..num_rounds {
-        let univariate_poly_evals: [F; 2] = (0..ra.len() / 2)
-            .into_par_iter()
+        let univariate_poly_evals: [F; 2] = maybe_into_par_iter!(0..ra.len() / 2)
             .map(|i| {
                 let ra_evals = ra.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
                 let val_evals = val.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
 
                 [ra_evals[0] * val_evals[0], ra_evals[1] * val_evals[1]]
             })
-            .reduce(
+            .reduce_with_identity(
                 || [F::zero(); 2],
                 |running, new| [running[0] + new[0], running[1] + new[1]],
             );
@@ -275,10 +272,9 @@ pub fn prove_core_shout_piop<F: JoltField, ProofTranscript: Transcript>(
         previous_claim = univariate_poly.evaluate(&r_j);
 
         // Bind polynomials
-        rayon::join(
-            || ra.bind_parallel(r_j, BindingOrder::LowToHigh),
-            || val.bind_parallel(r_j, BindingOrder::LowToHigh),
-        );
+        maybe_join!(|| ra.bind_parallel(r_j, BindingOrder::LowToHigh), || {
+            val.bind_parallel(r_j, BindingOrder::LowToHigh)
+        });
     }
 
     let ra_claim = ra.final_sumcheck_claim();
@@ -352,12 +348,10 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
         let inner_span = tracing::span!(tracing::Level::INFO, "Compute univariate poly");
         let _inner_guard = inner_span.enter();
 
-        let univariate_poly_evals: [F; 3] = (0..B.len() / 2)
-            .into_par_iter()
+        let univariate_poly_evals: [F; 3] = maybe_into_par_iter!(0..B.len() / 2)
             .map(|k_prime| {
                 let B_evals = B.sumcheck_evals(k_prime, DEGREE, BindingOrder::LowToHigh);
-                let inner_sum = G[k_prime << m..(k_prime + 1) << m]
-                    .par_iter()
+                let inner_sum = maybe_par_iter!(G[k_prime << m..(k_prime + 1) << m])
                     .enumerate()
                     .map(|(k, &G_k)| {
                         // Since we're binding variables from low to high, k_m is the high bit
@@ -375,7 +369,7 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
                             G_times_F * (eq_km_c_squared[k_m][2] * F_k - eq_km_c[k_m][2]),
                         ]
                     })
-                    .reduce(
+                    .reduce_with_identity(
                         || [F::zero(); 3],
                         |running, new| {
                             [
@@ -392,7 +386,7 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
                     B_evals[2] * inner_sum[2],
                 ]
             })
-            .reduce(
+            .reduce_with_identity(
                 || [F::zero(); 3],
                 |running, new| {
                     [
@@ -429,9 +423,8 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
 
         // Update F for this round (see Equation 55)
         let (F_left, F_right) = F.split_at_mut(1 << round);
-        F_left
-            .par_iter_mut()
-            .zip(F_right.par_iter_mut())
+        maybe_par_iter_mut!(F_left)
+            .zip(maybe_par_iter_mut!(F_right))
             .for_each(|(x, y)| {
                 *y = *x * r_j;
                 *x -= *y;
@@ -474,8 +467,7 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
         let inner_span = tracing::span!(tracing::Level::INFO, "Compute univariate poly");
         let _inner_guard = inner_span.enter();
 
-        let mut univariate_poly_evals: [F; 3] = (0..D.len() / 2)
-            .into_par_iter()
+        let mut univariate_poly_evals: [F; 3] = maybe_into_par_iter!(0..D.len() / 2)
             .map(|i| {
                 let D_evals = D.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
                 let H_evals = H.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
@@ -486,7 +478,7 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
                     D_evals[2] * (H_evals[2] * H_evals[2] - H_evals[2]),
                 ]
             })
-            .reduce(
+            .reduce_with_identity(
                 || [F::zero(); 3],
                 |running, new| {
                     [
@@ -523,10 +515,9 @@ pub fn prove_booleanity<F: JoltField, ProofTranscript: Transcript>(
         previous_claim = univariate_poly.evaluate(&r_j);
 
         // Bind polynomials
-        rayon::join(
-            || D.bind_parallel(r_j, BindingOrder::LowToHigh),
-            || H.bind_parallel(r_j, BindingOrder::LowToHigh),
-        );
+        maybe_join!(|| D.bind_parallel(r_j, BindingOrder::LowToHigh), || {
+            H.bind_parallel(r_j, BindingOrder::LowToHigh)
+        });
     }
 
     let ra_claim = H.final_sumcheck_claim();
@@ -554,8 +545,7 @@ pub fn prove_hamming_weight<F: JoltField, ProofTranscript: Transcript>(
     let mut r_address_double_prime: Vec<F> = Vec::with_capacity(num_rounds);
 
     let E: Vec<F> = EqPolynomial::evals(&r_cycle_prime);
-    let F: Vec<_> = (0..K)
-        .into_par_iter()
+    let F: Vec<_> = maybe_into_par_iter!(0..K)
         .map(|k| {
             read_addresses
                 .iter()
@@ -570,8 +560,7 @@ pub fn prove_hamming_weight<F: JoltField, ProofTranscript: Transcript>(
 
     let mut compressed_polys: Vec<CompressedUniPoly<F>> = Vec::with_capacity(num_rounds);
     for _ in 0..num_rounds {
-        let univariate_poly_eval: F = (0..ra.len() / 2)
-            .into_par_iter()
+        let univariate_poly_eval: F = maybe_into_par_iter!(0..ra.len() / 2)
             .map(|i| ra.get_bound_coeff(2 * i))
             .sum();
 
@@ -612,8 +601,7 @@ pub fn prove_raf_evaluation<F: JoltField, ProofTranscript: Transcript>(
     debug_assert_eq!(T.log_2(), r_cycle.len());
 
     let E: Vec<F> = EqPolynomial::evals(&r_cycle);
-    let F: Vec<_> = (0..K)
-        .into_par_iter()
+    let F: Vec<_> = maybe_into_par_iter!(0..K)
         .map(|k| {
             read_addresses
                 .iter()
@@ -635,15 +623,14 @@ pub fn prove_raf_evaluation<F: JoltField, ProofTranscript: Transcript>(
 
     let mut compressed_polys: Vec<CompressedUniPoly<F>> = Vec::with_capacity(num_rounds);
     for _ in 0..num_rounds {
-        let univariate_poly_evals: [F; 2] = (0..ra.len() / 2)
-            .into_par_iter()
+        let univariate_poly_evals: [F; 2] = maybe_into_par_iter!(0..ra.len() / 2)
             .map(|i| {
                 let ra_evals = ra.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
                 let int_evals: Vec<F> = int.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
 
                 [ra_evals[0] * int_evals[0], ra_evals[1] * int_evals[1]]
             })
-            .reduce(
+            .reduce_with_identity(
                 || [F::zero(); 2],
                 |running, new| [running[0] + new[0], running[1] + new[1]],
             );
@@ -664,10 +651,9 @@ pub fn prove_raf_evaluation<F: JoltField, ProofTranscript: Transcript>(
         previous_claim = univariate_poly.evaluate(&r_j);
 
         // Bind polynomials
-        rayon::join(
-            || ra.bind_parallel(r_j, BindingOrder::LowToHigh),
-            || int.bind_parallel(r_j, BindingOrder::LowToHigh),
-        );
+        maybe_join!(|| ra.bind_parallel(r_j, BindingOrder::LowToHigh), || {
+            int.bind_parallel(r_j, BindingOrder::LowToHigh)
+        });
     }
 
     let ra_claim = ra.final_sumcheck_claim();
@@ -763,8 +749,7 @@ mod tests {
         let r: Vec<Fr> = prover_transcript.challenge_vector(TABLE_SIZE.log_2());
         let r_prime: Vec<Fr> = prover_transcript.challenge_vector(NUM_LOOKUPS.log_2());
         let E: Vec<Fr> = EqPolynomial::evals(&r_prime);
-        let F: Vec<_> = (0..TABLE_SIZE)
-            .into_par_iter()
+        let F: Vec<_> = maybe_into_par_iter!(0..TABLE_SIZE)
             .map(|k| {
                 read_addresses
                     .iter()
@@ -16,6 +16,13 @@ use crate::subprotocols::QuarkHybridLayerDepth;
 use crate::utils::math::Math;
 use crate::utils::thread::drop_in_background_thread;
 use crate::utils::transcript::Transcript;
+#[cfg(not(feature = "parallel"))]
+use crate::utils::SequentialIteratorExt;
+use crate::{
+    maybe_into_par_iter, maybe_par_chunks, maybe_par_iter, maybe_par_iter_mut,
+    utils::MaybeParReduce,
+};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 /// A special bottom layer of a grand product, where boolean flags are used to
@@ -133,9 +140,7 @@ impl<F: JoltField> BatchedGrandProductToggleLayer<F> {
     /// 🏴  o  🏳️ o  🏳️ o  🏴  o  <- toggle layer
     #[tracing::instrument(skip_all, name = "BatchedGrandProductToggleLayer::layer_output")]
     fn layer_output(&self) -> SparseInterleavedPolynomial<F> {
-        let values: Vec<_> = self
-            .fingerprints
-            .par_iter()
+        let values: Vec<_> = maybe_par_iter!(self.fingerprints)
             .enumerate()
             .map(|(batch_index, fingerprints)| {
                 let flag_indices = &self.flag_indices[batch_index / 2];
@@ -225,12 +230,12 @@ impl<F: JoltField> Bindable<F> for BatchedGrandProductToggleLayer<F> {
                 flags_before_binding.bound_poly_var_bot(&r);
                 fingerprints_before_binding.bound_poly_var_bot(&r);
                 assert_eq!(
-                    bound_flags.Z[..bound_flags.len()],
-                    flags_before_binding.Z[..flags_before_binding.len()]
+                    bound_flags.evals_ref()[..bound_flags.len()],
+                    flags_before_binding.evals_ref()[..flags_before_binding.len()]
                 );
                 assert_eq!(
-                    bound_fingerprints.Z[..bound_fingerprints.len()],
-                    fingerprints_before_binding.Z[..fingerprints_before_binding.len()]
+                    bound_fingerprints.evals_ref()[..bound_fingerprints.len()],
+                    fingerprints_before_binding.evals_ref()[..fingerprints_before_binding.len()]
                 );
             }
 
@@ -240,14 +245,12 @@ impl<F: JoltField> Bindable<F> for BatchedGrandProductToggleLayer<F> {
         debug_assert!(self.layer_len % 4 == 0);
 
         // Bind the fingerprints
-        self.fingerprints
-            .par_iter_mut()
-            .for_each(|layer: &mut Vec<F>| {
-                let n = self.layer_len / 4;
-                for i in 0..n {
-                    layer[i] = layer[2 * i] + r.mul_0_optimized(layer[2 * i + 1] - layer[2 * i]);
-                }
-            });
+        maybe_par_iter_mut!(self.fingerprints).for_each(|layer: &mut Vec<F>| {
+            let n = self.layer_len / 4;
+            for i in 0..n {
+                layer[i] = layer[2 * i] + r.mul_0_optimized(layer[2 * i + 1] - layer[2 * i]);
+            }
+        });
 
         let is_first_bind = self.flag_values.is_empty();
         if is_first_bind {
@@ -255,9 +258,8 @@ impl<F: JoltField> Bindable<F> for BatchedGrandProductToggleLayer<F> {
         }
 
         // Bind the flags
-        self.flag_indices
-            .par_iter_mut()
-            .zip(self.flag_values.par_iter_mut())
+        maybe_par_iter_mut!(self.flag_indices)
+            .zip(maybe_par_iter_mut!(self.flag_values))
             .for_each(|(flag_indices, flag_values)| {
                 let mut next_index_to_process = 0usize;
 
@@ -338,12 +340,12 @@ impl<F: JoltField> Bindable<F> for BatchedGrandProductToggleLayer<F> {
             flags_before_binding.bound_poly_var_bot(&r);
             fingerprints_before_binding.bound_poly_var_bot(&r);
             assert_eq!(
-                bound_flags.Z[..bound_flags.len()],
-                flags_before_binding.Z[..flags_before_binding.len()]
+                bound_flags.evals_ref()[..bound_flags.len()],
+                flags_before_binding.evals_ref()[..flags_before_binding.len()]
             );
             assert_eq!(
-                bound_fingerprints.Z[..bound_fingerprints.len()],
-                fingerprints_before_binding.Z[..fingerprints_before_binding.len()]
+                bound_fingerprints.evals_ref()[..bound_fingerprints.len()],
+                fingerprints_before_binding.evals_ref()[..fingerprints_before_binding.len()]
             );
         }
 
@@ -357,12 +359,12 @@ impl<F: JoltField> Bindable<F> for BatchedGrandProductToggleLayer<F> {
             {
                 let (bound_flags, bound_fingerprints) = self.to_dense();
                 assert_eq!(
-                    bound_flags.Z[..bound_flags.len()],
-                    flags_before_binding.Z[..flags_before_binding.len()]
+                    bound_flags.evals_ref()[..bound_flags.len()],
+                    flags_before_binding.evals_ref()[..flags_before_binding.len()]
                 );
                 assert_eq!(
-                    bound_fingerprints.Z[..bound_fingerprints.len()],
-                    fingerprints_before_binding.Z[..fingerprints_before_binding.len()]
+                    bound_fingerprints.evals_ref()[..bound_fingerprints.len()],
+                    fingerprints_before_binding.evals_ref()[..fingerprints_before_binding.len()]
                 );
             }
         }
@@ -404,10 +406,9 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
             let cubic_evals = if eq_poly.E1_len == 1 {
                 // 1. Flags/fingerprints are coalesced, and E1 is fully bound
                 // This is similar to the if case of `DenseInterleavedPolynomial::compute_cubic`
-                coalesced_flags
-                    .par_chunks(2)
-                    .zip(coalesced_fingerprints.par_chunks(2))
-                    .zip(eq_poly.E2.par_chunks(2))
+                maybe_par_chunks!(coalesced_flags, 2)
+                    .zip(maybe_par_chunks!(coalesced_fingerprints, 2))
+                    .zip(maybe_par_chunks!(eq_poly.E2, 2))
                     .map(|((flags, fingerprints), eq_chunk)| {
                         let eq_evals = {
                             let eval_point_0 = eq_chunk[0];
@@ -433,15 +434,14 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                                 * (flag_eval_3 * fingerprint_eval_3 + F::one() - flag_eval_3),
                         )
                     })
-                    .reduce(
+                    .reduce_with_identity(
                         || (F::zero(), F::zero(), F::zero()),
                         |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                     )
             } else {
                 // 2. Flags/fingerprints are coalesced, and E1 isn't fully bound
                 // This is similar to the else case of `DenseInterleavedPolynomial::compute_cubic`
-                let E1_evals: Vec<_> = eq_poly.E1[..eq_poly.E1_len]
-                    .par_chunks(2)
+                let E1_evals: Vec<_> = maybe_par_chunks!(eq_poly.E1[..eq_poly.E1_len], 2)
                     .map(|E1_chunk| {
                         let eval_point_0 = E1_chunk[0];
                         let m_eq = E1_chunk[1] - E1_chunk[0];
@@ -455,10 +455,12 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                 let fingerprint_chunk_size =
                     coalesced_fingerprints.len().next_power_of_two() / eq_poly.E2_len;
 
-                eq_poly.E2[..eq_poly.E2_len]
-                    .par_iter()
-                    .zip(coalesced_flags.par_chunks(flag_chunk_size))
-                    .zip(coalesced_fingerprints.par_chunks(fingerprint_chunk_size))
+                maybe_par_iter!(eq_poly.E2[..eq_poly.E2_len])
+                    .zip(maybe_par_chunks!(coalesced_flags, flag_chunk_size))
+                    .zip(maybe_par_chunks!(
+                        coalesced_fingerprints,
+                        fingerprint_chunk_size
+                    ))
                     .map(|((E2_eval, flag_x2), fingerprint_x2)| {
                         let mut inner_sum = (F::zero(), F::zero(), F::zero());
                         for ((E1_evals, flag_chunk), fingerprint_chunk) in E1_evals
@@ -489,7 +491,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                             *E2_eval * inner_sum.2,
                         )
                     })
-                    .reduce(
+                    .reduce_with_identity(
                         || (F::zero(), F::zero(), F::zero()),
                         |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                     )
@@ -507,8 +509,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
         let cubic_evals = if eq_poly.E1_len == 1 {
             // 3. Flags/fingerprints aren't coalesced, and E1 is fully bound
             // This is similar to the if case of `SparseInterleavedPolynomial::compute_cubic`
-            let eq_evals: Vec<(F, F, F)> = eq_poly.E2[..eq_poly.E2_len]
-                .par_chunks(2)
+            let eq_evals: Vec<(F, F, F)> = maybe_par_chunks!(eq_poly.E2[..eq_poly.E2_len], 2)
                 .take(self.batched_layer_len / 4)
                 .map(|eq_chunk| {
                     let eval_point_0 = eq_chunk[0];
@@ -518,19 +519,13 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                     (eval_point_0, eval_point_2, eval_point_3)
                 })
                 .collect();
-            let eq_eval_sums: (F, F, F) = eq_evals
-                .par_iter()
-                .fold(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                )
-                .reduce(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                );
+            let eq_eval_sums: (F, F, F) = maybe_par_iter!(eq_evals).fold_reduce(
+                || (F::zero(), F::zero(), F::zero()),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+            );
 
-            let deltas: (F, F, F) = (0..self.fingerprints.len())
-                .into_par_iter()
+            let deltas: (F, F, F) = maybe_into_par_iter!(0..self.fingerprints.len())
                 .map(|batch_index| {
                     // Computes:
                     //     ∆ := Σ eq_evals[j] * (flag[j] * fingerprint[j] - flag[j])    ∀j where flag[j] ≠ 0
@@ -614,7 +609,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
 
                     (delta.0, delta.1, delta.2)
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -628,8 +623,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
         } else {
             // 4. Flags/fingerprints aren't coalesced, and E1 isn't fully bound
             // This is similar to the else case of `SparseInterleavedPolynomial::compute_cubic`
-            let E1_evals: Vec<_> = eq_poly.E1[..eq_poly.E1_len]
-                .par_chunks(2)
+            let E1_evals: Vec<_> = maybe_par_chunks!(eq_poly.E1[..eq_poly.E1_len], 2)
                 .map(|E1_chunk| {
                     let eval_point_0 = E1_chunk[0];
                     let m_eq = E1_chunk[1] - E1_chunk[0];
@@ -638,22 +632,16 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                     (eval_point_0, eval_point_2, eval_point_3)
                 })
                 .collect();
-            let E1_eval_sums: (F, F, F) = E1_evals
-                .par_iter()
-                .fold(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                )
-                .reduce(
-                    || (F::zero(), F::zero(), F::zero()),
-                    |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                );
+            let E1_eval_sums: (F, F, F) = maybe_par_iter!(E1_evals).fold_reduce(
+                || (F::zero(), F::zero(), F::zero()),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+            );
 
             let num_x1_bits = eq_poly.E1_len.log_2() - 1;
             let x1_bitmask = (1 << num_x1_bits) - 1;
 
-            let deltas = (0..self.fingerprints.len())
-                .into_par_iter()
+            let deltas = maybe_into_par_iter!(0..self.fingerprints.len())
                 .map(|batch_index| {
                     // Computes:
                     //     ∆ := Σ eq_evals[j] * (flag[j] * fingerprint[j] - flag[j])    ∀j where flag[j] ≠ 0
@@ -751,7 +739,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
 
                     delta
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -798,16 +786,13 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                     // e.g. 1 1 1 1 1 1 1 1 0 0 0 0
                     //
                     // This handles this last chunk:
-                    let last_chunk_evals = E1_evals[..(self.batched_layer_len % chunk_size) / 4]
-                        .par_iter()
-                        .fold(
-                            || (F::zero(), F::zero(), F::zero()),
-                            |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                        )
-                        .reduce(
-                            || (F::zero(), F::zero(), F::zero()),
-                            |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
-                        );
+                    let last_chunk_evals =
+                        maybe_par_iter!(E1_evals[..(self.batched_layer_len % chunk_size) / 4])
+                            .fold_reduce(
+                                || (F::zero(), F::zero(), F::zero()),
+                                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+                                |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
+                            );
                     (
                         E2_sum * E1_eval_sums.0
                             + eq_poly.E2[num_all_one_chunks] * last_chunk_evals.0,
@@ -837,11 +822,9 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
         {
             let (dense_flags, dense_fingerprints) = self.to_dense();
             let eq_merged = eq_poly.merge();
-            let dense_cubic_evals = dense_flags
-                .evals()
-                .par_chunks(2)
-                .zip(dense_fingerprints.evals().par_chunks(2))
-                .zip(eq_merged.evals().par_chunks(2))
+            let dense_cubic_evals = maybe_par_chunks!(dense_flags.evals(), 2)
+                .zip(maybe_par_chunks!(dense_fingerprints.evals(), 2))
+                .zip(maybe_par_chunks!(eq_merged.evals(), 2))
                 .map(|((flag_chunk, fingerprint_chunk), eq_chunk)| {
                     let eq_evals = {
                         let eval_point_0 = eq_chunk[0];
@@ -874,7 +857,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedCubicSumcheck<F, ProofTra
                         eq_evals.2 * (flag_eval_3 * fingerprint_eval_3 + F::one() - flag_eval_3),
                     )
                 })
-                .reduce(
+                .reduce_with_identity(
                     || (F::zero(), F::zero(), F::zero()),
                     |sum, evals| (sum.0 + evals.0, sum.1 + evals.1, sum.2 + evals.2),
                 );
@@ -919,8 +902,7 @@ impl<F: JoltField, ProofTranscript: Transcript> BatchedGrandProductLayer<F, Proo
         transcript.append_scalar(&left_claim);
         transcript.append_scalar(&right_claim);
 
-        r_sumcheck
-            .into_par_iter()
+        maybe_into_par_iter!(r_sumcheck)
             .rev()
             .collect_into_vec(r_grand_product);
 
@@ -1017,8 +999,7 @@ where
         // If there's a quark poly, then that's the claimed output
         if let Some(quark_poly) = &self.quark_poly {
             let chunk_size = quark_poly.len() / self.batch_size;
-            quark_poly
-                .par_chunks(chunk_size)
+            maybe_par_chunks!(quark_poly, chunk_size)
                 .map(|chunk| chunk.iter().product())
                 .collect()
         } else {
@@ -1134,7 +1115,7 @@ mod tests {
     use rand_core::SeedableRng;
 
     fn condense(sparse_layer: SparseInterleavedPolynomial<Fr>) -> Vec<Fr> {
-        sparse_layer.to_dense().Z
+        sparse_layer.to_dense().evals()
     }
 
     #[test]
@@ -1430,8 +1411,10 @@ mod tests {
             for layers in &circuit.sparse_layers {
                 let dense = layers.to_dense();
                 let chunk_size = layers.dense_len / batch_size;
-                for (chunk, expected_product) in
-                    dense.Z.chunks(chunk_size).zip(expected_outputs.iter())
+                for (chunk, expected_product) in dense
+                    .evals_ref()
+                    .chunks(chunk_size)
+                    .zip(expected_outputs.iter())
                 {
                     let actual_product: Fr = chunk.iter().product();
                     assert_eq!(*expected_product, actual_product);
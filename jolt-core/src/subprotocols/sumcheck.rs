@@ -13,7 +13,11 @@ use crate::utils::errors::ProofVerifyError;
 use crate::utils::mul_0_optimized;
 use crate::utils::thread::drop_in_background_thread;
 use crate::utils::transcript::{AppendToTranscript, Transcript};
+use crate::{
+    maybe_into_par_iter, maybe_join, maybe_par_iter, maybe_par_iter_mut, utils::MaybeParReduce,
+};
 use ark_serialize::*;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::marker::PhantomData;
 
@@ -126,8 +130,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
 
             let mle_half = polys[0].len() / 2;
 
-            let accum: Vec<Vec<F>> = (0..mle_half)
-                .into_par_iter()
+            let accum: Vec<Vec<F>> = maybe_into_par_iter!(0..mle_half)
                 .map(|poly_term_i| {
                     let mut accum = vec![F::zero(); combined_degree];
                     // TODO(moodlezoup): Optimize
@@ -150,12 +153,10 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
                 })
                 .collect();
 
-            eval_points
-                .par_iter_mut()
+            maybe_par_iter_mut!(eval_points)
                 .enumerate()
                 .for_each(|(poly_i, eval_point)| {
-                    *eval_point = accum
-                        .par_iter()
+                    *eval_point = maybe_par_iter!(accum)
                         .take(mle_half)
                         .map(|mle| mle[poly_i])
                         .sum::<F>();
@@ -171,9 +172,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
             r.push(r_j);
 
             // bound all tables to the verifier's challenge
-            polys
-                .par_iter_mut()
-                .for_each(|poly| poly.bind(r_j, BindingOrder::HighToLow));
+            maybe_par_iter_mut!(polys).for_each(|poly| poly.bind(r_j, BindingOrder::HighToLow));
             previous_claim = univariate_poly.evaluate(&r_j);
             compressed_polys.push(compressed_poly);
         }
@@ -252,8 +251,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
         let poly = {
             // eval_point_0 = \sum_i A[i] * B[i]
             // where B[i] = witness_value(i) for i in 0..len
-            let eval_point_0: F = (0..len)
-                .into_par_iter()
+            let eval_point_0: F = maybe_into_par_iter!(0..len)
                 .map(|i| {
                     if poly_A[i].is_zero() || witness_value(i).is_zero() {
                         F::zero()
@@ -264,8 +262,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
                 .sum();
             // eval_point_2 = \sum_i (2 * A[len + i] - A[i]) * (2 * B[len + i] - B[i])
             // where B[i] = witness_value(i) for i in 0..len, B[len] = 1, and B[i] = 0 for i > len
-            let mut eval_point_2: F = (1..len)
-                .into_par_iter()
+            let mut eval_point_2: F = maybe_into_par_iter!(1..len)
                 .map(|i| {
                     if witness_value(i).is_zero() {
                         F::zero()
@@ -298,19 +295,20 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
         claim_per_round = poly.evaluate(&r_i);
 
         // bound all tables to the verifier's challenge
-        let (_, mut poly_B) = rayon::join(
-            || poly_A.bound_poly_var_top_zero_optimized(&r_i),
-            || {
+        let (_, mut poly_B) =
+            maybe_join!(|| poly_A.bound_poly_var_top_zero_optimized(&r_i), || {
                 // Simulates `poly_B.bound_poly_var_top(&r_i)` by
                 // iterating over `witness_polynomials`
                 // We need to do this because we don't actually have
                 // a `DensePolynomial` instance for `poly_B` yet.
                 let zero = F::zero();
                 let one = [F::one()];
-                let W_iter = (0..len).into_par_iter().map(witness_value);
-                let Z_iter = W_iter
-                    .chain(one.into_par_iter())
-                    .chain(rayon::iter::repeatn(zero, len));
+                let W_iter = maybe_into_par_iter!(0..len).map(witness_value);
+                #[cfg(feature = "parallel")]
+                let zeros = rayon::iter::repeatn(zero, len);
+                #[cfg(not(feature = "parallel"))]
+                let zeros = std::iter::repeat(zero).take(len);
+                let Z_iter = W_iter.chain(maybe_into_par_iter!(one)).chain(zeros);
                 let left_iter = Z_iter.clone().take(len);
                 let right_iter = Z_iter.skip(len).take(len);
                 let B = left_iter
@@ -318,8 +316,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
                     .map(|(a, b)| if a == b { a } else { a + r_i * (b - a) })
                     .collect();
                 DensePolynomial::new(B)
-            },
-        );
+            });
 
         /*          Round 0 END          */
 
@@ -346,10 +343,9 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
             claim_per_round = poly.evaluate(&r_i);
 
             // bound all tables to the verifier's challenge
-            rayon::join(
-                || poly_A.bound_poly_var_top_zero_optimized(&r_i),
-                || poly_B.bound_poly_var_top_zero_optimized(&r_i),
-            );
+            maybe_join!(|| poly_A.bound_poly_var_top_zero_optimized(&r_i), || {
+                poly_B.bound_poly_var_top_zero_optimized(&r_i)
+            });
         }
 
         let evals = vec![poly_A[0], poly_B[0]];
@@ -365,8 +361,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
         poly_B: &DensePolynomial<F>,
     ) -> (F, F) {
         let len = poly_A.len() / 2;
-        (0..len)
-            .into_par_iter()
+        maybe_into_par_iter!(0..len)
             .map(|i| {
                 // eval 0: bound_func is A(low)
                 let eval_point_0 = if poly_B[i].is_zero() || poly_A[i].is_zero() {
@@ -386,7 +381,7 @@ impl<F: JoltField, ProofTranscript: Transcript> SumcheckInstanceProof<F, ProofTr
 
                 (eval_point_0, eval_point_2)
             })
-            .reduce(|| (F::zero(), F::zero()), |a, b| (a.0 + b.0, a.1 + b.1))
+            .reduce_with_identity(|| (F::zero(), F::zero()), |a, b| (a.0 + b.0, a.1 + b.1))
     }
 }
 
@@ -15,6 +15,12 @@ use crate::{
         transcript::{AppendToTranscript, Transcript},
     },
 };
+use crate::{
+    maybe_into_par_iter, maybe_join, maybe_par_chunks, maybe_par_chunks_exact,
+    maybe_par_chunks_mut, maybe_par_iter, maybe_par_iter_mut, utils::thread::current_num_threads,
+    utils::MaybeParReduce,
+};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 /// The Twist+Shout paper gives two different prover algorithms for the read-checking
@@ -250,7 +256,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     let mut r_cycle: Vec<F> = Vec::with_capacity(T.log_2());
     let mut r_address: Vec<F> = Vec::with_capacity(K.log_2());
 
-    let num_chunks = rayon::current_num_threads().next_power_of_two().min(T);
+    let num_chunks = current_num_threads().next_power_of_two().min(T);
     let chunk_size = T / num_chunks;
 
     #[cfg(test)]
@@ -258,15 +264,17 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         // Compute Val in cycle-major order, since we will be binding
         // from low-to-high starting with the cycle variables
         let mut val: Vec<u32> = vec![0; K * T];
-        val.par_chunks_mut(T).enumerate().for_each(|(k, val_k)| {
-            let mut current_val = 0;
-            for j in 0..T {
-                val_k[j] = current_val;
-                if write_addresses[j] == k {
-                    current_val = write_values[j];
+        maybe_par_chunks_mut!(val, T)
+            .enumerate()
+            .for_each(|(k, val_k)| {
+                let mut current_val = 0;
+                for j in 0..T {
+                    val_k[j] = current_val;
+                    if write_addresses[j] == k {
+                        current_val = write_values[j];
+                    }
                 }
-            }
-        });
+            });
         MultilinearPolynomial::from(val)
     };
     #[cfg(test)]
@@ -274,13 +282,15 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         // Compute ra in cycle-major order, since we will be binding
         // from low-to-high starting with the cycle variables
         let mut ra: Vec<F> = unsafe_allocate_zero_vec(K * T);
-        ra.par_chunks_mut(T).enumerate().for_each(|(k, ra_k)| {
-            for j in 0..T {
-                if read_addresses[j] == k {
-                    ra_k[j] = F::one();
+        maybe_par_chunks_mut!(ra, T)
+            .enumerate()
+            .for_each(|(k, ra_k)| {
+                for j in 0..T {
+                    if read_addresses[j] == k {
+                        ra_k[j] = F::one();
+                    }
                 }
-            }
-        });
+            });
         MultilinearPolynomial::from(ra)
     };
     #[cfg(test)]
@@ -288,30 +298,35 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         // Compute wa in cycle-major order, since we will be binding
         // from low-to-high starting with the cycle variables
         let mut wa: Vec<F> = unsafe_allocate_zero_vec(K * T);
-        wa.par_chunks_mut(T).enumerate().for_each(|(k, wa_k)| {
-            for j in 0..T {
-                if write_addresses[j] == k {
-                    wa_k[j] = F::one();
+        maybe_par_chunks_mut!(wa, T)
+            .enumerate()
+            .for_each(|(k, wa_k)| {
+                for j in 0..T {
+                    if write_addresses[j] == k {
+                        wa_k[j] = F::one();
+                    }
                 }
-            }
-        });
+            });
         MultilinearPolynomial::from(wa)
     };
 
     let span = tracing::span!(tracing::Level::INFO, "compute deltas");
     let _guard = span.enter();
 
-    let deltas: Vec<Vec<i64>> = write_addresses[..T - chunk_size]
-        .par_chunks_exact(chunk_size)
-        .zip(write_increments[..T - chunk_size].par_chunks_exact(chunk_size))
-        .map(|(address_chunk, increment_chunk)| {
-            let mut delta = vec![0i64; K];
-            for (k, increment) in address_chunk.iter().zip(increment_chunk.iter()) {
-                delta[*k] += increment;
-            }
-            delta
-        })
-        .collect();
+    let deltas: Vec<Vec<i64>> =
+        maybe_par_chunks_exact!(write_addresses[..T - chunk_size], chunk_size)
+            .zip(maybe_par_chunks_exact!(
+                write_increments[..T - chunk_size],
+                chunk_size
+            ))
+            .map(|(address_chunk, increment_chunk)| {
+                let mut delta = vec![0i64; K];
+                for (k, increment) in address_chunk.iter().zip(increment_chunk.iter()) {
+                    delta[*k] += increment;
+                }
+                delta
+            })
+            .collect();
 
     drop(_guard);
     drop(span);
@@ -325,9 +340,8 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     checkpoints.push(vec![0; K]);
 
     for (chunk_index, delta) in deltas.into_iter().enumerate() {
-        let next_checkpoint = checkpoints[chunk_index]
-            .par_iter()
-            .zip(delta.into_par_iter())
+        let next_checkpoint = maybe_par_iter!(checkpoints[chunk_index])
+            .zip(maybe_into_par_iter!(delta))
             .map(|(val_k, delta_k)| val_k + delta_k)
             .collect();
         checkpoints.push(next_checkpoint);
@@ -336,9 +350,8 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     // Generate checkpoints as a flat vector because it will be turned into the
     // materialized Val polynomial after the first half of sumcheck.
     let mut val_checkpoints: Vec<F> = unsafe_allocate_zero_vec(K * num_chunks);
-    val_checkpoints
-        .par_chunks_mut(K)
-        .zip(checkpoints.into_par_iter())
+    maybe_par_chunks_mut!(val_checkpoints, K)
+        .zip(maybe_into_par_iter!(checkpoints))
         .for_each(|(val_checkpoint, checkpoint)| {
             val_checkpoint
                 .iter_mut()
@@ -375,9 +388,8 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     let _guard = span.enter();
 
     // Data structure described in Equation (72)
-    let mut I: Vec<Vec<(usize, usize, F, F)>> = write_addresses
-        .par_chunks(chunk_size)
-        .zip(write_increments.par_chunks(chunk_size))
+    let mut I: Vec<Vec<(usize, usize, F, F)>> = maybe_par_chunks!(write_addresses, chunk_size)
+        .zip(maybe_par_chunks!(write_increments, chunk_size))
         .enumerate()
         .map(|(chunk_index, (address_chunk, increment_chunk))| {
             // Row index of the I matrix
@@ -413,9 +425,8 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     let _guard = span.enter();
 
     // z * Inc(r, r')
-    let inc_eval: F = write_addresses
-        .par_iter()
-        .zip(write_increments.par_iter())
+    let inc_eval: F = maybe_par_iter!(write_addresses)
+        .zip(maybe_par_iter!(write_increments))
         .enumerate()
         .map(|(cycle, (address, increment))| {
             z_eq_r.get_coeff(*address) * eq_r_prime.get_coeff(cycle) * F::from_i64(*increment)
@@ -465,8 +476,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         wa: [Vec<F>; 2],
         dirty_indices: Vec<usize>,
     }
-    let mut data_buffers: Vec<DataBuffers<F>> = (0..num_chunks)
-        .into_par_iter()
+    let mut data_buffers: Vec<DataBuffers<F>> = maybe_into_par_iter!(0..num_chunks)
         .map(|_| DataBuffers {
             val_j_0: Vec::with_capacity(K),
             val_j_r: [unsafe_allocate_zero_vec(K), unsafe_allocate_zero_vec(K)],
@@ -503,10 +513,9 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         let inner_span = tracing::span!(tracing::Level::INFO, "Compute univariate poly");
         let _inner_guard = inner_span.enter();
 
-        let univariate_poly_evals: [F; 3] = I
-            .par_iter()
-            .zip(data_buffers.par_iter_mut())
-            .zip(val_checkpoints.par_chunks(K))
+        let univariate_poly_evals: [F; 3] = maybe_par_iter!(I)
+            .zip(maybe_par_iter_mut!(data_buffers))
+            .zip(maybe_par_chunks!(val_checkpoints, K))
             .map(|((I_chunk, buffers), checkpoint)| {
                 let mut evals = [F::zero(), F::zero(), F::zero()];
 
@@ -657,7 +666,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
 
                 evals
             })
-            .reduce(
+            .reduce_with_identity(
                 || [F::zero(); DEGREE],
                 |running, new| {
                     [
@@ -691,7 +700,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         let _inner_guard = inner_span.enter();
 
         // Bind I
-        I.par_iter_mut().for_each(|I_chunk| {
+        maybe_par_iter_mut!(I).for_each(|I_chunk| {
             // Note: A given row in an I_chunk may not be ordered by k after binding
             let mut next_bound_index = 0;
             let mut bound_indices: Vec<Option<usize>> = vec![None; K];
@@ -724,10 +733,9 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
         drop(_inner_guard);
         drop(inner_span);
 
-        rayon::join(
-            || wv.bind_parallel(r_j, BindingOrder::LowToHigh),
-            || eq_r_prime.bind_parallel(r_j, BindingOrder::LowToHigh),
-        );
+        maybe_join!(|| wv.bind_parallel(r_j, BindingOrder::LowToHigh), || {
+            eq_r_prime.bind_parallel(r_j, BindingOrder::LowToHigh)
+        });
 
         #[cfg(test)]
         {
@@ -752,9 +760,8 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
 
         // Update A for this round (see Equation 55)
         let (A_left, A_right) = A.split_at_mut(1 << round);
-        A_left
-            .par_iter_mut()
-            .zip(A_right.par_iter_mut())
+        maybe_par_iter_mut!(A_left)
+            .zip(maybe_par_iter_mut!(A_right))
             .for_each(|(x, y)| {
                 *y = *x * r_j;
                 *x -= *y;
@@ -772,7 +779,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     let _guard = span.enter();
 
     let mut ra: Vec<F> = unsafe_allocate_zero_vec(K * num_chunks);
-    ra.par_chunks_mut(K)
+    maybe_par_chunks_mut!(ra, K)
         .enumerate()
         .for_each(|(chunk_index, ra_chunk)| {
             for (j_bound, k) in read_addresses
@@ -792,7 +799,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     let _guard = span.enter();
 
     let mut wa: Vec<F> = unsafe_allocate_zero_vec(K * num_chunks);
-    wa.par_chunks_mut(K)
+    maybe_par_chunks_mut!(wa, K)
         .enumerate()
         .for_each(|(chunk_index, wa_chunk)| {
             for (j_bound, k) in write_addresses
@@ -812,8 +819,8 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
     let _guard = span.enter();
 
     let mut val: Vec<F> = val_checkpoints;
-    val.par_chunks_mut(K)
-        .zip(I.into_par_iter())
+    maybe_par_chunks_mut!(val, K)
+        .zip(maybe_into_par_iter!(I))
         .enumerate()
         .for_each(|(chunk_index, (val_chunk, I_chunk))| {
             for (j, k, inc_lt, _inc) in I_chunk.into_iter() {
@@ -836,15 +843,13 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
 
         let univariate_poly_evals: [F; 3] = if eq_r_prime.len() > 1 {
             // Not done binding cycle variables yet
-            (0..eq_r_prime.len() / 2)
-                .into_par_iter()
+            maybe_into_par_iter!(0..eq_r_prime.len() / 2)
                 .map(|j| {
                     let eq_r_prime_evals =
                         eq_r_prime.sumcheck_evals(j, DEGREE, BindingOrder::HighToLow);
                     let wv_evals = wv.sumcheck_evals(j, DEGREE, BindingOrder::HighToLow);
 
-                    let inner_sum_evals: [F; 3] = (0..K)
-                        .into_par_iter()
+                    let inner_sum_evals: [F; 3] = maybe_into_par_iter!(0..K)
                         .map(|k| {
                             let index = j * K + k;
                             let ra_evals =
@@ -872,7 +877,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
                                     + wa_evals[2].mul_0_optimized(wv_evals[2] - val_evals[2]),
                             ]
                         })
-                        .reduce(
+                        .reduce_with_identity(
                             || [F::zero(); 3],
                             |running, new| {
                                 [
@@ -889,7 +894,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
                         eq_r_prime_evals[2] * inner_sum_evals[2],
                     ]
                 })
-                .reduce(
+                .reduce_with_identity(
                     || [F::zero(); 3],
                     |running, new| {
                         [
@@ -906,8 +911,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
             // ...and wv(r_cycle) is a constant
             let wv_eval = wv.final_sumcheck_claim();
 
-            let evals = (0..ra.len() / 2)
-                .into_par_iter()
+            let evals = maybe_into_par_iter!(0..ra.len() / 2)
                 .map(|k| {
                     let z_eq_r_evals = z_eq_r.sumcheck_evals(k, DEGREE, BindingOrder::HighToLow);
                     let ra_evals = ra.sumcheck_evals(k, DEGREE, BindingOrder::HighToLow);
@@ -923,7 +927,7 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
                             + z_eq_r_evals[2] * wa_evals[2] * (wv_eval - val_evals[2]),
                     ]
                 })
-                .reduce(
+                .reduce_with_identity(
                     || [F::zero(); 3],
                     |running, new| {
                         [
@@ -963,16 +967,14 @@ fn prove_read_write_checking_local<F: JoltField, ProofTranscript: Transcript>(
             r_cycle.insert(round, r_j);
             // Note that `eq_r` is a polynomial over only the address variables,
             // so it is not bound here
-            [&mut ra, &mut wa, &mut wv, &mut val, &mut eq_r_prime]
-                .into_par_iter()
+            maybe_into_par_iter!([&mut ra, &mut wa, &mut wv, &mut val, &mut eq_r_prime])
                 .for_each(|poly| poly.bind_parallel(r_j, BindingOrder::HighToLow));
         } else {
             // Bind an address variable k
             r_address.push(r_j);
             // Note that `wv` and `eq_r_prime` are polynomials over only the cycle
             // variables, so they are not bound here
-            [&mut ra, &mut wa, &mut val, &mut z_eq_r]
-                .into_par_iter()
+            maybe_into_par_iter!([&mut ra, &mut wa, &mut val, &mut z_eq_r])
                 .for_each(|poly| poly.bind_parallel(r_j, BindingOrder::HighToLow));
         }
     }
@@ -1015,9 +1017,8 @@ pub fn prove_val_evaluation<F: JoltField, ProofTranscript: Transcript>(
     let _guard = span.enter();
 
     // Compute the Inc polynomial using the above table
-    let inc: Vec<F> = write_addresses
-        .par_iter()
-        .zip(write_increments.par_iter())
+    let inc: Vec<F> = maybe_par_iter!(write_addresses)
+        .zip(maybe_par_iter!(write_increments))
         .map(|(k, increment)| eq_r_address[*k] * F::from_i64(*increment))
         .collect();
     let mut inc = MultilinearPolynomial::from(inc);
@@ -1031,9 +1032,8 @@ pub fn prove_val_evaluation<F: JoltField, ProofTranscript: Transcript>(
     let mut lt: Vec<F> = unsafe_allocate_zero_vec(T);
     for (i, r) in r_cycle.iter().rev().enumerate() {
         let (evals_left, evals_right) = lt.split_at_mut(1 << i);
-        evals_left
-            .par_iter_mut()
-            .zip(evals_right.par_iter_mut())
+        maybe_par_iter_mut!(evals_left)
+            .zip(maybe_par_iter_mut!(evals_right))
             .for_each(|(x, y)| {
                 *y = *x * r;
                 *x += *r - *y;
@@ -1069,15 +1069,14 @@ pub fn prove_val_evaluation<F: JoltField, ProofTranscript: Transcript>(
         let inner_span = tracing::span!(tracing::Level::INFO, "Compute univariate poly");
         let _inner_guard = inner_span.enter();
 
-        let univariate_poly_evals: [F; 2] = (0..inc.len() / 2)
-            .into_par_iter()
+        let univariate_poly_evals: [F; 2] = maybe_into_par_iter!(0..inc.len() / 2)
             .map(|i| {
                 let inc_evals = inc.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
                 let lt_evals = lt.sumcheck_evals(i, DEGREE, BindingOrder::LowToHigh);
 
                 [inc_evals[0] * lt_evals[0], inc_evals[1] * lt_evals[1]]
             })
-            .reduce(
+            .reduce_with_identity(
                 || [F::zero(); 2],
                 |running, new| [running[0] + new[0], running[1] + new[1]],
             );
@@ -1101,10 +1100,9 @@ pub fn prove_val_evaluation<F: JoltField, ProofTranscript: Transcript>(
         previous_claim = univariate_poly.evaluate(&r_j);
 
         // Bind polynomials
-        rayon::join(
-            || inc.bind_parallel(r_j, BindingOrder::LowToHigh),
-            || lt.bind_parallel(r_j, BindingOrder::LowToHigh),
-        );
+        maybe_join!(|| inc.bind_parallel(r_j, BindingOrder::LowToHigh), || {
+            lt.bind_parallel(r_j, BindingOrder::LowToHigh)
+        });
     }
 
     let proof = ValEvaluationProof {
@@ -1,5 +1,7 @@
+use crate::poly::commitment::dory::DoryError;
 use core::fmt::Debug;
 use thiserror::Error;
+use tracer::RV32IM;
 
 #[derive(Error, Debug, Default)]
 pub enum ProofVerifyError {
@@ -14,8 +16,127 @@ pub enum ProofVerifyError {
     DecompressionError([u8; 32]),
     #[error("R1CS proof verification failed: {0}")]
     SpartanError(String),
-    #[error("Length Error: SRS Length: {0}, Key Length: {1}")]
-    KeyLengthError(usize, usize),
+    #[error(
+        "Key too short: {required_powers} powers are required but only {available_powers} are available"
+    )]
+    KeyLengthError {
+        available_powers: usize,
+        required_powers: usize,
+    },
     #[error("Invalid key length: {0}, expected power of 2")]
     InvalidKeyLength(usize),
+    #[error("Invalid MSM window of {0} bits")]
+    InvalidWindowBits(usize),
+    #[error("Dory proof verification failed: {0}")]
+    DoryError(#[from] DoryError),
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] ark_serialize::SerializationError),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+/// A check of an instruction's virtual sequence that failed while tracing it, i.e. the
+/// sequence's advice is inconsistent with the instruction's operands `x` and `y`. The
+/// quotient and remainder are the advice values the sequence was given.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceError {
+    #[error("{opcode:?}: remainder {remainder} is invalid for {x} / {y}")]
+    InvalidRemainder {
+        opcode: RV32IM,
+        x: u64,
+        y: u64,
+        remainder: u64,
+    },
+    #[error("{opcode:?}: quotient {quotient} is invalid for the division of {x} by zero")]
+    InvalidDivByZero {
+        opcode: RV32IM,
+        x: u64,
+        quotient: u64,
+    },
+    #[error("{opcode:?}: quotient {quotient} overflows, as {quotient} * {y} > {x}")]
+    QuotientOverflow {
+        opcode: RV32IM,
+        x: u64,
+        y: u64,
+        quotient: u64,
+    },
+    #[error("{opcode:?}: {quotient} * {y} + {remainder} != {x}")]
+    InconsistentAdvice {
+        opcode: RV32IM,
+        x: u64,
+        y: u64,
+        quotient: u64,
+        remainder: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::poly::commitment::hyperkzg::{
+        HyperKZG, HyperKZGProverKey, HyperKZGSRS, HyperKZGVerifierKey,
+    };
+    use crate::poly::multilinear_polynomial::MultilinearPolynomial;
+    use crate::utils::transcript::KeccakTranscript;
+    use ark_bn254::{Bn254, Fr};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::UniformRand;
+    use rand_core::SeedableRng;
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Fr, ProofVerifyError> {
+        Ok(Fr::deserialize_compressed(bytes)?)
+    }
+
+    fn read_file(path: &str) -> Result<Vec<u8>, ProofVerifyError> {
+        Ok(std::fs::read(path)?)
+    }
+
+    #[test]
+    fn serialization_errors_convert() {
+        let mut bytes = Vec::new();
+        Fr::from(42u64).serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(deserialize_scalar(&bytes).unwrap(), Fr::from(42u64));
+
+        assert!(matches!(
+            deserialize_scalar(&bytes[..bytes.len() - 1]),
+            Err(ProofVerifyError::SerializationError(
+                ark_serialize::SerializationError::IoError(_)
+            ))
+        ));
+        // All-ones is larger than the modulus
+        assert!(matches!(
+            deserialize_scalar(&[0xff; 32]),
+            Err(ProofVerifyError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn key_length_error_fields() {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(229);
+        let srs = HyperKZGSRS::setup(&mut rng, 4);
+        let (pk, _): (HyperKZGProverKey<Bn254>, HyperKZGVerifierKey<Bn254>) = srs.trim(3);
+        let poly =
+            MultilinearPolynomial::from((0..8).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>());
+
+        let err = HyperKZG::<_, KeccakTranscript>::commit(&pk, &poly).unwrap_err();
+        assert!(matches!(
+            err,
+            ProofVerifyError::KeyLengthError {
+                available_powers: 4,
+                required_powers: 8,
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Key too short: 8 powers are required but only 4 are available"
+        );
+    }
+
+    #[test]
+    fn io_errors_convert() {
+        let err = read_file("/nonexistent/jolt/proof.bin").unwrap_err();
+        assert!(
+            matches!(&err, ProofVerifyError::IoError(e) if e.kind() == std::io::ErrorKind::NotFound)
+        );
+    }
 }
@@ -2,6 +2,9 @@
 use crate::field::JoltField;
 
 use ark_std::test_rng;
+#[cfg(not(feature = "parallel"))]
+use itertools::Itertools;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 pub mod errors;
@@ -20,12 +23,12 @@ pub mod transcript;
 /// Based on observations; multiple calls into icicle_msm functions can dramatically slow down GPU performance.
 #[macro_export]
 macro_rules! optimal_iter {
-    ($T:expr) => {{
-        #[cfg(feature = "icicle")]
+    ($T:expr $(,)?) => {{
+        #[cfg(any(feature = "icicle", not(feature = "parallel")))]
         {
             $T.iter()
         }
-        #[cfg(not(feature = "icicle"))]
+        #[cfg(all(not(feature = "icicle"), feature = "parallel"))]
         {
             $T.par_iter()
         }
@@ -34,12 +37,12 @@ macro_rules! optimal_iter {
 
 #[macro_export]
 macro_rules! into_optimal_iter {
-    ($T:expr) => {{
-        #[cfg(feature = "icicle")]
+    ($T:expr $(,)?) => {{
+        #[cfg(any(feature = "icicle", not(feature = "parallel")))]
         {
             $T.into_iter()
         }
-        #[cfg(not(feature = "icicle"))]
+        #[cfg(all(not(feature = "icicle"), feature = "parallel"))]
         {
             $T.into_par_iter()
         }
@@ -48,12 +51,12 @@ macro_rules! into_optimal_iter {
 
 #[macro_export]
 macro_rules! optimal_iter_mut {
-    ($T:expr) => {{
-        #[cfg(feature = "icicle")]
+    ($T:expr $(,)?) => {{
+        #[cfg(any(feature = "icicle", not(feature = "parallel")))]
         {
             $T.iter_mut()
         }
-        #[cfg(not(feature = "icicle"))]
+        #[cfg(all(not(feature = "icicle"), feature = "parallel"))]
         {
             $T.par_iter_mut()
         }
@@ -62,18 +65,213 @@ macro_rules! optimal_iter_mut {
 
 #[macro_export]
 macro_rules! join_conditional {
-    ($f1:expr, $f2:expr) => {{
-        #[cfg(feature = "icicle")]
+    ($f1:expr, $f2:expr $(,)?) => {{
+        #[cfg(any(feature = "icicle", not(feature = "parallel")))]
         {
             ($f1(), $f2())
         }
-        #[cfg(not(feature = "icicle"))]
+        #[cfg(all(not(feature = "icicle"), feature = "parallel"))]
         {
             rayon::join($f1, $f2)
         }
     }};
 }
 
+/// Macros that use a parallel iterator when the `parallel` feature is enabled, and fall back
+/// to sequential iteration otherwise (e.g. for wasm targets without threads).
+#[macro_export]
+macro_rules! maybe_par_iter {
+    ($T:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.par_iter()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.iter()
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_into_par_iter {
+    ($T:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.into_par_iter()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.into_iter()
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_par_iter_mut {
+    ($T:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.par_iter_mut()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.iter_mut()
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_par_chunks {
+    ($T:expr, $size:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.par_chunks($size)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.chunks($size)
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_par_chunks_mut {
+    ($T:expr, $size:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.par_chunks_mut($size)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.chunks_mut($size)
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_par_chunks_exact {
+    ($T:expr, $size:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.par_chunks_exact($size)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.chunks_exact($size)
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_par_chunk_by {
+    ($T:expr, $pred:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            $T.par_chunk_by($pred)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            $T.chunk_by($pred)
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! maybe_join {
+    ($f1:expr, $f2:expr $(,)?) => {{
+        #[cfg(feature = "parallel")]
+        {
+            rayon::join($f1, $f2)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            ($f1(), $f2())
+        }
+    }};
+}
+
+/// The rayon-only iterator adapters used by the prover, implemented sequentially for builds
+/// without the `parallel` feature.
+#[cfg(not(feature = "parallel"))]
+pub trait SequentialIteratorExt: Iterator + Sized {
+    fn flat_map_iter<U, F>(self, f: F) -> std::iter::FlatMap<Self, U, F>
+    where
+        U: IntoIterator,
+        F: FnMut(Self::Item) -> U,
+    {
+        self.flat_map(f)
+    }
+
+    fn collect_into_vec(self, target: &mut Vec<Self::Item>) {
+        target.clear();
+        target.extend(self);
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: Iterator> SequentialIteratorExt for I {}
+
+/// Reductions with the same signature with or without the `parallel` feature. rayon's
+/// `reduce` and `fold` take an identity closure, unlike their `Iterator` namesakes.
+pub trait MaybeParReduce<T>: Sized {
+    /// rayon's `reduce(identity, op)`.
+    fn reduce_with_identity<ID, OP>(self, identity: ID, op: OP) -> T
+    where
+        ID: Fn() -> T + Sync + Send,
+        OP: Fn(T, T) -> T + Sync + Send;
+
+    /// rayon's `fold(identity, fold_op).reduce(identity, reduce_op)`.
+    fn fold_reduce<A, ID, F, R>(self, identity: ID, fold_op: F, reduce_op: R) -> A
+    where
+        A: Send,
+        ID: Fn() -> A + Sync + Send,
+        F: Fn(A, T) -> A + Sync + Send,
+        R: Fn(A, A) -> A + Sync + Send;
+}
+
+#[cfg(feature = "parallel")]
+impl<T: Send, I: ParallelIterator<Item = T>> MaybeParReduce<T> for I {
+    fn reduce_with_identity<ID, OP>(self, identity: ID, op: OP) -> T
+    where
+        ID: Fn() -> T + Sync + Send,
+        OP: Fn(T, T) -> T + Sync + Send,
+    {
+        self.reduce(identity, op)
+    }
+
+    fn fold_reduce<A, ID, F, R>(self, identity: ID, fold_op: F, reduce_op: R) -> A
+    where
+        A: Send,
+        ID: Fn() -> A + Sync + Send,
+        F: Fn(A, T) -> A + Sync + Send,
+        R: Fn(A, A) -> A + Sync + Send,
+    {
+        self.fold(&identity, fold_op).reduce(&identity, reduce_op)
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<T, I: Iterator<Item = T>> MaybeParReduce<T> for I {
+    fn reduce_with_identity<ID, OP>(self, identity: ID, op: OP) -> T
+    where
+        ID: Fn() -> T + Sync + Send,
+        OP: Fn(T, T) -> T + Sync + Send,
+    {
+        self.fold(identity(), op)
+    }
+
+    fn fold_reduce<A, ID, F, R>(self, identity: ID, fold_op: F, _reduce_op: R) -> A
+    where
+        A: Send,
+        ID: Fn() -> A + Sync + Send,
+        F: Fn(A, T) -> A + Sync + Send,
+        R: Fn(A, A) -> A + Sync + Send,
+    {
+        self.fold(identity(), fold_op)
+    }
+}
+
 /// Converts an integer value to a bitvector (all values {0,1}) of field elements.
 /// Note: ordering has the MSB in the highest index. All of the following represent the integer 1:
 /// - [1]
@@ -107,8 +305,8 @@ pub fn index_to_field_bitvector<F: JoltField>(value: usize, bits: usize) -> Vec<
 
 #[tracing::instrument(skip_all)]
 pub fn compute_dotproduct<F: JoltField>(a: &[F], b: &[F]) -> F {
-    a.par_iter()
-        .zip_eq(b.par_iter())
+    maybe_par_iter!(a)
+        .zip_eq(maybe_par_iter!(b))
         .map(|(a_i, b_i)| *a_i * *b_i)
         .sum()
 }
@@ -116,8 +314,8 @@ pub fn compute_dotproduct<F: JoltField>(a: &[F], b: &[F]) -> F {
 /// Compute dotproduct optimized for values being 0 / 1
 #[tracing::instrument(skip_all)]
 pub fn compute_dotproduct_low_optimized<F: JoltField>(a: &[F], b: &[F]) -> F {
-    a.par_iter()
-        .zip_eq(b.par_iter())
+    maybe_par_iter!(a)
+        .zip_eq(maybe_par_iter!(b))
         .map(|(a_i, b_i)| mul_0_1_optimized(a_i, b_i))
         .sum()
 }
@@ -192,4 +390,75 @@ mod tests {
         assert_eq!(split_bits(0b00_01, 2), (0, 1));
         assert_eq!(split_bits(0b10_01, 2), (2, 1));
     }
+
+    #[test]
+    fn maybe_par_matches_sequential() {
+        // Whichever way the `parallel` feature is set, the results must match plain iteration
+        let values: Vec<u64> = (0..1000).map(|i| i * i + 7).collect();
+
+        let doubled: Vec<u64> = maybe_par_iter!(values).map(|x| 2 * x).collect();
+        assert_eq!(doubled, values.iter().map(|x| 2 * x).collect::<Vec<_>>());
+
+        let indexed: Vec<u64> = maybe_into_par_iter!(0..values.len() as u64)
+            .map(|i| values[i as usize] + i)
+            .collect();
+        assert_eq!(
+            indexed,
+            (0..values.len() as u64)
+                .map(|i| values[i as usize] + i)
+                .collect::<Vec<_>>()
+        );
+
+        let mut incremented = values.clone();
+        maybe_par_iter_mut!(incremented).for_each(|x| *x += 1);
+        assert!(incremented.iter().zip(&values).all(|(a, b)| *a == b + 1));
+
+        let (sum, max) = maybe_join!(|| values.iter().sum::<u64>(), || *values
+            .iter()
+            .max()
+            .unwrap());
+        assert_eq!(sum, values.iter().sum::<u64>());
+        assert_eq!(max, 999 * 999 + 7);
+    }
+
+    #[test]
+    fn maybe_par_reductions_keep_order() {
+        // Concatenation is associative but not commutative, so this checks that items are
+        // combined in order, with or without the `parallel` feature
+        let values: Vec<u64> = (0..1000).collect();
+        let concat = |mut a: Vec<u64>, mut b: Vec<u64>| {
+            a.append(&mut b);
+            a
+        };
+
+        let concatenated = maybe_par_iter!(values)
+            .map(|x| vec![*x])
+            .reduce_with_identity(Vec::new, concat);
+        assert_eq!(concatenated, values);
+
+        let folded = maybe_par_iter!(values).fold_reduce(
+            Vec::new,
+            |mut acc, x| {
+                acc.push(2 * x);
+                acc
+            },
+            concat,
+        );
+        assert_eq!(folded, values.iter().map(|x| 2 * x).collect::<Vec<_>>());
+
+        let repeated: Vec<u64> = maybe_par_iter!(values)
+            .flat_map_iter(|x| [*x, *x])
+            .collect();
+        assert_eq!(
+            repeated,
+            values.iter().flat_map(|x| [*x, *x]).collect::<Vec<_>>()
+        );
+
+        // collect_into_vec replaces the previous contents of the target
+        let mut target = vec![7, 7];
+        maybe_into_par_iter!(0..10usize)
+            .map(|i| i * i)
+            .collect_into_vec(&mut target);
+        assert_eq!(target, (0..10).map(|i| i * i).collect::<Vec<_>>());
+    }
 }
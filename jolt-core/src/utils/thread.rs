@@ -1,14 +1,32 @@
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::thread::{self, JoinHandle};
 
 use crate::field::JoltField;
+use crate::{maybe_into_par_iter, maybe_join, maybe_par_iter_mut};
 
 pub fn drop_in_background_thread<T>(data: T)
 where
     T: Send + 'static,
 {
     // h/t https://abrams.cc/rust-dropping-things-in-another-thread
+    #[cfg(feature = "parallel")]
     rayon::spawn(move || drop(data));
+    #[cfg(not(feature = "parallel"))]
+    drop(data);
+}
+
+/// The number of threads the prover splits its work across: rayon's thread count, or 1
+/// without the `parallel` feature.
+pub fn current_num_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
 }
 
 pub fn allocate_vec_in_background<T: Clone + Send + 'static>(
@@ -105,12 +123,10 @@ pub fn par_flatten_triple<T: Send + Sync + Copy, F: Fn(usize) -> Vec<T>>(
         c_rest = c_new_rest;
     }
 
-    triple
-        .into_par_iter()
+    maybe_into_par_iter!(triple)
         .zip(
-            a_slices
-                .par_iter_mut()
-                .zip(b_slices.par_iter_mut().zip(c_slices.par_iter_mut())),
+            maybe_par_iter_mut!(a_slices)
+                .zip(maybe_par_iter_mut!(b_slices).zip(maybe_par_iter_mut!(c_slices))),
         )
         .for_each(|(chunk, (a, (b, c)))| {
             join_triple(
@@ -132,6 +148,6 @@ where
     RB: Send,
     RC: Send,
 {
-    let (res_a, (res_b, res_c)) = rayon::join(oper_a, || rayon::join(oper_b, oper_c));
+    let (res_a, (res_b, res_c)) = maybe_join!(oper_a, || maybe_join!(oper_b, oper_c));
     (res_a, res_b, res_c)
 }
@@ -5,6 +5,14 @@ use sha3::{Digest, Keccak256};
 use std::borrow::Borrow;
 
 /// Represents the current state of the protocol's Fiat-Shamir transcript.
+///
+/// Every step hashes, with Keccak256, the 32-byte running state, the round counter as a
+/// big-endian 32-byte word and the absorbed data, so that the challenges can be rederived
+/// by the Solidity verifier (see
+/// `jolt-evm-verifier/src/subprotocols/FiatShamirTranscript.sol`).
+/// Messages are right padded to 32 bytes, scalars are absorbed as 32 big-endian bytes,
+/// points as their big-endian affine coordinates `x || y` (64 zero bytes for the
+/// identity), and a challenge is the new state read as a big-endian integer mod the order.
 #[derive(Clone)]
 pub struct KeccakTranscript {
     /// Ethereum-compatible 256-bit running state
@@ -22,6 +30,13 @@ pub struct KeccakTranscript {
     expected_state_history: Option<Vec<[u8; 32]>>,
 }
 
+/// A snapshot of a transcript's Fiat-Shamir state, taken by [`Transcript::checkpoint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TranscriptState {
+    state: [u8; 32],
+    n_rounds: u32,
+}
+
 impl KeccakTranscript {
     /// Gives the hasher object with the running seed and index added
     /// To load hash you must call finalize, after appending u8 vectors
@@ -59,6 +74,18 @@ impl KeccakTranscript {
         self.update_state(rand);
     }
 
+    fn initial_state(label: &'static [u8]) -> [u8; 32] {
+        // Hash in the label
+        assert!(label.len() < 33);
+        let hasher = if label.len() == 32 {
+            Keccak256::new().chain_update(label)
+        } else {
+            let zeros = vec![0_u8; 32 - label.len()];
+            Keccak256::new().chain_update(label).chain_update(zeros)
+        };
+        hasher.finalize().into()
+    }
+
     fn update_state(&mut self, new_state: [u8; 32]) {
         self.state = new_state;
         self.n_rounds += 1;
@@ -77,26 +104,65 @@ impl KeccakTranscript {
 
 impl Transcript for KeccakTranscript {
     fn new(label: &'static [u8]) -> Self {
-        // Hash in the label
-        assert!(label.len() < 33);
-        let hasher = if label.len() == 32 {
-            Keccak256::new().chain_update(label)
-        } else {
-            let zeros = vec![0_u8; 32 - label.len()];
-            Keccak256::new().chain_update(label).chain_update(zeros)
-        };
-        let out = hasher.finalize();
+        let state = Self::initial_state(label);
+        Self {
+            state,
+            n_rounds: 0,
+            #[cfg(test)]
+            state_history: vec![state],
+            #[cfg(test)]
+            expected_state_history: None,
+        }
+    }
+
+    fn reset(&mut self, label: &'static [u8]) {
+        self.state = Self::initial_state(label);
+        self.n_rounds = 0;
+        #[cfg(test)]
+        {
+            self.state_history.clear();
+            self.state_history.push(self.state);
+            self.expected_state_history = None;
+        }
+    }
 
+    fn fork(&self, label: &'static [u8]) -> Self {
+        // Hash the running state and round with a fork marker and the label, both right
+        // padded to 32 bytes like messages
+        assert!(label.len() < 33);
+        let mut packed = b"transcript_fork".to_vec();
+        packed.resize(32, 0);
+        packed.extend_from_slice(label);
+        packed.resize(64, 0);
+        let state: [u8; 32] = self.hasher().chain_update(packed).finalize().into();
         Self {
-            state: out.into(),
+            state,
             n_rounds: 0,
             #[cfg(test)]
-            state_history: vec![out.into()],
+            state_history: vec![state],
             #[cfg(test)]
             expected_state_history: None,
         }
     }
 
+    fn checkpoint(&self) -> TranscriptState {
+        TranscriptState {
+            state: self.state,
+            n_rounds: self.n_rounds,
+        }
+    }
+
+    fn restore(&mut self, state: TranscriptState) {
+        self.state = state.state;
+        self.n_rounds = state.n_rounds;
+        #[cfg(test)]
+        {
+            // Drop the states recorded after the checkpoint
+            self.state_history.truncate(state.n_rounds as usize);
+            self.state_history.push(state.state);
+        }
+    }
+
     #[cfg(test)]
     /// Compare this transcript to `other` and panic if/when they deviate.
     /// Typically used to compare the verifier's transcript to the prover's.
@@ -134,13 +200,9 @@ impl Transcript for KeccakTranscript {
     }
 
     fn append_scalar<F: JoltField>(&mut self, scalar: &F) {
-        let mut buf = vec![];
-        scalar.serialize_uncompressed(&mut buf).unwrap();
-        // Serialize uncompressed gives the scalar in LE byte order which is not
-        // a natural representation in the EVM for scalar math so we reverse
-        // to get an EVM compatible version.
-        buf = buf.into_iter().rev().collect();
-        self.append_bytes(&buf);
+        // Scalars are absorbed big-endian, which is the natural representation
+        // in the EVM for scalar math.
+        self.append_bytes(&scalar.to_bytes_be());
     }
 
     fn append_scalars<F: JoltField>(&mut self, scalars: &[impl Borrow<F>]) {
@@ -197,6 +259,24 @@ impl Transcript for KeccakTranscript {
             .collect::<Vec<F>>()
     }
 
+    fn challenge_scalars<F: JoltField>(&mut self, label: &'static [u8], n: usize) -> Vec<F> {
+        self.append_message(label);
+        if F::NUM_BYTES > 32 {
+            return self.challenge_vector(n);
+        }
+        // Same as `challenge_scalar`, squeezing each challenge straight from the hash output
+        // into one reused buffer
+        let mut buf = [0u8; 32];
+        (0..n)
+            .map(|_| {
+                self.challenge_bytes32(&mut buf);
+                let bytes = &mut buf[..F::NUM_BYTES];
+                bytes.reverse();
+                F::from_bytes(bytes)
+            })
+            .collect()
+    }
+
     // Compute powers of scalar q : (1, q, q^2, ..., q^(len-1))
     fn challenge_scalar_powers<F: JoltField>(&mut self, len: usize) -> Vec<F> {
         let q: F = self.challenge_scalar();
@@ -210,6 +290,22 @@ impl Transcript for KeccakTranscript {
 
 pub trait Transcript: Clone + Sync + Send + 'static {
     fn new(label: &'static [u8]) -> Self;
+    /// Returns the transcript to the state `Self::new(label)` would have, reusing its
+    /// allocations, so that one transcript can serve many prove/verify cycles.
+    fn reset(&mut self, label: &'static [u8]);
+    /// Returns a transcript derived from the current state and `label`, e.g. for proving
+    /// an independent sub-claim in parallel, leaving `self` untouched. Forks of the same
+    /// state with different labels are independent of each other and of the parent.
+    ///
+    /// Nothing absorbed by a fork reaches its parent: to bind the parent to a sub-proof,
+    /// absorb the sub-proof's messages (or the fork's outputs) into the parent afterwards.
+    fn fork(&self, label: &'static [u8]) -> Self;
+    /// Snapshots the current state, e.g. to compare the prover's and verifier's transcripts
+    /// at a phase boundary, or to derive a challenge speculatively and roll back.
+    fn checkpoint(&self) -> TranscriptState;
+    /// Returns the transcript to the state it had at `checkpoint`, discarding everything
+    /// absorbed and squeezed since.
+    fn restore(&mut self, checkpoint: TranscriptState);
     #[cfg(test)]
     fn compare_to(&mut self, other: Self);
     fn append_message(&mut self, msg: &'static [u8]);
@@ -221,6 +317,9 @@ pub trait Transcript: Clone + Sync + Send + 'static {
     fn append_points<G: CurveGroup>(&mut self, points: &[G]);
     fn challenge_scalar<F: JoltField>(&mut self) -> F;
     fn challenge_vector<F: JoltField>(&mut self, len: usize) -> Vec<F>;
+    /// Absorbs `label`, then squeezes `n` independent challenges; the same as
+    /// `append_message(label)` followed by `n` calls to `challenge_scalar`.
+    fn challenge_scalars<F: JoltField>(&mut self, label: &'static [u8], n: usize) -> Vec<F>;
     // Compute powers of scalar q : (1, q, q^2, ..., q^(len-1))
     fn challenge_scalar_powers<F: JoltField>(&mut self, len: usize) -> Vec<F>;
 }
@@ -228,3 +327,163 @@ pub trait Transcript: Clone + Sync + Send + 'static {
 pub trait AppendToTranscript {
     fn append_to_transcript<ProofTranscript: Transcript>(&self, transcript: &mut ProofTranscript);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fr, G1Affine, G1Projective};
+    use ark_std::UniformRand;
+    use rand_core::SeedableRng;
+
+    fn exercise(transcript: &mut KeccakTranscript) -> Vec<Fr> {
+        let mut rng = rand_chacha::ChaCha20Rng::seed_from_u64(228);
+        transcript.append_message(b"message");
+        transcript.append_u64(42);
+        transcript.append_scalar(&Fr::from(7u64));
+        transcript.append_point(&G1Projective::rand(&mut rng));
+        let mut challenges = vec![transcript.challenge_scalar()];
+        challenges.extend(transcript.challenge_vector::<Fr>(3));
+        challenges.extend(transcript.challenge_scalar_powers::<Fr>(3));
+        challenges
+    }
+
+    #[test]
+    fn reset_matches_new() {
+        let mut transcript = KeccakTranscript::new(b"first");
+        let first = exercise(&mut transcript);
+
+        for label in [&b"first"[..], b"second", &[0xab; 32]] {
+            transcript.reset(label);
+            let fresh = KeccakTranscript::new(label);
+            assert_eq!(transcript.state, fresh.state);
+            assert_eq!(transcript.state_history, fresh.state_history);
+            assert_eq!(exercise(&mut transcript), exercise(&mut fresh.clone()));
+        }
+
+        transcript.reset(b"first");
+        assert_eq!(exercise(&mut transcript), first);
+    }
+
+    #[test]
+    fn forks_diverge_and_are_reproducible() {
+        let mut parent = KeccakTranscript::new(b"parent");
+        parent.append_u64(277);
+        let state = parent.state;
+
+        let mut left = parent.fork(b"left");
+        let mut right = parent.fork(b"right");
+        assert_eq!(parent.state, state);
+        let left_challenges = exercise(&mut left);
+        let right_challenges = exercise(&mut right);
+        assert_ne!(left_challenges, right_challenges);
+
+        // The same fork of the same state replays identically
+        assert_eq!(exercise(&mut parent.fork(b"left")), left_challenges);
+        assert_eq!(exercise(&mut parent.fork(b"right")), right_challenges);
+
+        // A fork is not the parent absorbing its label
+        let mut absorbed = parent.clone();
+        absorbed.append_message(b"left");
+        assert_ne!(exercise(&mut absorbed), left_challenges);
+        assert_ne!(exercise(&mut parent.clone()), left_challenges);
+
+        // Forks of different states differ
+        parent.append_u64(0);
+        assert_ne!(exercise(&mut parent.fork(b"left")), left_challenges);
+    }
+
+    #[test]
+    fn restore_rewinds_to_checkpoint() {
+        let mut transcript = KeccakTranscript::new(b"checkpoint");
+        transcript.append_u64(278);
+        let checkpoint = transcript.checkpoint();
+        let history_len = transcript.state_history.len();
+
+        let speculative: Fr = transcript.challenge_scalar();
+        assert_ne!(transcript.checkpoint(), checkpoint);
+        transcript.restore(checkpoint);
+        assert_eq!(transcript.checkpoint(), checkpoint);
+        assert_eq!(transcript.state_history.len(), history_len);
+
+        // The same challenge is derived again after rolling back
+        let replayed: Fr = transcript.challenge_scalar();
+        assert_eq!(replayed, speculative);
+
+        // and the transcript then continues as one that never rolled back
+        let mut reference = KeccakTranscript::new(b"checkpoint");
+        reference.append_u64(278);
+        let _: Fr = reference.challenge_scalar();
+        assert_eq!(transcript.checkpoint(), reference.checkpoint());
+        assert_eq!(exercise(&mut transcript), exercise(&mut reference));
+        assert_eq!(transcript.state_history, reference.state_history);
+    }
+
+    #[test]
+    fn challenge_scalars_matches_sequential_challenges() {
+        for n in [0, 1, 5] {
+            let mut batched = KeccakTranscript::new(b"challenges");
+            let mut sequential = batched.clone();
+            exercise(&mut batched);
+            exercise(&mut sequential);
+
+            let challenges = batched.challenge_scalars::<Fr>(b"batch", n);
+            sequential.append_message(b"batch");
+            let expected: Vec<Fr> = (0..n).map(|_| sequential.challenge_scalar()).collect();
+            assert_eq!(challenges, expected);
+            assert_eq!(batched.checkpoint(), sequential.checkpoint());
+            assert_eq!(batched.state_history, sequential.state_history);
+        }
+    }
+
+    #[test]
+    fn challenge_scalar_powers_are_powers_of_one_challenge() {
+        for len in [0, 1, 2, 7] {
+            let mut transcript = KeccakTranscript::new(b"powers");
+            let mut manual = transcript.clone();
+            let powers = transcript.challenge_scalar_powers::<Fr>(len);
+
+            let q: Fr = manual.challenge_scalar();
+            let mut expected = vec![];
+            let mut power = Fr::from(1u64);
+            for _ in 0..len {
+                expected.push(power);
+                power *= q;
+            }
+            assert_eq!(powers, expected);
+            // A single challenge is drawn, whatever the number of powers
+            assert_eq!(transcript.checkpoint(), manual.checkpoint());
+        }
+    }
+
+    #[test]
+    fn keccak_test_vector() {
+        use std::str::FromStr;
+
+        let mut transcript = KeccakTranscript::new(b"test_vector");
+        transcript.append_message(b"jolt");
+        transcript.append_u64(279);
+        transcript.append_scalar(&Fr::from(7u64));
+        transcript.append_scalars(&[Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)]);
+        transcript.append_point(&G1Affine::generator().into_group());
+        transcript.append_point(&G1Affine::zero().into_group());
+        let challenges = transcript.challenge_vector::<Fr>(3);
+
+        // Computed independently from the encoding documented on `KeccakTranscript`
+        let expected = [
+            "12520921909502310084611958181445986764951625508539129386826297292940665395847",
+            "427654901508498902767337626893163385637650285099426402122565759536964277021",
+            "3731721237460453134176073803306159791918865750836077120832661441317809484606",
+        ]
+        .map(|c| Fr::from_str(c).unwrap());
+        assert_eq!(challenges, expected);
+        assert_eq!(transcript.n_rounds, 13);
+        assert_eq!(
+            transcript.state,
+            [
+                0x08, 0x40, 0x14, 0x51, 0xaa, 0x98, 0xa8, 0x88, 0x8f, 0x15, 0x2d, 0xc8, 0x79, 0x0d,
+                0x8d, 0x92, 0xab, 0xf2, 0x5b, 0xbe, 0xa0, 0x06, 0xfd, 0xb6, 0x93, 0x12, 0xb6, 0x50,
+                0xb0, 0xcc, 0xf3, 0x3e
+            ]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::emulator::cpu::{Instruction, INSTRUCTIONS};
+use crate::emulator::cpu::{Cpu, Instruction, Xlen, INSTRUCTIONS};
 
 pub fn decode_raw(word: u32) -> Result<Instruction, ()> {
     match decode_and_get_instruction_index(word) {
@@ -7,6 +7,19 @@ pub fn decode_raw(word: u32) -> Result<Instruction, ()> {
     }
 }
 
+/// Reads the instruction at the start of `bytes`, returning its 32-bit encoding and its
+/// length in bytes. Compressed (RVC) instructions are expanded to the equivalent 32-bit
+/// instruction, so they decode like any other. Returns `None` if `bytes` is shorter than
+/// the instruction.
+pub fn next_instruction_word(bytes: &[u8], xlen: &Xlen) -> Option<(u32, usize)> {
+    let halfword = u16::from_le_bytes(bytes.get(..2)?.try_into().unwrap()) as u32;
+    if halfword & 0x3 != 0x3 {
+        return Some((Cpu::uncompress_with_xlen(halfword, xlen), 2));
+    }
+    let word = u32::from_le_bytes(bytes.get(..4)?.try_into().unwrap());
+    Some((word, 4))
+}
+
 fn decode_and_get_instruction_index(word: u32) -> Result<usize, ()> {
     for (i, inst) in INSTRUCTIONS.iter().enumerate() {
         if (word & inst.mask) == inst.data {
@@ -15,3 +28,43 @@ fn decode_and_get_instruction_index(word: u32) -> Result<usize, ()> {
     }
     Err(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_expands_to(halfword: u16, expanded: u32) {
+        let (word, len) = next_instruction_word(&halfword.to_le_bytes(), &Xlen::Bit32).unwrap();
+        assert_eq!(len, 2);
+        assert_eq!(word, expanded);
+        assert_eq!(
+            decode_raw(word).unwrap().name,
+            decode_raw(expanded).unwrap().name
+        );
+    }
+
+    #[test]
+    fn compressed_instructions() {
+        // c.addi x1, 1 => addi x1, x1, 1
+        assert_expands_to(0x0085, 0x00108093);
+        // c.addi x10, -1 => addi x10, x10, -1
+        assert_expands_to(0x157d, 0xfff50513);
+        // c.lw x9, 4(x10) => lw x9, 4(x10)
+        assert_expands_to(0x4144, 0x00452483);
+        // c.jal 8 => jal x1, 8
+        assert_expands_to(0x2021, 0x008000ef);
+    }
+
+    #[test]
+    fn uncompressed_and_truncated_instructions() {
+        // addi x1, x1, 1
+        let bytes = 0x00108093u32.to_le_bytes();
+        assert_eq!(
+            next_instruction_word(&bytes, &Xlen::Bit32),
+            Some((0x00108093, 4))
+        );
+        assert_eq!(next_instruction_word(&bytes[..2], &Xlen::Bit32), None);
+        assert_eq!(next_instruction_word(&bytes[..1], &Xlen::Bit32), None);
+        assert_eq!(next_instruction_word(&[], &Xlen::Bit32), None);
+    }
+}
@@ -334,7 +334,9 @@ impl Cpu {
         match self.decode(word).cloned() {
             Ok(inst) => {
                 // setup trace
-                let trace_inst = inst.trace.unwrap()(&inst, &self.xlen, word, instruction_address);
+                let mut trace_inst =
+                    inst.trace.unwrap()(&inst, &self.xlen, word, instruction_address);
+                trace_inst.is_compressed = (original_word & 0x3) != 0x3;
                 self.tracer.start_instruction(trace_inst);
                 self.tracer.capture_pre_state(self.x, &self.xlen);
 
@@ -887,8 +889,14 @@ impl Cpu {
         }
     }
 
-    // @TODO: Optimize
     fn uncompress(&self, halfword: u32) -> u32 {
+        Self::uncompress_with_xlen(halfword, &self.xlen)
+    }
+
+    /// Expands a 16-bit compressed (RVC) instruction into the equivalent 32-bit instruction
+    /// for `xlen`, or returns `0xffffffff` if `halfword` is not a valid compressed instruction.
+    // @TODO: Optimize
+    pub fn uncompress_with_xlen(halfword: u32, xlen: &Xlen) -> u32 {
         let op = halfword & 0x3; // [1:0]
         let funct3 = (halfword >> 13) & 0x7; // [15:13]
 
@@ -1015,7 +1023,12 @@ impl Cpu {
                         // r == 0 and imm != 0 is HINTs
                     }
                     1 => {
-                        // @TODO: Support C.JAL in 32-bit mode
+                        if let Xlen::Bit32 = xlen {
+                            // C.JAL
+                            // jal x1, offset
+                            // Same offset encoding as C.J, which differs only in funct3[2]
+                            return Self::uncompress_with_xlen(halfword | 0x8000, xlen) | (1 << 7);
+                        }
                         // C.ADDIW
                         // addiw r, r, imm
                         let r = (halfword >> 7) & 0x1f;
@@ -1829,6 +1842,7 @@ fn trace_r(inst: &Instruction, xlen: &Xlen, word: u32, address: u64) -> ELFInstr
         rs2: Some(normalize_register(f.rs2)),
         rd: Some(normalize_register(f.rd)),
         virtual_sequence_remaining: None,
+        is_compressed: false,
     }
 }
 
@@ -1842,6 +1856,7 @@ fn trace_i(inst: &Instruction, xlen: &Xlen, word: u32, address: u64) -> ELFInstr
         rs2: None,
         rd: Some(normalize_register(f.rd)),
         virtual_sequence_remaining: None,
+        is_compressed: false,
     }
 }
 
@@ -1855,6 +1870,7 @@ fn trace_s(inst: &Instruction, xlen: &Xlen, word: u32, address: u64) -> ELFInstr
         rs2: Some(normalize_register(f.rs2)),
         rd: None,
         virtual_sequence_remaining: None,
+        is_compressed: false,
     }
 }
 
@@ -1868,6 +1884,7 @@ fn trace_b(inst: &Instruction, xlen: &Xlen, word: u32, address: u64) -> ELFInstr
         rs2: Some(normalize_register(f.rs2)),
         rd: None,
         virtual_sequence_remaining: None,
+        is_compressed: false,
     }
 }
 
@@ -1881,6 +1898,7 @@ fn trace_u(inst: &Instruction, xlen: &Xlen, word: u32, address: u64) -> ELFInstr
         rs2: None,
         rd: Some(normalize_register(f.rd)),
         virtual_sequence_remaining: None,
+        is_compressed: false,
     }
 }
 
@@ -1895,6 +1913,7 @@ fn trace_j(inst: &Instruction, xlen: &Xlen, word: u32, address: u64) -> ELFInstr
         rs2: None,
         rd: Some(normalize_register(f.rd)),
         virtual_sequence_remaining: None,
+        is_compressed: false,
     }
 }
 
@@ -1948,7 +1967,7 @@ pub const INSTRUCTIONS: [Instruction; INSTRUCTION_NUM] = [
             Ok(())
         },
         disassemble: dump_format_r,
-        trace: None,
+        trace: Some(trace_r),
     },
     Instruction {
         mask: 0xf800707f,
@@ -3178,7 +3197,7 @@ pub const INSTRUCTIONS: [Instruction; INSTRUCTION_NUM] = [
             Ok(())
         },
         disassemble: dump_format_r,
-        trace: None,
+        trace: Some(trace_r),
     },
     Instruction {
         mask: 0xffffffff,
@@ -3687,7 +3706,7 @@ pub const INSTRUCTIONS: [Instruction; INSTRUCTION_NUM] = [
             Ok(())
         },
         disassemble: dump_format_r,
-        trace: None,
+        trace: Some(trace_r),
     },
     Instruction {
         mask: 0x0000707f,
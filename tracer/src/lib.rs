@@ -16,11 +16,13 @@ mod decode;
 mod emulator;
 mod trace;
 
+#[cfg(feature = "register-snapshots")]
+use common::rv_trace::RegisterFile;
 pub use common::rv_trace::{
     ELFInstruction, JoltDevice, MemoryState, RVTraceRow, RegisterState, RV32IM,
 };
 
-use crate::decode::decode_raw;
+use crate::decode::{decode_raw, next_instruction_word};
 
 #[tracing::instrument(skip_all)]
 pub fn trace(
@@ -29,6 +31,42 @@ pub fn trace(
     input_size: u64,
     output_size: u64,
 ) -> (Vec<RVTraceRow>, JoltDevice) {
+    let mut emulator = run(elf, inputs, input_size, output_size);
+
+    let mut rows = emulator.get_mut_cpu().tracer.rows.try_borrow_mut().unwrap();
+    let mut output = Vec::new();
+    output.append(&mut rows);
+    drop(rows);
+
+    let device = emulator.get_mut_cpu().get_mut_mmu().jolt_device.clone();
+
+    (output, device)
+}
+
+/// Same as [`trace`], but also returns a snapshot of the (non-virtual) register file
+/// after each row, e.g. to diff against a reference emulator with
+/// [`common::rv_trace::first_register_divergence`].
+#[cfg(feature = "register-snapshots")]
+#[tracing::instrument(skip_all)]
+pub fn trace_with_register_snapshots(
+    elf: &PathBuf,
+    inputs: &[u8],
+    input_size: u64,
+    output_size: u64,
+) -> (Vec<RVTraceRow>, Vec<RegisterFile>, JoltDevice) {
+    let mut emulator = run(elf, inputs, input_size, output_size);
+
+    let cpu = emulator.get_mut_cpu();
+    let rows = std::mem::take(&mut *cpu.tracer.rows.try_borrow_mut().unwrap());
+    let snapshots = std::mem::take(&mut *cpu.tracer.register_snapshots.try_borrow_mut().unwrap());
+    assert_eq!(rows.len(), snapshots.len());
+
+    let device = emulator.get_mut_cpu().get_mut_mmu().jolt_device.clone();
+
+    (rows, snapshots, device)
+}
+
+fn run(elf: &PathBuf, inputs: &[u8], input_size: u64, output_size: u64) -> Emulator {
     let term = DefaultTerminal::new();
     let mut emulator = Emulator::new(Box::new(term));
     emulator.update_xlen(get_xlen());
@@ -59,14 +97,7 @@ pub fn trace(
         prev_pc = pc;
     }
 
-    let mut rows = emulator.get_mut_cpu().tracer.rows.try_borrow_mut().unwrap();
-    let mut output = Vec::new();
-    output.append(&mut rows);
-    drop(rows);
-
-    let device = emulator.get_mut_cpu().get_mut_mmu().jolt_device.clone();
-
-    (output, device)
+    emulator
 }
 
 #[tracing::instrument(skip_all)]
@@ -85,13 +116,18 @@ pub fn decode(elf: &[u8]) -> (Vec<ELFInstruction>, Vec<(u64, u8)>) {
         let raw_data = section.data().unwrap();
 
         if let SectionKind::Text = section.kind() {
-            for (chunk, word) in raw_data.chunks(4).enumerate() {
-                let word = u32::from_le_bytes(word.try_into().unwrap());
-                let address = chunk as u64 * 4 + section.address();
+            let mut offset = 0;
+            while offset < raw_data.len() {
+                let address = section.address() + offset as u64;
+                // Trailing bytes too short to hold an instruction are left as UNIMPL
+                let (word, len) = next_instruction_word(&raw_data[offset..], &get_xlen())
+                    .unwrap_or((0, raw_data.len() - offset));
+                offset += len;
 
                 if let Ok(inst) = decode_raw(word) {
                     if let Some(trace) = inst.trace {
-                        let inst = trace(&inst, &get_xlen(), word, address);
+                        let mut inst = trace(&inst, &get_xlen(), word, address);
+                        inst.is_compressed = len == 2;
                         instructions.push(inst);
                         continue;
                     }
@@ -105,6 +141,7 @@ pub fn decode(elf: &[u8]) -> (Vec<ELFInstruction>, Vec<(u64, u8)>) {
                     rd: None,
                     imm: None,
                     virtual_sequence_remaining: None,
+                    is_compressed: false,
                 });
             }
         }
@@ -1,11 +1,16 @@
 use std::cell::RefCell;
 
+#[cfg(feature = "register-snapshots")]
+use common::rv_trace::RegisterFile;
 use common::rv_trace::{ELFInstruction, MemoryState, RVTraceRow, RegisterState};
 
 use crate::emulator::cpu::Xlen;
 
 pub struct Tracer {
     pub rows: RefCell<Vec<RVTraceRow>>,
+    /// Register file after each row in `rows`
+    #[cfg(feature = "register-snapshots")]
+    pub register_snapshots: RefCell<Vec<RegisterFile>>,
     open: RefCell<bool>,
 }
 
@@ -13,6 +18,8 @@ impl Tracer {
     pub fn new() -> Self {
         Self {
             rows: RefCell::new(Vec::new()),
+            #[cfg(feature = "register-snapshots")]
+            register_snapshots: RefCell::new(Vec::new()),
             open: RefCell::new(false),
         }
     }
@@ -59,6 +66,18 @@ impl Tracer {
         if let Some(rd) = row.instruction.rd {
             row.register_state.rd_post_val = Some(normalize_register_value(reg[rd as usize], xlen));
         }
+
+        #[cfg(feature = "register-snapshots")]
+        {
+            let mut snapshot: RegisterFile = [0; common::constants::REGISTER_COUNT as usize];
+            for (value, reg) in snapshot.iter_mut().zip(reg.iter()) {
+                *value = normalize_register_value(*reg, xlen);
+            }
+            self.register_snapshots
+                .try_borrow_mut()
+                .unwrap()
+                .push(snapshot);
+        }
     }
 
     pub fn push_memory(&self, memory_state: MemoryState) {